@@ -5,9 +5,11 @@ use std::f32::consts::FRAC_PI_2;
 use bevy::prelude::*;
 use hana_conduit::AttachedTo;
 use hana_conduit::Cable;
+use hana_conduit::CableConnector;
 use hana_conduit::CableEnd;
 use hana_conduit::CableEndpoint;
 use hana_conduit::CableMeshConfig;
+use hana_conduit::CablePort;
 use hana_conduit::CapStyle;
 use hana_conduit::EndpointAlignment;
 use hana_conduit::Solver;
@@ -19,6 +21,7 @@ use super::constants::CONNECTOR_LANE_Z;
 use super::constants::CONNECTOR_MODEL_PATH;
 use super::constants::CONNECTOR_MODEL_SCALE;
 use super::constants::CONNECTOR_SECTION_INDEX;
+use super::constants::CONNECTOR_SOCKET_KIND;
 use super::constants::DEFAULT_CABLE_RESOLUTION;
 use super::constants::NODE_Y;
 use super::constants::SECTION_X;
@@ -41,6 +44,10 @@ use super::input;
 /// sits at (0, 0, 0), then re-export the GLB. The connector's local +Y axis (Blender +Z)
 /// should point along the cable-exit direction so the alignment system can orient it
 /// to match the cable tangent.
+///
+/// The plug model itself is `Draggable`, and the end follows it through `AttachedTo`.
+/// The start end is a `CableConnector` plugged into a `CablePort` socket, so it can be
+/// dragged by its cable into another lane's empty socket.
 pub(crate) fn setup_section_connector(
     commands: &mut Commands,
     cable_material: &Handle<StandardMaterial>,
@@ -130,8 +137,19 @@ pub(crate) fn setup_section_connector(
             .observe(input::on_drag_start)
             .id();
 
+        let socket = commands
+            .spawn((
+                CablePort::new(CONNECTOR_SOCKET_KIND).with_lead_length(0.0),
+                Transform::from_translation(start),
+            ))
+            .id();
+
         commands.entity(cable).with_children(|parent| {
-            parent.spawn(CableEndpoint::new(CableEnd::Start, start));
+            parent.spawn((
+                CableEndpoint::new(CableEnd::Start, Vec3::ZERO),
+                CableConnector::new(CONNECTOR_SOCKET_KIND),
+                AttachedTo(socket),
+            ));
             parent.spawn((
                 CableEndpoint::new(CableEnd::End, Vec3::ZERO)
                     .with_cap(CapStyle::None)
@@ -2,6 +2,8 @@
 
 use bevy::prelude::*;
 use fairy_dust::Face;
+use hana_conduit::CableConnectionSettings;
+use hana_conduit::ConnectorKind;
 use hana_diegetic::Pt;
 
 // animation
@@ -18,6 +20,12 @@ pub(crate) const LIGHT_TRAVEL_SEGMENT_DURATION_SECONDS: f32 = 2.0;
 pub(crate) const EXAMPLE_TITLE: &str = "Cable Playground";
 
 // cable
+/// Grab and snap distance for dragging cable ends, wider than a detach demo
+/// sphere so an end plugged into one can be grabbed by its cable.
+pub(crate) const CABLE_CONNECTION_SETTINGS: CableConnectionSettings = CableConnectionSettings {
+    grab_radius: 0.6,
+    snap_radius: 0.6,
+};
 pub(crate) const DEFAULT_CABLE_RESOLUTION: u32 = 0;
 pub(crate) const MIN_TAUT_CABLE_SLACK: f32 = 1.0;
 pub(crate) const SLACK_NORMAL: f32 = 1.15;
@@ -99,8 +107,12 @@ pub(crate) const CONNECTOR_LANE_ROTATING_INDEX: usize = 2;
 pub(crate) const CONNECTOR_LANE_Z: [f32; 3] = [1.5, 0.0, -1.5];
 pub(crate) const CONNECTOR_MODEL_PATH: &str = "models/power_plug.glb#Scene0";
 pub(crate) const CONNECTOR_MODEL_SCALE: f32 = 15.0;
+/// Plug kind of the start ends and the sockets they sit in.
+pub(crate) const CONNECTOR_SOCKET_KIND: ConnectorKind = ConnectorKind::new("connector_socket");
 
 // detach demo
+/// Plug kind shared by the spheres and the cable ends plugged into them.
+pub(crate) const DETACH_DEMO_CONNECTOR_KIND: ConnectorKind = ConnectorKind::new("detach_demo");
 pub(crate) const DETACH_DEMO_ENDPOINT_X_OFFSET: f32 = 2.0;
 /// Per-row sphere caption text, indexed by row (freeze, slack-bump, despawn).
 pub(crate) const DETACH_DEMO_LABELS: [&str; 3] = [
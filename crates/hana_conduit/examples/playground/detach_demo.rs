@@ -1,12 +1,18 @@
 //! Section 6: detach demo — cables respond to endpoint detach events.
+//!
+//! Clicking a sphere despawns it, which applies its end's `DetachPolicy`. Each
+//! sphere is also a `CablePort`: dragging an end out of it unplugs the end
+//! without applying the policy, and dropping it on an empty sphere plugs it in.
 
 use bevy::picking::Pickable;
 use bevy::prelude::*;
 use hana_conduit::AttachedTo;
 use hana_conduit::Cable;
+use hana_conduit::CableConnector;
 use hana_conduit::CableEnd;
 use hana_conduit::CableEndpoint;
 use hana_conduit::CableMeshConfig;
+use hana_conduit::CablePort;
 use hana_conduit::CatenarySolver;
 use hana_conduit::DetachPolicy;
 use hana_conduit::Solver;
@@ -18,6 +24,7 @@ use super::constants::DEFAULT_CABLE_RESOLUTION;
 use super::constants::DESPAWN_GREEN;
 use super::constants::DESPAWN_RED;
 use super::constants::DETACH_BUMP_BLUE;
+use super::constants::DETACH_DEMO_CONNECTOR_KIND;
 use super::constants::DETACH_DEMO_ENDPOINT_X_OFFSET;
 use super::constants::DETACH_DEMO_LABEL_COLORS;
 use super::constants::DETACH_DEMO_LABEL_SIDE_GAP;
@@ -142,6 +149,7 @@ fn spawn_detach_demo_row(
             Mesh3d(detach_demo_assets.sphere_mesh.clone()),
             MeshMaterial3d(sphere_material),
             Transform::from_translation(sphere_position),
+            CablePort::new(DETACH_DEMO_CONNECTOR_KIND).with_lead_length(0.0),
             Despawnable,
             DetachDemoEntity,
         ))
@@ -199,6 +207,7 @@ fn spawn_detach_demo_row(
             parent.spawn((
                 CableEndpoint::new(CableEnd::Start, Vec3::ZERO)
                     .with_detach_policy(row.detach_policy),
+                CableConnector::new(DETACH_DEMO_CONNECTOR_KIND),
                 AttachedTo(sphere),
             ));
             parent.spawn(CableEndpoint::new(CableEnd::End, anchor_position));
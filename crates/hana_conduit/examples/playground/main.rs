@@ -19,6 +19,8 @@
 //! - Zoom: Scroll wheel (or pinch)
 //! - +/-: Adjust catenary slack
 //! - R: Reset detach demo (Detach Policy section)
+//! - Drag a cable by its socketed end: move the end between sockets (Detach Policy and Connector
+//!   sections)
 //! - Esc: Pause tube light animation
 //! - Ctrl+Shift+R: Hot-restart
 
@@ -36,6 +38,7 @@ mod ui;
 
 use animation::LightAnimation;
 use bevy::prelude::*;
+use constants::CABLE_CONNECTION_SETTINGS;
 use constants::CATENARY_SECTION_INDEX;
 use constants::EXAMPLE_TITLE;
 use constants::GROUND_DEPTH;
@@ -103,6 +106,7 @@ fn main() {
         .wire_chip_to_events::<SlackMinusPulseBegin, SlackMinusPulseEnd>(SLACK_MINUS_SEGMENT_ID)
         .with_camera_control_panel()
         .add_plugins(CatenaryPlugin)
+        .insert_resource(CABLE_CONNECTION_SETTINGS)
         .init_resource::<input::DragState>()
         .init_resource::<input::SlackPulse>()
         .init_resource::<labels::RResetFlash>()
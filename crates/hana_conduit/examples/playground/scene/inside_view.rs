@@ -53,8 +53,9 @@ pub(super) fn setup_section_inside_view(
             CableMeshConfig {
                 tube_config: TubeConfig {
                     radius: TUBE_RADIUS * INSIDE_VIEW_RADIUS_MULTIPLIER,
-                    sides:  INSIDE_VIEW_TUBE_SIDES,
-                    faces:  Faces::Both,
+                    sides: INSIDE_VIEW_TUBE_SIDES,
+                    faces: Faces::Both,
+                    ..default()
                 },
                 material: Some(cable_material.clone()),
                 ..default()
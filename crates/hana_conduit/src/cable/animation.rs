@@ -3,26 +3,26 @@
 //! When a cable with `RouteAnimation` re-solves while its endpoints are at
 //! rest (an obstacle moved through the route), `animate_routes` blends the
 //! displayed route from the old route to the new [`SolvedRoute`] over a fixed
-//! duration instead of jumping, pushing in-flight points out of obstacle
-//! boxes so the cable sweeps around them. A solve whose anchors moved (the
+//! duration instead of jumping, pushing in-flight points out of obstacles so
+//! the cable sweeps around them. A solve whose anchors moved (the
 //! endpoint is being dragged) shows immediately: blending a moving endpoint
 //! reads as lag and corrupts the end tangents the plugs align to. Lead
 //! segments never blend: they are kept verbatim from the solved route so lead
 //! direction and jack alignment stay exact throughout a transition.
 
-use bevy::camera::primitives::Aabb;
 use bevy::prelude::*;
 use bevy_kana::ToF32;
 use bevy_kana::ToUsize;
+use serde::Deserialize;
+use serde::Serialize;
 
 use super::Cable;
-use super::RouteObstacle;
 use super::compute::ComputedCableGeometry;
 use super::constants::DEFAULT_ROUTE_ANIMATION_SECONDS;
 use super::constants::ROUTE_ANIMATION_LEAD_MATCH_DISTANCE;
 use super::constants::ROUTE_ANIMATION_OBSTACLE_CLEARANCE;
 use super::constants::ROUTE_ANIMATION_SNAP_DISTANCE;
-use super::route_obstacle;
+use super::route_obstacle::WorldObstacles;
 use crate::routing;
 use crate::routing::Anchor;
 use crate::routing::CableGeometry;
@@ -38,10 +38,10 @@ use crate::routing::Obstacle;
 /// the first solve and any solve whose anchors moved (the endpoint is being
 /// dragged) show immediately, so the cable always tracks the pointer exactly.
 /// A transition runs over [`RouteAnimation::seconds`] with an ease-out curve,
-/// pushing in-flight points out of obstacle boxes so it sweeps around
+/// pushing in-flight points out of obstacles so it sweeps around
 /// obstacles rather than through them, and always lands on the solver's
 /// geometry — sharp route bends included — when the duration elapses.
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(Component)]
 pub struct RouteAnimation {
     /// Seconds a transition takes to land on the newly solved route.
@@ -113,7 +113,7 @@ enum Blend {
         /// frame's span so obstacle push-out never feeds back into the blend
         /// and accumulates as jitter.
         blended:   Vec<Vec3>,
-        /// `blended` with interior samples pushed out of obstacle boxes — the
+        /// `blended` with interior samples pushed out of obstacles — the
         /// span polyline actually shown this frame.
         displayed: Vec<Vec3>,
     },
@@ -140,15 +140,9 @@ pub(super) fn animate_routes(
         &SolvedRoute,
         Option<&mut DisplayedRoute>,
     )>,
-    route_obstacles: Query<(Entity, &RouteObstacle, &GlobalTransform)>,
-    children: Query<&Children>,
-    aabbs: Query<&Aabb>,
-    transforms: Query<&GlobalTransform>,
+    mut world_obstacles: ResMut<WorldObstacles>,
     mut commands: Commands,
 ) {
-    // Resolved only when some cable is mid-transition this frame.
-    let mut world_obstacles: Option<Vec<Obstacle>> = None;
-
     for (cable_entity, cable, route_animation, solved_route, displayed_route) in &mut cables {
         let route_parts = split_route(solved_route);
 
@@ -205,21 +199,13 @@ pub(super) fn animate_routes(
             continue;
         }
 
-        let world = world_obstacles.get_or_insert_with(|| {
-            route_obstacle::resolve_obstacles(&route_obstacles, &children, &aabbs, &transforms)
-        });
-        let obstacles: Vec<Obstacle> = cable
-            .obstacles
-            .iter()
-            .copied()
-            .chain(world.iter().copied())
-            .collect();
+        let obstacle_index = world_obstacles.index_for(cable_entity, cable);
 
         match blend_span(
             &displayed_route.source,
             &displayed_route.target,
             ease_out_cubic(progress),
-            &obstacles,
+            obstacle_index.obstacles(),
         ) {
             Blend::Converged => {
                 let route = &mut *displayed_route;
@@ -297,7 +283,7 @@ fn is_lead(segment: &CableSegment, from: Vec3, to: Vec3) -> bool {
 
 /// One blend step: resample both span polylines to a common count, move each
 /// sample `ease` of the way from source to goal, and pin the endpoints. The
-/// displayed copy additionally pushes interior samples out of obstacle boxes.
+/// displayed copy additionally pushes interior samples out of obstacles.
 fn blend_span(source: &[Vec3], target: &[Vec3], ease: f32, obstacles: &[Obstacle]) -> Blend {
     let sample_count = target.len().max(MIN_CABLE_SAMPLE_POINTS.to_usize());
     let target_samples = resample_polyline(target, sample_count);
@@ -367,7 +353,7 @@ fn in_flight_geometry(
         segments.push(lead.clone());
         waypoints.push(solved_route.end.position);
     }
-    CableGeometry::from_segments(segments, waypoints)
+    CableGeometry::from_segments(segments, waypoints).with_outcome(solved_route.geometry.outcome)
 }
 
 /// A [`CableGeometry`]'s sample points as one polyline, with the duplicate
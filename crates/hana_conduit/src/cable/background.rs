@@ -0,0 +1,17 @@
+//! [`BackgroundSolve`]: opts a cable into solving its route off the main
+//! thread.
+
+use bevy::prelude::*;
+
+/// Solves this cable's route on Bevy's `AsyncComputeTaskPool` instead of
+/// inside `recompute_dirty_cables`, applying the result on the first frame
+/// it is ready.
+///
+/// Suited to cables whose grid pathfinding is expensive enough to stall a
+/// frame. Until the solve lands the cable keeps its previous geometry; a
+/// cable re-queued before then drops the stale solve and starts a fresh one.
+/// Cables in the shared [`CableAvoidance`](super::CableAvoidance) layout and
+/// simulated cables always solve inline.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct BackgroundSolve;
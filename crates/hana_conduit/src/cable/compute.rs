@@ -1,6 +1,13 @@
+use std::sync::Arc;
+
 use bevy::camera::primitives::Aabb;
+use bevy::ecs::entity::EntityHashMap;
 use bevy::ecs::entity::EntityHashSet;
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
+use bevy::tasks::AsyncComputeTaskPool;
+use bevy::tasks::Task;
+use bevy::tasks::futures::check_ready;
 
 use super::AttachedEndpoints;
 use super::AttachedTo;
@@ -12,33 +19,63 @@ use super::RouteObstacle;
 use super::animation;
 use super::animation::RouteAnimation;
 use super::animation::SolvedRoute;
-use super::route_obstacle;
+use super::background::BackgroundSolve;
+use super::constants::CABLE_CORRIDOR_MARGIN;
+use super::layout::CableAvoidance;
+use super::layout::CableBundle;
+use super::outcome::CableRouteOutcome;
+use super::outcome::CableRouteOutcomeChanged;
+use super::route_obstacle::ObstacleSources;
+use super::route_obstacle::WorldObstacles;
+use super::simulation;
+use super::simulation::SimulationTarget;
+use super::support::CableSupport;
+use super::tension;
+use super::tension::CableOverStretched;
+use super::tension::CableTension;
+use super::tension::OverStretchPolicy;
+use super::tension::TensionState;
+use super::waypoint::CableWaypoint;
+use crate::mesh::CableMeshConfig;
 use crate::routing::Anchor;
 use crate::routing::AnchorExit;
 use crate::routing::CableGeometry;
+use crate::routing::LayoutCable;
 use crate::routing::MIN_SEGMENT_LENGTH;
 use crate::routing::Obstacle;
+use crate::routing::ObstacleIndex;
 use crate::routing::RouteRequest;
+use crate::routing::Solver;
+use crate::routing::SupportSurface;
+use crate::routing::ViaPoint;
+use crate::routing::layout_cables;
 
 pub(super) struct ComputePlugin;
 
 impl Plugin for ComputePlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<DirtyCables>().add_systems(
-            Update,
-            (
+        app.init_resource::<DirtyCables>()
+            .init_resource::<ObstacleBounds>()
+            .init_resource::<WorldObstacles>()
+            .add_systems(
+                Update,
                 (
-                    queue_changed_cables,
-                    queue_endpoint_changes,
-                    queue_attached_target_moves,
-                    queue_obstacle_changes,
-                ),
-                recompute_dirty_cables,
-                animation::animate_routes,
-            )
-                .chain()
-                .in_set(CableSystems::Compute),
-        );
+                    (
+                        queue_changed_cables,
+                        queue_endpoint_changes,
+                        queue_waypoint_changes,
+                        queue_attached_target_moves,
+                        queue_obstacle_changes,
+                        queue_layout_changes,
+                    ),
+                    recompute_dirty_cables,
+                    apply_background_routes,
+                    animation::animate_routes,
+                    simulation::simulate_cables,
+                )
+                    .chain()
+                    .in_set(CableSystems::Compute),
+            );
     }
 }
 
@@ -55,6 +92,17 @@ pub(crate) enum CableSystems {
 #[derive(Resource, Default, Deref, DerefMut)]
 pub(super) struct DirtyCables(pub(super) EntityHashSet);
 
+/// World-space `(min, max)` boxes each [`RouteObstacle`] entity resolved to
+/// when `queue_obstacle_changes` last saw it, so a moved obstacle re-solves
+/// the cables near where it was as well as where it is.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ObstacleBounds(EntityHashMap<Vec<(Vec3, Vec3)>>);
+
+/// A [`BackgroundSolve`] cable's route solving on the
+/// `AsyncComputeTaskPool`. Replacing or removing it cancels the solve.
+#[derive(Component)]
+struct PendingRoute(Task<(ResolvedRoute, CableGeometry)>);
+
 /// Last world-space position resolved for an endpoint while it was attached.
 #[derive(Component, Clone, Copy)]
 pub(super) struct ResolvedEndpointPosition(pub(super) Vec3);
@@ -68,12 +116,20 @@ pub struct ComputedCableGeometry {
     pub cable_geometry: Option<CableGeometry>,
 }
 
-/// Queues cables whose own `Cable` component was inserted or mutated.
+/// Queues cables whose own `Cable` component was inserted or mutated, and
+/// drops the obstacle index [`WorldObstacles`] holds for them or for a
+/// removed cable.
 fn queue_changed_cables(
     cables: Query<Entity, Changed<Cable>>,
+    mut removed: RemovedComponents<Cable>,
+    mut world_obstacles: ResMut<WorldObstacles>,
     mut dirty_cables: ResMut<DirtyCables>,
 ) {
+    for cable_entity in removed.read() {
+        world_obstacles.forget_cable(cable_entity);
+    }
     for cable_entity in &cables {
+        world_obstacles.forget_cable(cable_entity);
         dirty_cables.insert(cable_entity);
     }
 }
@@ -89,6 +145,27 @@ fn queue_endpoint_changes(
     }
 }
 
+/// Queues the parent cable of any [`CableWaypoint`] that was inserted,
+/// mutated, or removed, and every cable whose children changed (a despawned
+/// waypoint leaves no parent to look up).
+fn queue_waypoint_changes(
+    waypoints: Query<&ChildOf, Changed<CableWaypoint>>,
+    mut removed: RemovedComponents<CableWaypoint>,
+    parents: Query<&ChildOf>,
+    reparented: Query<Entity, (With<Cable>, Changed<Children>)>,
+    mut dirty_cables: ResMut<DirtyCables>,
+) {
+    for child_of in &waypoints {
+        dirty_cables.insert(child_of.parent());
+    }
+    for waypoint in removed.read() {
+        if let Ok(child_of) = parents.get(waypoint) {
+            dirty_cables.insert(child_of.parent());
+        }
+    }
+    dirty_cables.extend(&reparented);
+}
+
 /// Queues cables whose attached targets had their world transform change.
 fn queue_attached_target_moves(
     targets: Query<&AttachedEndpoints, Changed<GlobalTransform>>,
@@ -105,79 +182,411 @@ fn queue_attached_target_moves(
     }
 }
 
-/// Queues every cable when any [`RouteObstacle`] entity moved, changed, or was
-/// removed, so routes re-solve against the obstacle's current bounds.
+/// Rebuilds [`WorldObstacles`] when a [`RouteObstacle`] or [`CableSupport`]
+/// moved, changed, or was removed, and queues the cables the change could
+/// reroute: those whose corridor — the bounding box of their current
+/// geometry, padded by `CABLE_CORRIDOR_MARGIN` — overlaps the obstacle's
+/// previous or new bounds. A render [`Aabb`] or mesh changing or moving
+/// anywhere in an obstacle's tree, or a mesh it draws finishing its load,
+/// counts as a change to that obstacle, since
+/// [`RouteObstacle::FromRenderAabb`] and [`RouteObstacle::FromMesh`] resolve
+/// to nothing until those exist. Cables without geometry yet, or with a background
+/// solve in flight, are always queued. Any [`CableSupport`] change queues
+/// every cable, since a sagging cable can come to rest on a surface outside
+/// its current corridor.
 fn queue_obstacle_changes(
-    changed: Query<
-        (),
+    changed_obstacles: Query<
+        Entity,
         (
             With<RouteObstacle>,
             Or<(Changed<GlobalTransform>, Changed<RouteObstacle>)>,
         ),
     >,
+    changed_supports: Query<
+        (),
+        (
+            With<CableSupport>,
+            Or<(Changed<GlobalTransform>, Changed<CableSupport>)>,
+        ),
+    >,
+    changed_obstacle_parts: Query<
+        Entity,
+        (
+            Or<(With<Aabb>, With<Mesh3d>)>,
+            Or<(Changed<Aabb>, Changed<Mesh3d>, Changed<GlobalTransform>)>,
+        ),
+    >,
+    mut mesh_events: MessageReader<AssetEvent<Mesh>>,
     mut removed: RemovedComponents<RouteObstacle>,
-    cables: Query<Entity, With<Cable>>,
+    mut removed_supports: RemovedComponents<CableSupport>,
+    obstacle_sources: ObstacleSources,
+    mut obstacle_bounds: ResMut<ObstacleBounds>,
+    mut world_obstacles: ResMut<WorldObstacles>,
+    cables: Query<(Entity, &ComputedCableGeometry, Has<PendingRoute>), With<Cable>>,
     mut dirty_cables: ResMut<DirtyCables>,
 ) {
-    if changed.is_empty() && removed.is_empty() {
+    let mut regions: Vec<(Vec3, Vec3)> = removed
+        .read()
+        .filter_map(|obstacle_entity| obstacle_bounds.remove(&obstacle_entity))
+        .flatten()
+        .collect();
+    let loaded_meshes: HashSet<AssetId<Mesh>> = mesh_events
+        .read()
+        .filter_map(|event| match *event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(id),
+            AssetEvent::Removed { .. } | AssetEvent::Unused { .. } => None,
+        })
+        .collect();
+    let mut changed: EntityHashSet = changed_obstacles.iter().collect();
+    for part in &changed_obstacle_parts {
+        changed.extend(obstacle_sources.obstacles_containing(part));
+    }
+    if !loaded_meshes.is_empty() {
+        changed.extend(obstacle_sources.obstacles_using(&loaded_meshes));
+    }
+    for obstacle_entity in changed {
+        let bounds: Vec<(Vec3, Vec3)> = obstacle_sources
+            .resolve_entity(obstacle_entity)
+            .iter()
+            .map(Obstacle::bounds)
+            .collect();
+        regions.extend(&bounds);
+        if let Some(previous) = obstacle_bounds.insert(obstacle_entity, bounds) {
+            regions.extend(previous);
+        }
+    }
+    let supports_changed = !changed_supports.is_empty() || !removed_supports.is_empty();
+    removed_supports.clear();
+    if !supports_changed && regions.is_empty() {
         return;
     }
-    removed.clear();
-    for cable_entity in &cables {
-        dirty_cables.insert(cable_entity);
+    world_obstacles.rebuild(&obstacle_sources);
+
+    for (cable_entity, computed, pending) in &cables {
+        let touched = supports_changed
+            || pending
+            || computed
+                .cable_geometry
+                .as_ref()
+                .and_then(corridor)
+                .is_none_or(|(min, max)| {
+                    regions
+                        .iter()
+                        .any(|&(low, high)| low.cmple(max).all() && high.cmpge(min).all())
+                });
+        if touched {
+            dirty_cables.insert(cable_entity);
+        }
     }
 }
 
-/// Drains [`DirtyCables`] and recomputes geometry for each queued cable.
+/// The bounding box of `cable_geometry`'s points, padded by
+/// `CABLE_CORRIDOR_MARGIN`, or `None` for empty geometry.
+fn corridor(cable_geometry: &CableGeometry) -> Option<(Vec3, Vec3)> {
+    let margin = Vec3::splat(CABLE_CORRIDOR_MARGIN);
+    cable_geometry
+        .all_points()
+        .fold(None, |bounds: Option<(Vec3, Vec3)>, &point| {
+            Some(bounds.map_or((point, point), |(min, max)| {
+                (min.min(point), max.max(point))
+            }))
+        })
+        .map(|(min, max)| (min - margin, max + margin))
+}
+
+/// Queues every [`CableAvoidance`] cable when a cable joined or left the
+/// layout, changed bundle, or changed tube radius, so the shared layout is
+/// redone. A cable that left is queued too, to route it on its own again.
+fn queue_layout_changes(
+    changed: Query<
+        (),
+        (
+            With<CableAvoidance>,
+            Or<(
+                Changed<CableAvoidance>,
+                Changed<CableBundle>,
+                Changed<CableMeshConfig>,
+            )>,
+        ),
+    >,
+    mut removed_avoidance: RemovedComponents<CableAvoidance>,
+    mut removed_bundles: RemovedComponents<CableBundle>,
+    participants: Query<Entity, With<CableAvoidance>>,
+    mut dirty_cables: ResMut<DirtyCables>,
+) {
+    let left: Vec<Entity> = removed_avoidance
+        .read()
+        .chain(removed_bundles.read())
+        .collect();
+    if changed.is_empty() && left.is_empty() {
+        return;
+    }
+    dirty_cables.extend(left);
+    dirty_cables.extend(&participants);
+}
+
+/// Query type for the cable-side components `recompute_cable_route` reads.
+type CableRouteQuery<'w> = (
+    &'w Cable,
+    &'w Children,
+    Has<RouteAnimation>,
+    Has<BackgroundSolve>,
+    Option<&'w CableTension>,
+    Option<&'w OverStretchPolicy>,
+    Option<&'w CableRouteOutcome>,
+);
+
+/// Query type for the endpoint-side components `recompute_cable_route` reads
+/// and updates.
+type EndpointRouteQuery<'w> = (
+    &'w mut CableEndpoint,
+    Option<&'w AttachedTo>,
+    Option<&'w mut ResolvedEndpointPosition>,
+);
+
+/// One endpoint as resolved by a recompute.
+#[derive(Clone, Copy)]
+pub(super) struct ResolvedEnd {
+    pub(super) entity:   Entity,
+    pub(super) anchor:   Anchor,
+    /// Whether the endpoint follows an [`AttachedTo`] target.
+    pub(super) attached: bool,
+    /// Whether the endpoint's world position changed since the last
+    /// recompute.
+    pub(super) moved:    bool,
+}
+
+/// Query type for the cables taking part in the shared layout.
+type LayoutQuery<'w> = (Entity, &'w CableMeshConfig, Option<&'w CableBundle>);
+
+/// Drains [`DirtyCables`] and recomputes geometry for each queued cable. When
+/// any queued cable takes part in the shared layout, every participant is
+/// laid out again together. Every cable routes against the shared
+/// [`WorldObstacles`] index; a [`BackgroundSolve`] cable's solve is handed to
+/// the `AsyncComputeTaskPool` instead.
 fn recompute_dirty_cables(
     mut commands: Commands,
     mut dirty_cables: ResMut<DirtyCables>,
-    cables: Query<(&Cable, &Children, Has<RouteAnimation>)>,
-    mut endpoints: Query<(
-        &CableEndpoint,
-        Option<&AttachedTo>,
-        Option<&mut ResolvedEndpointPosition>,
-    )>,
+    cables: Query<CableRouteQuery>,
+    mut endpoints: Query<EndpointRouteQuery>,
     transforms: Query<&GlobalTransform>,
-    route_obstacles: Query<(Entity, &RouteObstacle, &GlobalTransform)>,
-    children: Query<&Children>,
-    aabbs: Query<&Aabb>,
+    waypoints: Query<&CableWaypoint>,
+    participants: Query<LayoutQuery, With<CableAvoidance>>,
+    mut world_obstacles: ResMut<WorldObstacles>,
 ) {
     if dirty_cables.is_empty() {
         return;
     }
-    let world_obstacles =
-        route_obstacle::resolve_obstacles(&route_obstacles, &children, &aabbs, &transforms);
+    let in_layout = |cable_entity: Entity| {
+        participants.contains(cable_entity)
+            && cables
+                .get(cable_entity)
+                .is_ok_and(|(cable, ..)| !matches!(cable.solver, Solver::Simulated(_)))
+    };
+
+    let mut layout_dirty = false;
     for cable_entity in dirty_cables.drain() {
-        recompute_cable_route(
+        if in_layout(cable_entity) {
+            layout_dirty = true;
+            continue;
+        }
+        let Ok((cable, children, _, background, ..)) = cables.get(cable_entity) else {
+            continue;
+        };
+        let Some(route) = resolve_cable_route(
+            cable_entity,
+            cable,
+            children,
+            &mut commands,
+            &mut endpoints,
+            &transforms,
+            &waypoints,
+            &mut world_obstacles,
+        ) else {
+            continue;
+        };
+        if background && !matches!(cable.solver, Solver::Simulated(_)) {
+            let solver = cable.solver.clone();
+            let resolution = cable.resolution;
+            let task = AsyncComputeTaskPool::get().spawn(async move {
+                let cable_geometry = solver.solve(&route.request(resolution));
+                (route, cable_geometry)
+            });
+            commands.entity(cable_entity).insert(PendingRoute(task));
+            continue;
+        }
+        commands.entity(cable_entity).remove::<PendingRoute>();
+        let cable_geometry = cable.solver.solve(&route.request(cable.resolution));
+        finish_cable_route(
             cable_entity,
+            route,
+            cable_geometry,
+            &mut commands,
+            &cables,
+            &mut endpoints,
+        );
+    }
+
+    if layout_dirty {
+        let mut layout_entities: Vec<_> = participants
+            .iter()
+            .filter(|&(cable_entity, ..)| in_layout(cable_entity))
+            .collect();
+        layout_entities.sort_by_key(|&(cable_entity, ..)| cable_entity);
+        recompute_cable_layout(
+            &layout_entities,
             &mut commands,
             &cables,
             &mut endpoints,
             &transforms,
-            &world_obstacles,
+            &waypoints,
+            &mut world_obstacles,
         );
     }
 }
 
-fn recompute_cable_route(
-    cable_entity: Entity,
+/// Finishes each [`BackgroundSolve`] cable whose route solve completed.
+fn apply_background_routes(
+    mut commands: Commands,
+    mut pending_routes: Query<(Entity, &mut PendingRoute)>,
+    cables: Query<CableRouteQuery>,
+    mut endpoints: Query<EndpointRouteQuery>,
+) {
+    for (cable_entity, mut pending_route) in &mut pending_routes {
+        let Some((route, cable_geometry)) = check_ready(&mut pending_route.0) else {
+            continue;
+        };
+        commands.entity(cable_entity).remove::<PendingRoute>();
+        finish_cable_route(
+            cable_entity,
+            route,
+            cable_geometry,
+            &mut commands,
+            &cables,
+            &mut endpoints,
+        );
+    }
+}
+
+/// Resolves every layout participant's route, lays the cables out together
+/// in `participants` order, and finishes each with its laid-out geometry.
+fn recompute_cable_layout(
+    participants: &[(Entity, &CableMeshConfig, Option<&CableBundle>)],
     commands: &mut Commands,
-    cables: &Query<(&Cable, &Children, Has<RouteAnimation>)>,
-    endpoints: &mut Query<(
-        &CableEndpoint,
-        Option<&AttachedTo>,
-        Option<&mut ResolvedEndpointPosition>,
-    )>,
+    cables: &Query<CableRouteQuery>,
+    endpoints: &mut Query<EndpointRouteQuery>,
     transforms: &Query<&GlobalTransform>,
-    world_obstacles: &[Obstacle],
+    waypoints: &Query<&CableWaypoint>,
+    world_obstacles: &mut WorldObstacles,
 ) {
-    let Ok((cable, children, animated)) = cables.get(cable_entity) else {
-        return;
-    };
+    let routes: Vec<_> = participants
+        .iter()
+        .filter_map(|&(cable_entity, mesh_config, bundle)| {
+            let (cable, children, ..) = cables.get(cable_entity).ok()?;
+            let route = resolve_cable_route(
+                cable_entity,
+                cable,
+                children,
+                commands,
+                endpoints,
+                transforms,
+                waypoints,
+                world_obstacles,
+            )?;
+            Some((
+                cable_entity,
+                cable,
+                route,
+                mesh_config.tube_config.bounding_radius(),
+                bundle,
+            ))
+        })
+        .collect();
+
+    let geometries = layout_cables(
+        &routes
+            .iter()
+            .map(|(_, cable, route, radius, bundle)| LayoutCable {
+                solver:  &cable.solver,
+                request: route.request(cable.resolution),
+                radius:  *radius,
+                bundle:  bundle.map(|bundle| bundle.0),
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    for ((cable_entity, _, route, ..), cable_geometry) in routes.into_iter().zip(geometries) {
+        finish_cable_route(
+            cable_entity,
+            route,
+            cable_geometry,
+            commands,
+            cables,
+            endpoints,
+        );
+    }
+}
+
+/// A cable's endpoints, waypoints, obstacles, and supports as resolved for
+/// one recompute.
+struct ResolvedRoute {
+    start:          ResolvedEnd,
+    end:            ResolvedEnd,
+    /// The cable's [`CableWaypoint`]s, in route order.
+    vias:           Vec<ViaPoint>,
+    /// The cable's own static obstacles and the world's resolved
+    /// `RouteObstacle` snapshots, indexed.
+    obstacle_index: Arc<ObstacleIndex>,
+    /// The world's resolved [`CableSupport`] surfaces.
+    supports:       Arc<[SupportSurface]>,
+}
+
+impl ResolvedRoute {
+    fn request(&self, resolution: u32) -> RouteRequest<'_> {
+        route_request(
+            self.start.anchor,
+            self.end.anchor,
+            resolution,
+            &self.vias,
+            &self.obstacle_index,
+            &self.supports,
+        )
+    }
+}
 
-    let mut start_anchor = None;
-    let mut end_anchor = None;
+/// A request between `start` and `end` through `vias`, routed against
+/// `obstacle_index` and resting on `supports`.
+fn route_request<'a>(
+    start: Anchor,
+    end: Anchor,
+    resolution: u32,
+    vias: &'a [ViaPoint],
+    obstacle_index: &'a ObstacleIndex,
+    supports: &'a [SupportSurface],
+) -> RouteRequest<'a> {
+    RouteRequest::new(start, end, resolution)
+        .with_obstacles(obstacle_index.obstacles())
+        .with_vias(vias)
+        .with_supports(supports)
+        .with_obstacle_index(obstacle_index)
+}
+
+/// Resolves the cable's endpoints and waypoints from its children, or `None`
+/// when an end is missing or the ends coincide.
+fn resolve_cable_route(
+    cable_entity: Entity,
+    cable: &Cable,
+    children: &Children,
+    commands: &mut Commands,
+    endpoints: &mut Query<EndpointRouteQuery>,
+    transforms: &Query<&GlobalTransform>,
+    waypoints: &Query<&CableWaypoint>,
+    world_obstacles: &mut WorldObstacles,
+) -> Option<ResolvedRoute> {
+    let mut start = None;
+    let mut end = None;
 
     for child in children.iter() {
         let Ok((endpoint, attached_to, resolved_endpoint_position)) = endpoints.get_mut(child)
@@ -201,9 +610,11 @@ fn recompute_cable_route(
             },
         };
 
+        let mut moved = false;
         if let Some(mut resolved) = resolved_endpoint_position {
             if resolved.0 != endpoint_position {
                 resolved.0 = endpoint_position;
+                moved = true;
             }
         } else {
             commands
@@ -211,41 +622,157 @@ fn recompute_cable_route(
                 .insert(ResolvedEndpointPosition(endpoint_position));
         }
 
-        let anchor = Anchor {
-            position: endpoint_position,
-            exit,
+        let resolved_end = ResolvedEnd {
+            entity: child,
+            anchor: Anchor {
+                position: endpoint_position,
+                exit,
+            },
+            attached: attached_to.is_some(),
+            moved,
         };
         match endpoint.end {
-            CableEnd::Start => start_anchor = Some(anchor),
-            CableEnd::End => end_anchor = Some(anchor),
+            CableEnd::Start => start = Some(resolved_end),
+            CableEnd::End => end = Some(resolved_end),
         }
     }
 
-    let (Some(start), Some(end)) = (start_anchor, end_anchor) else {
-        return;
+    let (Some(start), Some(end)) = (start, end) else {
+        return None;
     };
 
-    if start.position.distance(end.position) < MIN_SEGMENT_LENGTH {
-        return;
+    if start.anchor.position.distance(end.anchor.position) < MIN_SEGMENT_LENGTH {
+        return None;
     }
 
-    // The cable's own static obstacles, merged with this frame's resolved
-    // `RouteObstacle` snapshots.
-    let obstacles: Vec<Obstacle> = cable
-        .obstacles
+    let mut ordered: Vec<(u32, Entity, ViaPoint)> = children
         .iter()
-        .copied()
-        .chain(world_obstacles.iter().copied())
+        .filter_map(|child| {
+            let waypoint = waypoints.get(child).ok()?;
+            Some((waypoint.index, child, waypoint.via_point()))
+        })
         .collect();
+    ordered.sort_by_key(|&(index, child, _)| (index, child));
 
-    let route_request = RouteRequest {
+    Some(ResolvedRoute {
         start,
         end,
-        obstacles: &obstacles,
-        resolution: cable.resolution,
+        vias: ordered.into_iter().map(|(.., via)| via).collect(),
+        obstacle_index: world_obstacles.index_for(cable_entity, cable),
+        supports: Arc::clone(world_obstacles.supports()),
+    })
+}
+
+/// Applies the cable's tension and over-stretch policy to `cable_geometry`
+/// and records its [`CableRouteOutcome`], then hands the geometry to the
+/// simulation, the route animation, or `ComputedCableGeometry` directly.
+fn finish_cable_route(
+    cable_entity: Entity,
+    route: ResolvedRoute,
+    mut cable_geometry: CableGeometry,
+    commands: &mut Commands,
+    cables: &Query<CableRouteQuery>,
+    endpoints: &mut Query<EndpointRouteQuery>,
+) {
+    let Ok((cable, _, animated, _, cable_tension, over_stretch_policy, route_outcome)) =
+        cables.get(cable_entity)
+    else {
+        return;
     };
+    let ResolvedRoute {
+        mut start,
+        mut end,
+        vias,
+        obstacle_index,
+        supports,
+    } = route;
+
+    // A clamped endpoint re-solves on its own; a laid-out cable rejoins the
+    // layout when the moved offset queues it next frame.
+    let solve = |start_anchor: Anchor, end_anchor: Anchor| {
+        cable.solver.solve(&route_request(
+            start_anchor,
+            end_anchor,
+            cable.resolution,
+            &vias,
+            &obstacle_index,
+            &supports,
+        ))
+    };
+
+    if let Some(length) = cable.solver.length() {
+        let tension_of = |cable_geometry: &CableGeometry| {
+            CableTension::new(cable_geometry.waypoint_length() / length.max(MIN_SEGMENT_LENGTH))
+        };
+        if tension_of(&cable_geometry).state == TensionState::OverStretched {
+            match over_stretch_policy.copied().unwrap_or_default() {
+                OverStretchPolicy::Report => {},
+                OverStretchPolicy::ClampEndpoint => {
+                    let overshoot = cable_geometry.waypoint_length() - length;
+                    if let Some(held) = tension::clamp_moved_end(&mut start, &mut end, overshoot) {
+                        if let Ok((mut endpoint, _, resolved)) = endpoints.get_mut(held.entity) {
+                            endpoint.offset = held.anchor.position;
+                            if let Some(mut resolved) = resolved {
+                                resolved.0 = held.anchor.position;
+                            }
+                        }
+                        cable_geometry = solve(start.anchor, end.anchor);
+                    }
+                },
+                OverStretchPolicy::Detach => {
+                    if let Some(detached) = tension::end_to_detach(&start, &end) {
+                        // `on_endpoint_detached` applies the endpoint's
+                        // `DetachPolicy`.
+                        commands.entity(detached).remove::<AttachedTo>();
+                    }
+                },
+            }
+        }
+
+        let tension = tension_of(&cable_geometry);
+        if cable_tension != Some(&tension) {
+            let newly_over_stretched = tension.state == TensionState::OverStretched
+                && cable_tension
+                    .is_none_or(|previous| previous.state != TensionState::OverStretched);
+            if newly_over_stretched {
+                commands.trigger(CableOverStretched {
+                    entity:  cable_entity,
+                    tension: tension.tension,
+                });
+            }
+            commands.entity(cable_entity).insert(tension);
+        }
+    } else if cable_tension.is_some() {
+        commands.entity(cable_entity).remove::<CableTension>();
+    }
+
+    let outcome = cable_geometry.outcome;
+    if route_outcome.map(|previous| previous.0) != Some(outcome) {
+        if route_outcome.copied().unwrap_or_default().0 != outcome {
+            commands.trigger(CableRouteOutcomeChanged {
+                entity: cable_entity,
+                outcome,
+            });
+        }
+        commands
+            .entity(cable_entity)
+            .insert(CableRouteOutcome(outcome));
+    }
+
+    if matches!(cable.solver, Solver::Simulated(_)) {
+        // `simulate_cables` runs after this system and steps the cable's
+        // particle chain toward these anchors every frame, writing
+        // `ComputedCableGeometry` itself. A simulated cable swings into its
+        // new shape, so it takes precedence over `RouteAnimation`.
+        commands.entity(cable_entity).insert(SimulationTarget {
+            start: start.anchor,
+            end: end.anchor,
+            obstacle_index,
+            supports,
+        });
+        return;
+    }
 
-    let cable_geometry = cable.solver.solve(&route_request);
     if animated {
         // `animate_routes` runs after this system and blends the displayed
         // geometry toward the `SolvedRoute` before writing
@@ -253,8 +780,8 @@ fn recompute_cable_route(
         // geometry's lead segments and keep them out of the blend.
         commands.entity(cable_entity).insert(SolvedRoute {
             geometry: cable_geometry,
-            start:    route_request.start,
-            end:      route_request.end,
+            start:    start.anchor,
+            end:      end.anchor,
         });
     } else {
         commands.entity(cable_entity).insert(ComputedCableGeometry {
@@ -1,4 +1,6 @@
-//! Constants for the cable module: endpoint alignment and route animation.
+//! Constants for the cable module: endpoint alignment, cable networks,
+//! obstacle invalidation, picking, ports, route animation, cable simulation,
+//! and tension.
 
 // alignment
 /// Dot-product threshold above which `on_endpoint_alignment_update` skips
@@ -6,6 +8,32 @@
 /// `ComputedCableGeometry` -> `Transform` -> `GlobalTransform`.
 pub(super) const ALIGNMENT_FEEDBACK_GUARD: f32 = 0.9999;
 
+// cable networks
+/// Document version `CableNetwork` writes and the only one it reads.
+pub(super) const CABLE_NETWORK_VERSION: u32 = 1;
+
+// obstacle invalidation
+/// Padding around the bounding box of a cable's current geometry; a moved
+/// `RouteObstacle` whose old or new bounds reach into it re-solves the cable,
+/// in metres. Covers the clearance planners keep from obstacle faces, so an
+/// obstacle the route currently detours around still counts as touching it.
+pub(super) const CABLE_CORRIDOR_MARGIN: f32 = 0.5;
+
+// picking
+/// Default distance every tube is widened by when picking cables, in metres.
+pub(super) const DEFAULT_CABLE_HIT_MARGIN: f32 = 0.02;
+
+// ports
+/// Default distance from a pointer's hit to a `CableConnector` end within
+/// which a picking drag grabs that end, in metres.
+pub(super) const DEFAULT_GRAB_RADIUS: f32 = 0.25;
+/// Default length of the straight lead a cable leaves a `CablePort` along,
+/// in metres.
+pub(super) const DEFAULT_PORT_LEAD_LENGTH: f32 = 0.1;
+/// Default distance within which a dragged end snaps to a compatible
+/// `CablePort`, in metres.
+pub(super) const DEFAULT_PORT_SNAP_RADIUS: f32 = 0.3;
+
 // route animation
 /// Default seconds a `RouteAnimation` transition takes to land on the newly
 /// solved route.
@@ -20,3 +48,16 @@ pub(super) const ROUTE_ANIMATION_OBSTACLE_CLEARANCE: f32 = 0.01;
 /// Once every in-flight sample is within this distance of its goal on the
 /// solved route, the animation snaps to the solved geometry, in metres.
 pub(super) const ROUTE_ANIMATION_SNAP_DISTANCE: f32 = 0.002;
+
+// cable simulation
+/// A simulated cable whose particles all moved less than this in one step is
+/// at rest and stops stepping until its anchors or obstacles change, in
+/// metres.
+pub(super) const SIMULATION_REST_DISTANCE: f32 = 1e-4;
+
+// tension
+/// `CableTension::tension` above which a cable is over-stretched. Slightly
+/// above `1.0` so a clamped endpoint's rounding does not flicker the state.
+pub(super) const OVER_STRETCH_TENSION_THRESHOLD: f32 = 1.01;
+/// `CableTension::tension` at or above which a cable counts as taut.
+pub(super) const TAUT_TENSION_THRESHOLD: f32 = 0.99;
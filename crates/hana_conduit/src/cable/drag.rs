@@ -0,0 +1,130 @@
+//! `on_pointer_drag_start`, `on_pointer_drag`, and `on_pointer_drag_end`:
+//! picking drags that grab a [`CableConnector`] end and carry it as a
+//! [`DraggedCableEnd`].
+
+use std::collections::HashMap;
+
+use bevy::picking::events::Drag;
+use bevy::picking::events::DragEnd;
+use bevy::picking::events::DragStart;
+use bevy::picking::events::Pointer;
+use bevy::picking::pointer::PointerButton;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+
+use super::AttachedTo;
+use super::CableEndpoint;
+use super::port::CableConnectionSettings;
+use super::port::CableConnector;
+use super::port::DraggedCableEnd;
+use super::port::endpoint_world_position;
+
+/// Cable ends being carried by each pointer.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(super) struct PointerDrags(HashMap<PointerId, PointerDrag>);
+
+/// A cable end carried by a pointer, moving in the plane facing the camera
+/// through the point where the end was grabbed.
+pub(super) struct PointerDrag {
+    endpoint:     Entity,
+    camera:       Entity,
+    plane_origin: Vec3,
+}
+
+/// Grabs the [`CableConnector`] end nearest the pointer's hit, within
+/// [`CableConnectionSettings::grab_radius`], when a primary-button drag starts
+/// on any entity — the cable's mesh or a plug model it is attached to.
+pub(super) fn on_pointer_drag_start(
+    drag_start: On<Pointer<DragStart>>,
+    settings: Res<CableConnectionSettings>,
+    endpoints: Query<
+        (Entity, &CableEndpoint, Option<&AttachedTo>),
+        (With<CableConnector>, Without<DraggedCableEnd>),
+    >,
+    transforms: Query<&GlobalTransform>,
+    mut pointer_drags: ResMut<PointerDrags>,
+    mut commands: Commands,
+) {
+    // Picking events bubble up the hierarchy; only the first one grabs.
+    if drag_start.button != PointerButton::Primary
+        || pointer_drags.contains_key(&drag_start.pointer_id)
+    {
+        return;
+    }
+    let Some(hit) = drag_start.hit.position else {
+        return;
+    };
+
+    let grabbed = endpoints
+        .iter()
+        .filter_map(|(entity, endpoint, attached)| {
+            let position = endpoint_world_position(endpoint, attached, &transforms)?;
+            let distance = position.distance(hit);
+            (distance <= settings.grab_radius).then_some((entity, position, distance))
+        })
+        .min_by(|a, b| a.2.total_cmp(&b.2));
+    let Some((endpoint, position, _)) = grabbed else {
+        return;
+    };
+
+    pointer_drags.insert(
+        drag_start.pointer_id,
+        PointerDrag {
+            endpoint,
+            camera: drag_start.hit.camera,
+            plane_origin: position,
+        },
+    );
+    commands
+        .entity(endpoint)
+        .insert(DraggedCableEnd::new(position));
+}
+
+/// Moves a grabbed end to where the pointer's ray crosses its drag plane. The
+/// pointer's window position is taken relative to the camera's viewport, so a
+/// camera rendering to part of the window casts the right ray.
+pub(super) fn on_pointer_drag(
+    drag: On<Pointer<Drag>>,
+    pointer_drags: Res<PointerDrags>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut dragged: Query<&mut DraggedCableEnd>,
+) {
+    let Some(pointer_drag) = pointer_drags.get(&drag.pointer_id) else {
+        return;
+    };
+    let Ok((camera, camera_transform)) = cameras.get(pointer_drag.camera) else {
+        return;
+    };
+    let Some(viewport) = camera.logical_viewport_rect() else {
+        return;
+    };
+    let Ok(ray) = camera.viewport_to_world(
+        camera_transform,
+        drag.pointer_location.position - viewport.min,
+    ) else {
+        return;
+    };
+    let plane = InfinitePlane3d {
+        normal: camera_transform.forward(),
+    };
+    let Some(distance) = ray.intersect_plane(pointer_drag.plane_origin, plane) else {
+        return;
+    };
+    if let Ok(mut dragged_cable_end) = dragged.get_mut(pointer_drag.endpoint) {
+        dragged_cable_end.target = ray.get_point(distance);
+    }
+}
+
+/// Drops the end a pointer was carrying.
+pub(super) fn on_pointer_drag_end(
+    drag_end: On<Pointer<DragEnd>>,
+    mut pointer_drags: ResMut<PointerDrags>,
+    mut commands: Commands,
+) {
+    let Some(pointer_drag) = pointer_drags.remove(&drag_end.pointer_id) else {
+        return;
+    };
+    commands
+        .entity(pointer_drag.endpoint)
+        .try_remove::<DraggedCableEnd>();
+}
@@ -3,18 +3,22 @@
 use std::f32::consts::FRAC_PI_2;
 
 use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
 
 use super::Cable;
 use super::ComputedCableGeometry;
 use super::compute::DirtyCables;
 use super::compute::ResolvedEndpointPosition;
 use super::constants::ALIGNMENT_FEEDBACK_GUARD;
+use super::port::DraggedCableEnd;
 use crate::mesh::CapStyle;
 use crate::routing::CurveKind;
+use crate::routing::SimulatedSolver;
 use crate::routing::Solver;
 
 /// Which end of the cable an endpoint represents.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Reflect)]
 pub enum CableEnd {
     /// The starting end of the cable.
     Start,
@@ -28,7 +32,7 @@ pub enum CableEnd {
 /// routed span begins at the lead's tip and the lead itself is always a
 /// straight segment. Curve-level exit behavior (bending a catenary's end
 /// tangent) is a solver concern and belongs on `CatenarySolver`, not here.
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Reflect)]
 pub enum EndpointExit {
     /// The solver routes directly from the endpoint position.
     #[default]
@@ -50,7 +54,7 @@ pub enum EndpointExit {
 ///
 /// Assumes the target's `+Y` axis is its "cable-exit" axis (matches Bevy's GLTF import
 /// convention). Models with a different local axis should wrap in a parent entity.
-#[derive(Clone, Copy, Debug, Default, Reflect)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Reflect)]
 pub enum EndpointAlignment {
     /// The target's rotation is not touched. It stays in whatever orientation it
     /// was spawned with.
@@ -71,7 +75,7 @@ pub enum EndpointAlignment {
 ///
 /// How the curve itself reacts to detachment (e.g. increasing slack on a catenary) is a
 /// per-solver concern — see `CatenarySolver::with_detach_slack_bump`.
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub enum DetachPolicy {
     /// Convert to world-attached at the last resolved position. The cable's
     /// `ComputedCableGeometry` curve stays unchanged.
@@ -87,7 +91,7 @@ pub enum DetachPolicy {
 /// - **World-attached** (no `AttachedTo`): `offset` is the world-space position.
 /// - **Entity-attached** (with `AttachedTo`): `offset` is in the target entity's local space. The
 ///   system transforms it to world space via the target's [`GlobalTransform`].
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(Component)]
 pub struct CableEndpoint {
     /// Which end of the cable this represents.
@@ -240,14 +244,18 @@ pub(super) fn on_endpoint_alignment_update(
 ///
 /// Bevy auto-removes `AttachedTo` when the target entity is despawned, which
 /// triggers `OnRemove<AttachedTo>`. This observer reads the endpoint's
-/// [`DetachPolicy`] and acts accordingly.
+/// [`DetachPolicy`] and acts accordingly. An end unplugged by a
+/// [`DraggedCableEnd`] is left to the drag.
 pub(super) fn on_endpoint_detached(
     trigger: On<Remove, AttachedTo>,
-    mut endpoints: Query<(
-        &mut CableEndpoint,
-        &ChildOf,
-        Option<&ResolvedEndpointPosition>,
-    )>,
+    mut endpoints: Query<
+        (
+            &mut CableEndpoint,
+            &ChildOf,
+            Option<&ResolvedEndpointPosition>,
+        ),
+        Without<DraggedCableEnd>,
+    >,
     mut cables: Query<&mut Cable>,
     mut commands: Commands,
     mut dirty_cables: ResMut<DirtyCables>,
@@ -288,6 +296,10 @@ const fn preserve_resolved_world_position(
 fn apply_solver_detach_response(solver: &mut Solver) {
     match solver {
         Solver::Catenary(catenary_solver)
+        | Solver::Simulated(SimulatedSolver {
+            catenary: catenary_solver,
+            ..
+        })
         | Solver::Routed {
             curve_kind: CurveKind::Catenary(catenary_solver),
             ..
@@ -0,0 +1,31 @@
+//! [`CableAvoidance`] and [`CableBundle`]: opting cables into a shared
+//! [`layout_cables`](crate::layout_cables) pass instead of routing each one
+//! on its own.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Lays the cable out together with every other `CableAvoidance` cable, so
+/// cables route around each other instead of interpenetrating.
+///
+/// Whenever any participating cable is recomputed, all of them are, in
+/// [`Entity`] order: each treats the cables before it as soft obstacles
+/// (keeping [`TubeConfig::bounding_radius`](crate::TubeConfig::bounding_radius)
+/// apart) and
+/// spreads into parallel lanes where it shares an axis-aligned corridor with
+/// them. Cables with a [`SimulatedSolver`](crate::SimulatedSolver) are
+/// simulated on their own and ignore this component.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component)]
+pub struct CableAvoidance;
+
+/// Groups the cable with every other cable carrying the same id into a
+/// bundle.
+///
+/// The cables share one spine, routed by the lowest-[`Entity`] member's
+/// solver, and fan out to their own endpoints near each end.
+#[derive(Component, Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+#[require(CableAvoidance)]
+pub struct CableBundle(pub u32);
@@ -1,14 +1,31 @@
 //! `Cable`, `CableEndpoint`, `AttachedTo`, `AttachedEndpoints`, `DetachPolicy`,
-//! `EndpointAlignment`, `EndpointExit`, `RouteObstacle`, and `RouteAnimation`,
-//! plus `on_endpoint_alignment_update` and `on_endpoint_detached`.
+//! `EndpointAlignment`, `EndpointExit`, `RouteObstacle`, `RouteAnimation`,
+//! `CableTension`, `OverStretchPolicy`, `CableAvoidance`, `CableBundle`,
+//! `CableWaypoint`, `CableSupport`, `BackgroundSolve`, `CableRouteOutcome`,
+//! `CablePort`, `CableConnector`, `DraggedCableEnd`, `CableNetwork`,
+//! `CableRayCast`, `CablePickingSettings`, `CablePointerHits`, and
+//! `CablePointerHit`, plus `on_endpoint_alignment_update` and
+//! `on_endpoint_detached`.
 
 mod animation;
+mod background;
 mod compute;
 mod constants;
+mod drag;
 mod endpoint;
+mod layout;
+mod network;
+mod outcome;
+mod picking;
+mod port;
 mod route_obstacle;
+mod simulation;
+mod support;
+mod tension;
+mod waypoint;
 
 pub use animation::RouteAnimation;
+pub use background::BackgroundSolve;
 use bevy::prelude::*;
 pub(crate) use compute::CableSystems;
 use compute::ComputePlugin;
@@ -20,7 +37,40 @@ pub use endpoint::CableEndpoint;
 pub use endpoint::DetachPolicy;
 pub use endpoint::EndpointAlignment;
 pub use endpoint::EndpointExit;
+pub use layout::CableAvoidance;
+pub use layout::CableBundle;
+pub use network::CableNetwork;
+pub use network::CableNetworkError;
+pub use network::SavedCable;
+pub use network::SavedCableSettings;
+pub use network::SavedEndpoint;
+pub use outcome::CableRouteOutcome;
+pub use outcome::CableRouteOutcomeChanged;
+pub use picking::CablePickingSettings;
+pub use picking::CablePointerHit;
+pub use picking::CablePointerHits;
+pub use picking::CableRayCast;
+use picking::PickingPlugin;
+pub use port::CableConnected;
+pub use port::CableConnectionSettings;
+pub use port::CableConnector;
+pub use port::CableDisconnected;
+pub use port::CablePort;
+pub use port::ConnectionRejected;
+pub use port::ConnectorKind;
+pub use port::DraggedCableEnd;
+use port::PortPlugin;
+pub use port::RejectionReason;
+pub(crate) use route_obstacle::ObstacleSources;
 pub use route_obstacle::RouteObstacle;
+use serde::Deserialize;
+use serde::Serialize;
+pub use support::CableSupport;
+pub use tension::CableOverStretched;
+pub use tension::CableTension;
+pub use tension::OverStretchPolicy;
+pub use tension::TensionState;
+pub use waypoint::CableWaypoint;
 
 use crate::mesh::CableMeshConfig;
 use crate::routing::Obstacle;
@@ -30,7 +80,7 @@ pub(super) struct CablePlugin;
 
 impl Plugin for CablePlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ComputePlugin)
+        app.add_plugins((ComputePlugin, PickingPlugin, PortPlugin))
             .add_observer(endpoint::on_endpoint_alignment_update)
             .add_observer(endpoint::on_endpoint_detached);
     }
@@ -40,14 +90,14 @@ impl Plugin for CablePlugin {
 ///
 /// The cable itself stores the solver, obstacles, and resolution. Endpoint positions
 /// come from child entities with [`CableEndpoint`] components.
-#[derive(Component, Clone, Debug, Reflect)]
+#[derive(Component, Clone, Debug, Serialize, Deserialize, Reflect)]
 #[reflect(Component)]
 #[require(ComputedCableGeometry, CableMeshConfig, Transform, Visibility)]
 pub struct Cable {
     /// The routing algorithm to use.
     pub solver:     Solver,
     /// Static obstacles to route around, merged at recompute time with the
-    /// boxes resolved from every [`RouteObstacle`] entity in the world.
+    /// shapes resolved from every [`RouteObstacle`] entity in the world.
     pub obstacles:  Vec<Obstacle>,
     /// Number of sample points per segment (0 = use solver default).
     pub resolution: u32,
@@ -0,0 +1,347 @@
+//! [`CableNetwork`]: a versioned document of every cable in a world, saved
+//! to RON and rebuilt into the same entities on load.
+//!
+//! # Versioning
+//!
+//! Every document carries a `version` field. [`CableNetwork::from_ron`] reads
+//! it before decoding the rest, and refuses versions it does not know rather
+//! than decoding them into something silently different. When the document's
+//! shape changes, bump `CABLE_NETWORK_VERSION` and keep a decoder for each
+//! version already shipped.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error;
+
+use super::AttachedTo;
+use super::BackgroundSolve;
+use super::Cable;
+use super::CableAvoidance;
+use super::CableBundle;
+use super::CableEndpoint;
+use super::CableWaypoint;
+use super::OverStretchPolicy;
+use super::RouteAnimation;
+use super::constants::CABLE_NETWORK_VERSION;
+use super::port::CableConnector;
+use crate::mesh::CableMeshConfig;
+use crate::mesh::CableMeshLod;
+
+/// A saved set of cables: each cable's solver, mesh configuration and level
+/// of detail, routing and over-stretch settings, endpoints and their
+/// connectors, and waypoints.
+///
+/// An endpoint attached to another entity is saved by that entity's [`Name`],
+/// since an [`Entity`] id means nothing in the next session; name the
+/// entities cables plug into so their ends re-attach on load. Everything a
+/// cable computes — its route, mesh, and tension — is left out and recomputed
+/// once the cable is restored. Materials are asset handles and are not saved
+/// either; set [`CableMeshConfig::material`] on the restored cables.
+///
+/// The types are `serde` types, so a network can be written to any format;
+/// [`to_ron`](Self::to_ron) and [`from_ron`](Self::from_ron) handle the
+/// version check for RON.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CableNetwork {
+    /// Document version this network was written with.
+    pub version: u32,
+    /// Every saved cable.
+    pub cables:  Vec<SavedCable>,
+}
+
+/// One cable in a [`CableNetwork`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedCable {
+    /// The cable entity's [`Name`], if it has one.
+    pub name:        Option<String>,
+    /// Solver, obstacles, and resolution.
+    pub cable:       Cable,
+    /// Tube mesh configuration, without its material.
+    pub mesh_config: CableMeshConfig,
+    /// The cable's endpoints.
+    pub endpoints:   Vec<SavedEndpoint>,
+    /// The cable's waypoints.
+    pub waypoints:   Vec<CableWaypoint>,
+    /// The cable's optional settings.
+    pub settings:    SavedCableSettings,
+}
+
+/// The optional components of a [`SavedCable`]; a `None` or `false` entry
+/// leaves the component off the restored cable.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SavedCableSettings {
+    /// The cable's [`CableMeshLod`].
+    pub mesh_lod:            Option<CableMeshLod>,
+    /// The cable's [`OverStretchPolicy`].
+    pub over_stretch_policy: Option<OverStretchPolicy>,
+    /// The cable's [`RouteAnimation`].
+    pub route_animation:     Option<RouteAnimation>,
+    /// Whether the cable carries [`BackgroundSolve`].
+    pub background_solve:    bool,
+    /// Whether the cable carries [`CableAvoidance`].
+    pub avoidance:           bool,
+    /// The cable's [`CableBundle`].
+    pub bundle:              Option<CableBundle>,
+}
+
+/// One endpoint of a [`SavedCable`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedEndpoint {
+    /// The endpoint, with its `offset` in the space of `attached_to`.
+    pub endpoint:       CableEndpoint,
+    /// [`Name`] of the entity the endpoint is [`AttachedTo`], or `None` when
+    /// world-attached.
+    pub attached_to:    Option<String>,
+    /// World position the endpoint resolved to when saved. A restored
+    /// endpoint whose named target is missing stays here instead.
+    pub world_position: Vec3,
+    /// The endpoint's [`CableConnector`], if it has one.
+    pub connector:      Option<CableConnector>,
+}
+
+/// Why a [`CableNetwork`] could not be written or read.
+#[derive(Debug, Error)]
+pub enum CableNetworkError {
+    /// The network could not be written as RON.
+    #[error("failed to encode cable network: {0}")]
+    Encode(#[from] ron::Error),
+    /// The document is not valid RON for a cable network.
+    #[error("failed to decode cable network: {0}")]
+    Decode(#[from] ron::error::SpannedError),
+    /// The document was written with a version this build cannot read.
+    #[error("cable network version {found} is not supported (expected {expected})")]
+    UnsupportedVersion {
+        /// Version the document declares.
+        found:    u32,
+        /// Version this build reads and writes.
+        expected: u32,
+    },
+}
+
+/// Just the version of a document, read before decoding the rest. Named
+/// like [`CableNetwork`] so documents written with struct names decode too.
+#[derive(Deserialize)]
+#[serde(rename = "CableNetwork")]
+struct VersionProbe {
+    version: u32,
+}
+
+impl CableNetwork {
+    /// Save every [`Cable`] in `world`.
+    ///
+    /// Endpoints attached to an entity without a [`Name`] are saved as
+    /// world-attached at their current position.
+    #[must_use]
+    pub fn capture(world: &mut World) -> Self {
+        let mut cables = world.query::<(
+            &Cable,
+            &CableMeshConfig,
+            &Children,
+            Option<&Name>,
+            CableSettingsQuery,
+        )>();
+        let mut endpoints =
+            world.query::<(&CableEndpoint, Option<&AttachedTo>, Option<&CableConnector>)>();
+        let mut waypoints = world.query::<&CableWaypoint>();
+        let mut targets = world.query::<(Option<&Name>, &GlobalTransform)>();
+        let world = &*world;
+
+        let cables = cables
+            .iter(world)
+            .map(|(cable, cable_mesh_config, children, name, settings)| {
+                let (
+                    mesh_lod,
+                    over_stretch_policy,
+                    route_animation,
+                    background_solve,
+                    avoidance,
+                    bundle,
+                ) = settings;
+                let mut saved_endpoints = Vec::new();
+                let mut saved_waypoints = Vec::new();
+                for child in children.iter() {
+                    if let Ok((endpoint, attached, connector)) = endpoints.get(world, child) {
+                        saved_endpoints.push(save_endpoint(
+                            endpoint,
+                            connector,
+                            attached.and_then(|attached| targets.get(world, attached.0).ok()),
+                        ));
+                    } else if let Ok(waypoint) = waypoints.get(world, child) {
+                        saved_waypoints.push(*waypoint);
+                    }
+                }
+                saved_waypoints.sort_by_key(|waypoint| waypoint.index);
+                SavedCable {
+                    name:        name.map(|name| name.as_str().to_owned()),
+                    cable:       cable.clone(),
+                    mesh_config: CableMeshConfig {
+                        material: None,
+                        ..cable_mesh_config.clone()
+                    },
+                    endpoints:   saved_endpoints,
+                    waypoints:   saved_waypoints,
+                    settings:    SavedCableSettings {
+                        mesh_lod: mesh_lod.cloned(),
+                        over_stretch_policy: over_stretch_policy.copied(),
+                        route_animation: route_animation.cloned(),
+                        background_solve,
+                        avoidance,
+                        bundle: bundle.copied(),
+                    },
+                }
+            })
+            .collect();
+
+        Self {
+            version: CABLE_NETWORK_VERSION,
+            cables,
+        }
+    }
+
+    /// Spawn every saved cable into `world` and return the cable entities, in
+    /// saved order. Each cable computes its route on the next update.
+    ///
+    /// Endpoints re-attach to the entity carrying their target's [`Name`] —
+    /// the first found when several share it. An endpoint whose target is
+    /// missing is restored world-attached where it was saved.
+    pub fn restore(&self, world: &mut World) -> Vec<Entity> {
+        let mut named = world.query::<(Entity, &Name)>();
+        let mut targets: HashMap<String, Entity> = HashMap::new();
+        for (entity, name) in named.iter(world) {
+            targets.entry(name.as_str().to_owned()).or_insert(entity);
+        }
+
+        self.cables
+            .iter()
+            .map(|saved_cable| {
+                let cable = world
+                    .spawn((saved_cable.cable.clone(), saved_cable.mesh_config.clone()))
+                    .id();
+                if let Some(name) = &saved_cable.name {
+                    world.entity_mut(cable).insert(Name::new(name.clone()));
+                }
+                restore_settings(&mut world.entity_mut(cable), &saved_cable.settings);
+                for saved_endpoint in &saved_cable.endpoints {
+                    let target = saved_endpoint
+                        .attached_to
+                        .as_ref()
+                        .and_then(|name| targets.get(name).copied());
+                    let mut endpoint = saved_endpoint.endpoint.clone();
+                    let mut endpoint_entity = world.spawn(ChildOf(cable));
+                    if let Some(connector) = &saved_endpoint.connector {
+                        endpoint_entity.insert(connector.clone());
+                    }
+                    if let Some(target) = target {
+                        endpoint_entity.insert((endpoint, AttachedTo(target)));
+                    } else {
+                        endpoint.offset = saved_endpoint.world_position;
+                        endpoint_entity.insert(endpoint);
+                    }
+                }
+                for waypoint in &saved_cable.waypoints {
+                    world.spawn((*waypoint, ChildOf(cable)));
+                }
+                cable
+            })
+            .collect()
+    }
+
+    /// Write the network as pretty-printed RON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CableNetworkError::Encode`] if RON serialization fails.
+    pub fn to_ron(&self) -> Result<String, CableNetworkError> {
+        Ok(ron::ser::to_string_pretty(self, PrettyConfig::default())?)
+    }
+
+    /// Read a network written by [`to_ron`](Self::to_ron).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CableNetworkError::UnsupportedVersion`] for a document
+    /// written with another version, and [`CableNetworkError::Decode`] for
+    /// anything that is not a valid cable network.
+    pub fn from_ron(contents: &str) -> Result<Self, CableNetworkError> {
+        let probe: VersionProbe = ron::from_str(contents)?;
+        if probe.version != CABLE_NETWORK_VERSION {
+            return Err(CableNetworkError::UnsupportedVersion {
+                found:    probe.version,
+                expected: CABLE_NETWORK_VERSION,
+            });
+        }
+        Ok(ron::from_str(contents)?)
+    }
+}
+
+/// Query type for the optional cable-side components [`SavedCableSettings`]
+/// keeps.
+type CableSettingsQuery<'w> = (
+    Option<&'w CableMeshLod>,
+    Option<&'w OverStretchPolicy>,
+    Option<&'w RouteAnimation>,
+    Has<BackgroundSolve>,
+    Has<CableAvoidance>,
+    Option<&'w CableBundle>,
+);
+
+/// Insert the components `settings` saved on `cable`.
+fn restore_settings(cable: &mut EntityWorldMut, settings: &SavedCableSettings) {
+    if let Some(mesh_lod) = &settings.mesh_lod {
+        cable.insert(mesh_lod.clone());
+    }
+    if let Some(over_stretch_policy) = settings.over_stretch_policy {
+        cable.insert(over_stretch_policy);
+    }
+    if let Some(route_animation) = &settings.route_animation {
+        cable.insert(route_animation.clone());
+    }
+    if settings.background_solve {
+        cable.insert(BackgroundSolve);
+    }
+    if settings.avoidance {
+        cable.insert(CableAvoidance);
+    }
+    if let Some(bundle) = settings.bundle {
+        cable.insert(bundle);
+    }
+}
+
+/// Save `endpoint` and its `connector`, naming its attached target when it
+/// has a [`Name`] and falling back to world-attached when it does not.
+fn save_endpoint(
+    endpoint: &CableEndpoint,
+    connector: Option<&CableConnector>,
+    target: Option<(Option<&Name>, &GlobalTransform)>,
+) -> SavedEndpoint {
+    let Some((name, target_transform)) = target else {
+        return SavedEndpoint {
+            endpoint:       endpoint.clone(),
+            attached_to:    None,
+            world_position: endpoint.offset,
+            connector:      connector.cloned(),
+        };
+    };
+    let world_position = target_transform.transform_point(endpoint.offset);
+    match name {
+        Some(name) => SavedEndpoint {
+            endpoint: endpoint.clone(),
+            attached_to: Some(name.as_str().to_owned()),
+            world_position,
+            connector: connector.cloned(),
+        },
+        None => SavedEndpoint {
+            endpoint: CableEndpoint {
+                offset: world_position,
+                ..endpoint.clone()
+            },
+            attached_to: None,
+            world_position,
+            connector: connector.cloned(),
+        },
+    }
+}
@@ -0,0 +1,26 @@
+//! [`CableRouteOutcome`] and [`CableRouteOutcomeChanged`]: how a cable's path
+//! strategy arrived at its route, so a cable drawn straight through an
+//! obstacle says why.
+
+use bevy::prelude::*;
+
+use crate::routing::RouteOutcome;
+
+/// How the cable's path strategy arrived at its current route.
+///
+/// Written on every recompute of the cable. With [`DebugGizmos`](crate::DebugGizmos)
+/// enabled, fallbacks and pushed-out endpoints are drawn in the
+/// [`CableGizmoGroup`](crate::CableGizmoGroup).
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CableRouteOutcome(pub RouteOutcome);
+
+/// Triggered on a cable when its [`CableRouteOutcome`] changes, or when its
+/// first route is anything but [`RouteOutcome::Success`].
+#[derive(EntityEvent, Clone, Copy, Debug, Reflect)]
+pub struct CableRouteOutcomeChanged {
+    /// The cable whose route outcome changed.
+    pub entity:  Entity,
+    /// The cable's new route outcome.
+    pub outcome: RouteOutcome,
+}
@@ -0,0 +1,194 @@
+//! [`CableRayCast`], [`CablePickingSettings`], [`CablePointerHits`], and
+//! [`CablePointerHit`]: ray hits against cables' routed tubes, plus
+//! `cable_picking_backend`, which reports them to Bevy picking.
+
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemParam;
+use bevy::picking::Pickable;
+use bevy::picking::PickingSystems;
+use bevy::picking::backend::HitData;
+use bevy::picking::backend::PointerHits;
+use bevy::picking::backend::ray::RayMap;
+use bevy::picking::pointer::PointerId;
+use bevy::prelude::*;
+use bevy_kana::ToF32;
+
+use super::ComputedCableGeometry;
+use super::constants::DEFAULT_CABLE_HIT_MARGIN;
+use crate::mesh::CableMeshConfig;
+use crate::routing::CableRayHit;
+
+pub(super) struct PickingPlugin;
+
+impl Plugin for PickingPlugin {
+    fn build(&self, app: &mut App) {
+        // Bevy's `PickingPlugin` provides the pointer rays; without it the
+        // backend stays idle and `CableRayCast` still works.
+        app.init_resource::<CablePickingSettings>()
+            .init_resource::<CablePointerHits>()
+            .add_systems(
+                PreUpdate,
+                cable_picking_backend
+                    .in_set(PickingSystems::Backend)
+                    .run_if(resource_exists::<RayMap>),
+            );
+    }
+}
+
+/// Casts rays against every cable's routed tube.
+///
+/// Each cable is hit as the capsule chain its [`ComputedCableGeometry`]
+/// sweeps at its tube's bounding radius, not as its triangle mesh, and each
+/// hit reports how far along the cable it landed — where to select, cut, or
+/// insert a waypoint.
+#[derive(SystemParam)]
+pub struct CableRayCast<'w, 's> {
+    cables: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static ComputedCableGeometry,
+            &'static CableMeshConfig,
+        ),
+    >,
+}
+
+impl CableRayCast<'_, '_> {
+    /// The nearest cable `ray` hits and where, among cables `filter` accepts.
+    /// `margin` widens every tube, in metres, so thin cables are easier to
+    /// hit.
+    pub fn cast_ray(
+        &self,
+        ray: Ray3d,
+        margin: f32,
+        filter: impl Fn(Entity) -> bool,
+    ) -> Option<(Entity, CableRayHit)> {
+        self.cables
+            .iter()
+            .filter(|(cable, ..)| filter(*cable))
+            .filter_map(|(cable, computed_cable_geometry, cable_mesh_config)| {
+                let cable_geometry = computed_cable_geometry.cable_geometry.as_ref()?;
+                let radius = cable_mesh_config.tube_config.bounding_radius() + margin.max(0.0);
+                cable_geometry.cast_ray(ray, radius).map(|hit| (cable, hit))
+            })
+            .min_by(|(_, a), (_, b)| a.distance.total_cmp(&b.distance))
+    }
+}
+
+/// How cables report to Bevy picking.
+///
+/// Hover, click, and drag events on a cable carry only the hit's world
+/// position and tube normal in their [`HitData`]; the arc length and the
+/// nearest point on the cable are in [`CablePointerHits`], keyed by the
+/// event's `pointer_id`, and in the [`CablePointerHit`] triggered on the
+/// cable.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CablePickingSettings {
+    /// Whether `cable_picking_backend` reports cable hits. Turn it off when
+    /// another backend already picks cable meshes.
+    pub enabled:    bool,
+    /// Distance every tube is widened by for picking, in metres.
+    pub hit_margin: f32,
+}
+
+impl Default for CablePickingSettings {
+    fn default() -> Self {
+        Self {
+            enabled:    true,
+            hit_margin: DEFAULT_CABLE_HIT_MARGIN,
+        }
+    }
+}
+
+/// The cable under each pointer and where along it the pointer lies,
+/// refreshed every frame by `cable_picking_backend`.
+///
+/// Picking events on a cable carry the hit's world position; look the
+/// pointer up here for its arc length and the nearest point on the cable.
+#[derive(Resource, Default, Debug, Deref)]
+pub struct CablePointerHits(HashMap<PointerId, (Entity, CableRayHit)>);
+
+/// Triggered on a cable every frame a pointer lies over it, as seen through
+/// the topmost camera, so observers read where along the cable the pointer
+/// is without looking it up in [`CablePointerHits`].
+#[derive(EntityEvent, Clone, Copy, Debug, Reflect)]
+pub struct CablePointerHit {
+    /// The cable under the pointer.
+    pub entity:     Entity,
+    /// The pointer over the cable.
+    pub pointer_id: PointerId,
+    /// Arc length from the cable's start to `point`, in metres.
+    pub arc_length: f32,
+    /// Point on the cable's centreline nearest the hit.
+    pub point:      Vec3,
+}
+
+/// Reports the nearest cable under each pointer to Bevy picking, so hover,
+/// click, and drag events reach cable entities, records the hit in
+/// [`CablePointerHits`], and triggers [`CablePointerHit`] on the cable with
+/// its arc length. Cables with a [`Pickable`] that is not hoverable are
+/// skipped.
+fn cable_picking_backend(
+    settings: Res<CablePickingSettings>,
+    ray_map: Res<RayMap>,
+    cameras: Query<&Camera>,
+    cable_ray_cast: CableRayCast,
+    pickables: Query<&Pickable>,
+    mut cable_pointer_hits: ResMut<CablePointerHits>,
+    mut pointer_hits: MessageWriter<PointerHits>,
+    mut commands: Commands,
+) {
+    cable_pointer_hits.0.clear();
+    if !settings.enabled {
+        return;
+    }
+
+    let mut pointer_orders: HashMap<PointerId, isize> = HashMap::new();
+    for (ray_id, ray) in ray_map.iter() {
+        let Ok(camera) = cameras.get(ray_id.camera) else {
+            continue;
+        };
+        let Some((cable, hit)) = cable_ray_cast.cast_ray(*ray, settings.hit_margin, |cable| {
+            pickables
+                .get(cable)
+                .ok()
+                .is_none_or(|pickable| pickable.is_hoverable)
+        }) else {
+            continue;
+        };
+
+        pointer_hits.write(PointerHits::new(
+            ray_id.pointer,
+            vec![(
+                cable,
+                HitData::new(
+                    ray_id.camera,
+                    hit.distance,
+                    Some(hit.position),
+                    hit.normal(),
+                ),
+            )],
+            i32::try_from(camera.order).unwrap_or_default().to_f32(),
+        ));
+        // A pointer seen through several cameras keeps the topmost camera's hit.
+        if pointer_orders
+            .get(&ray_id.pointer)
+            .is_none_or(|&order| camera.order > order)
+        {
+            pointer_orders.insert(ray_id.pointer, camera.order);
+            cable_pointer_hits.0.insert(ray_id.pointer, (cable, hit));
+        }
+    }
+
+    for (&pointer_id, &(cable, hit)) in cable_pointer_hits.iter() {
+        commands.trigger(CablePointerHit {
+            entity: cable,
+            pointer_id,
+            arc_length: hit.arc_length,
+            point: hit.point,
+        });
+    }
+}
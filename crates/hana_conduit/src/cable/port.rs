@@ -0,0 +1,444 @@
+//! [`CablePort`], [`CableConnector`], and [`DraggedCableEnd`]: sockets a
+//! cable end plugs into, and the drag that carries an end between them,
+//! plus `follow_dragged_ends`, `on_cable_end_grabbed`, and
+//! `on_cable_end_released`.
+
+use std::borrow::Cow;
+
+use bevy::ecs::relationship::RelationshipTarget;
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::AttachedEndpoints;
+use super::AttachedTo;
+use super::CableEndpoint;
+use super::EndpointExit;
+use super::compute::CableSystems;
+use super::constants::DEFAULT_GRAB_RADIUS;
+use super::constants::DEFAULT_PORT_LEAD_LENGTH;
+use super::constants::DEFAULT_PORT_SNAP_RADIUS;
+use super::drag;
+use super::drag::PointerDrags;
+
+pub(super) struct PortPlugin;
+
+impl Plugin for PortPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CableConnectionSettings>()
+            .init_resource::<PointerDrags>()
+            .add_systems(Update, follow_dragged_ends.before(CableSystems::Compute))
+            .add_observer(on_cable_end_grabbed)
+            .add_observer(on_cable_end_released)
+            .add_observer(drag::on_pointer_drag_start)
+            .add_observer(drag::on_pointer_drag)
+            .add_observer(drag::on_pointer_drag_end);
+    }
+}
+
+/// The kind of plug a [`CableConnector`] carries and a [`CablePort`] accepts.
+/// A port only takes ends whose kind matches its own.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub struct ConnectorKind(pub Cow<'static, str>);
+
+impl ConnectorKind {
+    /// Create a connector kind named `name`.
+    #[must_use]
+    pub const fn new(name: &'static str) -> Self { Self(Cow::Borrowed(name)) }
+}
+
+/// A socket cable ends plug into.
+///
+/// The socket sits at `offset` in the port entity's local space. A connected
+/// end is [`AttachedTo`] the port entity, so it follows the port as it moves,
+/// and leaves the socket along `exit` as a straight lead. A port holds at most
+/// `capacity` ends at once; every endpoint attached to the port entity counts.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+#[require(Transform)]
+pub struct CablePort {
+    /// Socket position in the port entity's local space.
+    pub offset:      Vec3,
+    /// Direction a connected cable leaves the socket, in the port entity's
+    /// local space.
+    pub exit:        Dir3,
+    /// Length of the straight lead a connected cable leaves along `exit`, in
+    /// metres. Zero leaves the exit to the solver.
+    pub lead_length: f32,
+    /// The connector kind this port accepts.
+    pub accepts:     ConnectorKind,
+    /// How many cable ends the port holds at once.
+    pub capacity:    usize,
+}
+
+impl CablePort {
+    /// Create a port accepting `accepts`, with its socket at the entity's
+    /// origin, exiting along `+Y` with the default lead, and holding one end.
+    #[must_use]
+    pub const fn new(accepts: ConnectorKind) -> Self {
+        Self {
+            offset: Vec3::ZERO,
+            exit: Dir3::Y,
+            lead_length: DEFAULT_PORT_LEAD_LENGTH,
+            accepts,
+            capacity: 1,
+        }
+    }
+
+    /// Set the socket position in the port entity's local space.
+    #[must_use]
+    pub const fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the direction a connected cable leaves the socket.
+    #[must_use]
+    pub const fn with_exit(mut self, exit: Dir3) -> Self {
+        self.exit = exit;
+        self
+    }
+
+    /// Set the length of the straight lead along the exit direction.
+    #[must_use]
+    pub const fn with_lead_length(mut self, lead_length: f32) -> Self {
+        self.lead_length = lead_length;
+        self
+    }
+
+    /// Set how many cable ends the port holds at once.
+    #[must_use]
+    pub const fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Whether the port takes an end carrying `connector` while holding
+    /// `occupied` ends.
+    fn admits(
+        &self,
+        connector: Option<&CableConnector>,
+        occupied: usize,
+    ) -> Result<(), RejectionReason> {
+        let Some(connector) = connector else {
+            return Err(RejectionReason::IncompatibleKind);
+        };
+        if connector.kind != self.accepts {
+            return Err(RejectionReason::IncompatibleKind);
+        }
+        if occupied >= self.capacity {
+            return Err(RejectionReason::PortFull);
+        }
+        Ok(())
+    }
+
+    /// The exit a connected endpoint takes.
+    fn endpoint_exit(&self) -> EndpointExit {
+        if self.lead_length > 0.0 {
+            EndpointExit::Lead {
+                axis:   self.exit,
+                length: self.lead_length,
+            }
+        } else {
+            EndpointExit::Unconstrained
+        }
+    }
+}
+
+/// The plug on a cable end. Only ends carrying one can be grabbed by a
+/// pointer drag or connect to a [`CablePort`].
+#[derive(Component, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct CableConnector {
+    /// The connector kind, matched against [`CablePort::accepts`].
+    pub kind: ConnectorKind,
+}
+
+impl CableConnector {
+    /// Create a connector of `kind`.
+    #[must_use]
+    pub const fn new(kind: ConnectorKind) -> Self { Self { kind } }
+}
+
+/// A cable end being carried to a new position.
+///
+/// While present, the end is world-attached and follows `target`, snapping to
+/// the nearest compatible [`CablePort`] with room within
+/// [`CableConnectionSettings::snap_radius`]; the cable re-solves every frame,
+/// previewing the route it will settle into. Inserting it unplugs the end
+/// from whatever it was attached to; removing it drops the end, connecting it
+/// to the port it snapped to. Pointer drags insert and remove it on the ends
+/// they grab, and other input can do the same.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DraggedCableEnd {
+    /// World-space point the end follows.
+    pub target:       Vec3,
+    /// The port the end is snapped to, if any. Written by
+    /// `follow_dragged_ends`.
+    pub snapped_port: Option<Entity>,
+}
+
+impl DraggedCableEnd {
+    /// Start carrying an end toward `target`.
+    #[must_use]
+    pub const fn new(target: Vec3) -> Self {
+        Self {
+            target,
+            snapped_port: None,
+        }
+    }
+}
+
+/// Distances used when grabbing and dropping cable ends.
+#[derive(Resource, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct CableConnectionSettings {
+    /// Distance from a pointer's hit within which a drag grabs a
+    /// [`CableConnector`] end, in metres.
+    pub grab_radius: f32,
+    /// Distance within which a dragged end snaps to a [`CablePort`], in
+    /// metres.
+    pub snap_radius: f32,
+}
+
+impl Default for CableConnectionSettings {
+    fn default() -> Self {
+        Self {
+            grab_radius: DEFAULT_GRAB_RADIUS,
+            snap_radius: DEFAULT_PORT_SNAP_RADIUS,
+        }
+    }
+}
+
+/// Why a [`CablePort`] refused a cable end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum RejectionReason {
+    /// The port accepts a different [`ConnectorKind`], or the end carries no
+    /// [`CableConnector`].
+    IncompatibleKind,
+    /// The port already holds [`CablePort::capacity`] ends.
+    PortFull,
+}
+
+/// Triggered on a cable end when it is dropped into a [`CablePort`].
+#[derive(EntityEvent, Clone, Copy, Debug, Reflect)]
+pub struct CableConnected {
+    /// The endpoint that connected.
+    pub entity: Entity,
+    /// The cable the endpoint belongs to.
+    pub cable:  Entity,
+    /// The port it connected to.
+    pub port:   Entity,
+}
+
+/// Triggered on a cable end when a drag pulls it out of a [`CablePort`].
+#[derive(EntityEvent, Clone, Copy, Debug, Reflect)]
+pub struct CableDisconnected {
+    /// The endpoint that disconnected.
+    pub entity: Entity,
+    /// The cable the endpoint belongs to.
+    pub cable:  Entity,
+    /// The port it was pulled out of.
+    pub port:   Entity,
+}
+
+/// Triggered on a cable end dropped within snap range of a [`CablePort`] that
+/// refused it, with no port nearby that would take it. The end stays where it
+/// was dropped.
+#[derive(EntityEvent, Clone, Copy, Debug, Reflect)]
+pub struct ConnectionRejected {
+    /// The endpoint that was refused.
+    pub entity: Entity,
+    /// The cable the endpoint belongs to.
+    pub cable:  Entity,
+    /// The nearest port that refused it.
+    pub port:   Entity,
+    /// Why the port refused it.
+    pub reason: RejectionReason,
+}
+
+/// Ports in snap range of a dragged end: the nearest that would take it, and
+/// the nearest that would not.
+#[derive(Default)]
+struct NearbyPorts {
+    admitting: Option<(Entity, Vec3)>,
+    refusing:  Option<(Entity, RejectionReason)>,
+}
+
+type PortQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static CablePort,
+        &'static GlobalTransform,
+        Option<&'static AttachedEndpoints>,
+    ),
+>;
+
+/// World position of `endpoint`, resolved through its [`AttachedTo`] target
+/// when it has one.
+pub(super) fn endpoint_world_position(
+    endpoint: &CableEndpoint,
+    attached: Option<&AttachedTo>,
+    transforms: &Query<&GlobalTransform>,
+) -> Option<Vec3> {
+    match attached {
+        Some(attached) => transforms
+            .get(attached.0)
+            .ok()
+            .map(|transform| transform.transform_point(endpoint.offset)),
+        None => Some(endpoint.offset),
+    }
+}
+
+/// Moves each [`DraggedCableEnd`] to its target, or to the socket of the
+/// nearest compatible port in snap range.
+fn follow_dragged_ends(
+    settings: Res<CableConnectionSettings>,
+    mut dragged: Query<(
+        &mut DraggedCableEnd,
+        &mut CableEndpoint,
+        Option<&CableConnector>,
+    )>,
+    ports: PortQuery,
+) {
+    for (mut dragged_cable_end, mut endpoint, connector) in &mut dragged {
+        let nearby = nearby_ports(
+            dragged_cable_end.target,
+            connector,
+            &ports,
+            settings.snap_radius,
+        );
+        let snapped_port = nearby.admitting.map(|(port, _)| port);
+        if dragged_cable_end.snapped_port != snapped_port {
+            dragged_cable_end.snapped_port = snapped_port;
+        }
+        let position = nearby
+            .admitting
+            .map_or(dragged_cable_end.target, |(_, socket)| socket);
+        if endpoint.offset != position {
+            endpoint.offset = position;
+        }
+    }
+}
+
+/// Unplugs an end as a drag picks it up, converting it to world-attached at
+/// its current position. Pulling it out of a [`CablePort`] triggers
+/// [`CableDisconnected`].
+fn on_cable_end_grabbed(
+    trigger: On<Insert, DraggedCableEnd>,
+    mut endpoints: Query<(&mut CableEndpoint, &ChildOf, Option<&AttachedTo>)>,
+    ports: Query<(), With<CablePort>>,
+    transforms: Query<&GlobalTransform>,
+    mut commands: Commands,
+) {
+    let endpoint_entity = trigger.event_target();
+    let Ok((mut endpoint, child_of, Some(attached))) = endpoints.get_mut(endpoint_entity) else {
+        return;
+    };
+    let target = attached.0;
+    if let Some(position) = endpoint_world_position(&endpoint, Some(attached), &transforms) {
+        endpoint.offset = position;
+    }
+    commands.entity(endpoint_entity).remove::<AttachedTo>();
+
+    if ports.contains(target) {
+        endpoint.exit = EndpointExit::Unconstrained;
+        commands.trigger(CableDisconnected {
+            entity: endpoint_entity,
+            cable:  child_of.parent(),
+            port:   target,
+        });
+    }
+}
+
+/// Drops an end as its drag ends: into the nearest compatible [`CablePort`]
+/// in snap range, triggering [`CableConnected`], or loose where it was let go,
+/// triggering [`ConnectionRejected`] if a port in range refused it.
+fn on_cable_end_released(
+    trigger: On<Remove, DraggedCableEnd>,
+    settings: Res<CableConnectionSettings>,
+    mut endpoints: Query<(
+        &DraggedCableEnd,
+        &mut CableEndpoint,
+        &ChildOf,
+        Option<&CableConnector>,
+    )>,
+    ports: PortQuery,
+    mut commands: Commands,
+) {
+    let endpoint_entity = trigger.event_target();
+    let Ok((dragged_cable_end, mut endpoint, child_of, connector)) =
+        endpoints.get_mut(endpoint_entity)
+    else {
+        return;
+    };
+    let cable = child_of.parent();
+    let nearby = nearby_ports(
+        dragged_cable_end.target,
+        connector,
+        &ports,
+        settings.snap_radius,
+    );
+
+    if let Some((port_entity, _)) = nearby.admitting
+        && let Ok((_, port, _, _)) = ports.get(port_entity)
+    {
+        endpoint.offset = port.offset;
+        endpoint.exit = port.endpoint_exit();
+        commands
+            .entity(endpoint_entity)
+            .try_insert(AttachedTo(port_entity));
+        commands.trigger(CableConnected {
+            entity: endpoint_entity,
+            cable,
+            port: port_entity,
+        });
+        return;
+    }
+
+    endpoint.offset = dragged_cable_end.target;
+    if let Some((port, reason)) = nearby.refusing {
+        commands.trigger(ConnectionRejected {
+            entity: endpoint_entity,
+            cable,
+            port,
+            reason,
+        });
+    }
+}
+
+/// The nearest port within `snap_radius` of `target` that admits `connector`,
+/// and the nearest that refuses it.
+fn nearby_ports(
+    target: Vec3,
+    connector: Option<&CableConnector>,
+    ports: &PortQuery,
+    snap_radius: f32,
+) -> NearbyPorts {
+    let mut nearby = NearbyPorts::default();
+    let mut admitting_distance = f32::INFINITY;
+    let mut refusing_distance = f32::INFINITY;
+    for (port_entity, port, transform, attached_endpoints) in ports {
+        let socket = transform.transform_point(port.offset);
+        let distance = socket.distance(target);
+        if distance > snap_radius {
+            continue;
+        }
+        let occupied = attached_endpoints.map_or(0, RelationshipTarget::len);
+        match port.admits(connector, occupied) {
+            Ok(()) if distance < admitting_distance => {
+                admitting_distance = distance;
+                nearby.admitting = Some((port_entity, socket));
+            },
+            Err(reason) if distance < refusing_distance => {
+                refusing_distance = distance;
+                nearby.refusing = Some((port_entity, reason));
+            },
+            _ => {},
+        }
+    }
+    nearby
+}
@@ -1,133 +1,312 @@
-//! `RouteObstacle` — declares an entity as a cable-routing obstacle — and
-//! `resolve_obstacles`, which snapshots every tagged entity into the routing
-//! layer's world-space [`Obstacle`] boxes at recompute time.
+//! `RouteObstacle` — declares an entity as a cable-routing obstacle —
+//! `ObstacleSources`, which snapshots every tagged entity into the routing
+//! layer's world-space [`Obstacle`] shapes, and every [`CableSupport`] into
+//! [`SupportSurface`]s, and `WorldObstacles`, which holds the latest snapshot
+//! indexed for routing.
+
+use std::sync::Arc;
 
 use bevy::camera::primitives::Aabb;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::ecs::system::SystemParam;
+use bevy::mesh::Indices;
+use bevy::mesh::PrimitiveTopology;
+use bevy::mesh::VertexAttributeValues;
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
+use bevy_kana::ToUsize;
 
+use super::Cable;
+use super::support::CableSupport;
 use crate::routing::Obstacle;
+use crate::routing::ObstacleIndex;
+use crate::routing::SupportSurface;
 
 /// Declares that cables route around this entity.
 ///
 /// The component stores no position: each recompute reads the entity's live
-/// `GlobalTransform` through `resolve_obstacles` and produces a fresh
-/// world-space [`Obstacle`] snapshot for the solver, so there is no stored
-/// copy of the transform to go stale.
+/// `GlobalTransform` through `ObstacleSources` and produces fresh world-space
+/// [`Obstacle`] snapshots for the solver, so there is no stored copy of the
+/// transform to go stale. Every variant follows the entity's rotation and
+/// scale.
 #[derive(Component, Clone, Copy, Debug, Default, Reflect)]
 #[reflect(Component)]
 pub enum RouteObstacle {
-    /// Bounds derived from the render [`Aabb`]s of the entity and its
-    /// descendants — covers entities whose meshes live on child entities.
+    /// One oriented box per render [`Aabb`] on the entity and its descendants
+    /// — covers entities whose meshes live on child entities, and keeps a
+    /// rotated or multi-part entity from inflating into one enclosing box.
     #[default]
     FromRenderAabb,
     /// Explicit local half-extents centered on the entity, for entities whose
     /// visual bounds differ from the bounds cables should respect.
     HalfExtents(Vec3),
+    /// A sphere of this local radius centered on the entity.
+    Sphere(f32),
+    /// A capsule centered on the entity along its local Y axis, matching
+    /// Bevy's `Capsule3d`.
+    Capsule {
+        /// Radius of the capsule.
+        radius:      f32,
+        /// Half the length of the capsule's straight section.
+        half_length: f32,
+    },
+    /// Convex hulls of the triangle meshes on the entity and its descendants,
+    /// each mesh decomposed into at most `pieces` hulls so a concave shape
+    /// blocks only its parts rather than the space between them.
+    FromMesh {
+        /// Maximum hulls per mesh; `1` wraps each mesh in a single hull.
+        pieces: u32,
+    },
 }
 
-/// World-space bounds accumulated from transformed box corners.
-struct WorldExtents {
-    min: Vec3,
-    max: Vec3,
+/// The queries `RouteObstacle` resolution reads, bundled so every system that
+/// needs world obstacles resolves them the same way.
+#[derive(SystemParam)]
+pub(crate) struct ObstacleSources<'w, 's> {
+    route_obstacles: Query<'w, 's, (Entity, &'static RouteObstacle, &'static GlobalTransform)>,
+    supports:        Query<'w, 's, (Entity, &'static CableSupport, &'static GlobalTransform)>,
+    children:        Query<'w, 's, &'static Children>,
+    parents:         Query<'w, 's, &'static ChildOf>,
+    aabbs:           Query<'w, 's, (&'static Aabb, &'static GlobalTransform)>,
+    meshes:          Query<'w, 's, (&'static Mesh3d, &'static GlobalTransform)>,
+    mesh_assets:     Res<'w, Assets<Mesh>>,
 }
 
-impl WorldExtents {
-    fn include(&mut self, corner: Vec3) {
-        self.min = self.min.min(corner);
-        self.max = self.max.max(corner);
+impl ObstacleSources<'_, '_> {
+    /// Snapshot every [`RouteObstacle`] entity into world-space [`Obstacle`]s.
+    /// Entities whose shape cannot be resolved yet (no render [`Aabb`] or
+    /// loaded mesh anywhere in their tree) contribute nothing.
+    pub(crate) fn resolve(&self) -> Vec<Obstacle> {
+        self.route_obstacles
+            .iter()
+            .flat_map(|(entity, route_obstacle, transform)| {
+                self.obstacles_of(entity, *route_obstacle, transform)
+            })
+            .collect()
     }
-}
 
-impl From<Vec3> for WorldExtents {
-    fn from(corner: Vec3) -> Self {
-        Self {
-            min: corner,
-            max: corner,
+    /// Snapshot one [`RouteObstacle`] entity into world-space [`Obstacle`]s;
+    /// nothing when `entity` is not an obstacle.
+    pub(crate) fn resolve_entity(&self, entity: Entity) -> Vec<Obstacle> {
+        self.route_obstacles
+            .get(entity)
+            .map(|(_, route_obstacle, transform)| {
+                self.obstacles_of(entity, *route_obstacle, transform)
+            })
+            .unwrap_or_default()
+    }
+
+    /// The [`RouteObstacle`] entities whose tree holds `part` — `part` itself
+    /// and every obstacle among its ancestors.
+    pub(crate) fn obstacles_containing(&self, part: Entity) -> impl Iterator<Item = Entity> + '_ {
+        std::iter::once(part)
+            .chain(self.parents.iter_ancestors(part))
+            .filter(|&candidate| self.route_obstacles.contains(candidate))
+    }
+
+    /// The [`RouteObstacle::FromMesh`] entities whose tree draws one of
+    /// `meshes`.
+    pub(crate) fn obstacles_using<'a>(
+        &'a self,
+        meshes: &'a HashSet<AssetId<Mesh>>,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        self.route_obstacles
+            .iter()
+            .filter(|(_, route_obstacle, _)| {
+                matches!(route_obstacle, RouteObstacle::FromMesh { .. })
+            })
+            .map(|(entity, _, _)| entity)
+            .filter(move |&entity| {
+                self.tree(entity).any(|candidate| {
+                    self.meshes
+                        .get(candidate)
+                        .is_ok_and(|(mesh3d, _)| meshes.contains(&mesh3d.id()))
+                })
+            })
+    }
+
+    /// Snapshot every [`CableSupport`] entity into world-space
+    /// [`SupportSurface`]s. An obstacle-top support on an entity without a
+    /// [`RouteObstacle`] contributes nothing.
+    pub(crate) fn resolve_supports(&self) -> Vec<SupportSurface> {
+        self.supports
+            .iter()
+            .flat_map(|(entity, support, transform)| match support {
+                CableSupport::Floor => vec![SupportSurface::plane(transform.translation())],
+                CableSupport::ObstacleTop => self
+                    .route_obstacles
+                    .get(entity)
+                    .map(|(_, route_obstacle, _)| {
+                        self.obstacles_of(entity, *route_obstacle, transform)
+                            .iter()
+                            .map(SupportSurface::top_of)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    /// The world-space shapes `route_obstacle` resolves to on `entity`.
+    fn obstacles_of(
+        &self,
+        entity: Entity,
+        route_obstacle: RouteObstacle,
+        transform: &GlobalTransform,
+    ) -> Vec<Obstacle> {
+        let (scale, rotation, translation) = transform.to_scale_rotation_translation();
+        match route_obstacle {
+            RouteObstacle::FromRenderAabb => self.render_boxes(entity),
+            RouteObstacle::HalfExtents(half_extents) => {
+                vec![Obstacle::oriented(
+                    half_extents * scale.abs(),
+                    translation,
+                    rotation,
+                )]
+            },
+            RouteObstacle::Sphere(radius) => {
+                vec![Obstacle::sphere(
+                    radius * scale.abs().max_element(),
+                    translation,
+                )]
+            },
+            RouteObstacle::Capsule {
+                radius,
+                half_length,
+            } => vec![Obstacle::capsule(
+                radius * scale.x.abs().max(scale.z.abs()),
+                transform.transform_point(Vec3::NEG_Y * half_length),
+                transform.transform_point(Vec3::Y * half_length),
+            )],
+            RouteObstacle::FromMesh { pieces } => self.mesh_hulls(entity, pieces.to_usize()),
         }
     }
-}
 
-impl From<WorldExtents> for Obstacle {
-    fn from(extents: WorldExtents) -> Self {
-        let half_extents = (extents.max - extents.min) / 2.0;
-        Self::new(half_extents, (extents.min + extents.max) / 2.0)
+    /// The entity and its descendants, in hierarchy order.
+    fn tree(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        std::iter::once(entity).chain(self.children.iter_descendants(entity))
+    }
+
+    /// One oriented box per render [`Aabb`] in `entity`'s tree.
+    fn render_boxes(&self, entity: Entity) -> Vec<Obstacle> {
+        self.tree(entity)
+            .filter_map(|candidate| self.aabbs.get(candidate).ok())
+            .map(|(aabb, transform)| {
+                let (scale, rotation, _) = transform.to_scale_rotation_translation();
+                Obstacle::oriented(
+                    Vec3::from(aabb.half_extents) * scale.abs(),
+                    transform.transform_point(aabb.center.into()),
+                    rotation,
+                )
+            })
+            .collect()
+    }
+
+    /// Convex decompositions of every loaded triangle mesh in `entity`'s tree.
+    fn mesh_hulls(&self, entity: Entity, pieces: usize) -> Vec<Obstacle> {
+        self.tree(entity)
+            .filter_map(|candidate| self.meshes.get(candidate).ok())
+            .filter_map(|(mesh3d, transform)| {
+                self.mesh_assets
+                    .get(&mesh3d.0)
+                    .and_then(|mesh| world_triangles(mesh, transform))
+            })
+            .flat_map(|triangles| Obstacle::convex_decomposition(&triangles, pieces))
+            .collect()
     }
 }
 
-/// Snapshot every [`RouteObstacle`] entity into a world-space [`Obstacle`] box.
-/// Entities whose bounds cannot be resolved (no render [`Aabb`] anywhere in
-/// their tree yet) contribute nothing.
-pub(super) fn resolve_obstacles(
-    route_obstacles: &Query<(Entity, &RouteObstacle, &GlobalTransform)>,
-    children: &Query<&Children>,
-    aabbs: &Query<&Aabb>,
-    transforms: &Query<&GlobalTransform>,
-) -> Vec<Obstacle> {
-    route_obstacles
-        .iter()
-        .filter_map(|(entity, route_obstacle, transform)| {
-            match route_obstacle {
-                RouteObstacle::FromRenderAabb => {
-                    render_extents(entity, children, aabbs, transforms)
-                },
-                RouteObstacle::HalfExtents(half_extents) => {
-                    let mut extents = None;
-                    include_world_corners(&mut extents, -*half_extents, *half_extents, transform);
-                    extents
-                },
-            }
-            .map(Obstacle::from)
-        })
-        .collect()
+/// The world's resolved [`RouteObstacle`]s and [`CableSupport`]s, indexed
+/// once and shared by every cable routed against them. `queue_obstacle_changes`
+/// rebuilds it only when an obstacle or support changes — including a render
+/// [`Aabb`], mesh, or transform anywhere in an obstacle's tree, and a mesh
+/// asset an obstacle draws finishing its load.
+#[derive(Resource)]
+pub(super) struct WorldObstacles {
+    index:         Arc<ObstacleIndex>,
+    supports:      Arc<[SupportSurface]>,
+    /// Each cable with static obstacles of its own, indexed together with the
+    /// world's. Dropped on every rebuild and whenever the cable changes.
+    cable_indices: EntityHashMap<Arc<ObstacleIndex>>,
 }
 
-/// Merge the render [`Aabb`]s of `entity` and its descendants into world-space
-/// extents.
-fn render_extents(
-    entity: Entity,
-    children: &Query<&Children>,
-    aabbs: &Query<&Aabb>,
-    transforms: &Query<&GlobalTransform>,
-) -> Option<WorldExtents> {
-    let mut extents = None;
-    for candidate in std::iter::once(entity).chain(children.iter_descendants(entity)) {
-        let (Ok(aabb), Ok(transform)) = (aabbs.get(candidate), transforms.get(candidate)) else {
-            continue;
-        };
-        include_world_corners(
-            &mut extents,
-            aabb.min().into(),
-            aabb.max().into(),
-            transform,
-        );
+impl Default for WorldObstacles {
+    fn default() -> Self {
+        Self {
+            index:         Arc::new(ObstacleIndex::from_obstacles(&[])),
+            supports:      Arc::default(),
+            cable_indices: EntityHashMap::default(),
+        }
     }
-    extents
 }
 
-/// Transform the 8 corners of a local-space box into world space and merge
-/// them into `extents`, so a rotated box still yields a conservative
-/// axis-aligned world box.
-fn include_world_corners(
-    extents: &mut Option<WorldExtents>,
-    min: Vec3,
-    max: Vec3,
-    transform: &GlobalTransform,
-) {
-    let corners = [
-        Vec3::new(min.x, min.y, min.z),
-        Vec3::new(max.x, min.y, min.z),
-        Vec3::new(min.x, max.y, min.z),
-        Vec3::new(max.x, max.y, min.z),
-        Vec3::new(min.x, min.y, max.z),
-        Vec3::new(max.x, min.y, max.z),
-        Vec3::new(min.x, max.y, max.z),
-        Vec3::new(max.x, max.y, max.z),
-    ];
-    for corner in corners {
-        let world_corner = transform.transform_point(corner);
-        match extents {
-            Some(extents) => extents.include(world_corner),
-            None => *extents = Some(WorldExtents::from(world_corner)),
+impl WorldObstacles {
+    /// Re-resolves every obstacle and support from `obstacle_sources`.
+    pub(super) fn rebuild(&mut self, obstacle_sources: &ObstacleSources) {
+        *self = Self {
+            index:         Arc::new(ObstacleIndex::from_obstacles(&obstacle_sources.resolve())),
+            supports:      obstacle_sources.resolve_supports().into(),
+            cable_indices: EntityHashMap::default(),
+        };
+    }
+
+    /// The world's resolved support surfaces.
+    pub(super) const fn supports(&self) -> &Arc<[SupportSurface]> { &self.supports }
+
+    /// The index `cable` routes against: the world's own when the cable has
+    /// no static obstacles, otherwise the cable's obstacles and the world's
+    /// indexed together, built on first use.
+    pub(super) fn index_for(&mut self, cable_entity: Entity, cable: &Cable) -> Arc<ObstacleIndex> {
+        if cable.obstacles.is_empty() {
+            return Arc::clone(&self.index);
         }
+        let world = &self.index;
+        Arc::clone(self.cable_indices.entry(cable_entity).or_insert_with(|| {
+            let obstacles: Vec<Obstacle> = cable
+                .obstacles
+                .iter()
+                .chain(world.obstacles())
+                .cloned()
+                .collect();
+            Arc::new(ObstacleIndex::from_obstacles(&obstacles))
+        }))
+    }
+
+    /// Drops `cable_entity`'s index so its next route indexes its current
+    /// static obstacles.
+    pub(super) fn forget_cable(&mut self, cable_entity: Entity) {
+        self.cable_indices.remove(&cable_entity);
+    }
+}
+
+/// The world-space triangles of a `TriangleList` mesh, or `None` for other
+/// topologies and meshes without positions.
+fn world_triangles(mesh: &Mesh, transform: &GlobalTransform) -> Option<Vec<[Vec3; 3]>> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList {
+        return None;
     }
+    let positions = mesh
+        .attribute(Mesh::ATTRIBUTE_POSITION)
+        .and_then(VertexAttributeValues::as_float3)?;
+    let world = |index: usize| {
+        positions
+            .get(index)
+            .map(|&position| transform.transform_point(Vec3::from(position)))
+    };
+    let corners: Vec<usize> = mesh.indices().map_or_else(
+        || (0..positions.len()).collect(),
+        |indices: &Indices| indices.iter().collect(),
+    );
+    Some(
+        corners
+            .chunks_exact(3)
+            .filter_map(|triangle| {
+                Some([
+                    world(triangle[0])?,
+                    world(triangle[1])?,
+                    world(triangle[2])?,
+                ])
+            })
+            .collect(),
+    )
 }
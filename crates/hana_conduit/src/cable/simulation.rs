@@ -0,0 +1,106 @@
+//! Per-frame stepping for cables whose solver is [`Solver::Simulated`].
+//!
+//! `recompute_dirty_cables` writes a [`SimulationTarget`] — the anchors,
+//! obstacles, and supports of the latest recompute — instead of solving a static route.
+//! `simulate_cables` seeds a [`CableParticles`] chain from the catenary the
+//! first time it sees the cable, then steps it every frame toward the latest
+//! target, writing `ComputedCableGeometry` until the chain comes to rest.
+
+use std::sync::Arc;
+
+use bevy::prelude::*;
+
+use super::Cable;
+use super::compute::ComputedCableGeometry;
+use super::constants::SIMULATION_REST_DISTANCE;
+use crate::routing::Anchor;
+use crate::routing::CableSimulation;
+use crate::routing::ObstacleIndex;
+use crate::routing::RouteRequest;
+use crate::routing::Solver;
+use crate::routing::SupportSurface;
+
+/// Anchors, obstacles, and supports a simulated cable's chain is pinned to
+/// and collides with, refreshed by every recompute of the cable.
+#[derive(Component)]
+pub(super) struct SimulationTarget {
+    pub(super) start:          Anchor,
+    pub(super) end:            Anchor,
+    pub(super) obstacle_index: Arc<ObstacleIndex>,
+    pub(super) supports:       Arc<[SupportSurface]>,
+}
+
+/// A simulated cable's particle chain.
+#[derive(Component)]
+pub(super) struct CableParticles {
+    simulation: CableSimulation,
+    /// Whether the last step moved no particle farther than
+    /// [`SIMULATION_REST_DISTANCE`]. A resting chain is not stepped again
+    /// until its target changes, so a still cable does not rebuild its mesh
+    /// every frame.
+    resting:    bool,
+}
+
+/// Steps every simulated cable's [`CableParticles`] toward its
+/// [`SimulationTarget`] and writes the chain to `ComputedCableGeometry`.
+pub(super) fn simulate_cables(
+    time: Res<Time>,
+    mut cables: Query<(
+        Entity,
+        &Cable,
+        Ref<SimulationTarget>,
+        Option<&mut CableParticles>,
+    )>,
+    mut commands: Commands,
+) {
+    for (cable_entity, cable, simulation_target, particles) in &mut cables {
+        let Solver::Simulated(solver) = &cable.solver else {
+            // The cable switched to a static solver, whose recompute already
+            // wrote its geometry.
+            commands
+                .entity(cable_entity)
+                .remove::<(SimulationTarget, CableParticles)>();
+            continue;
+        };
+
+        let request = RouteRequest::new(
+            simulation_target.start,
+            simulation_target.end,
+            cable.resolution,
+        )
+        .with_obstacles(simulation_target.obstacle_index.obstacles())
+        .with_supports(&simulation_target.supports)
+        .with_obstacle_index(&simulation_target.obstacle_index);
+        let resolution = request.effective_resolution(solver.catenary.resolution);
+
+        // First sight of the cable, or its resolution changed: seed a fresh
+        // chain on the catenary.
+        let Some(mut particles) =
+            particles.filter(|particles| particles.simulation.resolution() == resolution)
+        else {
+            let simulation = CableSimulation::seed(solver, &request);
+            commands.entity(cable_entity).insert((
+                ComputedCableGeometry {
+                    cable_geometry: Some(simulation.geometry(&request)),
+                },
+                CableParticles {
+                    simulation,
+                    resting: false,
+                },
+            ));
+            continue;
+        };
+
+        if particles.resting && !simulation_target.is_changed() {
+            continue;
+        }
+
+        particles
+            .simulation
+            .step(solver, &request, time.delta_secs());
+        particles.resting = particles.simulation.max_displacement() < SIMULATION_REST_DISTANCE;
+        commands.entity(cable_entity).insert(ComputedCableGeometry {
+            cable_geometry: Some(particles.simulation.geometry(&request)),
+        });
+    }
+}
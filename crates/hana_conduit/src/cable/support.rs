@@ -0,0 +1,25 @@
+//! [`CableSupport`]: floors and obstacle tops that catenary cables rest on
+//! instead of sagging through.
+
+use bevy::prelude::*;
+
+/// Declares a surface that sagging cables lie on — the floor of a room, or
+/// the top of a table.
+///
+/// Like [`RouteObstacle`](super::RouteObstacle), the component stores no
+/// position: each recompute reads the entity's live `GlobalTransform`. A
+/// catenary cable that would sag through a support rests on it, lifting off
+/// level with the surface into hanging spans at either end; a simulated cable
+/// collides with it. The cable's centreline lies on the surface, so place a
+/// floor a tube radius above the visible ground to rest the tube on top of it.
+#[derive(Component, Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+#[reflect(Component)]
+pub enum CableSupport {
+    /// An unbounded horizontal floor through the entity's origin.
+    #[default]
+    Floor,
+    /// The top face of each shape the entity's
+    /// [`RouteObstacle`](super::RouteObstacle) resolves to. A cable rests on
+    /// a top only where its whole run on the surface fits within it.
+    ObstacleTop,
+}
@@ -0,0 +1,125 @@
+//! [`CableTension`], [`OverStretchPolicy`], and [`CableOverStretched`]: how
+//! taut a cable with an absolute length is, and what happens when its
+//! endpoints pull farther apart than the cable reaches.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::compute::ResolvedEnd;
+use super::constants::OVER_STRETCH_TENSION_THRESHOLD;
+use super::constants::TAUT_TENSION_THRESHOLD;
+use crate::routing::MIN_SEGMENT_LENGTH;
+
+/// How taut a cable with an absolute length
+/// ([`CatenarySolver::with_length`](crate::CatenarySolver::with_length)) is,
+/// written on every recompute of the cable.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct CableTension {
+    /// Length of the shortest path the route allows over the cable's length:
+    /// below `1.0` the cable sags, at `1.0` it is pulled straight, and above
+    /// `1.0` its endpoints are farther apart than the cable reaches.
+    pub tension: f32,
+    /// `tension` bucketed for UI.
+    pub state:   TensionState,
+}
+
+impl CableTension {
+    pub(super) fn new(tension: f32) -> Self {
+        let state = if tension > OVER_STRETCH_TENSION_THRESHOLD {
+            TensionState::OverStretched
+        } else if tension >= TAUT_TENSION_THRESHOLD {
+            TensionState::Taut
+        } else {
+            TensionState::Slack
+        };
+        Self { tension, state }
+    }
+}
+
+/// Whether a cable hangs loose, is pulled straight, or is pulled past its
+/// length.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
+pub enum TensionState {
+    /// The cable is longer than the route and sags.
+    #[default]
+    Slack,
+    /// The cable is pulled (nearly) straight along its route.
+    Taut,
+    /// The endpoints are farther apart than the cable reaches. The cable is
+    /// drawn straight, and the cable's [`OverStretchPolicy`] applies.
+    OverStretched,
+}
+
+/// What happens when a cable's endpoints pull farther apart than its absolute
+/// length. Cables without this component [`Report`](Self::Report).
+#[derive(
+    Component, Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Reflect,
+)]
+#[reflect(Component)]
+pub enum OverStretchPolicy {
+    /// Only report the over-stretch, through [`CableTension`] and
+    /// [`CableOverStretched`]; the cable is drawn straight.
+    #[default]
+    Report,
+    /// Hold the endpoint that moved at the cable's length, pulling it back
+    /// toward the opposite endpoint. An endpoint attached to a target entity
+    /// cannot be held and only reports.
+    ClampEndpoint,
+    /// Detach the endpoint opposite the one that moved — the plug pops out of
+    /// its jack — and apply that endpoint's
+    /// [`DetachPolicy`](super::DetachPolicy). When only the moved endpoint is
+    /// attached, it detaches instead.
+    Detach,
+}
+
+/// Triggered on a cable when its [`CableTension`] becomes
+/// [`TensionState::OverStretched`].
+#[derive(EntityEvent, Clone, Copy, Debug, Reflect)]
+pub struct CableOverStretched {
+    /// The over-stretched cable.
+    pub entity:  Entity,
+    /// The cable's tension when it became over-stretched.
+    pub tension: f32,
+}
+
+/// Pull the world-placed endpoint that moved back toward the opposite endpoint
+/// by `overshoot` metres, for [`OverStretchPolicy::ClampEndpoint`]. Returns the
+/// held endpoint, or `None` when no endpoint that can be held moved.
+pub(super) fn clamp_moved_end(
+    start: &mut ResolvedEnd,
+    end: &mut ResolvedEnd,
+    overshoot: f32,
+) -> Option<ResolvedEnd> {
+    let (moved, fixed) = if end.moved && !end.attached {
+        (end, &*start)
+    } else if start.moved && !start.attached {
+        (start, &*end)
+    } else {
+        return None;
+    };
+
+    let offset = moved.anchor.position - fixed.anchor.position;
+    let distance = offset.length();
+    if distance <= MIN_SEGMENT_LENGTH {
+        return None;
+    }
+    let held_distance = (distance - overshoot).max(MIN_SEGMENT_LENGTH);
+    moved.anchor.position = fixed.anchor.position + offset * (held_distance / distance);
+    Some(*moved)
+}
+
+/// The attached endpoint [`OverStretchPolicy::Detach`] pops out: the one
+/// opposite the endpoint that moved, else the moved endpoint itself.
+pub(super) fn end_to_detach(start: &ResolvedEnd, end: &ResolvedEnd) -> Option<Entity> {
+    let (moved, opposite) = if end.moved {
+        (end, start)
+    } else {
+        (start, end)
+    };
+    [opposite, moved]
+        .into_iter()
+        .find(|resolved_end| resolved_end.attached)
+        .map(|resolved_end| resolved_end.entity)
+}
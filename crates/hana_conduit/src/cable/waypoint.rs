@@ -0,0 +1,54 @@
+//! [`CableWaypoint`]: user-authored points a cable's route passes through.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::routing::ViaPoint;
+
+/// A point the cable's route must pass through.
+///
+/// Spawn it as a child of a [`Cable`](super::Cable) alongside its
+/// [`CableEndpoint`](super::CableEndpoint)s to hook a cable over a hanger or
+/// thread it through a cable tray.
+///
+/// Waypoints are visited in ascending `index` order (ties broken by entity),
+/// and the path strategy plans each leg between them. Simulated cables hang
+/// freely and ignore waypoints.
+#[derive(Component, Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct CableWaypoint {
+    /// Position of this waypoint in the cable's route order.
+    pub index:    u32,
+    /// World-space position the route passes through.
+    pub position: Vec3,
+    /// World-space direction the cable must run through `position`, or `None`
+    /// to let the route bend freely there.
+    pub tangent:  Option<Dir3>,
+}
+
+impl CableWaypoint {
+    /// Create a waypoint without a required tangent.
+    #[must_use]
+    pub fn new(index: u32, position: impl Into<Vec3>) -> Self {
+        Self {
+            index,
+            position: position.into(),
+            tangent: None,
+        }
+    }
+
+    /// Require the cable to run along `tangent` through this waypoint.
+    #[must_use]
+    pub const fn with_tangent(mut self, tangent: Dir3) -> Self {
+        self.tangent = Some(tangent);
+        self
+    }
+
+    pub(super) const fn via_point(&self) -> ViaPoint {
+        ViaPoint {
+            position: self.position,
+            tangent:  self.tangent,
+        }
+    }
+}
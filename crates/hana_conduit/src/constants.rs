@@ -3,6 +3,14 @@ use bevy::color::Color;
 // cable gizmo
 pub(crate) const CABLE_GIZMO_COLOR: Color = Color::srgb(1.0, 0.6, 0.0);
 
+// obstacle
+pub(crate) const OBSTACLE_GIZMO_COLOR: Color = Color::srgb(0.0, 0.6, 1.0);
+
+// route outcome
+pub(crate) const ENDPOINT_PUSHED_OUT_GIZMO_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+pub(crate) const ROUTE_EXHAUSTED_GIZMO_COLOR: Color = Color::srgb(0.6, 0.0, 1.0);
+pub(crate) const ROUTE_FALLBACK_GIZMO_COLOR: Color = Color::srgb(1.0, 0.0, 0.5);
+
 // segment boundary
 pub(crate) const SEGMENT_BOUNDARY_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
 pub(crate) const SEGMENT_BOUNDARY_DOT_SIZE: f32 = 0.03;
@@ -1,9 +1,16 @@
 use bevy::prelude::*;
 use bevy_kana::ToUsize;
 
+use crate::cable::Cable;
+use crate::cable::CableRouteOutcome;
 use crate::cable::CableSystems;
 use crate::cable::ComputedCableGeometry;
+use crate::cable::ObstacleSources;
 use crate::constants::CABLE_GIZMO_COLOR;
+use crate::constants::ENDPOINT_PUSHED_OUT_GIZMO_COLOR;
+use crate::constants::OBSTACLE_GIZMO_COLOR;
+use crate::constants::ROUTE_EXHAUSTED_GIZMO_COLOR;
+use crate::constants::ROUTE_FALLBACK_GIZMO_COLOR;
 use crate::constants::SEGMENT_BOUNDARY_COLOR;
 use crate::constants::SEGMENT_BOUNDARY_DOT_SIZE;
 use crate::constants::TANGENT_GIZMO_COLOR;
@@ -12,6 +19,9 @@ use crate::constants::TANGENT_VECTOR_SCALE;
 use crate::constants::WAYPOINT_DOT_COLOR;
 use crate::constants::WAYPOINT_DOT_SIZE;
 use crate::routing::MIN_CABLE_SAMPLE_POINTS;
+use crate::routing::Obstacle;
+use crate::routing::ObstacleShape;
+use crate::routing::RouteOutcome;
 
 pub(super) struct GizmosPlugin;
 
@@ -21,7 +31,12 @@ impl Plugin for GizmosPlugin {
             .init_gizmo_group::<CableGizmoGroup>()
             .add_systems(
                 Update,
-                (render_cable_gizmos, render_debug_gizmos)
+                (
+                    render_cable_gizmos,
+                    render_debug_gizmos,
+                    render_obstacle_gizmos,
+                    render_route_outcome_gizmos,
+                )
                     .chain()
                     .after(CableSystems::Compute),
             );
@@ -120,6 +135,104 @@ fn render_debug_gizmos(
     }
 }
 
+/// Renders the true shape of every obstacle cables route around: each cable's
+/// static obstacles and every resolved `RouteObstacle` entity.
+fn render_obstacle_gizmos(
+    cables: Query<&Cable>,
+    obstacle_sources: ObstacleSources,
+    mut gizmos: Gizmos<CableGizmoGroup>,
+    debug_gizmos: Res<DebugGizmos>,
+) {
+    if *debug_gizmos == DebugGizmos::Disabled {
+        return;
+    }
+
+    let world_obstacles = obstacle_sources.resolve();
+    for obstacle in cables
+        .iter()
+        .flat_map(|cable| &cable.obstacles)
+        .chain(&world_obstacles)
+    {
+        draw_obstacle(&mut gizmos, obstacle);
+    }
+}
+
+/// Renders why a route went wrong: a route that fell back to running straight
+/// through obstacles is redrawn in a color naming the reason, and a pushed-out
+/// endpoint gets a line to the clear cell the search began from.
+fn render_route_outcome_gizmos(
+    cables: Query<(&ComputedCableGeometry, &CableRouteOutcome)>,
+    mut gizmos: Gizmos<CableGizmoGroup>,
+    debug_gizmos: Res<DebugGizmos>,
+) {
+    if *debug_gizmos == DebugGizmos::Disabled {
+        return;
+    }
+
+    for (computed_cable_geometry, route_outcome) in &cables {
+        let color = match route_outcome.0 {
+            RouteOutcome::FallbackToDirect => ROUTE_FALLBACK_GIZMO_COLOR,
+            RouteOutcome::SearchExhausted { .. } => ROUTE_EXHAUSTED_GIZMO_COLOR,
+            RouteOutcome::EndpointPushedOut { endpoint, clear } => {
+                gizmos.line(endpoint, clear, ENDPOINT_PUSHED_OUT_GIZMO_COLOR);
+                draw_dot(
+                    &mut gizmos,
+                    clear,
+                    WAYPOINT_DOT_SIZE,
+                    ENDPOINT_PUSHED_OUT_GIZMO_COLOR,
+                );
+                continue;
+            },
+            RouteOutcome::Success
+            | RouteOutcome::OrthogonalAxes { .. }
+            | RouteOutcome::OrthogonalDetour => continue,
+        };
+        let Some(cable_geometry) = &computed_cable_geometry.cable_geometry else {
+            continue;
+        };
+        for pair in cable_geometry.waypoints.windows(2) {
+            gizmos.line(pair[0], pair[1], color);
+        }
+    }
+}
+
+fn draw_obstacle(gizmos: &mut Gizmos<CableGizmoGroup>, obstacle: &Obstacle) {
+    match &obstacle.shape {
+        ObstacleShape::Cuboid {
+            half_extents,
+            position,
+            rotation,
+        } => {
+            gizmos.cube(
+                Transform::from_translation(*position)
+                    .with_rotation(*rotation)
+                    .with_scale(*half_extents * 2.0),
+                OBSTACLE_GIZMO_COLOR,
+            );
+        },
+        ObstacleShape::Sphere { center, radius } => {
+            gizmos.sphere(
+                Isometry3d::from_translation(*center),
+                *radius,
+                OBSTACLE_GIZMO_COLOR,
+            );
+        },
+        ObstacleShape::Capsule { start, end, radius } => {
+            let axis = (*end - *start).try_normalize().unwrap_or(Vec3::Y);
+            gizmos.primitive_3d(
+                &Capsule3d::new(*radius, start.distance(*end)),
+                Isometry3d::new(start.midpoint(*end), Quat::from_rotation_arc(Vec3::Y, axis)),
+                OBSTACLE_GIZMO_COLOR,
+            );
+        },
+        ObstacleShape::ConvexHull(hull) => {
+            for (from, to) in hull.edges() {
+                gizmos.line(from, to, OBSTACLE_GIZMO_COLOR);
+            }
+        },
+    }
+}
+
 fn draw_dot(gizmos: &mut Gizmos<CableGizmoGroup>, point: Vec3, size: f32, color: Color) {
     gizmos.line(point - Vec3::X * size, point + Vec3::X * size, color);
     gizmos.line(point - Vec3::Y * size, point + Vec3::Y * size, color);
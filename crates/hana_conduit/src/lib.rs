@@ -46,16 +46,45 @@ use bevy::prelude::*;
 // Cable
 pub use cable::AttachedEndpoints;
 pub use cable::AttachedTo;
+pub use cable::BackgroundSolve;
 pub use cable::Cable;
+pub use cable::CableAvoidance;
+pub use cable::CableBundle;
+pub use cable::CableConnected;
+pub use cable::CableConnectionSettings;
+pub use cable::CableConnector;
+pub use cable::CableDisconnected;
 pub use cable::CableEnd;
 pub use cable::CableEndpoint;
+pub use cable::CableNetwork;
+pub use cable::CableNetworkError;
+pub use cable::CableOverStretched;
+pub use cable::CablePickingSettings;
 use cable::CablePlugin;
+pub use cable::CablePointerHit;
+pub use cable::CablePointerHits;
+pub use cable::CablePort;
+pub use cable::CableRayCast;
+pub use cable::CableRouteOutcome;
+pub use cable::CableRouteOutcomeChanged;
+pub use cable::CableSupport;
+pub use cable::CableTension;
+pub use cable::CableWaypoint;
 pub use cable::ComputedCableGeometry;
+pub use cable::ConnectionRejected;
+pub use cable::ConnectorKind;
 pub use cable::DetachPolicy;
+pub use cable::DraggedCableEnd;
 pub use cable::EndpointAlignment;
 pub use cable::EndpointExit;
+pub use cable::OverStretchPolicy;
+pub use cable::RejectionReason;
 pub use cable::RouteAnimation;
 pub use cable::RouteObstacle;
+pub use cable::SavedCable;
+pub use cable::SavedCableSettings;
+pub use cable::SavedEndpoint;
+pub use cable::TensionState;
 // Gizmos
 pub use gizmos::CableGizmoGroup;
 pub use gizmos::DebugGizmos;
@@ -64,23 +93,33 @@ use gizmos::GizmosPlugin;
 pub use mesh::CableMeshChild;
 pub use mesh::CableMeshConfig;
 pub use mesh::CableMeshHandle;
+pub use mesh::CableMeshLod;
+pub use mesh::CableMeshLodBand;
 pub use mesh::CapConfig;
 pub use mesh::CapStyle;
+pub use mesh::CrossSection;
 pub use mesh::ElbowConfig;
 pub use mesh::ElbowMetadata;
 pub use mesh::Faces;
+pub use mesh::LodBand;
 use mesh::MeshPlugin;
+pub use mesh::StripeOrientation;
 pub use mesh::TrimConfig;
 pub use mesh::TubeConfig;
+pub use mesh::TwistConfig;
+pub use mesh::UvConfig;
 pub use mesh::compute_elbow_metadata;
 pub use mesh::generate_tube_mesh;
+pub use mesh::stripe_image;
 // Routing
 pub use routing::AStarPlanner;
 pub use routing::Anchor;
 pub use routing::AnchorExit;
 pub use routing::AxisOrder;
 pub use routing::CableGeometry;
+pub use routing::CableRayHit;
 pub use routing::CableSegment;
+pub use routing::CableSimulation;
 pub use routing::CatenarySolver;
 pub use routing::CurveKind;
 pub use routing::CurveSolver;
@@ -88,16 +127,24 @@ pub use routing::DEFAULT_GRAVITY;
 pub use routing::DEFAULT_RESOLUTION;
 pub use routing::DEFAULT_SLACK;
 pub use routing::DirectPlanner;
+pub use routing::LayoutCable;
 pub use routing::LinearSolver;
 pub use routing::Obstacle;
+pub use routing::ObstacleIndex;
 pub use routing::OrthogonalPlanner;
 pub use routing::PathPlanner;
 pub use routing::PathStrategy;
+pub use routing::RouteOutcome;
 pub use routing::RouteRequest;
 pub use routing::RouteSolver;
 pub use routing::Router;
+pub use routing::SimulatedSolver;
 pub use routing::Solver;
+pub use routing::SplineSolver;
+pub use routing::SupportSurface;
+pub use routing::ViaPoint;
 pub use routing::evaluate;
+pub use routing::layout_cables;
 pub use routing::sample_3d;
 pub use routing::solve_parameter;
 
@@ -107,10 +154,16 @@ pub use routing::solve_parameter;
 /// - [`DebugGizmos`] resource (default: off).
 /// - [`CableGizmoGroup`] for controlling debug visibility.
 /// - `CablePlugin`, including `queue_changed_cables`, `queue_endpoint_changes`,
-///   `queue_attached_target_moves`, `queue_obstacle_changes`, `recompute_dirty_cables`,
-///   `animate_routes`, `on_endpoint_alignment_update`, and `on_endpoint_detached`.
-/// - `MeshPlugin`, including `on_geometry_computed`.
-/// - `GizmosPlugin`, including `render_cable_gizmos` and `render_debug_gizmos`.
+///   `queue_waypoint_changes`, `queue_attached_target_moves`, `queue_obstacle_changes`,
+///   `queue_layout_changes`, `recompute_dirty_cables`, `apply_background_routes`, `animate_routes`,
+///   `simulate_cables`, `follow_dragged_ends`, `on_endpoint_alignment_update`,
+///   `on_endpoint_detached`, `on_cable_end_grabbed`, `on_cable_end_released`,
+///   `on_pointer_drag_start`, `on_pointer_drag`, `on_pointer_drag_end`, and
+///   `cable_picking_backend`.
+/// - `MeshPlugin`, including `on_geometry_computed`, `scroll_cable_textures`, and
+///   `update_cable_mesh_lod`.
+/// - `GizmosPlugin`, including `render_cable_gizmos`, `render_debug_gizmos`,
+///   `render_obstacle_gizmos`, and `render_route_outcome_gizmos`.
 pub struct CatenaryPlugin;
 
 impl Plugin for CatenaryPlugin {
@@ -1,5 +1,4 @@
 use std::f32::consts::FRAC_PI_2;
-use std::f32::consts::TAU;
 
 use bevy::prelude::*;
 use bevy_kana::ToF32;
@@ -12,9 +11,13 @@ use super::buffers::WindingOrder;
 use super::config::CableMeshConfig;
 use super::config::CapStyle;
 use super::config::Faces;
-use super::constants::CAP_UV_CENTER;
+use super::constants::CAP_CENTER_U;
 use super::constants::MIN_CAP_RINGS;
+use super::profile;
+use super::profile::ProfileVertex;
+use super::tube::TubePathData;
 use crate::routing::MIN_CABLE_SAMPLE_POINTS;
+use crate::routing::MIN_SEGMENT_LENGTH;
 
 /// Which side of a cap to generate.
 #[derive(Clone, Copy, Debug)]
@@ -23,32 +26,57 @@ enum CapSide {
     Inside,
 }
 
+/// The tube's cross-section, which each cap closes off.
+pub(super) struct CapProfile<'a> {
+    /// Ring vertices the tube was swept with.
+    pub(super) vertices: &'a [ProfileVertex],
+    /// How far a round cap bulges past the end of the tube.
+    pub(super) depth:    f32,
+    /// Tube side count, which sets how many rings a round cap has.
+    pub(super) sides:    u32,
+}
+
 /// Geometric parameters shared by `add_single_cap`, `add_hemisphere_cap`, and
 /// `add_flat_cap`.
 struct CapContext<'a> {
     center:        &'a Vec3,
     direction:     Vec3,
     frame:         (Vec3, Vec3),
-    radius:        f32,
-    sides:         u32,
+    profile:       &'a CapProfile<'a>,
     ring_base:     u32,
     faces:         &'a Faces,
     winding_order: WindingOrder,
+    /// Distance along the cable to the ring the cap closes, in metres.
+    distance:      f32,
+    /// `-1.0` for the start cap, whose surface runs back before the cable's
+    /// start, and `1.0` for the end cap.
+    distance_sign: f32,
+    repeat_length: f32,
+}
+
+impl CapContext<'_> {
+    /// V coordinate of a cap vertex `meridian` metres over the cap surface
+    /// from the ring, continuing the tube's V past its end.
+    fn v(&self, meridian: f32) -> f32 {
+        self.distance_sign.mul_add(meridian, self.distance) / self.repeat_length
+    }
 }
 
 /// Add start and end caps to the tube mesh.
 pub(super) fn add_end_caps(
-    all_points: &[Vec3],
-    all_tangents: &[Vec3],
-    frames: &[(Vec3, Vec3)],
+    tube_path_data: &TubePathData,
     cable_mesh_config: &CableMeshConfig,
-    sides: u32,
-    point_count: usize,
+    profile: &CapProfile,
     buffers: &mut MeshBuffers,
 ) {
+    let point_count = tube_path_data.points.len();
     if point_count < MIN_CABLE_SAMPLE_POINTS.to_usize() {
         return;
     }
+    let repeat_length = cable_mesh_config
+        .uv_config
+        .repeat_length
+        .max(MIN_SEGMENT_LENGTH);
 
     let render_inside = matches!(cable_mesh_config.tube_config.faces, Faces::Inside);
     let start_winding_order = if render_inside {
@@ -57,14 +85,16 @@ pub(super) fn add_end_caps(
         WindingOrder::Reversed
     };
     let start_context = CapContext {
-        center: &all_points[0],
-        direction: -all_tangents[0],
-        frame: frames[0],
-        radius: cable_mesh_config.tube_config.radius,
-        sides,
+        center: &tube_path_data.points[0],
+        direction: -tube_path_data.tangents[0],
+        frame: tube_path_data.frames[0],
+        profile,
         ring_base: 0,
         faces: &cable_mesh_config.tube_config.faces,
         winding_order: start_winding_order,
+        distance: tube_path_data.distances[0],
+        distance_sign: -1.0,
+        repeat_length,
     };
     add_single_cap(&cable_mesh_config.cap_config.start, &start_context, buffers);
 
@@ -75,14 +105,16 @@ pub(super) fn add_end_caps(
         WindingOrder::Standard
     };
     let end_context = CapContext {
-        center: &all_points[last],
-        direction: all_tangents[last],
-        frame: frames[last],
-        radius: cable_mesh_config.tube_config.radius,
-        sides,
-        ring_base: (last * sides.to_usize()).to_u32(),
+        center: &tube_path_data.points[last],
+        direction: tube_path_data.tangents[last],
+        frame: tube_path_data.frames[last],
+        profile,
+        ring_base: (last * profile.vertices.len()).to_u32(),
         faces: &cable_mesh_config.tube_config.faces,
         winding_order: end_winding_order,
+        distance: tube_path_data.distances[last],
+        distance_sign: 1.0,
+        repeat_length,
     };
     add_single_cap(&cable_mesh_config.cap_config.end, &end_context, buffers);
 }
@@ -91,7 +123,7 @@ pub(super) fn add_end_caps(
 fn add_single_cap(style: &CapStyle, context: &CapContext, buffers: &mut MeshBuffers) {
     let needs_outside = matches!(context.faces, Faces::Outside | Faces::Both);
     let needs_inside = matches!(context.faces, Faces::Inside | Faces::Both);
-    let cap_rings = context.sides.max(MIN_CAP_RINGS);
+    let cap_rings = context.profile.sides.max(MIN_CAP_RINGS);
 
     match style {
         CapStyle::Round => {
@@ -139,40 +171,53 @@ fn add_hemisphere_cap(
 
     let mut previous_ring_base = if matches!(cap_side, CapSide::Inside) {
         let base = buffers.positions.len().to_u32();
-        for j in 0..context.sides {
+        for j in 0..context.profile.vertices.len().to_u32() {
             let original_index = (context.ring_base + j).to_usize();
             buffers.positions.push(buffers.positions[original_index]);
             let normal = buffers.normals[original_index];
             buffers.normals.push([-normal[0], -normal[1], -normal[2]]);
-            buffers.uvs.push(CAP_UV_CENTER);
+            buffers.uvs.push(buffers.uvs[original_index]);
         }
         base
     } else {
         context.ring_base
     };
 
+    // Distance each column has travelled over the cap surface, so V carries
+    // on from the tube at the same rate.
+    let mut previous_positions: Vec<Vec3> = context
+        .profile
+        .vertices
+        .iter()
+        .map(|vertex| {
+            *context.center + frame_normal * vertex.position.x + binormal * vertex.position.y
+        })
+        .collect();
+    let mut meridians = vec![0.0_f32; previous_positions.len()];
+
     for k in 1..cap_rings {
         let phi = (k.to_f32() / cap_rings.to_f32()) * FRAC_PI_2;
-        let ring_radius = phi.cos() * context.radius;
-        let along_offset = phi.sin() * context.radius;
+        let along_offset = phi.sin() * context.profile.depth;
         let ring_center = *context.center + context.direction * along_offset;
         let ring_base = buffers.positions.len().to_u32();
 
-        for j in 0..context.sides {
-            let angle = (j.to_f32() / context.sides.to_f32()) * TAU;
-            let (sin_angle, cos_angle) = angle.sin_cos();
-            let radial = frame_normal * cos_angle + binormal * sin_angle;
-            let vertex_position = ring_center + radial * ring_radius;
-            let vertex_normal =
-                normal_sign * (radial * phi.cos() + context.direction * phi.sin()).normalize();
+        for (j, vertex) in context.profile.vertices.iter().enumerate() {
+            let offset = frame_normal * vertex.position.x + binormal * vertex.position.y;
+            let outward = frame_normal * vertex.normal.x + binormal * vertex.normal.y;
+            let vertex_position = ring_center + offset * phi.cos();
+            let vertex_normal = normal_sign
+                * (outward * phi.cos() + context.direction * phi.sin()).normalize_or_zero();
+            meridians[j] += previous_positions[j].distance(vertex_position);
+            previous_positions[j] = vertex_position;
 
             buffers.positions.push(vertex_position.to_array());
             buffers.normals.push(vertex_normal.to_array());
-            buffers.uvs.push(CAP_UV_CENTER);
+            buffers
+                .uvs
+                .push([vertex.perimeter, context.v(meridians[j])]);
         }
 
-        for j in 0..context.sides {
-            let next = (j + 1) % context.sides;
+        for (j, next) in profile::edges(context.profile.vertices) {
             buffers::push_quad(
                 buffers.indices,
                 previous_ring_base + j,
@@ -187,15 +232,20 @@ fn add_hemisphere_cap(
     }
 
     let pole_index = buffers.positions.len().to_u32();
-    let pole_position = *context.center + context.direction * context.radius;
+    let pole_position = *context.center + context.direction * context.profile.depth;
     buffers.positions.push(pole_position.to_array());
     buffers
         .normals
         .push((normal_sign * context.direction).to_array());
-    buffers.uvs.push(CAP_UV_CENTER);
+    let pole_meridian = previous_positions
+        .iter()
+        .zip(&meridians)
+        .map(|(position, meridian)| meridian + position.distance(pole_position))
+        .sum::<f32>()
+        / meridians.len().max(1).to_f32();
+    buffers.uvs.push([CAP_CENTER_U, context.v(pole_meridian)]);
 
-    for j in 0..context.sides {
-        let next = (j + 1) % context.sides;
+    for (j, next) in profile::edges(context.profile.vertices) {
         buffers::push_triangle(
             buffers.indices,
             previous_ring_base + j,
@@ -214,20 +264,27 @@ fn add_flat_cap(context: &CapContext, cap_side: CapSide, buffers: &mut MeshBuffe
     };
 
     let new_ring_base = buffers.positions.len().to_u32();
-    for j in 0..context.sides {
-        let original_index = (context.ring_base + j).to_usize();
+    for (j, vertex) in context.profile.vertices.iter().enumerate() {
+        let original_index = context.ring_base.to_usize() + j;
         buffers.positions.push(buffers.positions[original_index]);
         buffers.normals.push(cap_normal);
-        buffers.uvs.push([j.to_f32() / context.sides.to_f32(), 0.0]);
+        buffers.uvs.push([vertex.perimeter, context.v(0.0)]);
     }
 
+    // V runs in from the rim over the disc to the centre.
+    let center_meridian = context
+        .profile
+        .vertices
+        .iter()
+        .map(|vertex| vertex.position.length())
+        .sum::<f32>()
+        / context.profile.vertices.len().max(1).to_f32();
     let center_index = buffers.positions.len().to_u32();
     buffers.positions.push(context.center.to_array());
     buffers.normals.push(cap_normal);
-    buffers.uvs.push(CAP_UV_CENTER);
+    buffers.uvs.push([CAP_CENTER_U, context.v(center_meridian)]);
 
-    for j in 0..context.sides {
-        let next = (j + 1) % context.sides;
+    for (j, next) in profile::edges(context.profile.vertices) {
         buffers::push_triangle(
             buffers.indices,
             new_ring_base + j,
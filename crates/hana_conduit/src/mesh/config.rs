@@ -4,20 +4,24 @@
 )]
 
 use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
 
 use super::constants::DEFAULT_ARM_MULTIPLIER;
 use super::constants::DEFAULT_ELBOW_ANGLE_THRESHOLD_DEG;
 use super::constants::DEFAULT_ELBOW_BEND_RADIUS_MULTIPLIER;
 use super::constants::DEFAULT_ELBOW_RINGS_PER_RIGHT_ANGLE;
 use super::constants::DEFAULT_MIN_ELBOW_RADIUS_MULTIPLIER;
+use super::constants::DEFAULT_PATH_DENSITY;
 use super::constants::DEFAULT_TUBE_RADIUS;
 use super::constants::DEFAULT_TUBE_SIDES;
+use super::constants::DEFAULT_UV_REPEAT_LENGTH;
 
 /// How to cap each end of a tube mesh.
 ///
 /// Surface normal is only relevant for [`CapStyle::Flat`] caps and is encoded directly
 /// in the variant, so invalid states are unrepresentable.
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub enum CapStyle {
     /// Open end, so no cap geometry is generated.
     None,
@@ -46,7 +50,7 @@ impl CapStyle {
 }
 
 /// Which sides of the tube surface to render.
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub enum Faces {
     /// Render only the outside.
     #[default]
@@ -57,29 +61,122 @@ pub enum Faces {
     Both,
 }
 
+/// Shape of the profile swept along the cable path.
+///
+/// Profiles are laid out in the cross-section plane with +Y along
+/// [`TwistConfig::up`] at the start of the cable and +X across it, centred on
+/// the path.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
+pub enum CrossSection {
+    /// Circle of [`TubeConfig::radius`].
+    #[default]
+    Circle,
+    /// Flat ribbon cable: a band `width` across and `thickness` deep with
+    /// rounded edges.
+    Ribbon {
+        /// Width across the flat side, in metres.
+        width:     f32,
+        /// Thickness through the flat side, in metres.
+        thickness: f32,
+    },
+    /// Rectangular duct with sharp corners.
+    Rectangle {
+        /// Width along the profile's X axis, in metres.
+        width:  f32,
+        /// Height along the profile's Y axis, in metres.
+        height: f32,
+    },
+    /// Figure-eight zip cord: two round conductors side by side along the
+    /// profile's X axis, joined at a waist.
+    FigureEight {
+        /// Radius of each conductor, in metres.
+        conductor_radius: f32,
+        /// Distance between the conductor centres, in metres. Clamped to
+        /// twice `conductor_radius`, where the conductors touch.
+        separation:       f32,
+    },
+    /// User-supplied closed polygon, in metres. The polygon must be
+    /// star-shaped around the origin so flat caps can fan out from the path.
+    /// Corners turning more than 30 degrees are shaded as sharp creases.
+    Custom(Vec<Vec2>),
+}
+
+impl CrossSection {
+    /// Distance from the path to the farthest point of the profile, given the
+    /// tube `radius` used by [`CrossSection::Circle`].
+    #[must_use]
+    pub fn bounding_radius(&self, radius: f32) -> f32 {
+        match self {
+            Self::Circle => radius,
+            Self::Ribbon { width, thickness } => (width / 2.0).max(thickness / 2.0),
+            Self::Rectangle { width, height } => Vec2::new(*width, *height).length() / 2.0,
+            Self::FigureEight {
+                conductor_radius,
+                separation,
+            } => separation.clamp(0.0, conductor_radius * 2.0) / 2.0 + conductor_radius,
+            Self::Custom(points) => points.iter().copied().map(Vec2::length).fold(0.0, f32::max),
+        }
+    }
+}
+
+/// Orientation and twist of the cross-section along the cable.
+///
+/// The profile is carried along the path by rotation-minimizing frames, so it
+/// never flips at an elbow: a ribbon laid flat bends around its flat side
+/// wherever the path turns in the plane of the ribbon's thickness.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
+pub struct TwistConfig {
+    /// Direction the profile's +Y axis faces at the start of the cable,
+    /// projected perpendicular to the path. `None` picks an arbitrary
+    /// perpendicular, which is fine for circles.
+    pub up:    Option<Vec3>,
+    /// Extra rotation of the profile about the path, in radians, accumulated
+    /// evenly along the cable's length.
+    pub angle: f32,
+}
+
 /// Tube cross-section configuration.
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub struct TubeConfig {
-    /// Radius of the tube cross-section.
-    pub radius: f32,
-    /// Number of vertices around the cross-section circle.
-    pub sides:  u32,
+    /// Radius of the tube cross-section when [`CrossSection::Circle`].
+    pub radius:        f32,
+    /// Number of vertices around the cross-section circle, and around each
+    /// rounded part of the other cross-sections.
+    pub sides:         u32,
     /// Which sides of the tube surface to render.
-    pub faces:  Faces,
+    pub faces:         Faces,
+    /// Profile swept along the path.
+    pub cross_section: CrossSection,
+    /// Orientation and twist of the profile along the path.
+    pub twist:         TwistConfig,
+    /// Fraction of the cable's sample points the tube places rings at, from
+    /// `0.0` to `1.0`. Each segment keeps its first and last points, so
+    /// corners between segments survive any density.
+    pub path_density:  f32,
+}
+
+impl TubeConfig {
+    /// Distance from the path to the farthest point of the cross-section. Elbows
+    /// and bundle spacing use it as the tube's radius.
+    #[must_use]
+    pub fn bounding_radius(&self) -> f32 { self.cross_section.bounding_radius(self.radius) }
 }
 
 impl Default for TubeConfig {
     fn default() -> Self {
         Self {
-            radius: DEFAULT_TUBE_RADIUS,
-            sides:  DEFAULT_TUBE_SIDES,
-            faces:  Faces::default(),
+            radius:        DEFAULT_TUBE_RADIUS,
+            sides:         DEFAULT_TUBE_SIDES,
+            faces:         Faces::default(),
+            cross_section: CrossSection::default(),
+            twist:         TwistConfig::default(),
+            path_density:  DEFAULT_PATH_DENSITY,
         }
     }
 }
 
 /// Cap style at each end of the tube.
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub struct CapConfig {
     /// Cap style for the start end of the tube.
     pub start: CapStyle,
@@ -97,7 +194,7 @@ impl Default for CapConfig {
 }
 
 /// Distance to trim from each end of the tube path.
-#[derive(Clone, Debug, Default, Reflect)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 pub struct TrimConfig {
     /// Distance to trim from the start.
     pub start: f32,
@@ -105,8 +202,32 @@ pub struct TrimConfig {
     pub end:   f32,
 }
 
+/// Texture coordinate mapping and scrolling along the tube.
+///
+/// U runs around the cross-section and V along the cable in world units, so a
+/// texture keeps its proportions however long the cable is.
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+pub struct UvConfig {
+    /// Metres of cable one repeat of the texture covers along V.
+    pub repeat_length: f32,
+    /// Speed the material's texture scrolls along the cable, in metres per
+    /// second, flowing from the start to the end when positive. Scrolling
+    /// moves the material's `uv_transform`, so cables sharing a material
+    /// scroll together.
+    pub flow_speed:    f32,
+}
+
+impl Default for UvConfig {
+    fn default() -> Self {
+        Self {
+            repeat_length: DEFAULT_UV_REPEAT_LENGTH,
+            flow_speed:    0.0,
+        }
+    }
+}
+
 /// Elbow filleting configuration.
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub struct ElbowConfig {
     /// Elbow bend radius multiplier relative to tube radius.
     pub bend_radius_multiplier: f32,
@@ -136,10 +257,10 @@ impl Default for ElbowConfig {
 }
 
 /// Configuration for cable mesh generation.
-#[derive(Component, Clone, Debug, Default, Reflect)]
+#[derive(Component, Clone, Debug, Default, Serialize, Deserialize, Reflect)]
 #[reflect(Component)]
 pub struct CableMeshConfig {
-    /// Tube cross-section: radius, side count, and rendered faces.
+    /// Tube cross-section: profile, side count, twist, and rendered faces.
     pub tube_config:  TubeConfig,
     /// Cap styles at each end of the tube.
     pub cap_config:   CapConfig,
@@ -147,6 +268,20 @@ pub struct CableMeshConfig {
     pub trim_config:  TrimConfig,
     /// Elbow filleting between non-collinear tangents.
     pub elbow_config: ElbowConfig,
+    /// Texture coordinate mapping and scrolling.
+    pub uv_config:    UvConfig,
     /// Material to apply to the generated mesh. If `None`, no material is added.
+    /// Not serialized: asset handles do not outlive the session.
+    #[serde(skip)]
     pub material:     Option<Handle<StandardMaterial>>,
 }
+
+impl CableMeshConfig {
+    /// Radius the tube's elbows bend through, in metres. A
+    /// [`SplineSolver`](crate::SplineSolver) with this `min_bend_radius` bends
+    /// no tighter than an elbow would, so the mesh needs none.
+    #[must_use]
+    pub fn elbow_bend_radius(&self) -> f32 {
+        self.tube_config.bounding_radius() * self.elbow_config.bend_radius_multiplier
+    }
+}
@@ -3,6 +3,13 @@
 // cap defaults
 pub(super) const MIN_CAP_RINGS: u32 = 8;
 
+// cross-section
+/// Turn between neighbouring profile edges, in degrees, above which the corner
+/// is shaded as a sharp crease instead of smoothed.
+pub(super) const CROSS_SECTION_CREASE_ANGLE_DEG: f32 = 30.0;
+/// Fewest segments sampled along each rounded part of a non-circular profile.
+pub(super) const MIN_PROFILE_ARC_SEGMENTS: u32 = 2;
+
 // elbow defaults
 pub(super) const DEFAULT_ARM_MULTIPLIER: f32 = 1.0;
 pub(super) const DEFAULT_ELBOW_ANGLE_THRESHOLD_DEG: f32 = 25.0;
@@ -16,16 +23,37 @@ pub(super) const MAX_ARM_RATIO: f32 = 0.95;
 /// Minimum number of rings per elbow fillet.
 pub(super) const MIN_ELBOW_RINGS: f32 = 3.0;
 
+// level of detail
+/// Fraction an on-screen size must pass a `CableMeshLod` band edge by before
+/// the band changes.
+pub(super) const DEFAULT_LOD_HYSTERESIS: f32 = 0.2;
+/// On-screen tube diameter, as a fraction of the viewport's height, at or
+/// above which the default `CableMeshLod` keeps full detail.
+pub(super) const FULL_DETAIL_LOD_SCREEN_SIZE: f32 = 0.02;
+/// On-screen tube diameter, as a fraction of the viewport's height, at or
+/// above which the default `CableMeshLod` uses its medium band.
+pub(super) const MEDIUM_DETAIL_LOD_SCREEN_SIZE: f32 = 0.005;
+pub(super) const MEDIUM_DETAIL_LOD_SIDES: u32 = 12;
+pub(super) const MEDIUM_DETAIL_LOD_PATH_DENSITY: f32 = 0.5;
+pub(super) const MEDIUM_DETAIL_LOD_ELBOW_RINGS: u32 = 8;
+pub(super) const LOW_DETAIL_LOD_SIDES: u32 = 6;
+pub(super) const LOW_DETAIL_LOD_PATH_DENSITY: f32 = 0.25;
+pub(super) const LOW_DETAIL_LOD_ELBOW_RINGS: u32 = 3;
+
 // perpendicular detection
 /// Dot-product threshold above which a vector is considered near-parallel to an axis.
 pub(super) const PERPENDICULAR_AXIS_THRESHOLD: f32 = 0.9;
 
 // tube defaults
+pub(super) const DEFAULT_PATH_DENSITY: f32 = 1.0;
+/// Lowest `TubeConfig::path_density` honored, keeping one ring in a hundred.
+pub(super) const MIN_PATH_DENSITY: f32 = 0.01;
 pub(super) const DEFAULT_TUBE_RADIUS: f32 = 0.06;
 pub(super) const DEFAULT_TUBE_SIDES: u32 = 32;
 /// Minimum polygon sides needed to form a closed tube cross-section.
 pub(super) const MIN_TUBE_SIDES: u32 = 3;
 
 // uv mapping
-/// UV coordinate sampled at cap pole/center vertices.
-pub(super) const CAP_UV_CENTER: [f32; 2] = [0.5, 0.5];
+/// U coordinate sampled at cap pole/center vertices.
+pub(super) const CAP_CENTER_U: f32 = 0.5;
+pub(super) const DEFAULT_UV_REPEAT_LENGTH: f32 = 1.0;
@@ -45,15 +45,14 @@ struct ElbowParams {
 
 impl From<&CableMeshConfig> for ElbowParams {
     fn from(cable_mesh_config: &CableMeshConfig) -> Self {
-        let tube_radius = cable_mesh_config.tube_config.radius;
+        let tube_radius = cable_mesh_config.tube_config.bounding_radius();
         Self {
             angle_threshold_cos: cable_mesh_config
                 .elbow_config
                 .angle_threshold_deg
                 .to_radians()
                 .cos(),
-            bend_radius:         tube_radius
-                * cable_mesh_config.elbow_config.bend_radius_multiplier,
+            bend_radius:         cable_mesh_config.elbow_bend_radius(),
             min_bend_radius:     tube_radius * cable_mesh_config.elbow_config.min_radius_multiplier,
         }
     }
@@ -255,7 +254,8 @@ pub fn compute_elbow_metadata(
     geometry: &CableGeometry,
     cable_mesh_config: &CableMeshConfig,
 ) -> Vec<ElbowMetadata> {
-    let flattened_geometry = path::flatten_geometry(geometry);
+    let flattened_geometry =
+        path::flatten_geometry(geometry, cable_mesh_config.tube_config.path_density);
     let mut points = flattened_geometry.points;
     let mut arc_lengths = flattened_geometry.arc_lengths;
 
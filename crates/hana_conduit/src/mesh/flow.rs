@@ -0,0 +1,32 @@
+//! `scroll_cable_textures`: animates [`UvConfig::flow_speed`](super::UvConfig::flow_speed).
+
+use bevy::prelude::*;
+
+use super::CableMeshConfig;
+use crate::routing::MIN_SEGMENT_LENGTH;
+
+/// Scroll each flowing cable's material along V, so its texture travels from
+/// the cable's start toward its end at `flow_speed` metres per second.
+pub(super) fn scroll_cable_textures(
+    time: Res<Time>,
+    cables: Query<&CableMeshConfig>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for cable_mesh_config in &cables {
+        let uv_config = &cable_mesh_config.uv_config;
+        if uv_config.flow_speed.abs() < f32::EPSILON {
+            continue;
+        }
+        let Some(handle) = &cable_mesh_config.material else {
+            continue;
+        };
+        let Some(mut material) = materials.get_mut(handle) else {
+            continue;
+        };
+
+        let repeats = time.elapsed_secs() * uv_config.flow_speed
+            / uv_config.repeat_length.max(MIN_SEGMENT_LENGTH);
+        // Sampling behind the mesh's V moves the texture forward along it.
+        material.uv_transform.translation.y = -repeats.rem_euclid(1.0);
+    }
+}
@@ -2,10 +2,13 @@ use bevy::prelude::*;
 
 use super::constants::PERPENDICULAR_AXIS_THRESHOLD;
 
-/// Compute rotation-minimizing frames (parallel transport) along a curve.
+/// Compute rotation-minimizing frames (parallel transport) along a curve. The
+/// first frame's binormal faces `up`, projected perpendicular to the first
+/// tangent, when given and not parallel to it.
 pub(super) fn compute_rotation_minimizing_frames(
     points: &[Vec3],
     tangents: &[Vec3],
+    up: Option<Vec3>,
 ) -> Vec<(Vec3, Vec3)> {
     let point_count = points.len();
     if point_count == 0 {
@@ -14,7 +17,13 @@ pub(super) fn compute_rotation_minimizing_frames(
 
     let mut frames = Vec::with_capacity(point_count);
     let first_tangent = tangents[0];
-    let initial_normal = find_perpendicular(first_tangent);
+    let initial_normal = up
+        .map(|up| (up - first_tangent * up.dot(first_tangent)).normalize_or_zero())
+        .filter(|binormal| *binormal != Vec3::ZERO)
+        .map_or_else(
+            || find_perpendicular(first_tangent),
+            |binormal| binormal.cross(first_tangent).normalize_or_zero(),
+        );
     let initial_binormal = first_tangent.cross(initial_normal).normalize_or_zero();
     frames.push((initial_normal, initial_binormal));
 
@@ -55,6 +64,31 @@ pub(super) fn compute_rotation_minimizing_frames(
     frames
 }
 
+/// Rotate each frame about its tangent by its share of `angle`, in proportion
+/// to the arc length travelled.
+pub(super) fn apply_twist(
+    frames: &mut [(Vec3, Vec3)],
+    tangents: &[Vec3],
+    arc_lengths: &[f32],
+    angle: f32,
+) {
+    let (Some(&first), Some(&last)) = (arc_lengths.first(), arc_lengths.last()) else {
+        return;
+    };
+    let span = last - first;
+    if angle.abs() < f32::EPSILON || span < f32::EPSILON {
+        return;
+    }
+
+    for ((frame, tangent), arc_length) in frames.iter_mut().zip(tangents).zip(arc_lengths) {
+        let Ok(axis) = Dir3::new(*tangent) else {
+            continue;
+        };
+        let rotation = Quat::from_axis_angle(*axis, angle * (arc_length - first) / span);
+        *frame = (rotation * frame.0, rotation * frame.1);
+    }
+}
+
 fn find_perpendicular(direction: Vec3) -> Vec3 {
     let candidate = if direction.x.abs() < PERPENDICULAR_AXIS_THRESHOLD {
         Vec3::X
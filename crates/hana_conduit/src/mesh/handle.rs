@@ -3,6 +3,8 @@
 use bevy::prelude::*;
 
 use super::CableMeshConfig;
+use super::lod::CableMeshLod;
+use super::lod::CableMeshLodBand;
 use super::tube;
 use crate::cable::CableEnd;
 use crate::cable::CableEndpoint;
@@ -19,13 +21,15 @@ pub struct CableMeshHandle(pub Handle<Mesh>);
 pub struct CableMeshChild(pub Entity);
 
 /// Query type for accessing `ComputedCableGeometry`, `CableMeshConfig`,
-/// `CableMeshHandle`, and `CableMeshChild`.
+/// `CableMeshHandle`, `CableMeshChild`, and the cable's level of detail.
 type CableMeshQuery<'w> = (
     &'w ComputedCableGeometry,
     &'w CableMeshConfig,
     &'w Children,
     Option<&'w CableMeshHandle>,
     Option<&'w CableMeshChild>,
+    Option<&'w CableMeshLod>,
+    Option<&'w CableMeshLodBand>,
 );
 
 /// Observer that generates or updates the cable mesh when geometry is (re)computed.
@@ -41,8 +45,15 @@ pub(super) fn on_geometry_computed(
     mut commands: Commands,
 ) {
     let cable_entity = trigger.event_target();
-    let Ok((computed_cable_geometry, cable_mesh_config, children, mesh_handle, _)) =
-        cables.get(cable_entity)
+    let Ok((
+        computed_cable_geometry,
+        cable_mesh_config,
+        children,
+        mesh_handle,
+        _,
+        cable_mesh_lod,
+        cable_mesh_lod_band,
+    )) = cables.get(cable_entity)
     else {
         return;
     };
@@ -51,22 +62,10 @@ pub(super) fn on_geometry_computed(
         return;
     };
 
-    // Read endpoint cap styles from children
-    let mut cap_start = cable_mesh_config.cap_config.start.clone();
-    let mut cap_end = cable_mesh_config.cap_config.end.clone();
-    for child in children.iter() {
-        if let Ok(endpoint) = endpoints.get(child) {
-            match endpoint.end {
-                CableEnd::Start => cap_start = endpoint.cap_style.clone(),
-                CableEnd::End => cap_end = endpoint.cap_style.clone(),
-            }
-        }
-    }
-
-    // Build the config with endpoint cap styles applied
-    let mut updated_cable_mesh_config = cable_mesh_config.clone();
-    updated_cable_mesh_config.cap_config.start = cap_start;
-    updated_cable_mesh_config.cap_config.end = cap_end;
+    // A cable whose band is not chosen yet starts at full detail.
+    let lod = cable_mesh_lod.map(|lod| (lod, cable_mesh_lod_band.map_or(0, |band| band.0)));
+    let updated_cable_mesh_config =
+        effective_mesh_config(cable_mesh_config, children, &endpoints, lod);
 
     let new_mesh = tube::generate_tube_mesh(cable_geometry, &updated_cable_mesh_config);
 
@@ -91,3 +90,33 @@ pub(super) fn on_geometry_computed(
             .insert((CableMeshHandle(handle), CableMeshChild(child)));
     }
 }
+
+/// `cable_mesh_config` with the cable's endpoint cap styles and, when given,
+/// the detail of its level-of-detail band applied.
+pub(super) fn effective_mesh_config(
+    cable_mesh_config: &CableMeshConfig,
+    children: &Children,
+    endpoints: &Query<&CableEndpoint>,
+    lod: Option<(&CableMeshLod, usize)>,
+) -> CableMeshConfig {
+    // Read endpoint cap styles from children
+    let mut cap_start = cable_mesh_config.cap_config.start.clone();
+    let mut cap_end = cable_mesh_config.cap_config.end.clone();
+    for child in children.iter() {
+        if let Ok(endpoint) = endpoints.get(child) {
+            match endpoint.end {
+                CableEnd::Start => cap_start = endpoint.cap_style.clone(),
+                CableEnd::End => cap_end = endpoint.cap_style.clone(),
+            }
+        }
+    }
+
+    // Build the config with endpoint cap styles applied
+    let mut updated_cable_mesh_config = cable_mesh_config.clone();
+    updated_cable_mesh_config.cap_config.start = cap_start;
+    updated_cable_mesh_config.cap_config.end = cap_end;
+    if let Some((cable_mesh_lod, band)) = lod {
+        cable_mesh_lod.apply_band(band, &mut updated_cable_mesh_config);
+    }
+    updated_cable_mesh_config
+}
@@ -0,0 +1,286 @@
+//! [`CableMeshLod`], [`LodBand`], [`CableMeshLodBand`], and
+//! `update_cable_mesh_lod`.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::CableMeshConfig;
+use super::constants::DEFAULT_ELBOW_RINGS_PER_RIGHT_ANGLE;
+use super::constants::DEFAULT_LOD_HYSTERESIS;
+use super::constants::DEFAULT_PATH_DENSITY;
+use super::constants::DEFAULT_TUBE_SIDES;
+use super::constants::FULL_DETAIL_LOD_SCREEN_SIZE;
+use super::constants::LOW_DETAIL_LOD_ELBOW_RINGS;
+use super::constants::LOW_DETAIL_LOD_PATH_DENSITY;
+use super::constants::LOW_DETAIL_LOD_SIDES;
+use super::constants::MEDIUM_DETAIL_LOD_ELBOW_RINGS;
+use super::constants::MEDIUM_DETAIL_LOD_PATH_DENSITY;
+use super::constants::MEDIUM_DETAIL_LOD_SCREEN_SIZE;
+use super::constants::MEDIUM_DETAIL_LOD_SIDES;
+use super::handle;
+use super::handle::CableMeshHandle;
+use super::tube;
+use crate::cable::CableEndpoint;
+use crate::cable::ComputedCableGeometry;
+
+/// Tube detail a [`CableMeshLod`] cable uses while it appears at least
+/// `min_screen_size` across.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+pub struct LodBand {
+    /// Smallest on-screen tube diameter, as a fraction of the viewport's
+    /// height, at which this band applies.
+    pub min_screen_size:       f32,
+    /// Replaces [`TubeConfig::sides`](super::TubeConfig::sides).
+    pub sides:                 u32,
+    /// Replaces [`TubeConfig::path_density`](super::TubeConfig::path_density).
+    pub path_density:          f32,
+    /// Replaces
+    /// [`ElbowConfig::rings_per_right_angle`](super::ElbowConfig::rings_per_right_angle).
+    pub rings_per_right_angle: u32,
+}
+
+/// Distance-based level of detail for a cable's tube mesh.
+///
+/// Each frame the cable's on-screen size is measured from the active camera
+/// with the highest `order`, at the point of the cable's bounds nearest to
+/// it. The mesh is regenerated through its [`CableMeshHandle`] only when the
+/// size moves the cable into another band, and only once the size has passed
+/// the band's edge by `hysteresis`, so a cable resting on an edge does not
+/// flip between bands every frame.
+#[derive(Component, Clone, Debug, Serialize, Deserialize, Reflect)]
+#[reflect(Component)]
+pub struct CableMeshLod {
+    /// Bands ordered from most to least detailed, by descending
+    /// `min_screen_size`. A cable smaller than every band uses the last.
+    pub bands:      Vec<LodBand>,
+    /// Fraction of a band edge's screen size the cable must pass it by before
+    /// the band changes.
+    pub hysteresis: f32,
+}
+
+impl Default for CableMeshLod {
+    fn default() -> Self {
+        Self {
+            bands:      vec![
+                LodBand {
+                    min_screen_size:       FULL_DETAIL_LOD_SCREEN_SIZE,
+                    sides:                 DEFAULT_TUBE_SIDES,
+                    path_density:          DEFAULT_PATH_DENSITY,
+                    rings_per_right_angle: DEFAULT_ELBOW_RINGS_PER_RIGHT_ANGLE,
+                },
+                LodBand {
+                    min_screen_size:       MEDIUM_DETAIL_LOD_SCREEN_SIZE,
+                    sides:                 MEDIUM_DETAIL_LOD_SIDES,
+                    path_density:          MEDIUM_DETAIL_LOD_PATH_DENSITY,
+                    rings_per_right_angle: MEDIUM_DETAIL_LOD_ELBOW_RINGS,
+                },
+                LodBand {
+                    min_screen_size:       0.0,
+                    sides:                 LOW_DETAIL_LOD_SIDES,
+                    path_density:          LOW_DETAIL_LOD_PATH_DENSITY,
+                    rings_per_right_angle: LOW_DETAIL_LOD_ELBOW_RINGS,
+                },
+            ],
+            hysteresis: DEFAULT_LOD_HYSTERESIS,
+        }
+    }
+}
+
+impl CableMeshLod {
+    /// Index of the band a cable `screen_size` across falls in, ignoring
+    /// hysteresis.
+    fn band_for(&self, screen_size: f32) -> usize {
+        self.bands
+            .iter()
+            .position(|band| screen_size >= band.min_screen_size)
+            .unwrap_or_else(|| self.bands.len().saturating_sub(1))
+    }
+
+    /// The band a cable `screen_size` across moves to from `current`: a finer
+    /// band once the size exceeds its edge by the hysteresis, a coarser one
+    /// once the size falls short of the current band's edge by it.
+    fn select_band(&self, screen_size: f32, current: Option<usize>) -> usize {
+        let Some(current) = current else {
+            return self.band_for(screen_size);
+        };
+        let margin = 1.0 + self.hysteresis.max(0.0);
+        let finer = self.band_for(screen_size / margin);
+        if finer < current {
+            return finer;
+        }
+        let coarser = self.band_for(screen_size * margin);
+        if coarser > current {
+            return coarser;
+        }
+        current
+    }
+
+    /// Replace `cable_mesh_config`'s detail with `band`'s.
+    pub(super) fn apply_band(&self, band: usize, cable_mesh_config: &mut CableMeshConfig) {
+        if let Some(band) = self.bands.get(band) {
+            cable_mesh_config.tube_config.sides = band.sides;
+            cable_mesh_config.tube_config.path_density = band.path_density;
+            cable_mesh_config.elbow_config.rings_per_right_angle = band.rings_per_right_angle;
+        }
+    }
+}
+
+/// The [`CableMeshLod`] band a cable's tube mesh was last generated at, as an
+/// index into [`CableMeshLod::bands`]. Written by `update_cable_mesh_lod`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct CableMeshLodBand(pub usize);
+
+/// World-space `(min, max)` bounds of a [`CableMeshLod`] cable's geometry,
+/// cached by `update_cable_mesh_lod` so a moving camera does not rescan every
+/// point of every cable.
+#[derive(Component, Clone, Copy)]
+pub(super) struct CableMeshLodBounds(Vec3, Vec3);
+
+/// Query type for the cable-side components `update_cable_mesh_lod` reads.
+type CableMeshLodQuery<'w> = (
+    Entity,
+    Ref<'w, CableMeshLod>,
+    Ref<'w, ComputedCableGeometry>,
+    Ref<'w, CableMeshConfig>,
+    &'w Children,
+    &'w CableMeshHandle,
+    Option<&'w mut CableMeshLodBand>,
+    Option<&'w mut CableMeshLodBounds>,
+);
+
+/// Moves each [`CableMeshLod`] cable to the band its on-screen size calls
+/// for, regenerating its tube mesh when the band changes. A cable is only
+/// revisited when the camera, its geometry, its mesh configuration, or its
+/// [`CableMeshLod`] changed.
+pub(super) fn update_cable_mesh_lod(
+    cameras: Query<(Ref<Camera>, Ref<Projection>, Ref<GlobalTransform>)>,
+    mut cables: Query<CableMeshLodQuery>,
+    endpoints: Query<&CableEndpoint>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut commands: Commands,
+) {
+    let Some((camera, projection, camera_transform)) = cameras
+        .iter()
+        .filter(|(camera, ..)| camera.is_active)
+        .max_by_key(|(camera, ..)| camera.order)
+    else {
+        return;
+    };
+    let camera_changed =
+        camera.is_changed() || projection.is_changed() || camera_transform.is_changed();
+    let camera_position = camera_transform.translation();
+
+    for (
+        cable_entity,
+        cable_mesh_lod,
+        computed_cable_geometry,
+        cable_mesh_config,
+        children,
+        mesh_handle,
+        cable_mesh_lod_band,
+        cable_mesh_lod_bounds,
+    ) in &mut cables
+    {
+        let geometry_changed = computed_cable_geometry.is_changed();
+        if !camera_changed
+            && !geometry_changed
+            && !cable_mesh_config.is_changed()
+            && !cable_mesh_lod.is_changed()
+        {
+            continue;
+        }
+        let Some(cable_geometry) = &computed_cable_geometry.cable_geometry else {
+            continue;
+        };
+        let bounds = match cable_mesh_lod_bounds {
+            Some(cable_mesh_lod_bounds) if !geometry_changed => *cable_mesh_lod_bounds,
+            cable_mesh_lod_bounds => {
+                let Some(bounds) = geometry_bounds(
+                    cable_geometry
+                        .segments
+                        .iter()
+                        .flat_map(|segment| &segment.points),
+                ) else {
+                    continue;
+                };
+                match cable_mesh_lod_bounds {
+                    Some(mut cable_mesh_lod_bounds) => *cable_mesh_lod_bounds = bounds,
+                    None => {
+                        commands.entity(cable_entity).insert(bounds);
+                    },
+                }
+                bounds
+            },
+        };
+        let radius = cable_mesh_config.tube_config.bounding_radius();
+        let Some(screen_size) = screen_size(&projection, camera_position, bounds, radius) else {
+            continue;
+        };
+
+        // A cable without a band yet was generated at full detail.
+        let current = cable_mesh_lod_band.as_ref().map(|band| band.0);
+        let band = cable_mesh_lod.select_band(screen_size, current);
+        match cable_mesh_lod_band {
+            Some(mut cable_mesh_lod_band) if cable_mesh_lod_band.0 != band => {
+                cable_mesh_lod_band.0 = band;
+            },
+            Some(_) if !cable_mesh_lod.is_changed() => continue,
+            Some(_) => {},
+            None => {
+                commands.entity(cable_entity).insert(CableMeshLodBand(band));
+                if band == 0 && !cable_mesh_lod.is_changed() {
+                    continue;
+                }
+            },
+        }
+
+        let lod_mesh_config = handle::effective_mesh_config(
+            &cable_mesh_config,
+            children,
+            &endpoints,
+            Some((&*cable_mesh_lod, band)),
+        );
+        if let Some(mut existing) = meshes.get_mut(&mesh_handle.0) {
+            *existing = tube::generate_tube_mesh(cable_geometry, &lod_mesh_config);
+        }
+    }
+}
+
+/// The bounds of `points`, or `None` when there are none.
+fn geometry_bounds<'a>(points: impl Iterator<Item = &'a Vec3>) -> Option<CableMeshLodBounds> {
+    let (min, max) = points.fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), &point| (min.min(point), max.max(point)),
+    );
+    (!min.cmpgt(max).any()).then_some(CableMeshLodBounds(min, max))
+}
+
+/// On-screen diameter of a tube of `radius` within `bounds`, as a fraction of
+/// the viewport's height, measured where the bounds come nearest the camera.
+/// `None` for custom projections.
+fn screen_size(
+    projection: &Projection,
+    camera_position: Vec3,
+    CableMeshLodBounds(min, max): CableMeshLodBounds,
+    radius: f32,
+) -> Option<f32> {
+    let nearest = camera_position.clamp(min - radius, max + radius);
+    let diameter = radius * 2.0;
+    match projection {
+        Projection::Perspective(perspective) => {
+            let view_height =
+                2.0 * camera_position.distance(nearest) * (perspective.fov / 2.0).tan();
+            Some(if view_height > f32::EPSILON {
+                diameter / view_height
+            } else {
+                f32::INFINITY
+            })
+        },
+        Projection::Orthographic(orthographic) => {
+            Some(diameter / orthographic.area.height().max(f32::EPSILON))
+        },
+        Projection::Custom(_) => None,
+    }
+}
@@ -5,29 +5,52 @@ mod caps;
 mod config;
 mod constants;
 mod elbows;
+mod flow;
 mod frames;
 mod handle;
+mod lod;
 mod path;
+mod profile;
+mod stripes;
 mod tube;
 
 use bevy::prelude::*;
 pub use config::CableMeshConfig;
 pub use config::CapConfig;
 pub use config::CapStyle;
+pub use config::CrossSection;
 pub use config::ElbowConfig;
 pub use config::Faces;
 pub use config::TrimConfig;
 pub use config::TubeConfig;
+pub use config::TwistConfig;
+pub use config::UvConfig;
 pub use elbows::ElbowMetadata;
 pub use elbows::compute_elbow_metadata;
 pub use handle::CableMeshChild;
 pub use handle::CableMeshHandle;
+pub use lod::CableMeshLod;
+pub use lod::CableMeshLodBand;
+pub use lod::LodBand;
+pub use stripes::StripeOrientation;
+pub use stripes::stripe_image;
 pub use tube::generate_tube_mesh;
 
+use crate::cable::CableSystems;
+
 pub(super) struct MeshPlugin;
 
 impl Plugin for MeshPlugin {
     fn build(&self, app: &mut App) {
         app.add_observer(handle::on_geometry_computed.run_if(resource_exists::<Assets<Mesh>>));
+        app.add_systems(
+            Update,
+            (
+                flow::scroll_cable_textures.run_if(resource_exists::<Assets<StandardMaterial>>),
+                lod::update_cable_mesh_lod
+                    .after(CableSystems::Compute)
+                    .run_if(resource_exists::<Assets<Mesh>>),
+            ),
+        );
     }
 }
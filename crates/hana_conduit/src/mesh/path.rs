@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use bevy_kana::ToUsize;
 
+use super::constants::MIN_PATH_DENSITY;
 use crate::routing::CableGeometry;
 use crate::routing::MIN_CABLE_SAMPLE_POINTS;
 
@@ -12,7 +13,13 @@ pub(super) struct FlattenedGeometry {
 }
 
 /// Flatten all geometry segments into one continuous polyline, deduplicating boundaries.
-pub(super) fn flatten_geometry(geometry: &CableGeometry) -> FlattenedGeometry {
+/// Keeps `density` of each segment's interior points, evenly spaced, and always its
+/// first and last.
+pub(super) fn flatten_geometry(geometry: &CableGeometry, density: f32) -> FlattenedGeometry {
+    let stride = (1.0 / density.clamp(MIN_PATH_DENSITY, 1.0))
+        .round()
+        .to_usize()
+        .max(1);
     let mut points = Vec::new();
     let mut tangents = Vec::new();
     let mut arc_lengths = Vec::new();
@@ -25,7 +32,11 @@ pub(super) fn flatten_geometry(geometry: &CableGeometry) -> FlattenedGeometry {
         }
 
         let start_idx = usize::from(!points.is_empty());
+        let last_idx = segment.points.len() - 1;
         for i in start_idx..segment.points.len() {
+            if i % stride != 0 && i != last_idx {
+                continue;
+            }
             points.push(segment.points[i]);
             tangents.push(segment.tangents[i]);
             arc_lengths.push(segment.arc_lengths[i] + arc_offset);
@@ -121,3 +132,16 @@ pub(super) fn recompute_tangents(points: &[Vec3]) -> Vec<Vec3> {
     tangents.push((points[point_count - 1] - points[point_count - 2]).normalize_or_zero());
     tangents
 }
+
+/// Distance along the polyline to each point, starting from `start`.
+pub(super) fn distances_along(points: &[Vec3], start: f32) -> Vec<f32> {
+    let mut travelled = start;
+    let mut distances = Vec::with_capacity(points.len());
+    for (index, point) in points.iter().enumerate() {
+        if index > 0 {
+            travelled += points[index - 1].distance(*point);
+        }
+        distances.push(travelled);
+    }
+    distances
+}
@@ -0,0 +1,215 @@
+use std::f32::consts::PI;
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use bevy_kana::ToF32;
+use bevy_kana::ToU32;
+
+use super::config::CrossSection;
+use super::config::TubeConfig;
+use super::constants::CROSS_SECTION_CREASE_ANGLE_DEG;
+use super::constants::MIN_PROFILE_ARC_SEGMENTS;
+
+/// One vertex of the cross-section ring, in the profile plane.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ProfileVertex {
+    /// Offset from the path, with +X along the frame normal and +Y along the
+    /// frame binormal.
+    pub(super) position:  Vec2,
+    /// Outward surface normal in the profile plane.
+    pub(super) normal:    Vec2,
+    /// Fraction of the perimeter before this vertex, used as the V texture
+    /// coordinate.
+    pub(super) perimeter: f32,
+}
+
+/// Sample the cross-section ring for `tube_config`, counter-clockwise. A
+/// creased corner appears as two vertices at the same position, one per edge
+/// normal.
+pub(super) fn sample_profile(tube_config: &TubeConfig, sides: u32) -> Vec<ProfileVertex> {
+    let arc_segments = (sides / 2).max(MIN_PROFILE_ARC_SEGMENTS);
+    match &tube_config.cross_section {
+        CrossSection::Circle => circle(tube_config.radius, sides),
+        CrossSection::Ribbon { width, thickness } => {
+            let half_thickness = thickness / 2.0;
+            let half_run = (width / 2.0 - half_thickness).max(0.0);
+            let mut outline = arc(
+                Vec2::new(half_run, 0.0),
+                half_thickness,
+                -PI / 2.0,
+                PI / 2.0,
+                arc_segments,
+            );
+            outline.extend(arc(
+                Vec2::new(-half_run, 0.0),
+                half_thickness,
+                PI / 2.0,
+                3.0 * PI / 2.0,
+                arc_segments,
+            ));
+            polygon(&outline)
+        },
+        CrossSection::Rectangle { width, height } => {
+            let half = Vec2::new(*width, *height) / 2.0;
+            polygon(&[
+                Vec2::new(half.x, -half.y),
+                half,
+                Vec2::new(-half.x, half.y),
+                -half,
+            ])
+        },
+        CrossSection::FigureEight {
+            conductor_radius,
+            separation,
+        } => {
+            let half_separation = separation.clamp(0.0, conductor_radius * 2.0) / 2.0;
+            // Angle from each conductor's centre to the waist, measured from
+            // the line joining the centres.
+            let waist = (half_separation / conductor_radius.max(f32::EPSILON))
+                .clamp(-1.0, 1.0)
+                .acos();
+            let mut outline = arc(
+                Vec2::new(half_separation, 0.0),
+                *conductor_radius,
+                waist - PI,
+                PI - waist,
+                arc_segments,
+            );
+            outline.extend(arc(
+                Vec2::new(-half_separation, 0.0),
+                *conductor_radius,
+                waist,
+                TAU - waist,
+                arc_segments,
+            ));
+            polygon(&outline)
+        },
+        CrossSection::Custom(points) => {
+            let mut outline = points.clone();
+            outline.dedup_by(|a, b| a.distance_squared(*b) < f32::EPSILON);
+            // Wind counter-clockwise so edge normals face outward.
+            if signed_area(&outline) < 0.0 {
+                outline.reverse();
+            }
+            polygon(&outline)
+        },
+    }
+}
+
+/// How far a round cap bulges past the end of the tube: the distance from the
+/// path to the nearest side of the profile.
+pub(super) fn cap_depth(tube_config: &TubeConfig, profile: &[ProfileVertex]) -> f32 {
+    match &tube_config.cross_section {
+        CrossSection::Circle => tube_config.radius,
+        CrossSection::Ribbon { thickness, .. } => thickness / 2.0,
+        CrossSection::Rectangle { width, height } => width.min(*height) / 2.0,
+        CrossSection::FigureEight {
+            conductor_radius, ..
+        } => *conductor_radius,
+        CrossSection::Custom(_) => profile
+            .iter()
+            .zip(profile.iter().cycle().skip(1))
+            .map(|(current, next)| distance_to_origin(current.position, next.position))
+            .fold(f32::INFINITY, f32::min),
+    }
+}
+
+/// Index pairs of neighbouring ring vertices to join with surface, skipping
+/// the zero-width seam between the two vertices of a creased corner.
+pub(super) fn edges(profile: &[ProfileVertex]) -> impl Iterator<Item = (u32, u32)> + '_ {
+    let count = profile.len();
+    (0..count).filter_map(move |current| {
+        let next = (current + 1) % count;
+        (profile[current].position != profile[next].position)
+            .then_some((current.to_u32(), next.to_u32()))
+    })
+}
+
+/// Circle of `sides` vertices whose normals point exactly along the radius.
+fn circle(radius: f32, sides: u32) -> Vec<ProfileVertex> {
+    (0..sides)
+        .map(|j| {
+            let fraction = j.to_f32() / sides.to_f32();
+            let (sin_angle, cos_angle) = (fraction * TAU).sin_cos();
+            let direction = Vec2::new(cos_angle, sin_angle);
+            ProfileVertex {
+                position:  direction * radius,
+                normal:    direction,
+                perimeter: fraction,
+            }
+        })
+        .collect()
+}
+
+/// Points along an arc of `radius` around `center` from `from` to `to`
+/// radians, excluding the final point so consecutive arcs join without a
+/// duplicate.
+fn arc(center: Vec2, radius: f32, from: f32, to: f32, segments: u32) -> Vec<Vec2> {
+    (0..segments)
+        .map(|step| {
+            let angle = (to - from).mul_add(step.to_f32() / segments.to_f32(), from);
+            let (sin_angle, cos_angle) = angle.sin_cos();
+            center + Vec2::new(cos_angle, sin_angle) * radius
+        })
+        .collect()
+}
+
+/// Ring vertices for a closed counter-clockwise outline. Each vertex takes the
+/// average of its two edge normals, or is split in two at a crease.
+fn polygon(outline: &[Vec2]) -> Vec<ProfileVertex> {
+    let count = outline.len();
+    if count < 3 {
+        return Vec::new();
+    }
+
+    let edge_normal = |index: usize| {
+        let edge = outline[(index + 1) % count] - outline[index];
+        Vec2::new(edge.y, -edge.x).normalize_or_zero()
+    };
+    let edge_lengths: Vec<f32> = (0..count)
+        .map(|index| outline[index].distance(outline[(index + 1) % count]))
+        .collect();
+    let perimeter = edge_lengths.iter().sum::<f32>().max(f32::EPSILON);
+    let crease_cos = CROSS_SECTION_CREASE_ANGLE_DEG.to_radians().cos();
+
+    let mut vertices = Vec::with_capacity(count * 2);
+    let mut travelled = 0.0;
+    for (index, &position) in outline.iter().enumerate() {
+        let incoming = edge_normal((index + count - 1) % count);
+        let outgoing = edge_normal(index);
+        let fraction = travelled / perimeter;
+        if incoming.dot(outgoing) < crease_cos {
+            for normal in [incoming, outgoing] {
+                vertices.push(ProfileVertex {
+                    position,
+                    normal,
+                    perimeter: fraction,
+                });
+            }
+        } else {
+            vertices.push(ProfileVertex {
+                position,
+                normal: (incoming + outgoing).normalize_or(outgoing),
+                perimeter: fraction,
+            });
+        }
+        travelled += edge_lengths[index];
+    }
+    vertices
+}
+
+/// Twice the signed area of `outline`: positive when counter-clockwise.
+fn signed_area(outline: &[Vec2]) -> f32 {
+    outline
+        .iter()
+        .zip(outline.iter().cycle().skip(1))
+        .map(|(current, next)| current.perp_dot(*next))
+        .sum()
+}
+
+/// Distance from the origin to the segment between `a` and `b`.
+fn distance_to_origin(a: Vec2, b: Vec2) -> f32 {
+    let edge = b - a;
+    let t = (-a.dot(edge) / edge.length_squared().max(f32::EPSILON)).clamp(0.0, 1.0);
+    (a + edge * t).length()
+}
@@ -0,0 +1,59 @@
+//! [`stripe_image`]: repeating striped textures for cable materials.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::ImageAddressMode;
+use bevy::image::ImageSampler;
+use bevy::image::ImageSamplerDescriptor;
+use bevy::prelude::*;
+use bevy::render::render_resource::Extent3d;
+use bevy::render::render_resource::TextureDimension;
+use bevy::render::render_resource::TextureFormat;
+use bevy_kana::ToU32;
+
+/// Which way the stripes of a [`stripe_image`] run on a cable.
+#[derive(Clone, Copy, Debug, Default, Reflect)]
+pub enum StripeOrientation {
+    /// Bands around the cable, repeating every
+    /// [`UvConfig::repeat_length`](super::UvConfig::repeat_length) along it.
+    #[default]
+    Bands,
+    /// Tracer stripes running along the cable, side by side around it.
+    Tracers,
+}
+
+/// A texture of `colors` in equal stripes, repeating and sampled without
+/// blending, for use as a cable material's `base_color_texture`.
+#[must_use]
+pub fn stripe_image(colors: &[Color], orientation: StripeOrientation) -> Image {
+    let count = colors.len().max(1).to_u32();
+    let data = if colors.is_empty() {
+        Color::WHITE.to_srgba().to_u8_array().to_vec()
+    } else {
+        colors
+            .iter()
+            .flat_map(|color| color.to_srgba().to_u8_array())
+            .collect()
+    };
+    let (width, height) = match orientation {
+        StripeOrientation::Bands => (1, count),
+        StripeOrientation::Tracers => (count, 1),
+    };
+
+    let mut image = Image::new(
+        Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        address_mode_u: ImageAddressMode::Repeat,
+        address_mode_v: ImageAddressMode::Repeat,
+        ..ImageSamplerDescriptor::nearest()
+    });
+    image
+}
@@ -1,9 +1,6 @@
-use std::f32::consts::TAU;
-
 use bevy::mesh::Indices;
 use bevy::mesh::PrimitiveTopology;
 use bevy::prelude::*;
-use bevy_kana::ToF32;
 use bevy_kana::ToU32;
 use bevy_kana::ToUsize;
 
@@ -12,32 +9,41 @@ use super::buffers::MeshBuffers;
 use super::buffers::TubeMeshBuffers;
 use super::buffers::WindingOrder;
 use super::caps;
+use super::caps::CapProfile;
 use super::config::CableMeshConfig;
 use super::config::Faces;
 use super::constants::MIN_TUBE_SIDES;
 use super::elbows;
 use super::frames;
 use super::path;
+use super::profile;
+use super::profile::ProfileVertex;
 use crate::routing::CableGeometry;
 use crate::routing::MIN_CABLE_SAMPLE_POINTS;
 use crate::routing::MIN_SEGMENT_LENGTH;
 
 /// Immutable path data for tube mesh generation.
-struct TubePathData<'a> {
-    points:      &'a [Vec3],
-    tangents:    &'a [Vec3],
-    arc_lengths: &'a [f32],
-    frames:      &'a [(Vec3, Vec3)],
+pub(super) struct TubePathData<'a> {
+    pub(super) points:    &'a [Vec3],
+    pub(super) tangents:  &'a [Vec3],
+    /// Distance along the path to each point, in metres, measured from the
+    /// start of the untrimmed cable.
+    pub(super) distances: &'a [f32],
+    pub(super) frames:    &'a [(Vec3, Vec3)],
 }
 
 /// Generate cross-section rings along the path and connect them with triangles.
 fn generate_tube_rings(
     tube_path_data: &TubePathData,
     cable_mesh_config: &CableMeshConfig,
-    sides: u32,
-    total_length: f32,
+    profile: &[ProfileVertex],
     out: &mut TubeMeshBuffers,
 ) {
+    let ring_size = profile.len();
+    let repeat_length = cable_mesh_config
+        .uv_config
+        .repeat_length
+        .max(MIN_SEGMENT_LENGTH);
     for (i, ((point, ..), (frame_normal, binormal))) in tube_path_data
         .points
         .iter()
@@ -45,29 +51,24 @@ fn generate_tube_rings(
         .zip(tube_path_data.frames)
         .enumerate()
     {
-        let arc_u = tube_path_data.arc_lengths[i] / total_length;
+        let v = tube_path_data.distances[i] / repeat_length;
 
-        for j in 0..sides {
-            let angle = (j.to_f32() / sides.to_f32()) * TAU;
-            let (sin_angle, cos_angle) = angle.sin_cos();
-            let offset = *frame_normal * cos_angle * cable_mesh_config.tube_config.radius
-                + *binormal * sin_angle * cable_mesh_config.tube_config.radius;
+        for vertex in profile {
+            let offset = *frame_normal * vertex.position.x + *binormal * vertex.position.y;
             let vertex_position = *point + offset;
-            let vertex_normal = offset.normalize_or_zero();
+            let vertex_normal =
+                (*frame_normal * vertex.normal.x + *binormal * vertex.normal.y).normalize_or_zero();
 
             out.mesh_buffers.positions.push(vertex_position.to_array());
             out.mesh_buffers.normals.push(vertex_normal.to_array());
-            out.mesh_buffers
-                .uvs
-                .push([arc_u, j.to_f32() / sides.to_f32()]);
+            out.mesh_buffers.uvs.push([vertex.perimeter, v]);
         }
 
         if i > 0 {
-            let base = ((i - 1) * sides.to_usize()).to_u32();
-            let next_base = (i * sides.to_usize()).to_u32();
+            let base = ((i - 1) * ring_size).to_u32();
+            let next_base = (i * ring_size).to_u32();
 
-            for j in 0..sides {
-                let next = (j + 1) % sides;
+            for (j, next) in profile::edges(profile) {
                 let current = base + j;
                 let current_next = base + next;
                 let upcoming = next_base + j;
@@ -153,13 +154,18 @@ fn apply_inside_normals(
     }
 }
 
-/// All segments are flattened into a single continuous polyline.
+/// All segments are flattened into a single continuous polyline, and the
+/// [`CrossSection`](super::CrossSection) profile is swept along it.
 #[must_use]
 pub fn generate_tube_mesh(geometry: &CableGeometry, cable_mesh_config: &CableMeshConfig) -> Mesh {
-    let sides = cable_mesh_config.tube_config.sides.max(MIN_TUBE_SIDES);
-    let total_length = geometry.total_length.max(MIN_SEGMENT_LENGTH);
+    let tube_config = &cable_mesh_config.tube_config;
+    let sides = tube_config.sides.max(MIN_TUBE_SIDES);
+    let profile = profile::sample_profile(tube_config, sides);
+    if profile.len() < MIN_TUBE_SIDES.to_usize() {
+        return Mesh::new(PrimitiveTopology::TriangleList, default());
+    }
 
-    let flattened_geometry = path::flatten_geometry(geometry);
+    let flattened_geometry = path::flatten_geometry(geometry, tube_config.path_density);
     let mut all_points = flattened_geometry.points;
     let mut all_tangents = flattened_geometry.tangents;
     let mut all_arc_lengths = flattened_geometry.arc_lengths;
@@ -185,25 +191,37 @@ pub fn generate_tube_mesh(geometry: &CableGeometry, cable_mesh_config: &CableMes
     let (all_points, all_tangents, all_arc_lengths) =
         elbows::insert_knee_rings(all_points, all_arc_lengths, cable_mesh_config);
     let point_count = all_points.len();
-    let frames = frames::compute_rotation_minimizing_frames(&all_points, &all_tangents);
+    // Measured along the filleted path itself, so V keeps pace with the
+    // surface through elbows.
+    let distances = path::distances_along(&all_points, all_arc_lengths[0]);
+    let mut frames = frames::compute_rotation_minimizing_frames(
+        &all_points,
+        &all_tangents,
+        tube_config.twist.up,
+    );
+    frames::apply_twist(
+        &mut frames,
+        &all_tangents,
+        &distances,
+        tube_config.twist.angle,
+    );
 
-    let mut positions = Vec::with_capacity(point_count * sides.to_usize());
-    let mut normals = Vec::with_capacity(point_count * sides.to_usize());
-    let mut uvs = Vec::with_capacity(point_count * sides.to_usize());
+    let mut positions = Vec::with_capacity(point_count * profile.len());
+    let mut normals = Vec::with_capacity(point_count * profile.len());
+    let mut uvs = Vec::with_capacity(point_count * profile.len());
     let mut indices = Vec::new();
     let mut inside_indices = Vec::new();
 
     let tube_path_data = TubePathData {
-        points:      &all_points,
-        tangents:    &all_tangents,
-        arc_lengths: &all_arc_lengths,
-        frames:      &frames,
+        points:    &all_points,
+        tangents:  &all_tangents,
+        distances: &distances,
+        frames:    &frames,
     };
     generate_tube_rings(
         &tube_path_data,
         cable_mesh_config,
-        sides,
-        total_length,
+        &profile,
         &mut TubeMeshBuffers {
             mesh_buffers:   MeshBuffers {
                 positions: &mut positions,
@@ -216,7 +234,7 @@ pub fn generate_tube_mesh(geometry: &CableGeometry, cable_mesh_config: &CableMes
     );
 
     apply_inside_normals(
-        &cable_mesh_config.tube_config.faces,
+        &tube_config.faces,
         &mut positions,
         &mut normals,
         &mut uvs,
@@ -231,12 +249,13 @@ pub fn generate_tube_mesh(geometry: &CableGeometry, cable_mesh_config: &CableMes
         indices:   &mut indices,
     };
     caps::add_end_caps(
-        &all_points,
-        &all_tangents,
-        &frames,
+        &tube_path_data,
         cable_mesh_config,
-        sides,
-        point_count,
+        &CapProfile {
+            vertices: &profile,
+            depth: profile::cap_depth(tube_config, &profile),
+            sides,
+        },
         &mut buffers,
     );
 
@@ -15,6 +15,8 @@ use bevy::math::Vec3;
 use bevy::reflect::Reflect;
 use bevy_kana::ToF32;
 use bevy_kana::ToUsize;
+use serde::Deserialize;
+use serde::Serialize;
 
 use super::constants::DEFAULT_GRAVITY;
 use super::constants::DEFAULT_RESOLUTION;
@@ -33,15 +35,24 @@ use super::geometry::CableSegment;
 use super::geometry::RouteRequest;
 use super::solver::CurveSolver;
 use super::solver::RouteSolver;
+use super::support;
+use super::support::SupportSurface;
 
 /// Solver that computes catenary curves between cable endpoints.
 ///
 /// Implements both [`CurveSolver`] (for use with [`Router`](crate::Router)) and [`RouteSolver`]
 /// (for standalone use without obstacle avoidance).
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub struct CatenarySolver {
-    /// Cable length / straight-line distance. Values > 1.0 add sag.
+    /// Cable length / straight-line distance. Values > 1.0 add sag. Ignored
+    /// when `length` is set.
     pub slack:             f32,
+    /// Absolute cable length in metres, including any lead segments. When
+    /// set, the cable sags more as its endpoints move closer and pulls
+    /// straight once they are farther apart than this; `None` derives the
+    /// length from `slack` instead. Used as a per-segment [`CurveSolver`]
+    /// inside a [`Router`](crate::Router), the solver falls back to `slack`.
+    pub length:            Option<f32>,
     /// Gravity direction (not necessarily normalized; magnitude is ignored).
     pub gravity:           Vec3,
     /// Default sample resolution when not specified by the request.
@@ -56,6 +67,7 @@ impl CatenarySolver {
     pub const fn new() -> Self {
         Self {
             slack:             DEFAULT_SLACK,
+            length:            None,
             gravity:           DEFAULT_GRAVITY,
             resolution:        DEFAULT_RESOLUTION,
             detach_slack_bump: None,
@@ -69,6 +81,13 @@ impl CatenarySolver {
         self
     }
 
+    /// Set an absolute cable length, in metres, overriding `slack`.
+    #[must_use]
+    pub const fn with_length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
     /// Set the gravity vector.
     #[must_use]
     pub const fn with_gravity(mut self, gravity: Vec3) -> Self {
@@ -83,18 +102,69 @@ impl CatenarySolver {
         self
     }
 
-    /// Configure extra slack to apply when any endpoint of the owning cable detaches.
+    /// Configure extra slack to apply when any endpoint of the owning cable
+    /// detaches. Has no effect on a cable with an absolute `length`.
     #[must_use]
     pub const fn with_detach_slack_bump(mut self, bump: f32) -> Self {
         self.detach_slack_bump = Some(bump);
         self
     }
+
+    /// This solver with an absolute `length` converted to the `slack` of a
+    /// route whose straight path through its waypoints is `path_length` long,
+    /// after `lead_length` is spent on straight leads. Routes longer than the
+    /// cable come out taut. Without an absolute length, returns the solver
+    /// unchanged.
+    pub(super) fn fit_to_path(&self, path_length: f32, lead_length: f32) -> Self {
+        let Some(length) = self.length else {
+            return self.clone();
+        };
+        let slack = if path_length > MIN_SEGMENT_LENGTH {
+            ((length - lead_length) / path_length).max(MIN_TAUT_CABLE_SLACK)
+        } else {
+            MIN_TAUT_CABLE_SLACK
+        };
+        Self {
+            slack,
+            length: None,
+            ..self.clone()
+        }
+    }
+
+    /// [`CurveSolver::solve_segment`], with the cable resting on the highest
+    /// of `supports` it would otherwise sag through.
+    pub(super) fn solve_supported_segment(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        supports: &[SupportSurface],
+        resolution: u32,
+    ) -> CableSegment {
+        let free = self.solve_segment(start, end, resolution);
+        let gravity_direction = self.gravity.normalize_or_zero();
+        if supports.is_empty()
+            || gravity_direction.length_squared() < NEAR_ZERO_GRAVITY_THRESHOLD
+            || self.slack < STRAIGHT_LINE_THRESHOLD
+        {
+            return free;
+        }
+        support::rest_on_supports(
+            free,
+            start,
+            end,
+            start.distance(end) * self.slack,
+            -gravity_direction,
+            supports,
+            resolution,
+        )
+    }
 }
 
 impl Default for CatenarySolver {
     fn default() -> Self {
         Self {
             slack:             DEFAULT_SLACK,
+            length:            None,
             gravity:           DEFAULT_GRAVITY,
             resolution:        DEFAULT_RESOLUTION,
             detach_slack_bump: None,
@@ -112,7 +182,14 @@ impl CurveSolver for CatenarySolver {
 impl RouteSolver for CatenarySolver {
     fn solve(&self, request: &RouteRequest) -> CableGeometry {
         let resolution = request.effective_resolution(self.resolution);
-        let segment = self.solve_segment(request.start.position, request.end.position, resolution);
+        let segment = self
+            .fit_to_path(request.start.position.distance(request.end.position), 0.0)
+            .solve_supported_segment(
+                request.start.position,
+                request.end.position,
+                request.supports,
+                resolution,
+            );
         let waypoints = vec![request.start.position, request.end.position];
         CableGeometry::from_segments(vec![segment], waypoints)
     }
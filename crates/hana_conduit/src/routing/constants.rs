@@ -1,5 +1,6 @@
 //! Named constants for the routing module. No magic values.
 
+use bevy::math::Dir3;
 use bevy::math::Vec3;
 
 // catenary solver
@@ -30,10 +31,69 @@ pub(super) const DEFAULT_RESOLUTION_SENTINEL: u32 = 0;
 /// 1.0 = taut (straight line), values > 1.0 add sag.
 pub const DEFAULT_SLACK: f32 = 1.2;
 
+// cable simulation
+/// Default fraction of bending the simulated chain corrects per iteration.
+pub(super) const DEFAULT_SIMULATION_BENDING_STIFFNESS: f32 = 0.01;
+/// Default fraction of particle velocity lost per second.
+pub(super) const DEFAULT_SIMULATION_DAMPING: f32 = 0.9;
+/// Default constraint-projection iterations per simulation step.
+pub(super) const DEFAULT_SIMULATION_ITERATIONS: u32 = 24;
+/// Default distance particles keep from obstacle faces, in metres.
+pub(super) const DEFAULT_SIMULATION_COLLISION_RADIUS: f32 = 0.02;
+/// Longest time step one simulation step integrates, in seconds. Longer frames
+/// (a hitch, a backgrounded window) are clamped so the chain never explodes.
+pub(super) const MAX_SIMULATION_STEP_SECONDS: f32 = 1.0 / 30.0;
+
+// cable layout
+/// Fraction of a bundle's start-to-end distance over which its cables fan out
+/// from the shared spine at each end.
+pub(super) const BUNDLE_FAN_FRACTION: f32 = 0.2;
+/// Gap left between neighbouring cables in a corridor lane or bundle, in
+/// metres.
+pub(super) const CABLE_LANE_GAP: f32 = 0.02;
+/// Points sampled along each waypoint segment when checking whether a
+/// cable-avoiding route cut through a real obstacle.
+pub(super) const LAYOUT_SEGMENT_SAMPLE_STEPS: u32 = 20;
+
+// spline curves
+/// Default spline clearance from obstacle surfaces, in metres.
+pub(super) const DEFAULT_SPLINE_CLEARANCE: f32 = 0.05;
+/// Default smallest radius a spline bends through at a corner, in metres.
+pub(super) const DEFAULT_SPLINE_MIN_BEND_RADIUS: f32 = 0.2;
+/// Default spline tension: circular-arc corners.
+pub(super) const DEFAULT_SPLINE_TENSION: f32 = 0.0;
+/// Times a spline pulls its corners in toward the waypoint path after a
+/// sample cut through an obstacle, before leaving those corners sharp.
+pub(super) const SPLINE_CLEARANCE_REFINEMENTS: u32 = 6;
+
+// support surfaces
+/// Bisection steps taken when solving for a resting cable's catenary
+/// parameter.
+pub(super) const SUPPORT_BISECTION_ITERATIONS: u32 = 40;
+/// Depth below a support surface a sample must sag before the cable is laid on
+/// it, and the margin a lift-off point may overhang a bounded surface, in
+/// metres.
+pub(super) const SUPPORT_CONTACT_TOLERANCE: f32 = 0.001;
+
+// via points
+/// Length of the straight piece that holds a [`ViaPoint`](super::ViaPoint)'s
+/// tangent, in metres.
+pub(super) const VIA_TANGENT_LENGTH: f32 = 0.1;
+
+// convex hulls
+/// Fraction of a point set's largest extent within which a point counts as
+/// lying on a hull face rather than outside it.
+pub(super) const HULL_TOLERANCE_RATIO: f32 = 1e-5;
+
 // grid pathfinding
 /// Chebyshev radius, in cells, searched for a clear cell when a route
 /// endpoint's quantized cell lands inside an obstacle.
 pub(super) const ASTAR_CLEAR_CELL_SEARCH_RADIUS: i32 = 3;
+/// Fine cells along each edge of a coarse cell in hierarchical A*.
+pub(super) const ASTAR_COARSE_FACTOR: u32 = 4;
+/// Chebyshev distance, in fine cells, between a search's start and goal from
+/// which A* first routes on the coarse grid.
+pub(super) const ASTAR_HIERARCHICAL_MIN_CELLS: i32 = 16;
 pub(super) const ASTAR_SEGMENT_SAMPLE_STEPS: u32 = 20;
 /// Sample points per grid cell of segment length when testing whether a
 /// shortcut between two route waypoints stays clear of obstacles. Scaling by
@@ -42,10 +102,20 @@ pub(super) const ASTAR_SHORTCUT_SAMPLES_PER_CELL: f32 = 2.0;
 pub(super) const COLLINEARITY_THRESHOLD: f32 = 0.999;
 pub(super) const DEFAULT_ASTAR_MAX_CELLS: usize = 10_000;
 
+// obstacle index
+/// Default edge length of an [`ObstacleIndex`](super::ObstacleIndex) bucket,
+/// in metres.
+pub(super) const DEFAULT_OBSTACLE_INDEX_BUCKET_SIZE: f32 = 1.0;
+/// Most buckets one obstacle is filed under; larger obstacles are tested by
+/// every query instead.
+pub(super) const OBSTACLE_INDEX_MAX_BUCKETS: i64 = 512;
+
 // orthogonal routing
 pub(super) const AXIS_X_INDEX: usize = 0;
 pub(super) const AXIS_Y_INDEX: usize = 1;
 pub(super) const AXIS_Z_INDEX: usize = 2;
+/// The axis each `AXIS_*_INDEX` names, for reporting the order chosen.
+pub(super) const AXIS_DIRECTIONS: [Dir3; 3] = [Dir3::X, Dir3::Y, Dir3::Z];
 pub(super) const HORIZONTAL_FIRST_AXIS_ORDERS: [[usize; 3]; 4] = [
     [AXIS_X_INDEX, AXIS_Z_INDEX, AXIS_Y_INDEX],
     [AXIS_X_INDEX, AXIS_Y_INDEX, AXIS_Z_INDEX],
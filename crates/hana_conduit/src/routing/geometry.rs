@@ -1,16 +1,24 @@
-//! Route boundary types: `Anchor` connection points, `RouteRequest` inputs, and the
+//! Route boundary types: `Anchor` connection points, `ViaPoint` pass-through
+//! points, `RouteRequest` inputs, and the
 //! `CableSegment` / `CableGeometry` outputs that bridge route computation and rendering.
 
 use std::iter;
 
 use bevy::math::Dir3;
+use bevy::math::Ray3d;
 use bevy::math::Vec3;
 use bevy_kana::ToF32;
 use bevy_kana::ToUsize;
 
 use super::constants::DEFAULT_RESOLUTION_SENTINEL;
 use super::constants::MIN_CABLE_SAMPLE_POINTS;
+use super::constants::VIA_TANGENT_LENGTH;
 use super::obstacle::Obstacle;
+use super::obstacle_index::ObstacleIndex;
+use super::outcome::RouteOutcome;
+use super::ray_cast;
+use super::ray_cast::CableRayHit;
+use super::support::SupportSurface;
 
 enum TangentSample {
     Only,
@@ -80,20 +88,111 @@ impl From<Vec3> for Anchor {
     }
 }
 
+/// A point the route must pass through between its anchors — a hanger the
+/// cable hooks over, or a slot in a cable tray.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViaPoint {
+    /// World-space position the route passes through.
+    pub position: Vec3,
+    /// World-space direction the cable must run through `position`, or `None`
+    /// to let the route bend freely there. A tangent is held by a straight
+    /// piece of `VIA_TANGENT_LENGTH` centred on the point.
+    pub tangent:  Option<Dir3>,
+}
+
+impl ViaPoint {
+    /// Where the route enters and leaves the via point: the ends of its
+    /// straight through-piece, or `position` twice without a tangent.
+    #[must_use]
+    pub fn through_piece(&self) -> (Vec3, Vec3) {
+        self.tangent
+            .map_or((self.position, self.position), |tangent| {
+                let half = tangent * (VIA_TANGENT_LENGTH / 2.0);
+                (self.position - half, self.position + half)
+            })
+    }
+}
+
+impl From<Vec3> for ViaPoint {
+    fn from(position: Vec3) -> Self {
+        Self {
+            position,
+            tangent: None,
+        }
+    }
+}
+
 /// Everything a solver needs to compute a route.
 #[derive(Clone, Debug)]
 pub struct RouteRequest<'a> {
     /// Starting anchor of the cable.
-    pub start:      Anchor,
+    pub start:          Anchor,
     /// Ending anchor of the cable.
-    pub end:        Anchor,
+    pub end:            Anchor,
     /// Obstacles to route around (may be empty).
-    pub obstacles:  &'a [Obstacle],
+    pub obstacles:      &'a [Obstacle],
+    /// Points the route passes through, in order from `start` to `end` (may
+    /// be empty). Honored by [`Router`](super::Router) and
+    /// [`Solver`](super::Solver); the single-curve solvers run straight
+    /// between the anchors.
+    pub vias:           &'a [ViaPoint],
+    /// Surfaces a sagging cable rests on instead of passing through (may be
+    /// empty). Honored by catenary curves: [`CatenarySolver`](super::CatenarySolver)
+    /// and [`Solver`](super::Solver)'s catenary and simulated cables.
+    pub supports:       &'a [SupportSurface],
+    /// Prebuilt index of exactly `obstacles`, queried by grid pathfinding
+    /// instead of indexing them for every plan (may be `None`). Build it once
+    /// with [`ObstacleIndex::new`] and share it across every request routed
+    /// against the same obstacles.
+    pub obstacle_index: Option<&'a ObstacleIndex>,
     /// Number of sample points per segment.
-    pub resolution: u32,
+    pub resolution:     u32,
 }
 
-impl RouteRequest<'_> {
+impl<'a> RouteRequest<'a> {
+    /// Create a request between `start` and `end` with no obstacles, vias,
+    /// supports, or prebuilt index.
+    #[must_use]
+    pub const fn new(start: Anchor, end: Anchor, resolution: u32) -> Self {
+        Self {
+            start,
+            end,
+            obstacles: &[],
+            vias: &[],
+            supports: &[],
+            obstacle_index: None,
+            resolution,
+        }
+    }
+
+    /// Set the obstacles to route around.
+    #[must_use]
+    pub const fn with_obstacles(mut self, obstacles: &'a [Obstacle]) -> Self {
+        self.obstacles = obstacles;
+        self
+    }
+
+    /// Set the points the route passes through.
+    #[must_use]
+    pub const fn with_vias(mut self, vias: &'a [ViaPoint]) -> Self {
+        self.vias = vias;
+        self
+    }
+
+    /// Set the surfaces a sagging cable rests on.
+    #[must_use]
+    pub const fn with_supports(mut self, supports: &'a [SupportSurface]) -> Self {
+        self.supports = supports;
+        self
+    }
+
+    /// Set the prebuilt index of the request's obstacles.
+    #[must_use]
+    pub const fn with_obstacle_index(mut self, obstacle_index: &'a ObstacleIndex) -> Self {
+        self.obstacle_index = Some(obstacle_index);
+        self
+    }
+
     /// Returns the request's resolution if set, otherwise falls back to `default`.
     #[must_use]
     pub const fn effective_resolution(&self, default: u32) -> u32 {
@@ -187,6 +286,8 @@ pub struct CableGeometry {
     pub total_length: f32,
     /// Structural waypoints (start, intermediate bends, end).
     pub waypoints:    Vec<Vec3>,
+    /// How the path strategy arrived at `waypoints`.
+    pub outcome:      RouteOutcome,
 }
 
 impl CableGeometry {
@@ -198,11 +299,49 @@ impl CableGeometry {
             segments,
             total_length,
             waypoints,
+            outcome: RouteOutcome::Success,
         }
     }
 
+    /// Record how the path strategy arrived at the waypoints.
+    #[must_use]
+    pub const fn with_outcome(mut self, outcome: RouteOutcome) -> Self {
+        self.outcome = outcome;
+        self
+    }
+
     /// Iterate over all sample points across all segments.
     pub fn all_points(&self) -> impl Iterator<Item = &Vec3> {
         self.segments.iter().flat_map(|s| &s.points)
     }
+
+    /// Length of the straight path through the waypoints — the shortest
+    /// cable that can follow this route.
+    #[must_use]
+    pub fn waypoint_length(&self) -> f32 { polyline_length(&self.waypoints) }
+
+    /// Nearest hit of `ray` on the tube of `radius` swept along the cable's
+    /// sample points, or `None` when it misses or starts inside the tube.
+    #[must_use]
+    pub fn cast_ray(&self, ray: Ray3d, radius: f32) -> Option<CableRayHit> {
+        let mut nearest: Option<CableRayHit> = None;
+        let mut start_arc_length = 0.0;
+        for segment in &self.segments {
+            if let Some(hit) = ray_cast::cast_ray_at_segment(ray, segment, radius, start_arc_length)
+                && nearest.is_none_or(|nearest| hit.distance < nearest.distance)
+            {
+                nearest = Some(hit);
+            }
+            start_arc_length += segment.length;
+        }
+        nearest
+    }
+}
+
+/// Length of the straight polyline through `points`.
+pub(super) fn polyline_length(points: &[Vec3]) -> f32 {
+    points
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum()
 }
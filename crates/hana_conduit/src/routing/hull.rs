@@ -0,0 +1,330 @@
+//! `ConvexHull` — the planes bounding a convex point set — and the
+//! plane-clipping split that decomposes a triangle surface into convex pieces.
+
+use std::collections::HashSet;
+
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::constants::HULL_TOLERANCE_RATIO;
+
+/// A convex polyhedron stored as its vertices and outward-facing triangles.
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+pub(crate) struct ConvexHull {
+    pub(crate) vertices: Vec<Vec3>,
+    pub(crate) faces:    Vec<HullFace>,
+}
+
+/// One outward-facing hull triangle and the plane it lies in.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, Reflect)]
+pub(crate) struct HullFace {
+    pub(crate) corners: [usize; 3],
+    normal:             Vec3,
+    offset:             f32,
+}
+
+impl HullFace {
+    /// The face through `corners`, wound so its normal follows the right-hand
+    /// rule. `None` when the corners are collinear.
+    fn new(points: &[Vec3], corners: [usize; 3]) -> Option<Self> {
+        let [a, b, c] = corners.map(|corner| points[corner]);
+        let normal = (b - a).cross(c - a).try_normalize()?;
+        Some(Self {
+            corners,
+            normal,
+            offset: normal.dot(a),
+        })
+    }
+
+    const fn flipped(self) -> Self {
+        let [a, b, c] = self.corners;
+        Self {
+            corners: [a, c, b],
+            normal:  Vec3::new(-self.normal.x, -self.normal.y, -self.normal.z),
+            offset:  -self.offset,
+        }
+    }
+
+    /// Signed distance from the face plane, positive on the outside.
+    fn distance(&self, point: Vec3) -> f32 { self.normal.dot(point) - self.offset }
+}
+
+impl ConvexHull {
+    /// Build the hull of `points` incrementally: start from the widest
+    /// tetrahedron, then for each point outside the hull replace the faces it
+    /// can see with a fan from the horizon to the point. `None` when the points
+    /// are empty or flat (collinear or coplanar).
+    pub(crate) fn from_points(points: &[Vec3]) -> Option<Self> {
+        let (min, max) = bounds(points.iter().copied())?;
+        let tolerance = (max - min).max_element() * HULL_TOLERANCE_RATIO;
+        if tolerance <= 0.0 {
+            return None;
+        }
+
+        let simplex = initial_simplex(points, tolerance)?;
+        let centroid = simplex.iter().map(|&corner| points[corner]).sum::<Vec3>() / 4.0;
+        let [a, b, c, d] = simplex;
+        let mut faces = Vec::with_capacity(points.len());
+        for corners in [[a, b, c], [a, d, b], [b, d, c], [c, d, a]] {
+            let face = HullFace::new(points, corners)?;
+            faces.push(if face.distance(centroid) > 0.0 {
+                face.flipped()
+            } else {
+                face
+            });
+        }
+
+        for (index, &point) in points.iter().enumerate() {
+            let (visible, hidden): (Vec<HullFace>, Vec<HullFace>) = faces
+                .into_iter()
+                .partition(|face| face.distance(point) > tolerance);
+            faces = hidden;
+            if visible.is_empty() {
+                continue;
+            }
+
+            // A visible face's edge is on the horizon when the face across it
+            // is hidden, i.e. the reversed edge belongs to no visible face.
+            let visible_edges: HashSet<(usize, usize)> = visible
+                .iter()
+                .flat_map(|face| {
+                    let [a, b, c] = face.corners;
+                    [(a, b), (b, c), (c, a)]
+                })
+                .collect();
+            faces.extend(
+                visible_edges
+                    .iter()
+                    .filter(|&&(from, to)| !visible_edges.contains(&(to, from)))
+                    .filter_map(|&(from, to)| HullFace::new(points, [from, to, index])),
+            );
+        }
+
+        // Keep only the points the surviving faces reference.
+        let mut used: Vec<usize> = faces.iter().flat_map(|face| face.corners).collect();
+        used.sort_unstable();
+        used.dedup();
+        let vertices = used.iter().map(|&corner| points[corner]).collect();
+        for face in &mut faces {
+            face.corners = face
+                .corners
+                .map(|corner| used.binary_search(&corner).unwrap_or_default());
+        }
+        Some(Self { vertices, faces })
+    }
+
+    /// Largest face-plane distance of `point`: positive outside the hull,
+    /// zero or negative inside.
+    pub(crate) fn plane_distance(&self, point: Vec3) -> f32 {
+        self.faces
+            .iter()
+            .map(|face| face.distance(point))
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Move a point inside the hull out through its nearest face plane,
+    /// landing `clearance` metres beyond it.
+    pub(crate) fn push_out(&self, point: Vec3, clearance: f32) -> Vec3 {
+        self.faces
+            .iter()
+            .max_by(|a, b| a.distance(point).total_cmp(&b.distance(point)))
+            .filter(|face| face.distance(point) <= 0.0)
+            .map_or(point, |face| {
+                point + face.normal * (clearance - face.distance(point))
+            })
+    }
+
+    /// Each hull edge once, as a pair of vertex positions.
+    pub(crate) fn edges(&self) -> impl Iterator<Item = (Vec3, Vec3)> + '_ {
+        self.faces
+            .iter()
+            .flat_map(|face| {
+                let [a, b, c] = face.corners;
+                [(a, b), (b, c), (c, a)]
+            })
+            .filter(|(from, to)| from < to)
+            .map(|(from, to)| (self.vertices[from], self.vertices[to]))
+    }
+
+    pub(crate) fn bounds(&self) -> (Vec3, Vec3) {
+        bounds(self.vertices.iter().copied()).unwrap_or((Vec3::ZERO, Vec3::ZERO))
+    }
+}
+
+/// Component-wise minimum and maximum of `points`, or `None` when empty.
+pub(super) fn bounds(points: impl Iterator<Item = Vec3>) -> Option<(Vec3, Vec3)> {
+    points.fold(None, |extents, point| match extents {
+        None => Some((point, point)),
+        Some((min, max)) => Some((min.min(point), max.max(point))),
+    })
+}
+
+/// Four points spanning a tetrahedron thicker than `tolerance`: the lowest-x
+/// point, the point farthest from it, the point farthest from their line, and
+/// the point farthest from their plane.
+fn initial_simplex(points: &[Vec3], tolerance: f32) -> Option<[usize; 4]> {
+    let farthest = |distance: &dyn Fn(Vec3) -> f32| {
+        points
+            .iter()
+            .enumerate()
+            .map(|(index, &point)| (index, distance(point)))
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|&(_, distance)| distance > tolerance)
+            .map(|(index, _)| index)
+    };
+
+    let a = points
+        .iter()
+        .enumerate()
+        .min_by(|a, b| a.1.x.total_cmp(&b.1.x))
+        .map(|(index, _)| index)?;
+    let b = farthest(&|point| point.distance(points[a]))?;
+    let direction = (points[b] - points[a]).normalize_or_zero();
+    let c = farthest(&|point| (point - points[a]).cross(direction).length())?;
+    let normal = (points[b] - points[a])
+        .cross(points[c] - points[a])
+        .normalize_or_zero();
+    let d = farthest(&|point| normal.dot(point - points[a]).abs())?;
+    Some([a, b, c, d])
+}
+
+/// Split `triangles` into up to `pieces` groups of points whose convex hulls
+/// together cover the solid the triangles enclose.
+///
+/// The piece with the largest bounding box is repeatedly cut in half across
+/// its longest axis. Triangles straddling the cut are clipped against it, so
+/// each half keeps the outline where the solid crosses the cutting plane and
+/// its hull still covers its half of the solid.
+pub(crate) fn split_into_pieces(triangles: &[[Vec3; 3]], pieces: usize) -> Vec<Vec<Vec3>> {
+    let mut groups: Vec<Vec<Vec<Vec3>>> =
+        vec![triangles.iter().map(|triangle| triangle.to_vec()).collect()];
+
+    while groups.len() < pieces {
+        let Some((largest, (min, max))) = groups
+            .iter()
+            .enumerate()
+            .filter_map(|(index, group)| {
+                bounds(group.iter().flatten().copied()).map(|extents| (index, extents))
+            })
+            .max_by(|(_, a), (_, b)| volume(*a).total_cmp(&volume(*b)))
+        else {
+            break;
+        };
+        let size = max - min;
+        let axis = if size.x >= size.y && size.x >= size.z {
+            Vec3::X
+        } else if size.y >= size.z {
+            Vec3::Y
+        } else {
+            Vec3::Z
+        };
+        let cut = axis.dot((min + max) / 2.0);
+
+        let group = groups.swap_remove(largest);
+        let below: Vec<Vec<Vec3>> = group
+            .iter()
+            .filter_map(|polygon| clip(polygon, -axis, -cut))
+            .collect();
+        let above: Vec<Vec<Vec3>> = group
+            .iter()
+            .filter_map(|polygon| clip(polygon, axis, cut))
+            .collect();
+        if below.is_empty() || above.is_empty() {
+            groups.push(group);
+            break;
+        }
+        groups.push(below);
+        groups.push(above);
+    }
+
+    groups
+        .into_iter()
+        .map(|group| group.into_iter().flatten().collect())
+        .collect()
+}
+
+fn volume((min, max): (Vec3, Vec3)) -> f32 {
+    let size = max - min;
+    size.x * size.y * size.z
+}
+
+/// Keep the part of `polygon` where `normal · point >= offset`
+/// (Sutherland–Hodgman against one plane). `None` when nothing remains.
+fn clip(polygon: &[Vec3], normal: Vec3, offset: f32) -> Option<Vec<Vec3>> {
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (index, &current) in polygon.iter().enumerate() {
+        let next = polygon[(index + 1) % polygon.len()];
+        let current_distance = normal.dot(current) - offset;
+        let next_distance = normal.dot(next) - offset;
+        if current_distance >= 0.0 {
+            clipped.push(current);
+        }
+        if (current_distance >= 0.0) != (next_distance >= 0.0) {
+            let t = current_distance / (current_distance - next_distance);
+            clipped.push(current.lerp(next, t));
+        }
+    }
+    (!clipped.is_empty()).then_some(clipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cube_corners() -> Vec<Vec3> {
+        (0..8)
+            .map(|bits| {
+                Vec3::new(
+                    if (bits & 1) == 0 { -1.0 } else { 1.0 },
+                    if (bits & 2) == 0 { -1.0 } else { 1.0 },
+                    if (bits & 4) == 0 { -1.0 } else { 1.0 },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hull_of_cube_with_interior_points_keeps_only_corners() {
+        let mut points = cube_corners();
+        points.extend([Vec3::ZERO, Vec3::new(0.5, -0.25, 0.1)]);
+
+        let hull = ConvexHull::from_points(&points);
+
+        assert_eq!(hull.as_ref().map(|hull| hull.vertices.len()), Some(8));
+        assert!(
+            hull.as_ref()
+                .is_some_and(|hull| hull.plane_distance(Vec3::ZERO) < 0.0)
+        );
+        assert!(hull.as_ref().is_some_and(|hull| {
+            (hull.plane_distance(Vec3::new(1.5, 0.0, 0.0)) - 0.5).abs() < 1e-5
+        }));
+    }
+
+    #[test]
+    fn flat_points_have_no_hull() {
+        let points = [Vec3::ZERO, Vec3::X, Vec3::Z, Vec3::new(1.0, 0.0, 1.0)];
+        assert!(ConvexHull::from_points(&points).is_none());
+    }
+
+    #[test]
+    fn split_pieces_cover_both_halves() {
+        let pieces = split_into_pieces(
+            &[[
+                Vec3::new(-2.0, 0.0, 0.0),
+                Vec3::new(2.0, 1.0, 0.0),
+                Vec3::new(2.0, 0.0, 1.0),
+            ]],
+            2,
+        );
+
+        assert_eq!(pieces.len(), 2);
+        for piece in &pieces {
+            assert!(
+                bounds(piece.iter().copied()).is_some_and(|(min, max)| max.x - min.x <= 2.0 + 1e-5),
+                "each piece should span half the triangle"
+            );
+        }
+    }
+}
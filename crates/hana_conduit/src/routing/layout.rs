@@ -0,0 +1,388 @@
+//! [`layout_cables`]: routing several cables together so they keep out of
+//! each other's way.
+//!
+//! Cables are laid out one at a time, in the order given. Each cable's route
+//! has its axis-aligned segments spread into parallel lanes beside earlier
+//! cables sharing the same corridor. When the laned route still runs through
+//! an earlier cable, the cable is re-planned treating the earlier cables as
+//! soft obstacles, dropped again if avoiding them would cut through a real
+//! obstacle. Cables tagged with the same bundle share one spine and fan out
+//! near their endpoints. The result depends only on the input order.
+
+use bevy::math::Vec3;
+use bevy_kana::ToF32;
+
+use super::constants::BUNDLE_FAN_FRACTION;
+use super::constants::CABLE_LANE_GAP;
+use super::constants::COLLINEARITY_THRESHOLD;
+use super::constants::LAYOUT_SEGMENT_SAMPLE_STEPS;
+use super::constants::MIN_SEGMENT_LENGTH;
+use super::geometry::CableGeometry;
+use super::geometry::RouteRequest;
+use super::geometry::ViaPoint;
+use super::obstacle::Blockage;
+use super::obstacle::Obstacle;
+use super::obstacle::PointContainment;
+use super::obstacle::is_segment_blocked;
+use super::outcome::RouteOutcome;
+use super::solver_selection::Solver;
+use super::solver_selection::span_ends;
+
+/// One cable in a [`layout_cables`] pass.
+#[derive(Clone, Debug)]
+pub struct LayoutCable<'a> {
+    /// How the cable is routed and curved.
+    pub solver:  &'a Solver,
+    /// The cable's anchors, hard obstacles, and resolution.
+    pub request: RouteRequest<'a>,
+    /// Tube radius the cable keeps clear around itself, in metres.
+    pub radius:  f32,
+    /// Cables with the same bundle id share one spine.
+    pub bundle:  Option<u32>,
+}
+
+/// Route `cables` together, returning each cable's geometry in input order.
+///
+/// A bundle is laid out when its first member is reached: the members'
+/// span ends are averaged, the first member's solver plans a spine between
+/// the averages (pulled in by `BUNDLE_FAN_FRACTION` of their distance at each
+/// end), and every member follows the spine in its own slot of a hexagonal
+/// pack. The spine passes through the first member's via points. A bundle
+/// with a single member is laid out like an untagged cable.
+#[must_use]
+pub fn layout_cables(cables: &[LayoutCable]) -> Vec<CableGeometry> {
+    let mut geometries: Vec<Option<CableGeometry>> = vec![None; cables.len()];
+    let mut routed: Vec<RoutedCable> = Vec::new();
+    let mut corridors = Corridors::default();
+
+    for (index, cable) in cables.iter().enumerate() {
+        if geometries[index].is_some() {
+            continue;
+        }
+        let members: Vec<usize> = cable.bundle.map_or_else(
+            || vec![index],
+            |bundle| {
+                (index..cables.len())
+                    .filter(|&other| cables[other].bundle == Some(bundle))
+                    .collect()
+            },
+        );
+
+        let (plans, outcome) = if members.len() > 1 {
+            bundle_waypoints(cables, &members)
+        } else {
+            let (waypoints, outcome) = route_around(cable, &routed, &mut corridors);
+            (vec![waypoints], outcome)
+        };
+
+        for (&member, waypoints) in members.iter().zip(plans) {
+            let cable = &cables[member];
+            let geometry = cable
+                .solver
+                .solve_waypoints(waypoints, outcome, &cable.request);
+            routed.push(RoutedCable {
+                points: geometry.all_points().copied().collect(),
+                radius: cable.radius,
+            });
+            geometries[member] = Some(geometry);
+        }
+    }
+
+    geometries.into_iter().flatten().collect()
+}
+
+/// A cable already laid out, kept as a soft obstacle for the cables after it.
+struct RoutedCable {
+    points: Vec<Vec3>,
+    radius: f32,
+}
+
+/// Plan `cable` around its hard obstacles and spread it into lanes, then, if
+/// it still runs through a cable routed before it, re-plan around those
+/// cables too. Keeps the first route when avoiding the routed cables would
+/// cut through a hard obstacle. Returns the kept plan's outcome alongside.
+fn route_around(
+    cable: &LayoutCable,
+    routed: &[RoutedCable],
+    corridors: &mut Corridors,
+) -> (Vec<Vec3>, RouteOutcome) {
+    let (start, end) = span_ends(&cable.request);
+    let hard = cable.request.obstacles;
+
+    let mut laned = corridors.clone();
+    let (mut waypoints, outcome) = cable.solver.plan(
+        start,
+        end,
+        cable.request.vias,
+        hard,
+        cable.request.obstacle_index,
+    );
+    laned.spread(&mut waypoints, cable.radius, cable.request.vias);
+    let soft = soft_obstacles(routed, cable.radius, start, end);
+    if is_route_blocked(&waypoints, &soft) == Blockage::Clear {
+        *corridors = laned;
+        return (waypoints, outcome);
+    }
+
+    let obstacles: Vec<Obstacle> = hard.iter().cloned().chain(soft).collect();
+    let (mut avoiding, avoiding_outcome) =
+        cable
+            .solver
+            .plan(start, end, cable.request.vias, &obstacles, None);
+    if is_route_blocked(&avoiding, hard) == Blockage::Blocked {
+        *corridors = laned;
+        return (waypoints, outcome);
+    }
+    corridors.spread(&mut avoiding, cable.radius, cable.request.vias);
+    (avoiding, avoiding_outcome)
+}
+
+/// Capsules around each routed cable's sampled segments, grown by `radius`
+/// so the capsule surfaces mark where this cable's centreline may pass.
+/// Capsules containing this cable's own span ends are skipped: a cable
+/// sharing a port with an earlier one must still be able to leave it.
+fn soft_obstacles(routed: &[RoutedCable], radius: f32, start: Vec3, end: Vec3) -> Vec<Obstacle> {
+    routed
+        .iter()
+        .flat_map(|other| {
+            let radius = other.radius + radius;
+            other
+                .points
+                .windows(2)
+                .map(move |pair| Obstacle::capsule(radius, pair[0], pair[1]))
+        })
+        .filter(|capsule| {
+            [start, end]
+                .into_iter()
+                .all(|point| capsule.point_containment(point, 0.0) == PointContainment::Outside)
+        })
+        .collect()
+}
+
+fn is_route_blocked(waypoints: &[Vec3], obstacles: &[Obstacle]) -> Blockage {
+    let blocked = waypoints.windows(2).any(|pair| {
+        is_segment_blocked(
+            pair[0],
+            pair[1],
+            obstacles,
+            0.0,
+            LAYOUT_SEGMENT_SAMPLE_STEPS,
+        ) == Blockage::Blocked
+    });
+    if blocked {
+        Blockage::Blocked
+    } else {
+        Blockage::Clear
+    }
+}
+
+/// Waypoints for each bundle member, in `members` order, and the outcome of
+/// planning the shared spine.
+fn bundle_waypoints(cables: &[LayoutCable], members: &[usize]) -> (Vec<Vec<Vec3>>, RouteOutcome) {
+    let ends: Vec<(Vec3, Vec3)> = members
+        .iter()
+        .map(|&member| span_ends(&cables[member].request))
+        .collect();
+    let count = ends.len().to_f32();
+    let start_center = ends.iter().map(|&(start, _)| start).sum::<Vec3>() / count;
+    let end_center = ends.iter().map(|&(_, end)| end).sum::<Vec3>() / count;
+    let along = end_center - start_center;
+    let distance = along.length();
+    if distance < MIN_SEGMENT_LENGTH {
+        return (
+            ends.iter().map(|&(start, end)| vec![start, end]).collect(),
+            RouteOutcome::Success,
+        );
+    }
+
+    let direction = along / distance;
+    let fan = direction * (distance * BUNDLE_FAN_FRACTION);
+    let leader = &cables[members[0]];
+    let (spine, outcome) = leader.solver.plan(
+        start_center + fan,
+        end_center - fan,
+        leader.request.vias,
+        leader.request.obstacles,
+        leader.request.obstacle_index,
+    );
+    let widest = members
+        .iter()
+        .map(|&member| cables[member].radius)
+        .fold(0.0, f32::max);
+    let spacing = 2.0f32.mul_add(widest, CABLE_LANE_GAP);
+    let (across, up) = direction.any_orthonormal_pair();
+
+    let plans = ends
+        .iter()
+        .enumerate()
+        .map(|(slot, &(start, end))| {
+            let offset = bundle_slot(slot, spacing, across, up);
+            std::iter::once(start)
+                .chain(spine.iter().map(|&point| point + offset))
+                .chain(std::iter::once(end))
+                .collect()
+        })
+        .collect();
+    (plans, outcome)
+}
+
+/// Offset of bundle slot `slot` in a hexagonal pack: slot 0 on the spine,
+/// then rings of `6 × ring` slots at `ring × spacing` around it.
+fn bundle_slot(slot: usize, spacing: f32, across: Vec3, up: Vec3) -> Vec3 {
+    if slot == 0 {
+        return Vec3::ZERO;
+    }
+    let mut ring = 1;
+    let mut first = 1;
+    while slot >= first + 6 * ring {
+        first += 6 * ring;
+        ring += 1;
+    }
+    let angle = std::f32::consts::TAU * (slot - first).to_f32() / (6 * ring).to_f32();
+    (across * angle.cos() + up * angle.sin()) * (ring.to_f32() * spacing)
+}
+
+/// Axis-aligned corridor segments already claimed by laid-out cables.
+#[derive(Clone, Default)]
+struct Corridors(Vec<Corridor>);
+
+#[derive(Clone)]
+struct Corridor {
+    /// Unsigned unit axis the corridor runs along.
+    axis:  Vec3,
+    /// A point on the corridor's first (unshifted) lane.
+    point: Vec3,
+    low:   f32,
+    high:  f32,
+    /// Centre-to-centre distance between lanes.
+    width: f32,
+    lanes: u32,
+}
+
+impl Corridor {
+    fn overlaps(&self, low: f32, high: f32) -> bool {
+        low < self.high - MIN_SEGMENT_LENGTH && high > self.low + MIN_SEGMENT_LENGTH
+    }
+
+    /// Offset of `point` from the corridor's first lane, square to its axis.
+    fn offset_of(&self, point: Vec3) -> Vec3 {
+        let offset = point - self.point;
+        offset - self.axis * offset.dot(self.axis)
+    }
+}
+
+impl Corridors {
+    /// Shift each segment of `waypoints` that runs along a claimed corridor
+    /// into the corridor's next free lane, with a jog out of the route and
+    /// back into it at either end of the shifted segment. Segments touching a
+    /// via point stay put, so the cable still passes through it.
+    fn spread(&mut self, waypoints: &mut Vec<Vec3>, radius: f32, vias: &[ViaPoint]) {
+        let mut index = 0;
+        while index + 1 < waypoints.len() {
+            let (start, end) = (waypoints[index], waypoints[index + 1]);
+            if is_at_via(vias, start) || is_at_via(vias, end) {
+                index += 1;
+                continue;
+            }
+            let arrival = index
+                .checked_sub(1)
+                .map(|previous| start - waypoints[previous]);
+            let departure = waypoints.get(index + 2).map(|&next| next - end);
+            if let Some(offset) = self.claim(start, end, [arrival, departure], radius) {
+                let insert_at = index + 1;
+                waypoints.splice(insert_at..insert_at, [start + offset, end + offset]);
+                index += 3;
+            } else {
+                index += 1;
+            }
+        }
+        remove_reversals(waypoints, vias);
+    }
+
+    /// Claim a lane for the segment `start`→`end`, returning how far to shift
+    /// it onto the lane. The first cable along a corridor opens it and stays
+    /// in place; each later one takes the next lane out, one lane width
+    /// further from the first.
+    ///
+    /// A claimed lane is shifted along the route's arrival or departure
+    /// direction when either is square to the corridor, so the jogs extend
+    /// the neighbouring segments instead of doubling back on them.
+    fn claim(
+        &mut self,
+        start: Vec3,
+        end: Vec3,
+        neighbours: [Option<Vec3>; 2],
+        radius: f32,
+    ) -> Option<Vec3> {
+        let along = end - start;
+        let length = along.length();
+        if length < MIN_SEGMENT_LENGTH {
+            return None;
+        }
+        let direction = along / length;
+        if direction.abs().max_element() < COLLINEARITY_THRESHOLD {
+            return None;
+        }
+        let axis = direction.abs().round();
+        let width = 2.0f32.mul_add(radius, CABLE_LANE_GAP);
+        let (low, high) = {
+            let (a, b) = (start.dot(axis), end.dot(axis));
+            (a.min(b), a.max(b))
+        };
+
+        let Some(corridor) = self.0.iter_mut().find(|corridor| {
+            corridor.axis == axis
+                && corridor.overlaps(low, high)
+                && corridor.offset_of(start).length() < corridor.width.max(width)
+        }) else {
+            self.0.push(Corridor {
+                axis,
+                point: start,
+                low,
+                high,
+                width,
+                lanes: 1,
+            });
+            return None;
+        };
+
+        let shift = neighbours
+            .into_iter()
+            .flatten()
+            .map(Vec3::normalize_or_zero)
+            .find(|side| *side != Vec3::ZERO && side.dot(axis).abs() < 1.0 - COLLINEARITY_THRESHOLD)
+            .unwrap_or_else(|| axis.any_orthonormal_vector());
+        corridor.width = corridor.width.max(width);
+        let offset = shift * (corridor.lanes.to_f32() * corridor.width) - corridor.offset_of(start);
+        corridor.lanes += 1;
+        corridor.low = corridor.low.min(low);
+        corridor.high = corridor.high.max(high);
+        Some(offset)
+    }
+}
+
+/// Whether `point` is where the route enters or leaves one of `vias`.
+fn is_at_via(vias: &[ViaPoint], point: Vec3) -> bool {
+    vias.iter().any(|via| {
+        let (entry, exit) = via.through_piece();
+        entry == point || exit == point
+    })
+}
+
+/// Drop repeated waypoints and waypoints where the route doubles straight
+/// back on itself, as a lane's jog does when it runs back along the segment
+/// beside it. Via points are kept even where the route doubles back on them.
+fn remove_reversals(waypoints: &mut Vec<Vec3>, vias: &[ViaPoint]) {
+    waypoints.dedup_by(|a, b| a.distance(*b) < MIN_SEGMENT_LENGTH);
+    let mut index = 1;
+    while index + 1 < waypoints.len() {
+        let incoming = (waypoints[index] - waypoints[index - 1]).normalize_or_zero();
+        let outgoing = (waypoints[index + 1] - waypoints[index]).normalize_or_zero();
+        if incoming.dot(outgoing) < -COLLINEARITY_THRESHOLD && !is_at_via(vias, waypoints[index]) {
+            waypoints.remove(index);
+            index = (index - 1).max(1);
+        } else {
+            index += 1;
+        }
+    }
+}
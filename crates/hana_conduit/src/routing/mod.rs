@@ -6,11 +6,20 @@
 mod catenary;
 mod constants;
 mod geometry;
+mod hull;
+mod layout;
 mod obstacle;
+mod obstacle_index;
 mod orthogonal;
+mod outcome;
 mod pathfinding;
+mod ray_cast;
+mod simulation;
 mod solver;
 mod solver_selection;
+mod spline;
+mod support;
+mod via;
 
 pub use catenary::CatenarySolver;
 pub use catenary::evaluate;
@@ -26,11 +35,20 @@ pub use geometry::AnchorExit;
 pub use geometry::CableGeometry;
 pub use geometry::CableSegment;
 pub use geometry::RouteRequest;
+pub use geometry::ViaPoint;
+pub use layout::LayoutCable;
+pub use layout::layout_cables;
 pub use obstacle::Obstacle;
+pub(crate) use obstacle::ObstacleShape;
 pub(crate) use obstacle::push_out_of_obstacles;
+pub use obstacle_index::ObstacleIndex;
 pub use orthogonal::AxisOrder;
 pub use orthogonal::OrthogonalPlanner;
+pub use outcome::RouteOutcome;
 pub use pathfinding::AStarPlanner;
+pub use ray_cast::CableRayHit;
+pub use simulation::CableSimulation;
+pub use simulation::SimulatedSolver;
 pub use solver::CurveSolver;
 pub use solver::DirectPlanner;
 pub use solver::LinearSolver;
@@ -40,3 +58,5 @@ pub use solver::Router;
 pub use solver_selection::CurveKind;
 pub use solver_selection::PathStrategy;
 pub use solver_selection::Solver;
+pub use spline::SplineSolver;
+pub use support::SupportSurface;
@@ -1,9 +1,16 @@
-//! `Obstacle`, `PointContainment`, `Blockage`, `is_point_in_any_obstacle`, and
-//! `is_segment_blocked`.
+//! `Obstacle` shapes, `PointContainment`, `Blockage`, `is_point_in_any_obstacle`,
+//! `is_segment_blocked`, and `push_out_of_obstacles`.
 
+use bevy::math::Mat3;
+use bevy::math::Quat;
 use bevy::math::Vec3;
 use bevy::reflect::Reflect;
 use bevy_kana::ToF32;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::hull;
+use super::hull::ConvexHull;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(super) enum PointContainment {
@@ -17,86 +24,241 @@ pub(super) enum Blockage {
     Blocked,
 }
 
-/// An axis-aligned bounding box used as a routing obstacle.
-#[derive(Clone, Copy, Debug, Reflect)]
+/// A solid shape that cables route around.
+///
+/// Planners and route animation test points against the true shape: a
+/// rotated box, sphere, capsule, or convex hull blocks only the space it
+/// occupies, not its axis-aligned bounds.
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub struct Obstacle {
-    pub(super) half_extents: Vec3,
-    pub(super) position:     Vec3,
+    pub(crate) shape: ObstacleShape,
+}
+
+/// The geometry behind an [`Obstacle`], in world space.
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+pub(crate) enum ObstacleShape {
+    Cuboid {
+        half_extents: Vec3,
+        position:     Vec3,
+        rotation:     Quat,
+    },
+    Sphere {
+        center: Vec3,
+        radius: f32,
+    },
+    Capsule {
+        start:  Vec3,
+        end:    Vec3,
+        radius: f32,
+    },
+    ConvexHull(ConvexHull),
 }
 
 impl Obstacle {
     /// Create an axis-aligned obstacle.
     #[must_use]
     pub fn new(half_extents: Vec3, position: impl Into<Vec3>) -> Self {
+        Self::oriented(half_extents, position, Quat::IDENTITY)
+    }
+
+    /// Create a box obstacle rotated by `rotation` about its center.
+    #[must_use]
+    pub fn oriented(half_extents: Vec3, position: impl Into<Vec3>, rotation: Quat) -> Self {
+        Self {
+            shape: ObstacleShape::Cuboid {
+                half_extents,
+                position: position.into(),
+                rotation,
+            },
+        }
+    }
+
+    /// Create a sphere obstacle.
+    #[must_use]
+    pub fn sphere(radius: f32, center: impl Into<Vec3>) -> Self {
         Self {
-            half_extents,
-            position: position.into(),
+            shape: ObstacleShape::Sphere {
+                center: center.into(),
+                radius,
+            },
+        }
+    }
+
+    /// Create a capsule obstacle: every point within `radius` of the segment
+    /// from `start` to `end`.
+    #[must_use]
+    pub fn capsule(radius: f32, start: impl Into<Vec3>, end: impl Into<Vec3>) -> Self {
+        Self {
+            shape: ObstacleShape::Capsule {
+                start: start.into(),
+                end: end.into(),
+                radius,
+            },
+        }
+    }
+
+    /// Create an obstacle from the convex hull of `points`, or `None` when the
+    /// points are empty or flat (collinear or coplanar).
+    #[must_use]
+    pub fn convex_hull(points: &[Vec3]) -> Option<Self> {
+        ConvexHull::from_points(points).map(|hull| Self {
+            shape: ObstacleShape::ConvexHull(hull),
+        })
+    }
+
+    /// Decompose the solid enclosed by `triangles` into at most `pieces`
+    /// convex hulls, so a concave part such as an L-shaped bracket blocks its
+    /// arms instead of the whole space between them.
+    ///
+    /// Pieces too flat to form a hull fall back to their axis-aligned bounds.
+    #[must_use]
+    pub fn convex_decomposition(triangles: &[[Vec3; 3]], pieces: usize) -> Vec<Self> {
+        hull::split_into_pieces(triangles, pieces.max(1))
+            .into_iter()
+            .filter_map(|points| {
+                Self::convex_hull(&points).or_else(|| {
+                    hull::bounds(points.iter().copied())
+                        .map(|(min, max)| Self::new((max - min) / 2.0, (min + max) / 2.0))
+                })
+            })
+            .collect()
+    }
+
+    /// World-space axis-aligned bounds of the shape, as `(min, max)`.
+    pub(crate) fn bounds(&self) -> (Vec3, Vec3) {
+        match &self.shape {
+            ObstacleShape::Cuboid {
+                half_extents,
+                position,
+                rotation,
+            } => {
+                let axes = Mat3::from_quat(*rotation);
+                let extent = axes.x_axis.abs() * half_extents.x
+                    + axes.y_axis.abs() * half_extents.y
+                    + axes.z_axis.abs() * half_extents.z;
+                (*position - extent, *position + extent)
+            },
+            ObstacleShape::Sphere { center, radius } => (
+                *center - Vec3::splat(*radius),
+                *center + Vec3::splat(*radius),
+            ),
+            ObstacleShape::Capsule { start, end, radius } => (
+                start.min(*end) - Vec3::splat(*radius),
+                start.max(*end) + Vec3::splat(*radius),
+            ),
+            ObstacleShape::ConvexHull(hull) => hull.bounds(),
         }
     }
 
-    fn point_containment(&self, position: Vec3, margin: f32) -> PointContainment {
-        let min = self.position - self.half_extents - Vec3::splat(margin);
-        let max = self.position + self.half_extents + Vec3::splat(margin);
-        if position.x >= min.x
-            && position.x <= max.x
-            && position.y >= min.y
-            && position.y <= max.y
-            && position.z >= min.z
-            && position.z <= max.z
-        {
+    pub(super) fn point_containment(&self, position: Vec3, margin: f32) -> PointContainment {
+        let inside = match &self.shape {
+            ObstacleShape::Cuboid {
+                half_extents,
+                position: center,
+                rotation,
+            } => {
+                let local = rotation.inverse() * (position - *center);
+                local.abs().cmple(*half_extents + Vec3::splat(margin)).all()
+            },
+            ObstacleShape::Sphere { center, radius } => {
+                position.distance_squared(*center) <= (radius + margin) * (radius + margin)
+            },
+            ObstacleShape::Capsule { start, end, radius } => {
+                position.distance(closest_on_segment(position, *start, *end)) <= radius + margin
+            },
+            ObstacleShape::ConvexHull(hull) => hull.plane_distance(position) <= margin,
+        };
+        if inside {
             PointContainment::Inside
         } else {
             PointContainment::Outside
         }
     }
 
-    /// Move a point inside this box out through its nearest face, landing
-    /// `clearance` metres beyond the face. Points already outside pass through
-    /// unchanged.
+    /// Move a point inside this shape out through its nearest surface,
+    /// landing `clearance` metres beyond it. Points already outside pass
+    /// through unchanged.
     fn push_out(&self, point: Vec3, clearance: f32) -> Vec3 {
         match self.point_containment(point, 0.0) {
             PointContainment::Outside => point,
-            PointContainment::Inside => {
-                let min = self.position - self.half_extents;
-                let max = self.position + self.half_extents;
-                let exits = [
-                    (
-                        point.x - min.x,
-                        Vec3::new(min.x - clearance, point.y, point.z),
-                    ),
-                    (
-                        max.x - point.x,
-                        Vec3::new(max.x + clearance, point.y, point.z),
-                    ),
-                    (
-                        point.y - min.y,
-                        Vec3::new(point.x, min.y - clearance, point.z),
-                    ),
-                    (
-                        max.y - point.y,
-                        Vec3::new(point.x, max.y + clearance, point.z),
-                    ),
-                    (
-                        point.z - min.z,
-                        Vec3::new(point.x, point.y, min.z - clearance),
-                    ),
-                    (
-                        max.z - point.z,
-                        Vec3::new(point.x, point.y, max.z + clearance),
-                    ),
-                ];
-                exits
-                    .into_iter()
-                    .min_by(|a, b| a.0.total_cmp(&b.0))
-                    .map_or(point, |(_, exit)| exit)
+            PointContainment::Inside => match &self.shape {
+                ObstacleShape::Cuboid {
+                    half_extents,
+                    position,
+                    rotation,
+                } => {
+                    let local = rotation.inverse() * (point - *position);
+                    *position + *rotation * push_out_of_box(local, *half_extents, clearance)
+                },
+                ObstacleShape::Sphere { center, radius } => {
+                    let direction = (point - *center).try_normalize().unwrap_or(Vec3::Y);
+                    *center + direction * (radius + clearance)
+                },
+                ObstacleShape::Capsule { start, end, radius } => {
+                    let closest = closest_on_segment(point, *start, *end);
+                    let axis = (*end - *start).try_normalize().unwrap_or(Vec3::Y);
+                    let direction = (point - closest)
+                        .try_normalize()
+                        .unwrap_or_else(|| axis.any_orthonormal_vector());
+                    closest + direction * (radius + clearance)
+                },
+                ObstacleShape::ConvexHull(hull) => hull.push_out(point, clearance),
             },
         }
     }
 }
 
-/// Move `point` outside every [`Obstacle`] box it falls inside, exiting each
-/// through its nearest face plus `clearance`. Used by route animation to keep
-/// an in-flight cable from sweeping through the boxes its target route avoids.
+/// Move a point inside the origin-centered box with `half_extents` out
+/// through its nearest face, landing `clearance` metres beyond the face.
+fn push_out_of_box(point: Vec3, half_extents: Vec3, clearance: f32) -> Vec3 {
+    let min = -half_extents;
+    let max = half_extents;
+    let exits = [
+        (
+            point.x - min.x,
+            Vec3::new(min.x - clearance, point.y, point.z),
+        ),
+        (
+            max.x - point.x,
+            Vec3::new(max.x + clearance, point.y, point.z),
+        ),
+        (
+            point.y - min.y,
+            Vec3::new(point.x, min.y - clearance, point.z),
+        ),
+        (
+            max.y - point.y,
+            Vec3::new(point.x, max.y + clearance, point.z),
+        ),
+        (
+            point.z - min.z,
+            Vec3::new(point.x, point.y, min.z - clearance),
+        ),
+        (
+            max.z - point.z,
+            Vec3::new(point.x, point.y, max.z + clearance),
+        ),
+    ];
+    exits
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .map_or(point, |(_, exit)| exit)
+}
+
+/// The point on the segment from `start` to `end` nearest `point`.
+fn closest_on_segment(point: Vec3, start: Vec3, end: Vec3) -> Vec3 {
+    let span = end - start;
+    let length_squared = span.length_squared();
+    if length_squared <= f32::EPSILON {
+        return start;
+    }
+    start + span * ((point - start).dot(span) / length_squared).clamp(0.0, 1.0)
+}
+
+/// Move `point` outside every [`Obstacle`] it falls inside, exiting each
+/// through its nearest surface plus `clearance`. Used by route animation to
+/// keep an in-flight cable from sweeping through the obstacles its target
+/// route avoids, and by cable simulation for particle collision.
 #[must_use]
 pub(crate) fn push_out_of_obstacles(point: Vec3, obstacles: &[Obstacle], clearance: f32) -> Vec3 {
     obstacles.iter().fold(point, |current, obstacle| {
@@ -104,7 +266,7 @@ pub(crate) fn push_out_of_obstacles(point: Vec3, obstacles: &[Obstacle], clearan
     })
 }
 
-/// Check whether a point falls inside any obstacle's `AABB`, expanded by `margin`.
+/// Check whether a point falls inside any obstacle, expanded by `margin`.
 #[must_use]
 pub(super) fn is_point_in_any_obstacle(
     position: Vec3,
@@ -162,4 +324,34 @@ mod tests {
         let point = Vec3::new(2.0, 0.0, 0.0);
         assert_eq!(push_out_of_obstacles(point, &[obstacle], CLEARANCE), point);
     }
+
+    #[test]
+    fn oriented_box_blocks_only_its_rotated_volume() {
+        let obstacle = Obstacle::oriented(
+            Vec3::new(2.0, 0.1, 0.1),
+            Vec3::ZERO,
+            Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+        );
+        // Inside the axis-aligned bounds of the rotated plank, but off its
+        // diagonal.
+        assert_eq!(
+            obstacle.point_containment(Vec3::new(1.0, -1.0, 0.0), 0.0),
+            PointContainment::Outside
+        );
+        assert_eq!(
+            obstacle.point_containment(Vec3::new(1.0, 1.0, 0.0), 0.0),
+            PointContainment::Inside
+        );
+    }
+
+    #[test]
+    fn push_out_leaves_round_shapes_radially() {
+        let sphere = Obstacle::sphere(1.0, Vec3::ZERO);
+        let pushed = push_out_of_obstacles(Vec3::new(0.0, 0.5, 0.0), &[sphere], CLEARANCE);
+        assert!((pushed - Vec3::new(0.0, 1.0 + CLEARANCE, 0.0)).length() < 1e-5);
+
+        let capsule = Obstacle::capsule(0.5, Vec3::new(-2.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0));
+        let pushed = push_out_of_obstacles(Vec3::new(1.5, 0.0, 0.25), &[capsule], CLEARANCE);
+        assert!((pushed - Vec3::new(1.5, 0.0, 0.5 + CLEARANCE)).length() < 1e-5);
+    }
 }
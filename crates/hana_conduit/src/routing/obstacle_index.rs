@@ -0,0 +1,124 @@
+//! [`ObstacleIndex`]: a spatial hash over obstacle bounds, so grid pathfinding
+//! tests each cell against the few obstacles near it instead of every
+//! obstacle in the scene.
+
+use std::collections::HashMap;
+
+use bevy::math::IVec3;
+use bevy::math::Vec3;
+use bevy_kana::ToF32;
+use bevy_kana::ToI32;
+
+use super::constants::DEFAULT_OBSTACLE_INDEX_BUCKET_SIZE;
+use super::constants::MIN_SEGMENT_LENGTH;
+use super::constants::OBSTACLE_INDEX_MAX_BUCKETS;
+use super::obstacle::Blockage;
+use super::obstacle::Obstacle;
+use super::obstacle::PointContainment;
+
+/// Obstacles bucketed by the cubes of a uniform grid their bounds overlap.
+///
+/// Build one per set of obstacles and share it across every cable routed
+/// against them through [`RouteRequest::obstacle_index`](super::RouteRequest::obstacle_index):
+/// the index owns a copy of the obstacles, so it can be wrapped in an `Arc`
+/// and handed to solves running on other threads.
+#[derive(Clone, Debug)]
+pub struct ObstacleIndex {
+    obstacles:   Vec<Obstacle>,
+    bucket_size: f32,
+    buckets:     HashMap<IVec3, Vec<usize>>,
+    /// Obstacles overlapping more than `OBSTACLE_INDEX_MAX_BUCKETS` buckets
+    /// (a floor slab, a wall), tested by every query instead.
+    oversized:   Vec<usize>,
+}
+
+impl ObstacleIndex {
+    /// Index `obstacles` in buckets of `bucket_size` metres.
+    #[must_use]
+    pub fn new(obstacles: &[Obstacle], bucket_size: f32) -> Self {
+        let mut index = Self {
+            obstacles:   obstacles.to_vec(),
+            bucket_size: bucket_size.max(MIN_SEGMENT_LENGTH),
+            buckets:     HashMap::new(),
+            oversized:   Vec::new(),
+        };
+        for (position, obstacle) in obstacles.iter().enumerate() {
+            let (min, max) = obstacle.bounds();
+            let (low, high) = (index.bucket_of(min), index.bucket_of(max));
+            let span = (high - low + IVec3::ONE).as_i64vec3();
+            if span.x * span.y * span.z > OBSTACLE_INDEX_MAX_BUCKETS {
+                index.oversized.push(position);
+                continue;
+            }
+            for bucket in buckets_between(low, high) {
+                index.buckets.entry(bucket).or_default().push(position);
+            }
+        }
+        index
+    }
+
+    /// Index `obstacles` in buckets of the default size.
+    #[must_use]
+    pub fn from_obstacles(obstacles: &[Obstacle]) -> Self {
+        Self::new(obstacles, DEFAULT_OBSTACLE_INDEX_BUCKET_SIZE)
+    }
+
+    /// The indexed obstacles.
+    #[must_use]
+    pub fn obstacles(&self) -> &[Obstacle] { &self.obstacles }
+
+    /// Whether `position` falls inside any indexed obstacle, expanded by
+    /// `margin`.
+    pub(super) fn point_containment(&self, position: Vec3, margin: f32) -> PointContainment {
+        let reach = Vec3::splat(margin.max(0.0));
+        let nearby = buckets_between(
+            self.bucket_of(position - reach),
+            self.bucket_of(position + reach),
+        )
+        .filter_map(|bucket| self.buckets.get(&bucket))
+        .flatten();
+        let inside = self.oversized.iter().chain(nearby).any(|&candidate| {
+            self.obstacles[candidate].point_containment(position, margin)
+                == PointContainment::Inside
+        });
+        if inside {
+            PointContainment::Inside
+        } else {
+            PointContainment::Outside
+        }
+    }
+
+    /// Whether any indexed obstacle, expanded by `margin`, intersects the
+    /// segment — sampled at `steps` evenly spaced points like
+    /// `is_segment_blocked`.
+    pub(super) fn segment_blockage(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        margin: f32,
+        steps: u32,
+    ) -> Blockage {
+        let steps = steps.max(1);
+        let blocked = (0..=steps).any(|step| {
+            let point = start.lerp(end, step.to_f32() / steps.to_f32());
+            self.point_containment(point, margin) == PointContainment::Inside
+        });
+        if blocked {
+            Blockage::Blocked
+        } else {
+            Blockage::Clear
+        }
+    }
+
+    fn bucket_of(&self, position: Vec3) -> IVec3 {
+        let scaled = (position / self.bucket_size).floor();
+        IVec3::new(scaled.x.to_i32(), scaled.y.to_i32(), scaled.z.to_i32())
+    }
+}
+
+/// Every bucket in the box from `low` to `high`, inclusive.
+fn buckets_between(low: IVec3, high: IVec3) -> impl Iterator<Item = IVec3> {
+    (low.x..=high.x).flat_map(move |x| {
+        (low.y..=high.y).flat_map(move |y| (low.z..=high.z).map(move |z| IVec3::new(x, y, z)))
+    })
+}
@@ -2,6 +2,7 @@
 
 use bevy::math::Vec3;
 
+use super::constants::AXIS_DIRECTIONS;
 use super::constants::DEFAULT_OBSTACLE_MARGIN;
 use super::constants::HORIZONTAL_FIRST_AXIS_ORDERS;
 use super::constants::OBSTACLE_CLEARANCE_MULTIPLIER;
@@ -10,6 +11,7 @@ use super::constants::VERTICAL_FIRST_AXIS_ORDERS;
 use super::obstacle;
 use super::obstacle::Blockage;
 use super::obstacle::Obstacle;
+use super::outcome::RouteOutcome;
 use super::solver::PathPlanner;
 
 /// Whether to route vertically or horizontally first.
@@ -113,20 +115,20 @@ impl OrthogonalPlanner {
     /// Generate a U-shaped orthogonal path with `start`, `below_start`,
     /// `below_corner`, `below_end`, and `end` waypoints.
     fn u_path(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Vec<Vec3> {
-        // `offset` uses the largest `Obstacle::half_extents.max_element()` plus `margin`.
+        // `offset` uses the largest half-extent of any obstacle's bounds plus `margin`.
         let offset = self.margin.mul_add(
             OBSTACLE_CLEARANCE_MULTIPLIER,
             obstacles.iter().fold(0.0_f32, |acc, obstacle| {
-                let extent = obstacle.half_extents.max_element();
-                acc.max(extent)
+                let (min, max) = obstacle.bounds();
+                acc.max(((max - min) / 2.0).max_element())
             }),
         );
 
         // Build `below_start`, `below_corner`, and `below_end` below the route
-        // endpoints and every obstacle's expanded lower face.
+        // endpoints and every obstacle's expanded lowest point.
         let route_min_y = start.y.min(end.y);
         let obstacle_min_y = obstacles.iter().fold(route_min_y, |acc, obstacle| {
-            acc.min(obstacle.position.y - obstacle.half_extents.y - self.margin)
+            acc.min(obstacle.bounds().0.y - self.margin)
         });
         let below = obstacle_min_y - offset;
         let below_start = Vec3::new(start.x, below, start.z);
@@ -163,8 +165,16 @@ impl Default for OrthogonalPlanner {
     }
 }
 
-impl PathPlanner for OrthogonalPlanner {
-    fn plan(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Vec<Vec3> {
+impl OrthogonalPlanner {
+    /// Plan a path from `start` to `end` around `obstacles`, along with the
+    /// axis order it settled on.
+    #[must_use]
+    pub fn plan_with_outcome(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        obstacles: &[Obstacle],
+    ) -> (Vec<Vec3>, RouteOutcome) {
         // `axis_order` selects `VERTICAL_FIRST_AXIS_ORDERS` or
         // `HORIZONTAL_FIRST_AXIS_ORDERS`.
         let orders = if matches!(self.axis_order, AxisOrder::VerticalFirst) {
@@ -175,17 +185,29 @@ impl PathPlanner for OrthogonalPlanner {
 
         for order in orders {
             let path = Self::axis_path(start, end, order);
+            let outcome = RouteOutcome::OrthogonalAxes {
+                axes: order.map(|axis| AXIS_DIRECTIONS[axis]),
+            };
             if obstacles.is_empty() {
-                return path;
+                return (path, outcome);
             }
 
             match self.is_path_blocked(&path, obstacles) {
-                Blockage::Clear => return path,
+                Blockage::Clear => return (path, outcome),
                 Blockage::Blocked => {},
             }
         }
 
         // `Blockage::Blocked` for every axis path uses `u_path`.
-        self.u_path(start, end, obstacles)
+        (
+            self.u_path(start, end, obstacles),
+            RouteOutcome::OrthogonalDetour,
+        )
+    }
+}
+
+impl PathPlanner for OrthogonalPlanner {
+    fn plan(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Vec<Vec3> {
+        self.plan_with_outcome(start, end, obstacles).0
     }
 }
@@ -0,0 +1,74 @@
+//! [`RouteOutcome`]: how a path strategy arrived at a route's waypoints.
+
+use bevy::math::Dir3;
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+
+/// How the path strategy arrived at a route's waypoints, carried on
+/// [`CableGeometry::outcome`](super::CableGeometry::outcome).
+///
+/// A route planned in several legs (through via points) reports the most
+/// severe outcome among its legs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum RouteOutcome {
+    /// The path strategy found a clear route, or had nothing to route around.
+    #[default]
+    Success,
+    /// Orthogonal routing found a clear axis-aligned path, moving along these
+    /// axes in order.
+    OrthogonalAxes {
+        /// The axes the path moves along, first to last.
+        axes: [Dir3; 3],
+    },
+    /// Orthogonal routing found every axis order blocked and detoured below
+    /// all obstacles instead.
+    OrthogonalDetour,
+    /// An endpoint started inside an obstacle, and grid pathfinding began
+    /// the search from the nearest clear cell instead.
+    EndpointPushedOut {
+        /// The endpoint inside the obstacle.
+        endpoint: Vec3,
+        /// Center of the clear cell the search began from.
+        clear:    Vec3,
+    },
+    /// Grid pathfinding found no clear route, or no clear cell near an
+    /// endpoint, and the route runs straight through the obstacles.
+    FallbackToDirect,
+    /// Grid pathfinding explored its whole cell budget
+    /// ([`AStarPlanner::max_cells`](super::AStarPlanner::max_cells)) without
+    /// reaching the goal, and the route runs straight through the obstacles.
+    SearchExhausted {
+        /// Cells the search explored before giving up.
+        explored: usize,
+    },
+}
+
+impl RouteOutcome {
+    /// Whether the route gave up on avoiding obstacles and runs straight
+    /// between its ends.
+    #[must_use]
+    pub const fn is_fallback(&self) -> bool {
+        matches!(self, Self::FallbackToDirect | Self::SearchExhausted { .. })
+    }
+
+    /// The more severe of `self` and `other`, keeping `self` on a tie.
+    #[must_use]
+    pub const fn most_severe(self, other: Self) -> Self {
+        if other.severity() > self.severity() {
+            other
+        } else {
+            self
+        }
+    }
+
+    const fn severity(&self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::OrthogonalAxes { .. } => 1,
+            Self::OrthogonalDetour => 2,
+            Self::EndpointPushedOut { .. } => 3,
+            Self::FallbackToDirect => 4,
+            Self::SearchExhausted { .. } => 5,
+        }
+    }
+}
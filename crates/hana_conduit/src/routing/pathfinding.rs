@@ -1,8 +1,15 @@
 //! 3D grid-based A* pathfinding for cable routing around obstacles.
+//!
+//! Cells are tested against an [`ObstacleIndex`], so a search only checks the
+//! obstacles near each cell. Long searches are hierarchical: A* first routes
+//! on a grid `ASTAR_COARSE_FACTOR` times coarser, then searches the fine grid
+//! only within a corridor around the coarse path.
 
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::iter;
 
 use bevy::math::Vec3;
 use bevy_kana::ToF32;
@@ -11,6 +18,8 @@ use bevy_kana::ToU32;
 use bevy_kana::ToUsize;
 
 use super::constants::ASTAR_CLEAR_CELL_SEARCH_RADIUS;
+use super::constants::ASTAR_COARSE_FACTOR;
+use super::constants::ASTAR_HIERARCHICAL_MIN_CELLS;
 use super::constants::ASTAR_SEGMENT_SAMPLE_STEPS;
 use super::constants::ASTAR_SHORTCUT_SAMPLES_PER_CELL;
 use super::constants::COLLINEARITY_THRESHOLD;
@@ -18,10 +27,11 @@ use super::constants::DEFAULT_ASTAR_MAX_CELLS;
 use super::constants::DEFAULT_GRID_SIZE;
 use super::constants::DEFAULT_OBSTACLE_MARGIN;
 use super::constants::MIN_CABLE_SAMPLE_POINTS;
-use super::obstacle;
 use super::obstacle::Blockage;
 use super::obstacle::Obstacle;
 use super::obstacle::PointContainment;
+use super::obstacle_index::ObstacleIndex;
+use super::outcome::RouteOutcome;
 use super::solver::PathPlanner;
 
 /// 3D grid-based A* path planner that routes around obstacles.
@@ -31,7 +41,9 @@ pub struct AStarPlanner {
     pub grid_size: f32,
     /// Clearance margin around obstacles.
     pub margin:    f32,
-    /// Maximum number of cells to explore before giving up.
+    /// Maximum number of cells to explore before giving up. A fine search
+    /// kept inside a coarse corridor may explore the whole corridor instead
+    /// when that is larger.
     pub max_cells: usize,
 }
 
@@ -70,9 +82,81 @@ impl AStarPlanner {
         }
     }
 
+    /// Plan a path from `start` to `end` around the obstacles in `index`,
+    /// which can be shared across every cable routed against them, along with
+    /// how the search arrived at it.
+    #[must_use]
+    pub fn plan_with_index(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        index: &ObstacleIndex,
+    ) -> (Vec<Vec3>, RouteOutcome) {
+        if index.obstacles().is_empty() {
+            return (vec![start, end], RouteOutcome::Success);
+        }
+
+        match self.is_direct_path_blocked(start, end, index) {
+            Blockage::Clear => return (vec![start, end], RouteOutcome::Success),
+            Blockage::Blocked => {},
+        }
+
+        let origin = start;
+        // Snap each endpoint's cell to the nearest clear cell; the exact
+        // start/end positions are restored on the waypoint list below.
+        let snapped_cells = (
+            self.nearest_clear_cell(self.world_to_cell(start, origin), origin, index),
+            self.nearest_clear_cell(self.world_to_cell(end, origin), origin, index),
+        );
+        let (Some(start_cell), Some(goal_cell)) = snapped_cells else {
+            return (vec![start, end], RouteOutcome::FallbackToDirect);
+        };
+
+        let path_cells = match self.search(start_cell, goal_cell, origin, index) {
+            Ok(path_cells) => path_cells,
+            Err(outcome) => return (vec![start, end], outcome),
+        };
+
+        // An endpoint inside an obstacle (not merely one whose cell center
+        // is) was pushed out to its snapped cell.
+        let outcome = [(start, start_cell), (end, goal_cell)]
+            .into_iter()
+            .find(|&(endpoint, _)| self.is_blocked(endpoint, index) == Blockage::Blocked)
+            .map_or(RouteOutcome::Success, |(endpoint, cell)| {
+                RouteOutcome::EndpointPushedOut {
+                    endpoint,
+                    clear: cell.to_world(origin, self.grid_size),
+                }
+            });
+
+        // Convert `path_cells` into `waypoints` with `Cell::to_world`.
+        let mut waypoints: Vec<Vec3> = path_cells
+            .iter()
+            .map(|c| c.to_world(origin, self.grid_size))
+            .collect();
+
+        // Ensure exact start and end positions
+        if let Some(first) = waypoints.first_mut() {
+            *first = start;
+        }
+        if let Some(last) = waypoints.last_mut() {
+            *last = end;
+        }
+
+        // Pull the path taut before dropping collinear points: A*'s
+        // cell-by-cell moves leave staircase jogs that `shortcut_path`
+        // replaces with the longest clear straight runs.
+        self.shortcut_path(&mut waypoints, index);
+
+        // `simplify_path` removes collinear entries from `waypoints`.
+        simplify_path(&mut waypoints);
+
+        (waypoints, outcome)
+    }
+
     /// Check if a world-space point is inside any obstacle (with margin).
-    fn is_blocked(&self, position: Vec3, obstacles: &[Obstacle]) -> Blockage {
-        match obstacle::is_point_in_any_obstacle(position, obstacles, self.margin) {
+    fn is_blocked(&self, position: Vec3, index: &ObstacleIndex) -> Blockage {
+        match index.point_containment(position, self.margin) {
             PointContainment::Inside => Blockage::Blocked,
             PointContainment::Outside => Blockage::Clear,
         }
@@ -84,7 +168,7 @@ impl AStarPlanner {
     /// (when `margin` or `grid_size` exceeds the endpoint's distance to the
     /// obstacle face); snapping keeps `find_path`'s goal reachable instead of
     /// silently falling back to a straight line through the obstacle.
-    fn nearest_clear_cell(&self, cell: Cell, origin: Vec3, obstacles: &[Obstacle]) -> Option<Cell> {
+    fn nearest_clear_cell(&self, cell: Cell, origin: Vec3, index: &ObstacleIndex) -> Option<Cell> {
         let target = cell.to_world(origin, self.grid_size);
         let radius = ASTAR_CLEAR_CELL_SEARCH_RADIUS;
         (-radius..=radius)
@@ -98,7 +182,7 @@ impl AStarPlanner {
                 z: cell.z + dz,
             })
             .filter(|candidate| {
-                match self.is_blocked(candidate.to_world(origin, self.grid_size), obstacles) {
+                match self.is_blocked(candidate.to_world(origin, self.grid_size), index) {
                     Blockage::Clear => true,
                     Blockage::Blocked => false,
                 }
@@ -132,18 +216,78 @@ impl AStarPlanner {
         dz.mul_add(dz, dx.mul_add(dx, dy * dy)).sqrt()
     }
 
-    /// Run `A*` and return the path as grid cells.
+    /// Run `A*` between the snapped cells. Searches spanning at least
+    /// `ASTAR_HIERARCHICAL_MIN_CELLS` first try the fine grid within a coarse
+    /// corridor, falling back to the whole fine grid when the corridor holds
+    /// no path (a gap too narrow for the coarse grid to see).
+    fn search(
+        &self,
+        start: Cell,
+        goal: Cell,
+        origin: Vec3,
+        index: &ObstacleIndex,
+    ) -> Result<Vec<Cell>, RouteOutcome> {
+        if start.chebyshev_distance(goal) >= ASTAR_HIERARCHICAL_MIN_CELLS
+            && let Some(corridor) = self.coarse_corridor(start, goal, origin, index)
+            && let Ok(path) = self.find_path(start, goal, origin, index, Some(&corridor))
+        {
+            return Ok(path);
+        }
+        self.find_path(start, goal, origin, index, None)
+    }
+
+    /// The coarse cells on and around an A* path between `start` and `goal`
+    /// on a grid `ASTAR_COARSE_FACTOR` times coarser, or `None` when the
+    /// coarse search finds no path.
+    fn coarse_corridor(
+        &self,
+        start: Cell,
+        goal: Cell,
+        origin: Vec3,
+        index: &ObstacleIndex,
+    ) -> Option<CoarseCorridor> {
+        let coarse = Self {
+            grid_size: self.grid_size * ASTAR_COARSE_FACTOR.to_f32(),
+            ..self.clone()
+        };
+        let to_coarse =
+            |cell: Cell| coarse.world_to_cell(cell.to_world(origin, self.grid_size), origin);
+        let coarse_start = coarse.nearest_clear_cell(to_coarse(start), origin, index)?;
+        let coarse_goal = coarse.nearest_clear_cell(to_coarse(goal), origin, index)?;
+        let path = coarse
+            .find_path(coarse_start, coarse_goal, origin, index, None)
+            .ok()?;
+
+        let cells = path
+            .into_iter()
+            .flat_map(|cell| iter::once(cell).chain(Self::neighbors(cell)))
+            .chain([to_coarse(start), to_coarse(goal)])
+            .collect();
+        Some(CoarseCorridor {
+            planner: coarse,
+            cells,
+        })
+    }
+
+    /// Run `A*` and return the path as grid cells, exploring only cells
+    /// inside `corridor` when one is given — up to every fine cell the
+    /// corridor spans, since the corridor already bounds the search. Fails
+    /// with the reason the search gave up.
     fn find_path(
         &self,
         start: Cell,
         goal: Cell,
         origin: Vec3,
-        obstacles: &[Obstacle],
-    ) -> Option<Vec<Cell>> {
+        index: &ObstacleIndex,
+        corridor: Option<&CoarseCorridor>,
+    ) -> Result<Vec<Cell>, RouteOutcome> {
         let mut open = BinaryHeap::new();
         let mut came_from: HashMap<Cell, Cell> = HashMap::new();
         let mut g_score: HashMap<Cell, f32> = HashMap::new();
         let mut explored = 0_usize;
+        let max_cells = corridor.map_or(self.max_cells, |corridor| {
+            corridor.fine_cell_count().max(self.max_cells)
+        });
 
         g_score.insert(start, 0.0);
         open.push(OpenEntry {
@@ -163,20 +307,23 @@ impl AStarPlanner {
                     node = prev;
                 }
                 path.reverse();
-                return Some(path);
+                return Ok(path);
             }
 
-            explored += 1;
-            if explored > self.max_cells {
-                return None;
+            if explored >= max_cells {
+                return Err(RouteOutcome::SearchExhausted { explored });
             }
+            explored += 1;
 
             let current_g = g_score[&current];
 
             for neighbor in Self::neighbors(current) {
                 let neighbor_world = neighbor.to_world(origin, self.grid_size);
+                if corridor.is_some_and(|corridor| !corridor.contains(neighbor_world, origin)) {
+                    continue;
+                }
 
-                match self.is_blocked(neighbor_world, obstacles) {
+                match self.is_blocked(neighbor_world, index) {
                     Blockage::Blocked => continue,
                     Blockage::Clear => {},
                 }
@@ -200,24 +347,18 @@ impl AStarPlanner {
             }
         }
 
-        None
+        Err(RouteOutcome::FallbackToDirect)
     }
 
     /// Check if any obstacle intersects the direct line from start to end.
-    fn is_direct_path_blocked(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Blockage {
-        obstacle::is_segment_blocked(
-            start,
-            end,
-            obstacles,
-            self.margin,
-            ASTAR_SEGMENT_SAMPLE_STEPS,
-        )
+    fn is_direct_path_blocked(&self, start: Vec3, end: Vec3, index: &ObstacleIndex) -> Blockage {
+        index.segment_blockage(start, end, self.margin, ASTAR_SEGMENT_SAMPLE_STEPS)
     }
 
     /// Pull the path taut: from each kept waypoint, jump straight to the
     /// farthest later waypoint whose connecting segment clears every obstacle,
     /// discarding the grid staircase in between.
-    fn shortcut_path(&self, waypoints: &mut Vec<Vec3>, obstacles: &[Obstacle]) {
+    fn shortcut_path(&self, waypoints: &mut Vec<Vec3>, index: &ObstacleIndex) {
         let Some(&first) = waypoints.first() else {
             return;
         };
@@ -227,11 +368,8 @@ impl AStarPlanner {
             let next = (current + 1..waypoints.len())
                 .rev()
                 .find(|&candidate| {
-                    match self.is_shortcut_blocked(
-                        waypoints[current],
-                        waypoints[candidate],
-                        obstacles,
-                    ) {
+                    match self.is_shortcut_blocked(waypoints[current], waypoints[candidate], index)
+                    {
                         Blockage::Clear => true,
                         Blockage::Blocked => false,
                     }
@@ -246,12 +384,12 @@ impl AStarPlanner {
     /// Segment blockage test whose sample count scales with segment length
     /// ([`ASTAR_SHORTCUT_SAMPLES_PER_CELL`] per grid cell), so a long shortcut
     /// cannot step over a thin obstacle between samples.
-    fn is_shortcut_blocked(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Blockage {
+    fn is_shortcut_blocked(&self, start: Vec3, end: Vec3, index: &ObstacleIndex) -> Blockage {
         let steps = (start.distance(end) / self.grid_size * ASTAR_SHORTCUT_SAMPLES_PER_CELL)
             .ceil()
             .to_u32()
             .max(1);
-        obstacle::is_segment_blocked(start, end, obstacles, self.margin, steps)
+        index.segment_blockage(start, end, self.margin, steps)
     }
 }
 
@@ -267,53 +405,8 @@ impl Default for AStarPlanner {
 
 impl PathPlanner for AStarPlanner {
     fn plan(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Vec<Vec3> {
-        if obstacles.is_empty() {
-            return vec![start, end];
-        }
-
-        match self.is_direct_path_blocked(start, end, obstacles) {
-            Blockage::Clear => return vec![start, end],
-            Blockage::Blocked => {},
-        }
-
-        let origin = start;
-        // Snap each endpoint's cell to the nearest clear cell; the exact
-        // start/end positions are restored on the waypoint list below.
-        let snapped_cells = (
-            self.nearest_clear_cell(self.world_to_cell(start, origin), origin, obstacles),
-            self.nearest_clear_cell(self.world_to_cell(end, origin), origin, obstacles),
-        );
-        let (Some(start_cell), Some(goal_cell)) = snapped_cells else {
-            return vec![start, end];
-        };
-
-        let Some(path_cells) = self.find_path(start_cell, goal_cell, origin, obstacles) else {
-            return vec![start, end];
-        };
-
-        // Convert `path_cells` into `waypoints` with `Cell::to_world`.
-        let mut waypoints: Vec<Vec3> = path_cells
-            .iter()
-            .map(|c| c.to_world(origin, self.grid_size))
-            .collect();
-
-        // Ensure exact start and end positions
-        if let Some(first) = waypoints.first_mut() {
-            *first = start;
-        }
-        if let Some(last) = waypoints.last_mut() {
-            *last = end;
-        }
-
-        // Pull the path taut before dropping collinear points: A*'s
-        // cell-by-cell moves leave staircase jogs that `shortcut_path`
-        // replaces with the longest clear straight runs.
-        self.shortcut_path(&mut waypoints, obstacles);
-
-        // `simplify_path` removes collinear entries from `waypoints`.
-        simplify_path(&mut waypoints);
-
-        waypoints
+        self.plan_with_index(start, end, &ObstacleIndex::from_obstacles(obstacles))
+            .0
     }
 }
 
@@ -326,6 +419,13 @@ struct Cell {
 }
 
 impl Cell {
+    fn chebyshev_distance(self, other: Self) -> i32 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
     fn to_world(self, origin: Vec3, grid_size: f32) -> Vec3 {
         origin
             + Vec3::new(
@@ -336,6 +436,23 @@ impl Cell {
     }
 }
 
+/// The coarse cells a hierarchical search may pass through, with the coarse
+/// planner that maps world positions onto them.
+struct CoarseCorridor {
+    planner: AStarPlanner,
+    cells:   HashSet<Cell>,
+}
+
+impl CoarseCorridor {
+    fn contains(&self, position: Vec3, origin: Vec3) -> bool {
+        self.cells
+            .contains(&self.planner.world_to_cell(position, origin))
+    }
+
+    /// Number of fine cells inside the corridor.
+    fn fine_cell_count(&self) -> usize { self.cells.len() * ASTAR_COARSE_FACTOR.pow(3).to_usize() }
+}
+
 /// Entry in the A* priority queue (min-heap by `f_score`).
 struct OpenEntry {
     cell:    Cell,
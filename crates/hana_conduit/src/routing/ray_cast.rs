@@ -0,0 +1,136 @@
+//! [`CableRayHit`]: where a ray strikes a cable swept as a chain of capsules,
+//! found by [`CableGeometry::cast_ray`](super::CableGeometry::cast_ray).
+
+use bevy::math::Ray3d;
+use bevy::math::Vec3;
+
+use super::geometry::CableSegment;
+
+/// Where a ray strikes a cable's tube.
+///
+/// The tube is the cable's sample polyline swept by a sphere: a capsule
+/// around each piece between neighbouring samples. This follows the route
+/// rather than the rendered triangles, so the hit is the same whatever the
+/// mesh's cross-section, level of detail, or trim.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CableRayHit {
+    /// Distance along the ray to where it enters the tube.
+    pub distance:   f32,
+    /// World-space point where the ray enters the tube.
+    pub position:   Vec3,
+    /// Point on the cable's centreline nearest `position`.
+    pub point:      Vec3,
+    /// Arc length from the cable's start to `point`, in metres.
+    pub arc_length: f32,
+}
+
+impl CableRayHit {
+    /// Outward surface normal at `position`, or `None` when the hit lies on
+    /// the centreline itself.
+    #[must_use]
+    pub fn normal(&self) -> Option<Vec3> { (self.position - self.point).try_normalize() }
+}
+
+/// Nearest hit of `ray` on the capsules of `radius` around `segment`'s
+/// samples, whose first sample sits `start_arc_length` along the cable. The
+/// hit's arc length interpolates the segment's own `arc_lengths` across the
+/// piece it lands on.
+pub(super) fn cast_ray_at_segment(
+    ray: Ray3d,
+    segment: &CableSegment,
+    radius: f32,
+    start_arc_length: f32,
+) -> Option<CableRayHit> {
+    let mut nearest: Option<CableRayHit> = None;
+    for (pair, arc_lengths) in segment
+        .points
+        .windows(2)
+        .zip(segment.arc_lengths.windows(2))
+    {
+        let (start, end) = (pair[0], pair[1]);
+        if let Some(distance) = ray_capsule_distance(ray, start, end, radius)
+            && nearest.is_none_or(|nearest| distance < nearest.distance)
+        {
+            let position = ray.get_point(distance);
+            let fraction = fraction_along_piece(start, end, position);
+            nearest = Some(CableRayHit {
+                distance,
+                position,
+                point: start.lerp(end, fraction),
+                arc_length: start_arc_length
+                    + fraction.mul_add(arc_lengths[1] - arc_lengths[0], arc_lengths[0]),
+            });
+        }
+    }
+    nearest
+}
+
+/// Distance along `ray` to where it enters the capsule of `radius` from
+/// `start` to `end`, or `None` when it misses or starts inside.
+fn ray_capsule_distance(ray: Ray3d, start: Vec3, end: Vec3, radius: f32) -> Option<f32> {
+    [
+        ray_cylinder_distance(ray, start, end, radius),
+        ray_sphere_distance(ray, start, radius),
+        ray_sphere_distance(ray, end, radius),
+    ]
+    .into_iter()
+    .flatten()
+    .min_by(f32::total_cmp)
+}
+
+/// Distance along `ray` to where it enters the side of the cylinder of
+/// `radius` from `start` to `end`. A ray parallel to the axis can only enter
+/// the capsule through its end spheres, so it misses here.
+fn ray_cylinder_distance(ray: Ray3d, start: Vec3, end: Vec3, radius: f32) -> Option<f32> {
+    let direction = *ray.direction;
+    let axis = end - start;
+    let to_origin = ray.origin - start;
+    let axis_length_squared = axis.length_squared();
+    let axis_along_ray = axis.dot(direction);
+    let axis_along_origin = axis.dot(to_origin);
+
+    let a = axis_along_ray.mul_add(-axis_along_ray, axis_length_squared);
+    if a <= f32::EPSILON {
+        return None;
+    }
+    let b = axis_length_squared.mul_add(
+        direction.dot(to_origin),
+        -(axis_along_origin * axis_along_ray),
+    );
+    let c = axis_length_squared.mul_add(
+        radius.mul_add(-radius, to_origin.length_squared()),
+        -(axis_along_origin * axis_along_origin),
+    );
+    let discriminant = b.mul_add(b, -(a * c));
+    if discriminant < 0.0 {
+        return None;
+    }
+    let distance = (-b - discriminant.sqrt()) / a;
+    let along_axis = distance.mul_add(axis_along_ray, axis_along_origin);
+    (distance >= 0.0 && along_axis > 0.0 && along_axis < axis_length_squared).then_some(distance)
+}
+
+/// Distance along `ray` to where it enters the sphere of `radius` around
+/// `center`, or `None` when it misses or starts inside.
+fn ray_sphere_distance(ray: Ray3d, center: Vec3, radius: f32) -> Option<f32> {
+    let to_origin = ray.origin - center;
+    let b = ray.direction.dot(to_origin);
+    let c = radius.mul_add(-radius, to_origin.length_squared());
+    let discriminant = b.mul_add(b, -c);
+    if discriminant < 0.0 {
+        return None;
+    }
+    let distance = -b - discriminant.sqrt();
+    (distance >= 0.0).then_some(distance)
+}
+
+/// How far along the piece from `start` to `end` the point nearest
+/// `position` lies, from 0 to 1.
+fn fraction_along_piece(start: Vec3, end: Vec3, position: Vec3) -> f32 {
+    let axis = end - start;
+    let axis_length_squared = axis.length_squared();
+    if axis_length_squared <= f32::EPSILON {
+        return 0.0;
+    }
+    ((position - start).dot(axis) / axis_length_squared).clamp(0.0, 1.0)
+}
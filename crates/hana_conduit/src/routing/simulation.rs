@@ -0,0 +1,297 @@
+//! Position-based cable dynamics: [`SimulatedSolver`] settings and the
+//! [`CableSimulation`] particle chain they drive.
+//!
+//! Each step integrates the interior particles with Verlet (a particle's
+//! velocity is the difference between its current and previous positions),
+//! then projects constraints for a fixed number of iterations: pinned ends,
+//! fixed segment lengths, bending, and collision with obstacles and support
+//! surfaces. The chain is seeded from the catenary solution, so a cable starts
+//! at rest in the shape [`CatenarySolver`] would draw.
+
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+use bevy_kana::ToF32;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::catenary::CatenarySolver;
+use super::constants::DEFAULT_SIMULATION_BENDING_STIFFNESS;
+use super::constants::DEFAULT_SIMULATION_COLLISION_RADIUS;
+use super::constants::DEFAULT_SIMULATION_DAMPING;
+use super::constants::DEFAULT_SIMULATION_ITERATIONS;
+use super::constants::MAX_SIMULATION_STEP_SECONDS;
+use super::geometry::CableGeometry;
+use super::geometry::CableSegment;
+use super::geometry::RouteRequest;
+use super::geometry::polyline_length;
+use super::obstacle::push_out_of_obstacles;
+use super::solver_selection::span_ends;
+use super::solver_selection::spent_on_leads;
+use super::solver_selection::wrap_with_leads;
+
+/// Settings for a cable simulated as a particle chain.
+///
+/// The embedded [`CatenarySolver`] supplies the seed shape, the cable length
+/// (`slack` × the distance between the lead tips at seeding), the gravity, and
+/// the default particle count (`resolution`). Unlike the static catenary, the
+/// simulation uses the gravity vector's magnitude as an acceleration in m/s².
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+pub struct SimulatedSolver {
+    /// Seed shape, cable length, gravity, and default resolution.
+    pub catenary:          CatenarySolver,
+    /// Fraction of bending corrected per iteration: `0.0` hangs like a limp
+    /// rope, `1.0` resists folding like a stiff cable.
+    pub bending_stiffness: f32,
+    /// Fraction of particle velocity lost per second: `0.0` swings forever,
+    /// `1.0` stops dead.
+    pub damping:           f32,
+    /// Constraint-projection iterations per step. More iterations stretch less.
+    pub iterations:        u32,
+    /// Distance particles keep from obstacle surfaces, in metres.
+    pub collision_radius:  f32,
+}
+
+impl SimulatedSolver {
+    /// Create a simulated solver with default parameters.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            catenary:          CatenarySolver::new(),
+            bending_stiffness: DEFAULT_SIMULATION_BENDING_STIFFNESS,
+            damping:           DEFAULT_SIMULATION_DAMPING,
+            iterations:        DEFAULT_SIMULATION_ITERATIONS,
+            collision_radius:  DEFAULT_SIMULATION_COLLISION_RADIUS,
+        }
+    }
+
+    /// Set the catenary the chain is seeded from.
+    #[must_use]
+    pub const fn with_catenary(mut self, catenary: CatenarySolver) -> Self {
+        self.catenary = catenary;
+        self
+    }
+
+    /// Set the bending stiffness.
+    #[must_use]
+    pub const fn with_bending_stiffness(mut self, bending_stiffness: f32) -> Self {
+        self.bending_stiffness = bending_stiffness;
+        self
+    }
+
+    /// Set the damping.
+    #[must_use]
+    pub const fn with_damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Set the constraint-projection iterations per step.
+    #[must_use]
+    pub const fn with_iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Set the obstacle collision radius.
+    #[must_use]
+    pub const fn with_collision_radius(mut self, collision_radius: f32) -> Self {
+        self.collision_radius = collision_radius;
+        self
+    }
+}
+
+impl Default for SimulatedSolver {
+    fn default() -> Self { Self::new() }
+}
+
+/// A cable's particle chain: one particle per sample point, the first and last
+/// pinned to the routed span's ends (the lead tips, when the anchors have
+/// leads).
+#[derive(Clone, Debug)]
+pub struct CableSimulation {
+    positions:      Vec<Vec3>,
+    previous:       Vec<Vec3>,
+    segment_length: f32,
+    slack:          f32,
+    resolution:     u32,
+}
+
+impl CableSimulation {
+    /// Seed a chain at rest on the catenary `solver` draws for `request`.
+    #[must_use]
+    pub fn seed(solver: &SimulatedSolver, request: &RouteRequest) -> Self {
+        let resolution = request.effective_resolution(solver.catenary.resolution);
+        let (start, end) = span_ends(request);
+        let positions = solver
+            .catenary
+            .fit_to_path(start.distance(end), spent_on_leads(request))
+            .solve_supported_segment(start, end, request.supports, resolution)
+            .points;
+        let segment_length =
+            polyline_length(&positions) / positions.len().saturating_sub(1).max(1).to_f32();
+        Self {
+            previous: positions.clone(),
+            positions,
+            segment_length,
+            slack: solver.catenary.slack,
+            resolution,
+        }
+    }
+
+    /// Advance the chain by `delta_seconds` toward the anchors in `request`,
+    /// colliding with its obstacles and supports. Steps longer than
+    /// `MAX_SIMULATION_STEP_SECONDS` are clamped so a frame hitch cannot fling
+    /// the chain.
+    pub fn step(&mut self, solver: &SimulatedSolver, request: &RouteRequest, delta_seconds: f32) {
+        let delta_seconds = delta_seconds.min(MAX_SIMULATION_STEP_SECONDS);
+        let Some(last) = self.positions.len().checked_sub(1) else {
+            return;
+        };
+        if delta_seconds <= 0.0 || last == 0 {
+            return;
+        }
+        self.follow_catenary(&solver.catenary, request);
+
+        let retained = (1.0 - solver.damping.clamp(0.0, 1.0)).powf(delta_seconds);
+        let gravity = solver.catenary.gravity * delta_seconds * delta_seconds;
+        for (position, previous) in self
+            .positions
+            .iter_mut()
+            .zip(&mut self.previous)
+            .take(last)
+            .skip(1)
+        {
+            let velocity = (*position - *previous) * retained;
+            *previous = *position;
+            *position += velocity + gravity;
+        }
+
+        let (start, end) = span_ends(request);
+        self.positions[0] = start;
+        self.previous[0] = start;
+        self.positions[last] = end;
+        self.previous[last] = end;
+
+        let bending_stiffness = solver.bending_stiffness.clamp(0.0, 1.0);
+        for _ in 0..solver.iterations.max(1) {
+            for index in 0..last {
+                self.correct_distance(index, index + 1, self.segment_length, 1.0);
+            }
+            // Bending keeps every other particle at least a straight two
+            // segments apart; a chain folded tighter is pushed open.
+            let straight = 2.0 * self.segment_length;
+            for index in 0..last.saturating_sub(1) {
+                if self.positions[index].distance(self.positions[index + 2]) < straight {
+                    self.correct_distance(index, index + 2, straight, bending_stiffness);
+                }
+            }
+            self.collide(request, solver, last);
+        }
+    }
+
+    /// The chain's current shape as cable geometry: one span segment through
+    /// the particles, wrapped with the request's lead segments.
+    #[must_use]
+    pub fn geometry(&self, request: &RouteRequest) -> CableGeometry {
+        let waypoints = self
+            .positions
+            .first()
+            .into_iter()
+            .chain(self.positions.last())
+            .copied()
+            .collect();
+        let span = CableGeometry::from_segments(
+            vec![CableSegment::from(self.positions.clone())],
+            waypoints,
+        );
+        wrap_with_leads(span, request)
+    }
+
+    /// Current particle positions, start to end.
+    #[must_use]
+    pub fn points(&self) -> &[Vec3] { &self.positions }
+
+    /// Rest length of the chain, in metres.
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        self.segment_length * self.positions.len().saturating_sub(1).to_f32()
+    }
+
+    /// Particle count requested when the chain was seeded.
+    #[must_use]
+    pub const fn resolution(&self) -> u32 { self.resolution }
+
+    /// Farthest any particle moved during the last step, in metres.
+    #[must_use]
+    pub fn max_displacement(&self) -> f32 {
+        self.positions
+            .iter()
+            .zip(&self.previous)
+            .map(|(position, previous)| position.distance(*previous))
+            .fold(0.0, f32::max)
+    }
+
+    /// Keep the rest length in step with the catenary: an absolute length
+    /// (less the leads) is divided evenly across the segments, and a slack
+    /// changed since seeding (e.g. a detach slack bump) rescales the chain in
+    /// place instead of snapping it to a new seed.
+    fn follow_catenary(&mut self, catenary: &CatenarySolver, request: &RouteRequest) {
+        if let Some(length) = catenary.length {
+            let segments = self.positions.len().saturating_sub(1).max(1).to_f32();
+            self.segment_length = (length - spent_on_leads(request)).max(0.0) / segments;
+        } else if self.slack > 0.0 && (catenary.slack - self.slack).abs() > f32::EPSILON {
+            self.segment_length *= catenary.slack / self.slack;
+            self.slack = catenary.slack;
+        }
+    }
+
+    /// Move particles `a` and `b` `stiffness` of the way toward
+    /// `target_distance` apart. Pinned ends have no inverse mass, so the free
+    /// particle takes the whole correction.
+    fn correct_distance(&mut self, a: usize, b: usize, target_distance: f32, stiffness: f32) {
+        let weight_a = self.inverse_mass(a);
+        let weight_b = self.inverse_mass(b);
+        let total_weight = weight_a + weight_b;
+        let delta = self.positions[b] - self.positions[a];
+        let distance = delta.length();
+        if total_weight <= 0.0 || distance <= f32::EPSILON {
+            return;
+        }
+        let correction =
+            delta * (stiffness * (distance - target_distance) / (distance * total_weight));
+        self.positions[a] += correction * weight_a;
+        self.positions[b] -= correction * weight_b;
+    }
+
+    const fn inverse_mass(&self, index: usize) -> f32 {
+        if index == 0 || index + 1 == self.positions.len() {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Push interior particles out of the request's obstacles, then lift any
+    /// that sank through a support surface back onto it. A pushed particle
+    /// loses its velocity, so a cable draped over an obstacle or lying on the
+    /// floor settles instead of bouncing.
+    fn collide(&mut self, request: &RouteRequest, solver: &SimulatedSolver, last: usize) {
+        let up = -solver.catenary.gravity.normalize_or_zero();
+        for (position, previous) in self
+            .positions
+            .iter_mut()
+            .zip(&mut self.previous)
+            .take(last)
+            .skip(1)
+        {
+            let pushed = request.supports.iter().fold(
+                push_out_of_obstacles(*position, request.obstacles, solver.collision_radius),
+                |point, support| support.lift(point, up),
+            );
+            if pushed != *position {
+                *position = pushed;
+                *previous = pushed;
+            }
+        }
+    }
+}
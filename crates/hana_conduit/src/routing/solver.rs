@@ -16,6 +16,7 @@ use super::geometry::CableGeometry;
 use super::geometry::CableSegment;
 use super::geometry::RouteRequest;
 use super::obstacle::Obstacle;
+use super::via;
 
 /// The primary routing trait. Produces complete cable geometry from a route request.
 ///
@@ -85,8 +86,9 @@ impl RouteSolver for LinearSolver {
 
 /// Composes a [`PathPlanner`] and [`CurveSolver`] into a [`RouteSolver`].
 ///
-/// The `path_planner` finds waypoints, then the `curve_solver` fills each
-/// waypoint-to-waypoint gap with smooth geometry.
+/// The `path_planner` finds waypoints, one leg at a time between the request's
+/// via points, then the `curve_solver` fills each waypoint-to-waypoint gap
+/// with smooth geometry. A via's tangent through-piece is drawn straight.
 pub struct Router {
     path_planner: Box<dyn PathPlanner>,
     curve_solver: Box<dyn CurveSolver>,
@@ -116,17 +118,25 @@ impl Router {
 
 impl RouteSolver for Router {
     fn solve(&self, request: &RouteRequest) -> CableGeometry {
-        let waypoints = self.path_planner.plan(
+        let waypoints = via::plan_through_vias(
             request.start.position,
             request.end.position,
-            request.obstacles,
+            request.vias,
+            |leg_start, leg_end| {
+                self.path_planner
+                    .plan(leg_start, leg_end, request.obstacles)
+            },
         );
         let resolution = request.effective_resolution(self.resolution);
         let segments: Vec<CableSegment> = waypoints
             .windows(2)
             .map(|pair| {
-                self.curve_solver
-                    .solve_segment(pair[0], pair[1], resolution)
+                if via::is_through_piece(request.vias, pair[0], pair[1]) {
+                    LinearSolver.solve_segment(pair[0], pair[1], resolution)
+                } else {
+                    self.curve_solver
+                        .solve_segment(pair[0], pair[1], resolution)
+                }
             })
             .collect();
 
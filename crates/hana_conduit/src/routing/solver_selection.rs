@@ -9,29 +9,42 @@
 //! `RouteSolver`, `PathPlanner`, and `CurveSolver` remain as internal implementation
 //! details.
 
+use std::cell::OnceCell;
+
 use bevy::math::Vec3;
 use bevy::reflect::Reflect;
 use bevy_kana::ToUsize;
+use serde::Deserialize;
+use serde::Serialize;
 
 use super::catenary::CatenarySolver;
 use super::constants::DEFAULT_RESOLUTION;
 use super::constants::DEFAULT_RESOLUTION_SENTINEL;
 use super::constants::MIN_CABLE_SAMPLE_POINTS;
 use super::constants::MIN_SEGMENT_LENGTH;
+use super::geometry::Anchor;
+use super::geometry::AnchorExit;
 use super::geometry::CableGeometry;
 use super::geometry::CableSegment;
 use super::geometry::RouteRequest;
+use super::geometry::ViaPoint;
+use super::geometry::polyline_length;
 use super::obstacle::Obstacle;
+use super::obstacle_index::ObstacleIndex;
 use super::orthogonal::OrthogonalPlanner;
+use super::outcome::RouteOutcome;
 use super::pathfinding::AStarPlanner;
+use super::simulation::SimulatedSolver;
 use super::solver::CurveSolver;
 use super::solver::DirectPlanner;
 use super::solver::LinearSolver;
 use super::solver::PathPlanner;
-use super::solver::RouteSolver;
+use super::spline::SplineSolver;
+use super::support::SupportSurface;
+use super::via;
 
 /// Path planning strategy (finds waypoints around obstacles).
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub enum PathStrategy {
     /// No obstacle avoidance — direct path.
     Direct,
@@ -47,16 +60,19 @@ pub enum PathStrategy {
 }
 
 /// Curve generation strategy (fills between waypoints).
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub enum CurveKind {
     /// Catenary (hanging cable) curve.
     Catenary(CatenarySolver),
     /// Straight line segment.
     Linear,
+    /// Smooth spline fitted through all the waypoints at once, with corners
+    /// rounded to a minimum bend radius.
+    Spline(SplineSolver),
 }
 
 /// Top-level solver selection for a cable.
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
 pub enum Solver {
     /// Direct catenary curve between endpoints.
     Catenary(CatenarySolver),
@@ -71,6 +87,11 @@ pub enum Solver {
         /// Sample resolution per segment (0 = use solver default).
         resolution:    u32,
     },
+    /// Particle chain simulated over time. [`Solver::solve`] returns the
+    /// catenary rest shape the simulation is seeded from; the cable plugin
+    /// steps a [`CableSimulation`](super::CableSimulation) every frame so the
+    /// cable swings and sways.
+    Simulated(SimulatedSolver),
 }
 
 impl Solver {
@@ -82,85 +103,315 @@ impl Solver {
     /// strategy and curve kind honors leads.
     #[must_use]
     pub fn solve(&self, request: &RouteRequest) -> CableGeometry {
-        let start_tip = request.start.lead_tip();
-        let end_tip = request.end.lead_tip();
-        let span_start = start_tip.unwrap_or(request.start.position);
-        let span_end = end_tip.unwrap_or(request.end.position);
-
-        // Leads that leave the tips closer than `MIN_SEGMENT_LENGTH` (e.g. a
-        // drag hovering next to the source jack) collapse the routed span;
-        // route the bare anchor positions instead.
-        if span_start.distance(span_end) < MIN_SEGMENT_LENGTH {
-            return self.solve_span(request.start.position, request.end.position, request);
+        let (span_start, span_end) = span_ends(request);
+        let (waypoints, outcome) = self.plan(
+            span_start,
+            span_end,
+            request.vias,
+            request.obstacles,
+            request.obstacle_index,
+        );
+        self.solve_waypoints(waypoints, outcome, request)
+    }
+
+    /// The cable's absolute length in metres, when its catenary was authored
+    /// with [`CatenarySolver::with_length`].
+    #[must_use]
+    pub const fn length(&self) -> Option<f32> {
+        match self {
+            Self::Catenary(catenary)
+            | Self::Simulated(SimulatedSolver { catenary, .. })
+            | Self::Routed {
+                curve_kind: CurveKind::Catenary(catenary),
+                ..
+            } => catenary.length,
+            Self::Linear | Self::Routed { .. } => None,
         }
+    }
 
-        let span = self.solve_span(span_start, span_end, request);
-        wrap_with_leads(span, request)
+    /// Span waypoints from `start` to `end` through `vias`, one leg per pair
+    /// of consecutive stops: the path strategy's route around `obstacles` for
+    /// [`Solver::Routed`], the bare leg otherwise. `obstacle_index`, when
+    /// given, indexes exactly `obstacles`; otherwise grid pathfinding indexes
+    /// them on its first leg. Returns the most severe outcome of any leg
+    /// alongside.
+    pub(super) fn plan(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        vias: &[ViaPoint],
+        obstacles: &[Obstacle],
+        obstacle_index: Option<&ObstacleIndex>,
+    ) -> (Vec<Vec3>, RouteOutcome) {
+        let obstacle_index = LazyObstacleIndex {
+            obstacles,
+            prebuilt: obstacle_index,
+            built: OnceCell::new(),
+        };
+        let mut outcome = RouteOutcome::Success;
+        let waypoints = via::plan_through_vias(start, end, vias, |leg_start, leg_end| match self {
+            Self::Routed { path_strategy, .. } => {
+                let (leg, leg_outcome) =
+                    path_strategy.plan(leg_start, leg_end, obstacles, &obstacle_index);
+                outcome = outcome.most_severe(leg_outcome);
+                leg
+            },
+            Self::Catenary(_) | Self::Linear | Self::Simulated(_) => vec![leg_start, leg_end],
+        });
+        (waypoints, outcome)
     }
 
-    /// Route between two bare positions, ignoring anchor exits.
-    fn solve_span(&self, start: Vec3, end: Vec3, request: &RouteRequest) -> CableGeometry {
-        let span_request = RouteRequest {
-            start:      start.into(),
-            end:        end.into(),
-            obstacles:  request.obstacles,
-            resolution: request.resolution,
+    /// Fill the span `waypoints` with this solver's curves, then wrap them
+    /// with the request's leads. Via through-pieces are drawn straight, and an
+    /// absolute cable length counts them as spent, like the leads. `outcome`
+    /// records how the waypoints were planned.
+    pub(super) fn solve_waypoints(
+        &self,
+        waypoints: Vec<Vec3>,
+        outcome: RouteOutcome,
+        request: &RouteRequest,
+    ) -> CableGeometry {
+        let (curve_kind, default_resolution) = self.curve();
+        let resolution = request.effective_resolution(default_resolution);
+        let through_pieces = via::through_piece_length(request.vias);
+        let curve_kind = curve_kind.fit_to_path(
+            polyline_length(&waypoints) - through_pieces,
+            spent_on_leads(request) + through_pieces,
+        );
+
+        let segments: Vec<CableSegment> = match &curve_kind {
+            CurveKind::Spline(spline) => spline_segments(spline, &waypoints, request, resolution),
+            CurveKind::Catenary(_) | CurveKind::Linear => waypoints
+                .windows(2)
+                .map(|pair| {
+                    if via::is_through_piece(request.vias, pair[0], pair[1]) {
+                        LinearSolver.solve_segment(pair[0], pair[1], resolution)
+                    } else {
+                        curve_kind.solve_segment(pair[0], pair[1], request.supports, resolution)
+                    }
+                })
+                .collect(),
         };
+        wrap_with_leads(CableGeometry::from_segments(segments, waypoints), request)
+            .with_outcome(outcome)
+    }
 
+    /// The curve kind that fills between waypoints, and its default sample
+    /// resolution.
+    fn curve(&self) -> (CurveKind, u32) {
         match self {
-            Self::Catenary(catenary) => catenary.solve(&span_request),
-            Self::Linear => LinearSolver.solve(&span_request),
+            Self::Catenary(catenary) | Self::Simulated(SimulatedSolver { catenary, .. }) => {
+                (CurveKind::Catenary(catenary.clone()), catenary.resolution)
+            },
+            Self::Linear => (CurveKind::Linear, DEFAULT_RESOLUTION_SENTINEL),
             Self::Routed {
-                path_strategy,
                 curve_kind,
                 resolution,
+                ..
             } => {
-                let waypoints = path_strategy.plan(start, end, span_request.obstacles);
                 let default_resolution = if *resolution == DEFAULT_RESOLUTION_SENTINEL {
                     DEFAULT_RESOLUTION
                 } else {
                     *resolution
                 };
-                let resolution = span_request.effective_resolution(default_resolution);
-
-                let segments: Vec<CableSegment> = waypoints
-                    .windows(2)
-                    .map(|pair| curve_kind.solve_segment(pair[0], pair[1], resolution))
-                    .collect();
-
-                CableGeometry::from_segments(segments, waypoints)
+                (curve_kind.clone(), default_resolution)
             },
         }
     }
 }
 
 impl PathStrategy {
-    /// Find waypoints from `start` to `end`, routing around `obstacles`.
-    fn plan(&self, start: Vec3, end: Vec3, obstacles: &[Obstacle]) -> Vec<Vec3> {
+    /// Find waypoints from `start` to `end`, routing around `obstacles`, and
+    /// how they were found. Grid pathfinding queries `obstacle_index`, an
+    /// index of the same obstacles.
+    fn plan(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        obstacles: &[Obstacle],
+        obstacle_index: &LazyObstacleIndex,
+    ) -> (Vec<Vec3>, RouteOutcome) {
         match self {
-            Self::Direct => DirectPlanner.plan(start, end, obstacles),
-            Self::Orthogonal => OrthogonalPlanner::new().plan(start, end, obstacles),
-            Self::AStar { grid_size, margin } => AStarPlanner::new()
-                .with_grid_size(*grid_size)
-                .with_margin(*margin)
-                .plan(start, end, obstacles),
+            Self::Direct => (
+                DirectPlanner.plan(start, end, obstacles),
+                RouteOutcome::Success,
+            ),
+            Self::Orthogonal => OrthogonalPlanner::new().plan_with_outcome(start, end, obstacles),
+            Self::AStar { grid_size, margin } => {
+                let planner = AStarPlanner::new()
+                    .with_grid_size(*grid_size)
+                    .with_margin(*margin);
+                planner.plan_with_index(start, end, obstacle_index.get())
+            },
         }
     }
 }
 
+/// An [`ObstacleIndex`] of a plan's obstacles: the caller's prebuilt index,
+/// or one built the first time grid pathfinding asks and shared by every
+/// later leg.
+struct LazyObstacleIndex<'a> {
+    obstacles: &'a [Obstacle],
+    prebuilt:  Option<&'a ObstacleIndex>,
+    built:     OnceCell<ObstacleIndex>,
+}
+
+impl LazyObstacleIndex<'_> {
+    fn get(&self) -> &ObstacleIndex {
+        self.prebuilt.unwrap_or_else(|| {
+            self.built
+                .get_or_init(|| ObstacleIndex::from_obstacles(self.obstacles))
+        })
+    }
+}
+
 impl CurveKind {
-    /// Generate a curve segment between two waypoints.
-    fn solve_segment(&self, start: Vec3, end: Vec3, resolution: u32) -> CableSegment {
+    /// This curve kind with an absolute catenary length resolved to slack for
+    /// a route of `path_length` — see `CatenarySolver::fit_to_path`.
+    fn fit_to_path(&self, path_length: f32, lead_length: f32) -> Self {
+        match self {
+            Self::Catenary(catenary) => {
+                Self::Catenary(catenary.fit_to_path(path_length, lead_length))
+            },
+            Self::Linear => Self::Linear,
+            Self::Spline(spline) => Self::Spline(spline.clone()),
+        }
+    }
+
+    /// Generate a curve segment between two waypoints, a catenary resting on
+    /// any of `supports` it sags through. A spline through just two waypoints
+    /// is a straight line.
+    fn solve_segment(
+        &self,
+        start: Vec3,
+        end: Vec3,
+        supports: &[SupportSurface],
+        resolution: u32,
+    ) -> CableSegment {
         match self {
-            Self::Catenary(catenary) => catenary.solve_segment(start, end, resolution),
-            Self::Linear => LinearSolver.solve_segment(start, end, resolution),
+            Self::Catenary(catenary) => {
+                catenary.solve_supported_segment(start, end, supports, resolution)
+            },
+            Self::Linear | Self::Spline(_) => LinearSolver.solve_segment(start, end, resolution),
         }
     }
 }
 
+/// Fit `spline` through `waypoints` one run at a time: each via through-piece
+/// is drawn straight, and the runs on either side leave and join it along the
+/// via's tangent. The first and last runs carry on from the anchors' leads.
+/// Via points without a tangent are passed through, not rounded off.
+fn spline_segments(
+    spline: &SplineSolver,
+    waypoints: &[Vec3],
+    request: &RouteRequest,
+    resolution: u32,
+) -> Vec<CableSegment> {
+    let Some(&first) = waypoints.first() else {
+        return Vec::new();
+    };
+    let pinned: Vec<Vec3> = request
+        .vias
+        .iter()
+        .filter(|via| via.tangent.is_none())
+        .map(|via| via.position)
+        .collect();
+    let leads = lead_span(request).map(|_| {
+        (
+            lead_direction(&request.start),
+            lead_direction(&request.end).map(|direction| -direction),
+        )
+    });
+    let (start_direction, end_direction) = leads.unwrap_or_default();
+
+    let mut segments = Vec::new();
+    let mut run = vec![first];
+    let mut run_direction = start_direction;
+    for pair in waypoints.windows(2) {
+        if via::is_through_piece(request.vias, pair[0], pair[1]) {
+            let tangent = (pair[1] - pair[0]).normalize_or_zero();
+            if run.len() > 1 {
+                segments.push(spline.solve_run(
+                    &run,
+                    (run_direction, Some(tangent)),
+                    &pinned,
+                    request.obstacles,
+                    resolution,
+                ));
+            }
+            segments.push(LinearSolver.solve_segment(pair[0], pair[1], resolution));
+            run = vec![pair[1]];
+            run_direction = Some(tangent);
+        } else {
+            run.push(pair[1]);
+        }
+    }
+    if run.len() > 1 {
+        segments.push(spline.solve_run(
+            &run,
+            (run_direction, end_direction),
+            &pinned,
+            request.obstacles,
+            resolution,
+        ));
+    }
+    segments
+}
+
+/// Direction the anchor's lead points along, if it has one.
+fn lead_direction(anchor: &Anchor) -> Option<Vec3> {
+    match anchor.exit {
+        AnchorExit::Unconstrained => None,
+        AnchorExit::Lead { direction, .. } => Some(direction.into()),
+    }
+}
+
+/// The positions a solver routes between: the lead tips (or the anchor
+/// positions of anchors without a lead). `None` when leads leave the tips
+/// closer than `MIN_SEGMENT_LENGTH` (e.g. a drag hovering next to the source
+/// jack), which collapses the routed span — route the bare anchor positions
+/// instead, without leads.
+fn lead_span(request: &RouteRequest) -> Option<(Vec3, Vec3)> {
+    let span_start = request.start.lead_tip().unwrap_or(request.start.position);
+    let span_end = request.end.lead_tip().unwrap_or(request.end.position);
+    (span_start.distance(span_end) >= MIN_SEGMENT_LENGTH).then_some((span_start, span_end))
+}
+
+/// The ends of the routed span: the lead span, or the bare anchor positions
+/// when leads collapse it.
+pub(super) fn span_ends(request: &RouteRequest) -> (Vec3, Vec3) {
+    lead_span(request).unwrap_or((request.start.position, request.end.position))
+}
+
+/// Cable length spent on the straight leads around the routed span — none
+/// when leads collapse the span.
+pub(super) fn spent_on_leads(request: &RouteRequest) -> f32 {
+    if lead_span(request).is_none() {
+        return 0.0;
+    }
+    [
+        request
+            .start
+            .lead_tip()
+            .map(|tip| tip.distance(request.start.position)),
+        request
+            .end
+            .lead_tip()
+            .map(|tip| tip.distance(request.end.position)),
+    ]
+    .into_iter()
+    .flatten()
+    .sum()
+}
+
 /// Wrap a routed span with the straight lead segments declared by the request's
 /// anchors, extending the waypoint list back out to the true anchor positions.
-fn wrap_with_leads(span: CableGeometry, request: &RouteRequest) -> CableGeometry {
+/// A span the leads collapsed already runs between the bare anchor positions
+/// and is returned as-is.
+pub(super) fn wrap_with_leads(span: CableGeometry, request: &RouteRequest) -> CableGeometry {
+    if lead_span(request).is_none() {
+        return span;
+    }
     let start_tip = request.start.lead_tip();
     let end_tip = request.end.lead_tip();
     if start_tip.is_none() && end_tip.is_none() {
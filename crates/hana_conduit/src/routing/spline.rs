@@ -0,0 +1,318 @@
+//! [`SplineSolver`]: a smooth curve through a route's waypoints.
+//!
+//! Each corner of the waypoint path is rounded by setting the curve back from
+//! the corner along both neighbouring segments, far enough that a circular arc
+//! of `min_bend_radius` joins them. The curve is a chain of cubic Bezier
+//! pieces: round arcs across the corners, straight runs along the segments,
+//! and Catmull-Rom-style pieces through pinned points the curve must touch.
+//! Where a sample lands inside an obstacle, the set-backs of the corners it
+//! belongs to are halved, and a corner that still cuts through is left sharp.
+
+use bevy::math::Vec3;
+use bevy::reflect::Reflect;
+use bevy_kana::ToF32;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::constants::COLLINEARITY_THRESHOLD;
+use super::constants::DEFAULT_SPLINE_CLEARANCE;
+use super::constants::DEFAULT_SPLINE_MIN_BEND_RADIUS;
+use super::constants::DEFAULT_SPLINE_TENSION;
+use super::constants::MIN_CABLE_SAMPLE_POINTS;
+use super::constants::MIN_SEGMENT_LENGTH;
+use super::constants::SPLINE_CLEARANCE_REFINEMENTS;
+use super::geometry::CableSegment;
+use super::obstacle::Obstacle;
+use super::obstacle::PointContainment;
+use super::obstacle::is_point_in_any_obstacle;
+
+/// Smooth spline curve through a route's waypoints, for
+/// [`CurveKind::Spline`](super::CurveKind::Spline).
+///
+/// Unlike the other curve kinds, the spline is fitted through the whole
+/// waypoint path at once, so its corners bend smoothly instead of meeting at
+/// a kink.
+#[derive(Clone, Debug, Serialize, Deserialize, Reflect)]
+pub struct SplineSolver {
+    /// How tightly the curve pulls into each corner: `0.0` rounds corners
+    /// into circular arcs, `1.0` pulls them in toward sharp bends.
+    pub tension:         f32,
+    /// Smallest radius the curve bends through at a corner, in metres, as far
+    /// as the waypoint spacing allows. Match it to
+    /// [`CableMeshConfig::elbow_bend_radius`](crate::CableMeshConfig::elbow_bend_radius)
+    /// and the tube mesh needs no elbows.
+    pub min_bend_radius: f32,
+    /// Distance curve samples keep from obstacle surfaces, in metres.
+    pub clearance:       f32,
+}
+
+impl SplineSolver {
+    /// Create a spline solver with default parameters.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            tension:         DEFAULT_SPLINE_TENSION,
+            min_bend_radius: DEFAULT_SPLINE_MIN_BEND_RADIUS,
+            clearance:       DEFAULT_SPLINE_CLEARANCE,
+        }
+    }
+
+    /// Set the tension.
+    #[must_use]
+    pub const fn with_tension(mut self, tension: f32) -> Self {
+        self.tension = tension;
+        self
+    }
+
+    /// Set the minimum bend radius.
+    #[must_use]
+    pub const fn with_min_bend_radius(mut self, min_bend_radius: f32) -> Self {
+        self.min_bend_radius = min_bend_radius;
+        self
+    }
+
+    /// Set the obstacle clearance.
+    #[must_use]
+    pub const fn with_clearance(mut self, clearance: f32) -> Self {
+        self.clearance = clearance;
+        self
+    }
+
+    /// Fit the spline through `waypoints` as one segment sampled with
+    /// `resolution` points per piece. `directions` fix the curve's direction
+    /// at its start and end (to run on from a lead or a via's through-piece);
+    /// interior waypoints listed in `pinned` are passed through exactly
+    /// instead of being rounded off.
+    pub(super) fn solve_run(
+        &self,
+        waypoints: &[Vec3],
+        directions: (Option<Vec3>, Option<Vec3>),
+        pinned: &[Vec3],
+        obstacles: &[Obstacle],
+        resolution: u32,
+    ) -> CableSegment {
+        let [first, .., last] = waypoints else {
+            return CableSegment::from(waypoints.to_vec());
+        };
+        let mut corners: Vec<Corner> = waypoints
+            .windows(3)
+            .map(|triple| self.corner(triple, pinned))
+            .collect();
+        let start_direction = directions
+            .0
+            .unwrap_or_else(|| (waypoints[1] - *first).normalize_or_zero());
+        let end_direction = directions
+            .1
+            .unwrap_or_else(|| (*last - waypoints[waypoints.len() - 2]).normalize_or_zero());
+        let samples_per_piece = resolution.max(MIN_CABLE_SAMPLE_POINTS);
+
+        let mut refinements = 0;
+        loop {
+            let knots = knots(&corners, *first, *last, start_direction, end_direction);
+            let pieces: Vec<Vec<Vec3>> = knots
+                .windows(2)
+                .map(|pair| self.sample_piece(&pair[0], &pair[1], samples_per_piece))
+                .collect();
+
+            let mut cutting: Vec<usize> = knots
+                .windows(2)
+                .zip(&pieces)
+                .filter(|(_, samples)| self.cuts_through(samples, obstacles))
+                .flat_map(|(pair, _)| [pair[0].corner, pair[1].corner])
+                .flatten()
+                .collect();
+            cutting.sort_unstable();
+            cutting.dedup();
+
+            if cutting.is_empty() || refinements == SPLINE_CLEARANCE_REFINEMENTS {
+                return CableSegment::from(join_pieces(pieces));
+            }
+            for index in cutting {
+                corners[index].pull_in(refinements + 1 == SPLINE_CLEARANCE_REFINEMENTS);
+            }
+            refinements += 1;
+        }
+    }
+
+    /// The rounding of the middle waypoint of `triple`.
+    fn corner(&self, triple: &[Vec3], pinned: &[Vec3]) -> Corner {
+        let (previous, point, next) = (triple[0], triple[1], triple[2]);
+        let incoming_length = point.distance(previous);
+        let outgoing_length = point.distance(next);
+        let incoming = (point - previous).normalize_or_zero();
+        let outgoing = (next - point).normalize_or_zero();
+        let turn = incoming.dot(outgoing).clamp(-1.0, 1.0).acos();
+
+        let setback = if pinned.contains(&point) || incoming.dot(outgoing) > COLLINEARITY_THRESHOLD
+        {
+            0.0
+        } else {
+            // Half of each neighbouring segment, so adjacent corners never
+            // overlap.
+            let room = incoming_length.min(outgoing_length) / 2.0;
+            (self.min_bend_radius * (turn / 2.0).tan()).min(room)
+        };
+        Corner {
+            point,
+            incoming,
+            outgoing,
+            through: (next - previous).normalize_or(incoming),
+            setback,
+            sharp: false,
+        }
+    }
+
+    /// Sample the cubic Bezier piece between two knots.
+    fn sample_piece(&self, from: &Knot, to: &Knot, samples: u32) -> Vec<Vec3> {
+        let chord = from.position.distance(to.position);
+        let turn = from.leaving.dot(to.arriving).clamp(-1.0, 1.0).acos();
+        // A Bezier whose handles are `4/3 tan(turn / 4)` of the radius traces a
+        // near-circular arc; expressed against the chord that radius spans.
+        let handle_ratio = if turn < MIN_SEGMENT_LENGTH {
+            1.0 / 3.0
+        } else {
+            (2.0 / 3.0) * (turn / 4.0).tan() / (turn / 2.0).sin()
+        };
+        let handle = (chord * handle_ratio * (1.0 - self.tension.clamp(0.0, 1.0))).min(chord);
+        let controls = [
+            from.position,
+            from.position + from.leaving * handle,
+            to.position - to.arriving * handle,
+            to.position,
+        ];
+
+        let last = samples.saturating_sub(1).max(1);
+        (0..samples)
+            .map(|index| cubic_bezier(&controls, index.to_f32() / last.to_f32()))
+            .collect()
+    }
+
+    /// Whether a piece dips into an obstacle between two samples that sit
+    /// clear of it. A piece whose ends are already inside an obstacle (an
+    /// anchor on a device face) cannot be improved by rounding.
+    fn cuts_through(&self, samples: &[Vec3], obstacles: &[Obstacle]) -> bool {
+        let inside = |point: Vec3| {
+            is_point_in_any_obstacle(point, obstacles, self.clearance) == PointContainment::Inside
+        };
+        let [first, .., last] = samples else {
+            return false;
+        };
+        !inside(*first) && !inside(*last) && samples.iter().any(|&point| inside(point))
+    }
+}
+
+impl Default for SplineSolver {
+    fn default() -> Self { Self::new() }
+}
+
+/// How the curve turns at one interior waypoint.
+struct Corner {
+    point:    Vec3,
+    incoming: Vec3,
+    outgoing: Vec3,
+    /// Direction a smooth curve passes through a pinned point.
+    through:  Vec3,
+    /// Distance the curve leaves the path before the corner and rejoins it
+    /// after. Zero passes through the corner point itself.
+    setback:  f32,
+    /// Whether the curve turns at the point with a kink instead of smoothly.
+    sharp:    bool,
+}
+
+impl Corner {
+    /// Bend the curve closer to the corner after it cut through an obstacle:
+    /// halve the set-back, or pass sharply through the point on the last
+    /// refinement (or when there is no set-back left to halve).
+    fn pull_in(&mut self, last_refinement: bool) {
+        self.setback /= 2.0;
+        if last_refinement || self.setback < MIN_SEGMENT_LENGTH {
+            self.setback = 0.0;
+            self.sharp = true;
+        }
+    }
+}
+
+/// A point the curve passes through, with the directions it arrives and
+/// leaves along.
+struct Knot {
+    position: Vec3,
+    arriving: Vec3,
+    leaving:  Vec3,
+    /// The corner this knot rounds, if any.
+    corner:   Option<usize>,
+}
+
+/// The knots for `corners`: one pair straddling each rounded corner, one on
+/// each pinned or sharp corner, plus the two ends.
+fn knots(
+    corners: &[Corner],
+    start: Vec3,
+    end: Vec3,
+    start_direction: Vec3,
+    end_direction: Vec3,
+) -> Vec<Knot> {
+    let mut knots = Vec::with_capacity(corners.len() * 2 + 2);
+    knots.push(Knot {
+        position: start,
+        arriving: start_direction,
+        leaving:  start_direction,
+        corner:   None,
+    });
+    for (index, corner) in corners.iter().enumerate() {
+        let corner_index = Some(index);
+        if corner.setback > 0.0 {
+            knots.push(Knot {
+                position: corner.point - corner.incoming * corner.setback,
+                arriving: corner.incoming,
+                leaving:  corner.incoming,
+                corner:   corner_index,
+            });
+            knots.push(Knot {
+                position: corner.point + corner.outgoing * corner.setback,
+                arriving: corner.outgoing,
+                leaving:  corner.outgoing,
+                corner:   corner_index,
+            });
+        } else if corner.sharp {
+            knots.push(Knot {
+                position: corner.point,
+                arriving: corner.incoming,
+                leaving:  corner.outgoing,
+                corner:   corner_index,
+            });
+        } else {
+            knots.push(Knot {
+                position: corner.point,
+                arriving: corner.through,
+                leaving:  corner.through,
+                corner:   corner_index,
+            });
+        }
+    }
+    knots.push(Knot {
+        position: end,
+        arriving: end_direction,
+        leaving:  end_direction,
+        corner:   None,
+    });
+    knots
+}
+
+fn cubic_bezier(controls: &[Vec3; 4], t: f32) -> Vec3 {
+    let u = 1.0 - t;
+    controls[0] * (u * u * u)
+        + controls[1] * (3.0 * u * u * t)
+        + controls[2] * (3.0 * u * t * t)
+        + controls[3] * (t * t * t)
+}
+
+/// Join sampled pieces into one polyline, dropping each piece's first sample
+/// (the previous piece's last).
+fn join_pieces(pieces: Vec<Vec<Vec3>>) -> Vec<Vec3> {
+    let mut points = Vec::new();
+    for piece in pieces {
+        let skip = usize::from(!points.is_empty());
+        points.extend(piece.into_iter().skip(skip));
+    }
+    points
+}
@@ -0,0 +1,236 @@
+//! [`SupportSurface`]: floors and obstacle tops a sagging cable comes to rest
+//! on.
+//!
+//! A catenary that would sag through a surface lies on it instead: a straight
+//! run along the surface joins two hanging spans at the lift-off points. Each
+//! hanging span is a catenary whose lowest point is its lift-off point, so the
+//! cable leaves the surface level with it, and both spans share one catenary
+//! parameter — the horizontal tension balances across the frictionless run.
+//!
+//! For a span hanging `h` above the surface with parameter `a`, the lift-off
+//! point lies `a · acosh(1 + h / a)` short of the anchor's foot and the span
+//! is `sqrt(h² + 2ah)` long. Shrinking `a` lengthens the cable (more of it
+//! lies flat, the spans steepen), so `a` is found by bisection against the
+//! cable length.
+
+use bevy::math::Vec3;
+use bevy_kana::ToF32;
+use bevy_kana::ToUsize;
+
+use super::constants::MIN_CABLE_SAMPLE_POINTS;
+use super::constants::MIN_CATENARY_PARAM;
+use super::constants::SUPPORT_BISECTION_ITERATIONS;
+use super::constants::SUPPORT_CONTACT_TOLERANCE;
+use super::geometry::CableSegment;
+use super::obstacle::Obstacle;
+
+/// A surface facing against gravity that a sagging catenary rests on instead
+/// of passing through — the floor of a room, or the top of a table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SupportSurface {
+    /// A point on the surface. The surface is the plane through it,
+    /// perpendicular to the solver's gravity.
+    pub point:  Vec3,
+    /// World-space `(min, max)` box whose footprint bounds the surface, or
+    /// `None` for an unbounded plane.
+    pub bounds: Option<(Vec3, Vec3)>,
+}
+
+impl SupportSurface {
+    /// An unbounded surface through `point`.
+    #[must_use]
+    pub fn plane(point: impl Into<Vec3>) -> Self {
+        Self {
+            point:  point.into(),
+            bounds: None,
+        }
+    }
+
+    /// The top face of `obstacle`'s world-space bounding box, taking +Y as up.
+    #[must_use]
+    pub fn top_of(obstacle: &Obstacle) -> Self {
+        let (min, max) = obstacle.bounds();
+        let center = (min + max) / 2.0;
+        Self {
+            point:  Vec3::new(center.x, max.y, center.z),
+            bounds: Some((min, max)),
+        }
+    }
+
+    /// Height of `point` above the surface along `up`.
+    fn height_of(&self, point: Vec3, up: Vec3) -> f32 { (point - self.point).dot(up) }
+
+    /// Whether `point`, dropped onto the surface along `up`, lands within its
+    /// bounds.
+    fn is_under(&self, point: Vec3, up: Vec3) -> bool {
+        let Some((min, max)) = self.bounds else {
+            return true;
+        };
+        let dropped = point - up * self.height_of(point, up);
+        let tolerance = Vec3::splat(SUPPORT_CONTACT_TOLERANCE);
+        dropped.cmpge(min - tolerance).all() && dropped.cmple(max + tolerance).all()
+    }
+
+    /// `point` lifted back onto the surface if it sank below it within its
+    /// bounds.
+    pub(super) fn lift(&self, point: Vec3, up: Vec3) -> Vec3 {
+        let height = self.height_of(point, up);
+        if height < 0.0 && self.is_under(point, up) {
+            point - up * height
+        } else {
+            point
+        }
+    }
+}
+
+/// `free`, the catenary hanging `cable_length` between `start` and `end`,
+/// resting on the highest of `supports` it sags through. The cable rests on
+/// a bounded surface only where its whole run on the surface lies within the
+/// bounds, and only while both ends are above it; otherwise the next surface
+/// down is tried, and `free` is returned when none fits.
+pub(super) fn rest_on_supports(
+    free: CableSegment,
+    start: Vec3,
+    end: Vec3,
+    cable_length: f32,
+    up: Vec3,
+    supports: &[SupportSurface],
+    resolution: u32,
+) -> CableSegment {
+    let mut sagged_through: Vec<&SupportSurface> = supports
+        .iter()
+        .filter(|support| {
+            free.points.iter().any(|&point| {
+                support.height_of(point, up) < -SUPPORT_CONTACT_TOLERANCE
+                    && support.is_under(point, up)
+            })
+        })
+        .collect();
+    sagged_through.sort_by(|a, b| b.point.dot(up).total_cmp(&a.point.dot(up)));
+
+    sagged_through
+        .into_iter()
+        .find_map(|support| rest_on(support, start, end, cable_length, up, resolution))
+        .map_or(free, CableSegment::from)
+}
+
+/// Sample points of the cable resting on `support`, or `None` when an end is
+/// below the surface or the run on it leaves its bounds.
+fn rest_on(
+    support: &SupportSurface,
+    start: Vec3,
+    end: Vec3,
+    cable_length: f32,
+    up: Vec3,
+    resolution: u32,
+) -> Option<Vec<Vec3>> {
+    let start_height = support.height_of(start, up);
+    let end_height = support.height_of(end, up);
+    if start_height < -SUPPORT_CONTACT_TOLERANCE || end_height < -SUPPORT_CONTACT_TOLERANCE {
+        return None;
+    }
+    let (start_height, end_height) = (start_height.max(0.0), end_height.max(0.0));
+    let start_foot = start - up * start_height;
+    let end_foot = end - up * end_height;
+    let horizontal_distance = start_foot.distance(end_foot);
+    let along = (end_foot - start_foot).normalize_or_zero();
+
+    let param = resting_parameter(start_height, end_height, horizontal_distance, cable_length);
+    let start_lift = start_foot + along * reach(start_height, param);
+    let end_lift = end_foot - along * reach(end_height, param);
+    if !support.is_under(start_lift, up) || !support.is_under(end_lift, up) {
+        return None;
+    }
+
+    // Samples are spaced evenly along the cable, so the run on the surface
+    // is sampled as densely as the hanging spans.
+    let start_span = span_length(start_height, param);
+    let end_span = span_length(end_height, param);
+    let run = start_lift.distance(end_lift);
+    let total = start_span + run + end_span;
+    let samples = resolution.max(MIN_CABLE_SAMPLE_POINTS).to_usize();
+    let last = (samples - 1).to_f32();
+    Some(
+        (0..samples)
+            .map(|index| {
+                let distance = total * index.to_f32() / last;
+                if distance <= start_span {
+                    hanging_point(start_lift, -along, up, param, start_span - distance)
+                } else if distance <= start_span + run {
+                    start_lift + along * (distance - start_span)
+                } else {
+                    hanging_point(end_lift, along, up, param, distance - start_span - run)
+                }
+            })
+            .collect(),
+    )
+}
+
+/// The catenary parameter that gives a resting cable of `cable_length` with
+/// ends `start_height` and `end_height` above the surface and
+/// `horizontal_distance` apart. A cable too long to take up its length lying
+/// straight gets the smallest parameter — vertical drops to the surface.
+fn resting_parameter(
+    start_height: f32,
+    end_height: f32,
+    horizontal_distance: f32,
+    cable_length: f32,
+) -> f32 {
+    let total_reach = |param: f32| reach(start_height, param) + reach(end_height, param);
+    let resting_length = |param: f32| {
+        span_length(start_height, param) + span_length(end_height, param) + horizontal_distance
+            - total_reach(param)
+    };
+
+    // The largest parameter keeps the lift-off points from crossing: the free
+    // catenary that just touches the surface.
+    let mut touching = horizontal_distance.max(MIN_CATENARY_PARAM);
+    for _ in 0..SUPPORT_BISECTION_ITERATIONS {
+        if total_reach(touching) >= horizontal_distance {
+            break;
+        }
+        touching *= 2.0;
+    }
+    let touching = bisect(MIN_CATENARY_PARAM, touching, |param| {
+        total_reach(param) < horizontal_distance
+    });
+
+    bisect(MIN_CATENARY_PARAM, touching, |param| {
+        resting_length(param) > cable_length
+    })
+}
+
+/// The boundary within `low..high` where `below` stops holding, assuming it
+/// holds up to some point and fails after.
+fn bisect(mut low: f32, mut high: f32, below: impl Fn(f32) -> bool) -> f32 {
+    if low >= high {
+        return high;
+    }
+    for _ in 0..SUPPORT_BISECTION_ITERATIONS {
+        let middle = f32::midpoint(low, high);
+        if below(middle) {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    f32::midpoint(low, high)
+}
+
+/// Horizontal distance from a span's lift-off point to the foot of an anchor
+/// `height` above the surface.
+fn reach(height: f32, param: f32) -> f32 { param * (1.0 + height / param).acosh() }
+
+/// Length of a span hanging from an anchor `height` above the surface.
+fn span_length(height: f32, param: f32) -> f32 {
+    height.mul_add(height, 2.0 * param * height).sqrt()
+}
+
+/// The point `arc` along a hanging span from its lift-off point `lift`,
+/// heading out along `outward`: with the lift-off point as the catenary's
+/// lowest point, `x = a·asinh(s/a)` and `y = √(a² + s²) − a`.
+fn hanging_point(lift: Vec3, outward: Vec3, up: Vec3, param: f32, arc: f32) -> Vec3 {
+    let horizontal = param * (arc / param).asinh();
+    let rise = param.hypot(arc) - param;
+    lift + outward * horizontal + up * rise
+}
@@ -0,0 +1,62 @@
+//! Routing through [`ViaPoint`]s: a route is planned one leg at a time between
+//! consecutive stops, and each via with a tangent is crossed by a straight
+//! through-piece so the legs on either side meet it head-on.
+
+use bevy::math::Vec3;
+
+use super::constants::MIN_SEGMENT_LENGTH;
+use super::geometry::ViaPoint;
+
+/// Span waypoints from `start` to `end` through `vias`: each leg between
+/// consecutive stops comes from `plan_leg`, and each tangent via adds its
+/// through-piece between the legs around it.
+pub(super) fn plan_through_vias(
+    start: Vec3,
+    end: Vec3,
+    vias: &[ViaPoint],
+    mut plan_leg: impl FnMut(Vec3, Vec3) -> Vec<Vec3>,
+) -> Vec<Vec3> {
+    let mut waypoints = vec![start];
+    let mut from = start;
+    for via in vias {
+        let (entry, exit) = via.through_piece();
+        extend_leg(&mut waypoints, plan_leg(from, entry));
+        if exit != entry {
+            waypoints.push(exit);
+        }
+        from = exit;
+    }
+    extend_leg(&mut waypoints, plan_leg(from, end));
+    waypoints
+}
+
+/// Whether the waypoint pair `start`→`end` is a via's through-piece, which is
+/// drawn straight instead of with the route's curve.
+pub(super) fn is_through_piece(vias: &[ViaPoint], start: Vec3, end: Vec3) -> bool {
+    vias.iter()
+        .filter(|via| via.tangent.is_some())
+        .any(|via| via.through_piece() == (start, end))
+}
+
+/// Total length of the vias' straight through-pieces.
+pub(super) fn through_piece_length(vias: &[ViaPoint]) -> f32 {
+    vias.iter()
+        .map(|via| {
+            let (entry, exit) = via.through_piece();
+            entry.distance(exit)
+        })
+        .sum()
+}
+
+/// Append a planned leg, skipping its first waypoint — the stop the previous
+/// leg already ended on — and any waypoint that would repeat the last one.
+fn extend_leg(waypoints: &mut Vec<Vec3>, leg: Vec<Vec3>) {
+    for waypoint in leg.into_iter().skip(1) {
+        if waypoints
+            .last()
+            .is_none_or(|last| last.distance(waypoint) >= MIN_SEGMENT_LENGTH)
+        {
+            waypoints.push(waypoint);
+        }
+    }
+}
@@ -6,36 +6,86 @@
 //!
 //! Uses `MinimalPlugins` for headless testing: no window, no renderer.
 
+use std::thread;
+use std::time::Duration;
+
 use bevy::app::App;
 use bevy::asset::AssetPlugin;
+use bevy::camera::NormalizedRenderTarget;
+use bevy::camera::RenderTargetInfo;
+use bevy::camera::Viewport;
+use bevy::camera::visibility::calculate_bounds;
+use bevy::ecs::system::RunSystemOnce;
 use bevy::gizmos::GizmoPlugin;
 use bevy::math::Vec3;
 use bevy::mesh::MeshPlugin;
+use bevy::picking::Pickable;
+use bevy::picking::backend::HitData;
+use bevy::picking::backend::PointerHits;
+use bevy::picking::backend::ray::RayId;
+use bevy::picking::backend::ray::RayMap;
+use bevy::picking::events::Drag;
+use bevy::picking::events::DragEnd;
+use bevy::picking::events::DragStart;
+use bevy::picking::pointer::Location;
+use bevy::picking::pointer::PointerId;
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
 use bevy::transform::TransformPlugin;
 use hana_conduit::AttachedTo;
+use hana_conduit::BackgroundSolve;
 use hana_conduit::Cable;
+use hana_conduit::CableAvoidance;
+use hana_conduit::CableBundle;
+use hana_conduit::CableConnected;
+use hana_conduit::CableConnector;
+use hana_conduit::CableDisconnected;
 use hana_conduit::CableEnd;
 use hana_conduit::CableEndpoint;
+use hana_conduit::CableMeshConfig;
+use hana_conduit::CableMeshHandle;
+use hana_conduit::CableMeshLod;
+use hana_conduit::CableMeshLodBand;
+use hana_conduit::CableNetwork;
+use hana_conduit::CableNetworkError;
+use hana_conduit::CableOverStretched;
+use hana_conduit::CablePointerHit;
+use hana_conduit::CablePointerHits;
+use hana_conduit::CablePort;
+use hana_conduit::CableRayCast;
+use hana_conduit::CableRouteOutcome;
+use hana_conduit::CableRouteOutcomeChanged;
+use hana_conduit::CableSupport;
+use hana_conduit::CableTension;
+use hana_conduit::CableWaypoint;
 use hana_conduit::CatenaryPlugin;
 use hana_conduit::CatenarySolver;
 use hana_conduit::ComputedCableGeometry;
+use hana_conduit::ConnectionRejected;
+use hana_conduit::ConnectorKind;
 use hana_conduit::CurveKind;
 use hana_conduit::DEFAULT_SLACK;
 use hana_conduit::DetachPolicy;
+use hana_conduit::DraggedCableEnd;
 use hana_conduit::Obstacle;
+use hana_conduit::OverStretchPolicy;
 use hana_conduit::PathStrategy;
+use hana_conduit::RejectionReason;
 use hana_conduit::RouteAnimation;
 use hana_conduit::RouteObstacle;
+use hana_conduit::RouteOutcome;
 use hana_conduit::Solver;
+use hana_conduit::TensionState;
+use hana_conduit::UvConfig;
 
-/// Spawn a world-attached cable and return the cable entity.
-fn spawn_world_cable(app: &mut App, start: Vec3, end: Vec3) -> Entity {
+/// Spawn a world-attached cable routed by `solver` and return the cable and
+/// its end endpoint.
+fn spawn_cable(app: &mut App, solver: Solver, start: Vec3, end: Vec3) -> (Entity, Entity) {
     let cable = app
         .world_mut()
         .spawn(Cable {
-            solver:     Solver::Catenary(CatenarySolver::new().with_slack(DEFAULT_SLACK)),
-            obstacles:  vec![],
+            solver,
+            obstacles: vec![],
             resolution: 0,
         })
         .id();
@@ -43,9 +93,19 @@ fn spawn_world_cable(app: &mut App, start: Vec3, end: Vec3) -> Entity {
     app.world_mut()
         .spawn((CableEndpoint::new(CableEnd::Start, start), ChildOf(cable)));
 
-    app.world_mut()
-        .spawn((CableEndpoint::new(CableEnd::End, end), ChildOf(cable)));
-    cable
+    let end = app
+        .world_mut()
+        .spawn((CableEndpoint::new(CableEnd::End, end), ChildOf(cable)))
+        .id();
+    (cable, end)
+}
+
+/// A catenary hanging with the default slack.
+const SLACK_CATENARY: Solver = Solver::Catenary(CatenarySolver::new().with_slack(DEFAULT_SLACK));
+
+/// Spawn a world-attached cable and return the cable entity.
+fn spawn_world_cable(app: &mut App, start: Vec3, end: Vec3) -> Entity {
+    spawn_cable(app, SLACK_CATENARY, start, end).0
 }
 
 fn build_test_app() -> App {
@@ -621,3 +681,1262 @@ fn routed_waypoints(app: &App, cable: Entity) -> Vec<Vec3> {
         .waypoints
         .clone()
 }
+
+/// A catenary five metres long, whatever the span between its endpoints.
+const FIVE_METRE_CATENARY: Solver = Solver::Catenary(CatenarySolver::new().with_length(5.0));
+
+fn drag_endpoint(app: &mut App, endpoint: Entity, offset: Vec3) {
+    app.world_mut()
+        .get_mut::<CableEndpoint>(endpoint)
+        .unwrap()
+        .offset = offset;
+    app.update();
+}
+
+fn cable_length(app: &App, cable: Entity) -> f32 {
+    app.world()
+        .get::<ComputedCableGeometry>(cable)
+        .unwrap()
+        .cable_geometry
+        .as_ref()
+        .unwrap()
+        .total_length
+}
+
+#[test]
+fn absolute_length_cable_keeps_its_length_as_endpoints_close() {
+    let mut app = build_test_app();
+    let (cable, end) = spawn_cable(
+        &mut app,
+        FIVE_METRE_CATENARY,
+        Vec3::ZERO,
+        Vec3::new(4.0, 0.0, 0.0),
+    );
+    app.world_mut()
+        .entity_mut(cable)
+        .insert(OverStretchPolicy::Report);
+
+    app.update();
+    assert!((cable_length(&app, cable) - 5.0).abs() < 0.05);
+    let far_tension = app.world().get::<CableTension>(cable).unwrap().tension;
+
+    drag_endpoint(&mut app, end, Vec3::new(2.0, 0.0, 0.0));
+
+    assert!(
+        (cable_length(&app, cable) - 5.0).abs() < 0.05,
+        "moving the endpoints closer sags the cable instead of shrinking it"
+    );
+    let tension = app.world().get::<CableTension>(cable).unwrap();
+    assert_eq!(tension.state, TensionState::Slack);
+    assert!(tension.tension < far_tension);
+}
+
+#[test]
+fn over_stretched_cable_reports_tension_once() {
+    #[derive(Resource, Default)]
+    struct OverStretchCount(u32);
+
+    let mut app = build_test_app();
+    app.init_resource::<OverStretchCount>();
+    app.add_observer(|_: On<CableOverStretched>, mut count: ResMut<OverStretchCount>| count.0 += 1);
+    let (cable, end) = spawn_cable(
+        &mut app,
+        FIVE_METRE_CATENARY,
+        Vec3::ZERO,
+        Vec3::new(4.0, 0.0, 0.0),
+    );
+    app.world_mut()
+        .entity_mut(cable)
+        .insert(OverStretchPolicy::Report);
+    app.update();
+
+    drag_endpoint(&mut app, end, Vec3::new(6.0, 0.0, 0.0));
+    drag_endpoint(&mut app, end, Vec3::new(6.5, 0.0, 0.0));
+
+    let tension = app.world().get::<CableTension>(cable).unwrap();
+    assert_eq!(tension.state, TensionState::OverStretched);
+    assert!((tension.tension - 1.3).abs() < 1e-3);
+    assert_eq!(
+        app.world().resource::<OverStretchCount>().0,
+        1,
+        "the event fires on becoming over-stretched, not every recompute"
+    );
+}
+
+#[test]
+fn clamp_policy_holds_dragged_endpoint_at_cable_length() {
+    let mut app = build_test_app();
+    let (cable, end) = spawn_cable(
+        &mut app,
+        FIVE_METRE_CATENARY,
+        Vec3::ZERO,
+        Vec3::new(4.0, 0.0, 0.0),
+    );
+    app.world_mut()
+        .entity_mut(cable)
+        .insert(OverStretchPolicy::ClampEndpoint);
+    app.update();
+
+    drag_endpoint(&mut app, end, Vec3::new(8.0, 0.0, 0.0));
+
+    let offset = app.world().get::<CableEndpoint>(end).unwrap().offset;
+    assert!(
+        (offset.length() - 5.0).abs() < 1e-3,
+        "the dragged endpoint should be held at the cable's length, got {offset}"
+    );
+    assert_eq!(
+        app.world().get::<CableTension>(cable).unwrap().state,
+        TensionState::Taut
+    );
+}
+
+#[test]
+fn detach_policy_pops_the_opposite_endpoint() {
+    let mut app = build_test_app();
+    let jack = app.world_mut().spawn(Transform::default()).id();
+    let cable = app
+        .world_mut()
+        .spawn((
+            Cable {
+                solver:     Solver::Catenary(CatenarySolver::new().with_length(5.0)),
+                obstacles:  vec![],
+                resolution: 0,
+            },
+            OverStretchPolicy::Detach,
+        ))
+        .id();
+    let plugged = app
+        .world_mut()
+        .spawn((
+            CableEndpoint::new(CableEnd::Start, Vec3::ZERO),
+            AttachedTo(jack),
+            ChildOf(cable),
+        ))
+        .id();
+    let dragged = app
+        .world_mut()
+        .spawn((
+            CableEndpoint::new(CableEnd::End, Vec3::new(4.0, 0.0, 0.0)),
+            ChildOf(cable),
+        ))
+        .id();
+    app.update();
+    assert!(app.world().get::<AttachedTo>(plugged).is_some());
+
+    drag_endpoint(&mut app, dragged, Vec3::new(7.0, 0.0, 0.0));
+
+    assert!(
+        app.world().get::<AttachedTo>(plugged).is_none(),
+        "over-stretching should pop the plugged endpoint out of its jack"
+    );
+    assert!(
+        app.world().get_entity(cable).is_ok(),
+        "the plugged endpoint's `DetachPolicy::Remain` keeps the cable"
+    );
+}
+
+/// Orthogonal routing joined by straight segments.
+const ORTHOGONAL_LINEAR: Solver = Solver::Routed {
+    path_strategy: PathStrategy::Orthogonal,
+    curve_kind:    CurveKind::Linear,
+    resolution:    0,
+};
+
+#[test]
+fn avoiding_cables_take_separate_lanes_until_avoidance_is_removed() {
+    let mut app = build_test_app();
+    let (first, _) = spawn_cable(
+        &mut app,
+        ORTHOGONAL_LINEAR,
+        Vec3::ZERO,
+        Vec3::new(4.0, 0.0, 0.0),
+    );
+    let (second, _) = spawn_cable(
+        &mut app,
+        ORTHOGONAL_LINEAR,
+        Vec3::new(0.0, 0.01, 0.0),
+        Vec3::new(4.0, 0.01, 0.0),
+    );
+    for cable in [first, second] {
+        app.world_mut().entity_mut(cable).insert(CableAvoidance);
+    }
+    app.update();
+
+    // Cables are laid out in `Entity` order, which need not be spawn order.
+    let (leader, follower) = if first < second {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    assert_eq!(
+        routed_waypoints(&app, leader).len(),
+        2,
+        "the lowest entity keeps its straight route"
+    );
+    assert!(
+        routed_waypoints(&app, follower).len() > 2,
+        "the overlapping cable should jog into a lane beside the leader"
+    );
+
+    app.world_mut()
+        .entity_mut(follower)
+        .remove::<CableAvoidance>();
+    app.update();
+
+    assert_eq!(
+        routed_waypoints(&app, follower).len(),
+        2,
+        "without `CableAvoidance` the cable routes on its own again"
+    );
+}
+
+#[test]
+fn cable_waypoints_shape_the_route_in_index_order() {
+    let mut app = build_test_app();
+    let cable = app
+        .world_mut()
+        .spawn(Cable {
+            solver:     Solver::Linear,
+            obstacles:  vec![],
+            resolution: 0,
+        })
+        .id();
+    app.world_mut().spawn((
+        CableEndpoint::new(CableEnd::Start, Vec3::ZERO),
+        ChildOf(cable),
+    ));
+    app.world_mut().spawn((
+        CableEndpoint::new(CableEnd::End, Vec3::new(4.0, 0.0, 0.0)),
+        ChildOf(cable),
+    ));
+    // Spawned out of order: the route follows `index`, not spawn order.
+    app.world_mut().spawn((
+        CableWaypoint::new(1, Vec3::new(3.0, 1.0, 0.0)),
+        ChildOf(cable),
+    ));
+    let first_waypoint = app
+        .world_mut()
+        .spawn((
+            CableWaypoint::new(0, Vec3::new(1.0, 1.0, 0.0)),
+            ChildOf(cable),
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(
+        routed_waypoints(&app, cable),
+        vec![
+            Vec3::ZERO,
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(3.0, 1.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+        ]
+    );
+
+    app.world_mut().entity_mut(first_waypoint).despawn();
+    app.update();
+
+    assert_eq!(
+        routed_waypoints(&app, cable),
+        vec![
+            Vec3::ZERO,
+            Vec3::new(3.0, 1.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+        ],
+        "despawning a waypoint should re-route without it"
+    );
+}
+
+/// The sample points of `cable`'s computed geometry.
+fn routed_points(app: &App, cable: Entity) -> Vec<Vec3> {
+    app.world()
+        .get::<ComputedCableGeometry>(cable)
+        .unwrap()
+        .cable_geometry
+        .as_ref()
+        .unwrap()
+        .all_points()
+        .copied()
+        .collect()
+}
+
+#[test]
+fn cable_rests_on_a_support_and_follows_it_when_it_moves() {
+    let mut app = build_test_app();
+    let floor = app
+        .world_mut()
+        .spawn((CableSupport::Floor, Transform::default()))
+        .id();
+    let cable = app
+        .world_mut()
+        .spawn(Cable {
+            solver:     Solver::Catenary(CatenarySolver::new().with_slack(1.3)),
+            obstacles:  vec![],
+            resolution: 0,
+        })
+        .id();
+    app.world_mut().spawn((
+        CableEndpoint::new(CableEnd::Start, Vec3::new(0.0, 1.0, 0.0)),
+        ChildOf(cable),
+    ));
+    app.world_mut().spawn((
+        CableEndpoint::new(CableEnd::End, Vec3::new(4.0, 1.0, 0.0)),
+        ChildOf(cable),
+    ));
+    app.update();
+
+    let lowest = |app: &App| {
+        routed_points(app, cable)
+            .iter()
+            .map(|point| point.y)
+            .fold(f32::INFINITY, f32::min)
+    };
+    assert!(
+        lowest(&app).abs() < 1e-3,
+        "the cable should lie on the floor, lowest sample {}",
+        lowest(&app)
+    );
+
+    // Raising the floor re-queues the cable via `queue_obstacle_changes`.
+    app.world_mut()
+        .entity_mut(floor)
+        .insert(Transform::from_translation(Vec3::new(0.0, 0.5, 0.0)));
+    app.update();
+    app.update();
+
+    assert!(
+        (lowest(&app) - 0.5).abs() < 1e-3,
+        "the cable should rest on the raised floor, lowest sample {}",
+        lowest(&app)
+    );
+}
+
+/// A* routing around obstacles, joined by straight segments.
+const ASTAR_LINEAR: Solver = Solver::Routed {
+    path_strategy: PathStrategy::AStar {
+        grid_size: 0.25,
+        margin:    0.1,
+    },
+    curve_kind:    CurveKind::Linear,
+    resolution:    0,
+};
+
+#[test]
+fn moved_obstacle_recomputes_only_cables_whose_corridor_it_touches() {
+    let mut app = build_test_app();
+    let obstacle = app
+        .world_mut()
+        .spawn((
+            RouteObstacle::HalfExtents(Vec3::splat(0.5)),
+            Transform::from_translation(Vec3::new(0.0, 50.0, 0.0)),
+        ))
+        .id();
+    let (near, _) = spawn_cable(
+        &mut app,
+        ASTAR_LINEAR,
+        Vec3::new(-2.0, 0.0, 0.0),
+        Vec3::new(2.0, 0.0, 0.0),
+    );
+    let (far, _) = spawn_cable(
+        &mut app,
+        ASTAR_LINEAR,
+        Vec3::new(-2.0, 0.0, 20.0),
+        Vec3::new(2.0, 0.0, 20.0),
+    );
+    app.update();
+    app.update();
+    assert_eq!(routed_waypoints(&app, near).len(), 2);
+
+    // Tag the far cable's geometry with a repeated end point; a recompute
+    // would replace it with a fresh two-point route.
+    let mut computed = app
+        .world_mut()
+        .get_mut::<ComputedCableGeometry>(far)
+        .unwrap();
+    let cable_geometry = computed.cable_geometry.as_mut().unwrap();
+    let last = *cable_geometry.waypoints.last().unwrap();
+    cable_geometry.waypoints.push(last);
+
+    // Dropping the obstacle onto the near cable's path re-queues it through
+    // `queue_obstacle_changes`, while the far cable's corridor stays clear.
+    app.world_mut()
+        .entity_mut(obstacle)
+        .insert(Transform::default());
+    app.update();
+    app.update();
+
+    assert!(
+        routed_waypoints(&app, near).len() > 2,
+        "the near cable should divert around the moved obstacle"
+    );
+    assert_eq!(
+        routed_waypoints(&app, far).len(),
+        3,
+        "the far cable should not be recomputed"
+    );
+}
+
+#[test]
+fn render_aabb_obstacle_diverts_the_cable_once_its_mesh_loads() {
+    let mut app = build_test_app();
+    app.add_systems(PostUpdate, calculate_bounds);
+    // A reserved handle stands in for a mesh still loading: `calculate_bounds`
+    // gives the child its `Aabb` only once the asset arrives.
+    let mesh = app.world().resource::<Assets<Mesh>>().reserve_handle();
+    let obstacle = app
+        .world_mut()
+        .spawn((RouteObstacle::FromRenderAabb, Transform::default()))
+        .id();
+    app.world_mut()
+        .spawn((Mesh3d(mesh.clone()), ChildOf(obstacle)));
+    let (cable, _) = spawn_cable(
+        &mut app,
+        ASTAR_LINEAR,
+        Vec3::new(-2.0, 0.0, 0.0),
+        Vec3::new(2.0, 0.0, 0.0),
+    );
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(
+        routed_waypoints(&app, cable).len(),
+        2,
+        "an obstacle without render bounds yet should not divert the cable"
+    );
+
+    app.world_mut()
+        .resource_mut::<Assets<Mesh>>()
+        .insert(&mesh, Cuboid::from_length(1.0).into())
+        .unwrap();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(
+        routed_waypoints(&app, cable).len() > 2,
+        "the cable should divert once the obstacle's child gains its render bounds"
+    );
+}
+
+#[test]
+fn background_solve_applies_route_once_the_task_completes() {
+    let mut app = build_test_app();
+    app.world_mut().spawn((
+        RouteObstacle::HalfExtents(Vec3::splat(0.5)),
+        Transform::default(),
+    ));
+    let (cable, _) = spawn_cable(
+        &mut app,
+        ASTAR_LINEAR,
+        Vec3::new(-2.0, 0.0, 0.0),
+        Vec3::new(2.0, 0.0, 0.0),
+    );
+    app.world_mut().entity_mut(cable).insert(BackgroundSolve);
+
+    let computed = |app: &App| {
+        app.world()
+            .get::<ComputedCableGeometry>(cable)
+            .unwrap()
+            .cable_geometry
+            .is_some()
+    };
+    for _ in 0..200 {
+        app.update();
+        if computed(&app) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    assert!(
+        computed(&app),
+        "the background solve should land within a few frames"
+    );
+    assert!(
+        routed_waypoints(&app, cable).len() > 2,
+        "the background route should divert around the obstacle"
+    );
+}
+
+#[test]
+fn route_outcome_reports_endpoint_pushed_out_of_obstacle() {
+    #[derive(Resource, Default)]
+    struct OutcomeChanges(Vec<RouteOutcome>);
+
+    let mut app = build_test_app();
+    app.init_resource::<OutcomeChanges>();
+    app.add_observer(
+        |changed: On<CableRouteOutcomeChanged>, mut changes: ResMut<OutcomeChanges>| {
+            changes.0.push(changed.outcome);
+        },
+    );
+    app.world_mut().spawn((
+        RouteObstacle::HalfExtents(Vec3::splat(0.5)),
+        Transform::default(),
+    ));
+    let (cable, _) = spawn_cable(&mut app, ASTAR_LINEAR, Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0));
+    app.update();
+
+    let outcome = app.world().get::<CableRouteOutcome>(cable).unwrap().0;
+    assert!(
+        matches!(outcome, RouteOutcome::EndpointPushedOut { endpoint, .. } if endpoint == Vec3::ZERO),
+        "the start inside the obstacle should be reported, got {outcome:?}"
+    );
+    assert_eq!(app.world().resource::<OutcomeChanges>().0, vec![outcome]);
+}
+
+#[test]
+fn flowing_cable_scrolls_its_material_along_the_cable() {
+    let mut app = build_test_app();
+    app.init_asset::<StandardMaterial>();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+        100,
+    )));
+    let material = app
+        .world_mut()
+        .resource_mut::<Assets<StandardMaterial>>()
+        .add(StandardMaterial::default());
+    let uv_config = UvConfig {
+        repeat_length: 2.0,
+        flow_speed:    0.5,
+    };
+    let cable = spawn_world_cable(&mut app, Vec3::ZERO, Vec3::new(3.0, 0.0, 0.0));
+    app.world_mut().entity_mut(cable).insert(CableMeshConfig {
+        material: Some(material.clone()),
+        uv_config: uv_config.clone(),
+        ..default()
+    });
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let elapsed = app.world().resource::<Time>().elapsed_secs();
+    assert!(elapsed > 0.0, "time should advance between updates");
+    let expected = -(elapsed * uv_config.flow_speed / uv_config.repeat_length).rem_euclid(1.0);
+    let scrolled = app
+        .world()
+        .resource::<Assets<StandardMaterial>>()
+        .get(&material)
+        .unwrap()
+        .uv_transform
+        .translation
+        .y;
+    assert!(
+        (scrolled - expected).abs() < 1e-5,
+        "the material should scroll {expected} along V, got {scrolled}"
+    );
+}
+
+const JACK: ConnectorKind = ConnectorKind::new("jack");
+const PORT_SOCKET: Vec3 = Vec3::new(3.0, 1.0, 0.0);
+
+/// Where connector test cables hang from, and where their plug ends start.
+const CONNECTOR_CABLE_START: Vec3 = Vec3::new(-3.0, 1.0, 0.0);
+const CONNECTOR_CABLE_END: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
+fn spawn_port(app: &mut App, port: CablePort) -> Entity {
+    app.world_mut()
+        .spawn((port, Transform::from_translation(PORT_SOCKET)))
+        .id()
+}
+
+/// Carry `end` to `target` and let go of it there.
+fn drop_end(app: &mut App, end: Entity, target: Vec3) {
+    app.world_mut()
+        .entity_mut(end)
+        .insert(DraggedCableEnd::new(target));
+    app.update();
+    app.world_mut().entity_mut(end).remove::<DraggedCableEnd>();
+    app.update();
+}
+
+#[test]
+fn dragged_end_snaps_into_a_compatible_port_and_connects_on_release() {
+    #[derive(Resource, Default)]
+    struct Connections(Vec<Entity>);
+
+    let mut app = build_test_app();
+    app.init_resource::<Connections>();
+    app.add_observer(
+        |connected: On<CableConnected>, mut connections: ResMut<Connections>| {
+            connections.0.push(connected.port);
+        },
+    );
+    let port = spawn_port(&mut app, CablePort::new(JACK));
+    let (_, end) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(end)
+        .insert(CableConnector::new(JACK));
+    app.update();
+
+    app.world_mut()
+        .entity_mut(end)
+        .insert(DraggedCableEnd::new(PORT_SOCKET + Vec3::new(0.1, 0.1, 0.0)));
+    app.update();
+
+    let offset = app.world().get::<CableEndpoint>(end).unwrap().offset;
+    assert!(
+        offset.distance(PORT_SOCKET) < 1e-4,
+        "an end dragged within snap range should preview in the socket, got {offset}"
+    );
+    assert_eq!(
+        app.world()
+            .get::<DraggedCableEnd>(end)
+            .unwrap()
+            .snapped_port,
+        Some(port)
+    );
+    assert!(app.world().resource::<Connections>().0.is_empty());
+
+    app.world_mut().entity_mut(end).remove::<DraggedCableEnd>();
+    app.update();
+
+    assert_eq!(
+        app.world()
+            .get::<AttachedTo>(end)
+            .map(|attached| attached.0),
+        Some(port)
+    );
+    assert_eq!(app.world().resource::<Connections>().0, vec![port]);
+}
+
+#[test]
+fn port_rejects_other_connector_kinds_and_ends_past_its_capacity() {
+    #[derive(Resource, Default)]
+    struct Rejections(Vec<RejectionReason>);
+
+    let mut app = build_test_app();
+    app.init_resource::<Rejections>();
+    app.add_observer(
+        |rejected: On<ConnectionRejected>, mut rejections: ResMut<Rejections>| {
+            rejections.0.push(rejected.reason);
+        },
+    );
+    let port = spawn_port(&mut app, CablePort::new(JACK).with_capacity(1));
+    let (_, other_kind) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(other_kind)
+        .insert(CableConnector::new(ConnectorKind::new("usb")));
+    let (_, first) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(first)
+        .insert(CableConnector::new(JACK));
+    let (_, second) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(second)
+        .insert(CableConnector::new(JACK));
+    app.update();
+
+    drop_end(&mut app, other_kind, PORT_SOCKET);
+    drop_end(&mut app, first, PORT_SOCKET);
+    drop_end(&mut app, second, PORT_SOCKET);
+
+    assert!(app.world().get::<AttachedTo>(other_kind).is_none());
+    assert_eq!(
+        app.world()
+            .get::<AttachedTo>(first)
+            .map(|attached| attached.0),
+        Some(port)
+    );
+    assert!(app.world().get::<AttachedTo>(second).is_none());
+    assert_eq!(
+        app.world().resource::<Rejections>().0,
+        vec![RejectionReason::IncompatibleKind, RejectionReason::PortFull]
+    );
+    let offset = app.world().get::<CableEndpoint>(second).unwrap().offset;
+    assert!(
+        offset.distance(PORT_SOCKET) < 1e-4,
+        "a refused end should stay where it was dropped, got {offset}"
+    );
+}
+
+#[test]
+fn grabbing_a_connected_end_unplugs_it_without_applying_its_detach_policy() {
+    #[derive(Resource, Default)]
+    struct Disconnections(Vec<Entity>);
+
+    let mut app = build_test_app();
+    app.init_resource::<Disconnections>();
+    app.add_observer(
+        |disconnected: On<CableDisconnected>, mut disconnections: ResMut<Disconnections>| {
+            disconnections.0.push(disconnected.port);
+        },
+    );
+    let port = spawn_port(&mut app, CablePort::new(JACK));
+    let (cable, end) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(end)
+        .insert(CableConnector::new(JACK));
+    app.world_mut()
+        .get_mut::<CableEndpoint>(end)
+        .unwrap()
+        .detach_policy = DetachPolicy::Despawn;
+    app.update();
+    drop_end(&mut app, end, PORT_SOCKET);
+    assert!(app.world().get::<AttachedTo>(end).is_some());
+
+    let target = Vec3::new(1.0, 2.0, 0.0);
+    app.world_mut()
+        .entity_mut(end)
+        .insert(DraggedCableEnd::new(target));
+    app.update();
+
+    assert!(app.world().get::<AttachedTo>(end).is_none());
+    assert!(
+        app.world().get_entity(cable).is_ok(),
+        "unplugging by hand should not despawn a `DetachPolicy::Despawn` cable"
+    );
+    assert_eq!(app.world().resource::<Disconnections>().0, vec![port]);
+    let offset = app.world().get::<CableEndpoint>(end).unwrap().offset;
+    assert!(
+        offset.distance(target) < 1e-4,
+        "the unplugged end should follow the drag, got {offset}"
+    );
+}
+
+/// A window-sized pointer location at `position`.
+const fn pointer_location(position: Vec2) -> Location {
+    Location {
+        target: NormalizedRenderTarget::None {
+            width:  800,
+            height: 600,
+        },
+        position,
+    }
+}
+
+#[test]
+fn pointer_drag_grabs_the_nearest_end_snaps_it_to_a_port_and_connects_on_release() {
+    let mut app = build_test_app();
+    let port = spawn_port(&mut app, CablePort::new(JACK));
+    let (cable, end) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(end)
+        .insert(CableConnector::new(JACK));
+
+    // A camera drawing to the right half of an 800×600 window, looking down
+    // -Z at the cable, so drag planes are `z = 0`.
+    let mut camera = Camera {
+        viewport: Some(Viewport {
+            physical_position: UVec2::new(400, 0),
+            physical_size: UVec2::new(400, 600),
+            ..default()
+        }),
+        ..default()
+    };
+    camera.computed.target_info = Some(RenderTargetInfo {
+        physical_size: UVec2::new(800, 600),
+        scale_factor:  1.0,
+    });
+    camera.computed.clip_from_view =
+        Mat4::perspective_infinite_reverse_rh(std::f32::consts::FRAC_PI_4, 400.0 / 600.0, 0.1);
+    let camera = app
+        .world_mut()
+        .spawn((camera, Transform::from_xyz(1.5, 1.0, 10.0)))
+        .id();
+    app.update();
+
+    let drag_start = |app: &mut App, hit: Vec3| {
+        app.world_mut().trigger(Pointer::new(
+            PointerId::Mouse,
+            pointer_location(Vec2::ZERO),
+            DragStart {
+                button: PointerButton::Primary,
+                hit:    HitData::new(camera, 0.0, Some(hit), None),
+            },
+            cable,
+        ));
+        app.update();
+    };
+
+    drag_start(&mut app, Vec3::new(-1.5, 1.0, 0.0));
+    assert!(
+        app.world().get::<DraggedCableEnd>(end).is_none(),
+        "a hit beyond the grab radius of every connector end should grab nothing"
+    );
+
+    drag_start(&mut app, Vec3::new(0.1, 1.0, 0.0));
+    assert!(
+        app.world().get::<DraggedCableEnd>(end).is_some(),
+        "a hit within the grab radius should grab the connector end"
+    );
+
+    // The socket's window position: its viewport position shifted by the
+    // viewport's offset in the window.
+    let camera_transform = *app.world().get::<GlobalTransform>(camera).unwrap();
+    let camera_component = app.world().get::<Camera>(camera).unwrap();
+    let socket = camera_component
+        .world_to_viewport(&camera_transform, PORT_SOCKET)
+        .unwrap()
+        + camera_component.logical_viewport_rect().unwrap().min;
+    app.world_mut().trigger(Pointer::new(
+        PointerId::Mouse,
+        pointer_location(socket),
+        Drag {
+            button:   PointerButton::Primary,
+            distance: socket,
+            delta:    socket,
+        },
+        cable,
+    ));
+    app.update();
+
+    assert_eq!(
+        app.world()
+            .get::<DraggedCableEnd>(end)
+            .unwrap()
+            .snapped_port,
+        Some(port),
+        "dragging the pointer over the socket should snap the end into the port"
+    );
+
+    app.world_mut().trigger(Pointer::new(
+        PointerId::Mouse,
+        pointer_location(socket),
+        DragEnd {
+            button:   PointerButton::Primary,
+            distance: socket,
+        },
+        cable,
+    ));
+    app.update();
+
+    assert!(app.world().get::<DraggedCableEnd>(end).is_none());
+    assert_eq!(
+        app.world()
+            .get::<AttachedTo>(end)
+            .map(|attached| attached.0),
+        Some(port),
+        "releasing the drag should connect the end to the port it snapped to"
+    );
+}
+
+fn lod_band(app: &App, cable: Entity) -> usize {
+    app.world().get::<CableMeshLodBand>(cable).unwrap().0
+}
+
+fn mesh_vertex_count(app: &App, cable: Entity) -> usize {
+    let handle = &app.world().get::<CableMeshHandle>(cable).unwrap().0;
+    app.world()
+        .resource::<Assets<Mesh>>()
+        .get(handle)
+        .unwrap()
+        .count_vertices()
+}
+
+/// Move the camera to `z` and let its `GlobalTransform` catch up before the
+/// level of detail reads it.
+fn move_camera(app: &mut App, camera: Entity, z: f32) {
+    app.world_mut()
+        .get_mut::<Transform>(camera)
+        .unwrap()
+        .translation
+        .z = z;
+    app.update();
+    app.update();
+}
+
+#[test]
+fn mesh_lod_coarsens_distant_cables_past_band_edges_with_hysteresis() {
+    let mut app = build_test_app();
+    let cable = spawn_world_cable(
+        &mut app,
+        Vec3::new(-1.0, 1.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+    );
+    app.world_mut()
+        .entity_mut(cable)
+        .insert(CableMeshLod::default());
+    // The default 45 degree field of view sees the 0.12 m tube as
+    // 0.145 / distance of the viewport's height, so the default bands' edges
+    // sit about 7.2 m and 29 m away.
+    let camera = app
+        .world_mut()
+        .spawn((
+            Camera::default(),
+            Projection::default(),
+            Transform::from_xyz(0.0, 1.0, 2.0),
+        ))
+        .id();
+    app.update();
+    app.update();
+    assert_eq!(lod_band(&app, cable), 0);
+    let full_detail = mesh_vertex_count(&app, cable);
+
+    move_camera(&mut app, camera, 8.0);
+    assert_eq!(
+        lod_band(&app, cable),
+        0,
+        "just past the edge, hysteresis should hold full detail"
+    );
+
+    move_camera(&mut app, camera, 10.0);
+    assert_eq!(lod_band(&app, cable), 1);
+    let medium_detail = mesh_vertex_count(&app, cable);
+    assert!(medium_detail < full_detail);
+
+    move_camera(&mut app, camera, 7.0);
+    assert_eq!(
+        lod_band(&app, cable),
+        1,
+        "just inside the edge, hysteresis should hold the medium band"
+    );
+
+    move_camera(&mut app, camera, 50.0);
+    assert_eq!(lod_band(&app, cable), 2);
+    assert!(mesh_vertex_count(&app, cable) < medium_detail);
+}
+
+#[test]
+fn cable_network_round_trips_through_ron_and_reattaches_by_name() {
+    let mut app = build_test_app();
+    let socket = app
+        .world_mut()
+        .spawn((Name::new("socket"), Transform::from_xyz(3.0, 2.0, 0.0)))
+        .id();
+    let cable = app
+        .world_mut()
+        .spawn((
+            Name::new("patch"),
+            Cable {
+                solver:     Solver::Linear,
+                obstacles:  vec![],
+                resolution: 0,
+            },
+        ))
+        .id();
+    app.world_mut().spawn((
+        CableEndpoint::new(CableEnd::Start, Vec3::new(-3.0, 2.0, 0.0)),
+        ChildOf(cable),
+    ));
+    app.world_mut().spawn((
+        CableEndpoint::new(CableEnd::End, Vec3::ZERO),
+        AttachedTo(socket),
+        ChildOf(cable),
+    ));
+    app.world_mut().spawn((
+        CableWaypoint::new(0, Vec3::new(0.0, 3.0, 0.0)),
+        ChildOf(cable),
+    ));
+    app.update();
+    app.update();
+
+    let saved = CableNetwork::capture(app.world_mut()).to_ron().unwrap();
+
+    // The socket has moved since the save: the end should follow it by name.
+    let mut restored_app = build_test_app();
+    let moved_socket = Vec3::new(3.0, 0.0, 0.0);
+    restored_app.world_mut().spawn((
+        Name::new("socket"),
+        Transform::from_translation(moved_socket),
+    ));
+    let restored = CableNetwork::from_ron(&saved)
+        .unwrap()
+        .restore(restored_app.world_mut());
+    restored_app.update();
+    restored_app.update();
+
+    assert_eq!(restored.len(), 1);
+    assert_eq!(
+        restored_app
+            .world()
+            .get::<Name>(restored[0])
+            .unwrap()
+            .as_str(),
+        "patch"
+    );
+    assert_eq!(
+        routed_waypoints(&restored_app, restored[0]),
+        vec![
+            Vec3::new(-3.0, 2.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.0),
+            moved_socket
+        ],
+        "the restored cable should route through its waypoint to the named socket"
+    );
+}
+
+#[test]
+fn cable_network_round_trips_cable_settings_and_connectors() {
+    let mut app = build_test_app();
+    let port = app
+        .world_mut()
+        .spawn((
+            Name::new("jack"),
+            CablePort::new(JACK),
+            Transform::from_translation(PORT_SOCKET),
+        ))
+        .id();
+    let (cable, end) = spawn_cable(
+        &mut app,
+        SLACK_CATENARY,
+        CONNECTOR_CABLE_START,
+        CONNECTOR_CABLE_END,
+    );
+    app.world_mut()
+        .entity_mut(end)
+        .insert(CableConnector::new(JACK));
+    app.world_mut().entity_mut(cable).insert((
+        CableMeshLod {
+            hysteresis: 0.4,
+            ..default()
+        },
+        OverStretchPolicy::ClampEndpoint,
+        RouteAnimation { seconds: 0.75 },
+        BackgroundSolve,
+        CableBundle(7),
+    ));
+    app.update();
+    drop_end(&mut app, end, PORT_SOCKET);
+    assert_eq!(
+        app.world()
+            .get::<AttachedTo>(end)
+            .map(|attached| attached.0),
+        Some(port)
+    );
+
+    let saved = CableNetwork::capture(app.world_mut()).to_ron().unwrap();
+
+    let mut restored_app = build_test_app();
+    let restored_port = restored_app
+        .world_mut()
+        .spawn((
+            Name::new("jack"),
+            CablePort::new(JACK),
+            Transform::from_translation(PORT_SOCKET),
+        ))
+        .id();
+    let restored = CableNetwork::from_ron(&saved)
+        .unwrap()
+        .restore(restored_app.world_mut());
+    restored_app.update();
+
+    let world = restored_app.world();
+    assert_eq!(restored.len(), 1);
+    let cable = restored[0];
+    assert!((world.get::<CableMeshLod>(cable).unwrap().hysteresis - 0.4).abs() < f32::EPSILON);
+    assert_eq!(
+        world.get::<OverStretchPolicy>(cable),
+        Some(&OverStretchPolicy::ClampEndpoint)
+    );
+    assert!((world.get::<RouteAnimation>(cable).unwrap().seconds - 0.75).abs() < f32::EPSILON);
+    assert!(world.get::<BackgroundSolve>(cable).is_some());
+    assert!(world.get::<CableAvoidance>(cable).is_some());
+    assert_eq!(world.get::<CableBundle>(cable), Some(&CableBundle(7)));
+
+    let connected: Vec<_> = world
+        .get::<Children>(cable)
+        .unwrap()
+        .iter()
+        .filter_map(|child| {
+            let connector = world.get::<CableConnector>(child)?;
+            Some((connector.kind.clone(), world.get::<AttachedTo>(child)?.0))
+        })
+        .collect();
+    assert_eq!(
+        connected,
+        vec![(JACK, restored_port)],
+        "the connector end should be restored plugged into the named port"
+    );
+}
+
+#[test]
+fn cable_network_reads_documents_written_with_struct_names() {
+    let mut app = build_test_app();
+    spawn_world_cable(
+        &mut app,
+        Vec3::new(-1.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+    );
+    let saved = CableNetwork::capture(app.world_mut()).to_ron().unwrap();
+    let named = format!("CableNetwork{saved}");
+
+    let network = CableNetwork::from_ron(&named).unwrap();
+    assert_eq!(network.cables.len(), 1);
+}
+
+#[test]
+fn cable_network_rejects_unsupported_versions() {
+    let mut network = CableNetwork::capture(build_test_app().world_mut());
+    network.version += 1;
+    let saved = network.to_ron().unwrap();
+
+    assert!(matches!(
+        CableNetwork::from_ron(&saved),
+        Err(CableNetworkError::UnsupportedVersion { .. })
+    ));
+}
+
+#[test]
+fn cable_ray_cast_hits_the_nearest_cable_at_its_arc_length() {
+    let mut app = build_test_app();
+    let (near, _) = spawn_cable(
+        &mut app,
+        Solver::Linear,
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(4.0, 1.0, 0.0),
+    );
+    let (far, _) = spawn_cable(
+        &mut app,
+        Solver::Linear,
+        Vec3::new(0.0, 1.0, -2.0),
+        Vec3::new(4.0, 1.0, -2.0),
+    );
+    app.update();
+    let ray = Ray3d::new(Vec3::new(1.5, 1.0, 5.0), Dir3::NEG_Z);
+
+    let (cable, hit) = app
+        .world_mut()
+        .run_system_once(move |cable_ray_cast: CableRayCast| {
+            cable_ray_cast.cast_ray(ray, 0.0, |_| true)
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(cable, near);
+    assert!((hit.arc_length - 1.5).abs() < 1e-3);
+    assert!((hit.point - Vec3::new(1.5, 1.0, 0.0)).length() < 1e-3);
+
+    let (cable, _) = app
+        .world_mut()
+        .run_system_once(move |cable_ray_cast: CableRayCast| {
+            cable_ray_cast.cast_ray(ray, 0.0, |cable| cable != near)
+        })
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        cable, far,
+        "a filtered-out cable should let the ray through"
+    );
+}
+
+#[test]
+fn cable_picking_reports_hoverable_cables_and_keeps_the_topmost_cameras_hit() {
+    #[derive(Resource, Default)]
+    struct CableHits(Vec<CablePointerHit>);
+
+    let mut app = build_test_app();
+    app.init_resource::<CableHits>();
+    app.add_observer(|hit: On<CablePointerHit>, mut hits: ResMut<CableHits>| {
+        hits.0.push(*hit);
+    });
+    // Bevy's `PickingPlugin` would rebuild the ray map every frame; the test
+    // supplies the rays itself.
+    app.add_message::<PointerHits>();
+    app.init_resource::<RayMap>();
+    let (cable, _) = spawn_cable(
+        &mut app,
+        Solver::Linear,
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(4.0, 1.0, 0.0),
+    );
+    let (hidden, _) = spawn_cable(
+        &mut app,
+        Solver::Linear,
+        Vec3::new(0.0, 1.0, 1.0),
+        Vec3::new(4.0, 1.0, 1.0),
+    );
+    app.world_mut().entity_mut(hidden).insert(Pickable {
+        should_block_lower: true,
+        is_hoverable:       false,
+    });
+    // The backend runs in `PreUpdate`, so route the cables a frame ahead.
+    app.update();
+    let lower = app
+        .world_mut()
+        .spawn(Camera {
+            order: 0,
+            ..default()
+        })
+        .id();
+    let upper = app
+        .world_mut()
+        .spawn(Camera {
+            order: 1,
+            ..default()
+        })
+        .id();
+    // Each camera sees the mouse over a different spot on both cables, with
+    // the non-hoverable cable nearer.
+    let mut ray_map = app.world_mut().resource_mut::<RayMap>();
+    ray_map.map.insert(
+        RayId::new(lower, PointerId::Mouse),
+        Ray3d::new(Vec3::new(1.0, 1.0, 5.0), Dir3::NEG_Z),
+    );
+    ray_map.map.insert(
+        RayId::new(upper, PointerId::Mouse),
+        Ray3d::new(Vec3::new(3.0, 1.0, 5.0), Dir3::NEG_Z),
+    );
+    app.update();
+
+    let mut pointer_hits: Vec<&PointerHits> = app
+        .world()
+        .resource::<Messages<PointerHits>>()
+        .iter_current_update_messages()
+        .collect();
+    pointer_hits.sort_by(|a, b| a.order.total_cmp(&b.order));
+    assert_eq!(
+        pointer_hits.len(),
+        2,
+        "each camera's ray should report a hit"
+    );
+    for (pointer_hit, camera) in pointer_hits.iter().zip([lower, upper]) {
+        assert_eq!(pointer_hit.pointer, PointerId::Mouse);
+        assert_eq!(pointer_hit.picks.len(), 1);
+        let (picked, hit_data) = &pointer_hit.picks[0];
+        assert_eq!(
+            *picked, cable,
+            "a cable whose `Pickable` is not hoverable should be skipped"
+        );
+        assert_eq!(hit_data.camera, camera);
+        assert!(hit_data.depth < 5.0);
+    }
+    assert!(pointer_hits[0].order.abs() < f32::EPSILON);
+    assert!((pointer_hits[1].order - 1.0).abs() < f32::EPSILON);
+
+    let (hit_cable, hit) = app.world().resource::<CablePointerHits>()[&PointerId::Mouse];
+    assert_eq!(hit_cable, cable);
+    assert!(
+        (hit.arc_length - 3.0).abs() < 1e-3,
+        "the pointer's hit should come from the highest-order camera"
+    );
+
+    let hits = &app.world().resource::<CableHits>().0;
+    assert_eq!(hits.len(), 1, "each pointer should trigger one cable hit");
+    assert_eq!(hits[0].entity, cable);
+    assert_eq!(hits[0].pointer_id, PointerId::Mouse);
+    assert!(
+        (hits[0].arc_length - 3.0).abs() < 1e-3,
+        "the triggered hit should match the stored one"
+    );
+}
@@ -0,0 +1,304 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    reason = "tests should panic on unexpected values"
+)]
+//! Tests for `hana_conduit` tube mesh generation.
+
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::math::Vec2;
+use bevy::math::Vec3;
+use bevy::mesh::Mesh;
+use bevy::mesh::MeshVertexAttribute;
+use bevy::mesh::VertexAttributeValues;
+use hana_conduit::CableGeometry;
+use hana_conduit::CableMeshConfig;
+use hana_conduit::CableSegment;
+use hana_conduit::CapConfig;
+use hana_conduit::CapStyle;
+use hana_conduit::CrossSection;
+use hana_conduit::TubeConfig;
+use hana_conduit::TwistConfig;
+use hana_conduit::UvConfig;
+use hana_conduit::generate_tube_mesh;
+
+const TOLERANCE: f32 = 0.001;
+
+const RIBBON_WIDTH: f32 = 0.2;
+const RIBBON_THICKNESS: f32 = 0.02;
+
+/// Mesh config sweeping `cross_section` with its start facing `up`, twisted
+/// by `angle`, and without caps.
+fn uncapped_config(cross_section: CrossSection, up: Vec3, angle: f32) -> CableMeshConfig {
+    CableMeshConfig {
+        tube_config: TubeConfig {
+            cross_section,
+            twist: TwistConfig {
+                up: Some(up),
+                angle,
+            },
+            ..TubeConfig::default()
+        },
+        cap_config: CapConfig {
+            start: CapStyle::None,
+            end:   CapStyle::None,
+        },
+        ..CableMeshConfig::default()
+    }
+}
+
+const fn ribbon() -> CrossSection {
+    CrossSection::Ribbon {
+        width:     RIBBON_WIDTH,
+        thickness: RIBBON_THICKNESS,
+    }
+}
+
+fn geometry_through(points: Vec<Vec3>) -> CableGeometry {
+    CableGeometry::from_segments(vec![CableSegment::from(points.clone())], points)
+}
+
+fn uvs(mesh: &Mesh) -> Vec<[f32; 2]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(VertexAttributeValues::Float32x2(values)) => values.clone(),
+        _ => Vec::new(),
+    }
+}
+
+fn vertex_attribute(mesh: &Mesh, id: MeshVertexAttribute) -> Vec<Vec3> {
+    mesh.attribute(id)
+        .and_then(|values| values.as_float3())
+        .expect("mesh should have a float3 attribute")
+        .iter()
+        .map(|&value| Vec3::from(value))
+        .collect()
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Cross-sections
+// ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn ribbon_stays_flat_through_an_elbow_in_its_plane() {
+    let geometry = geometry_through(vec![
+        Vec3::ZERO,
+        Vec3::new(0.0, 0.0, 2.0),
+        Vec3::new(2.0, 0.0, 2.0),
+    ]);
+    let mesh = generate_tube_mesh(&geometry, &uncapped_config(ribbon(), Vec3::Y, 0.0));
+    let positions = vertex_attribute(&mesh, Mesh::ATTRIBUTE_POSITION);
+
+    assert!(!positions.is_empty(), "ribbon mesh should have vertices");
+    for position in &positions {
+        assert!(
+            position.y.abs() <= RIBBON_THICKNESS / 2.0 + TOLERANCE,
+            "ribbon should lie flat through the elbow, got vertex at {position}"
+        );
+    }
+    assert!(
+        positions.iter().any(|position| position.z.abs() < TOLERANCE
+            && (position.x.abs() - RIBBON_WIDTH / 2.0).abs() < TOLERANCE),
+        "the start ring should span the ribbon's full width"
+    );
+}
+
+#[test]
+fn twist_turns_the_profile_by_its_angle_along_the_cable() {
+    let end = Vec3::new(0.0, 0.0, 2.0);
+    let geometry = CableGeometry::from_segments(
+        vec![CableSegment::straight_line(Vec3::ZERO, end, 9)],
+        vec![Vec3::ZERO, end],
+    );
+    let mesh = generate_tube_mesh(&geometry, &uncapped_config(ribbon(), Vec3::Y, FRAC_PI_2));
+    let positions = vertex_attribute(&mesh, Mesh::ATTRIBUTE_POSITION);
+
+    let start_ring: Vec<&Vec3> = positions
+        .iter()
+        .filter(|position| position.z.abs() < TOLERANCE)
+        .collect();
+    let end_ring: Vec<&Vec3> = positions
+        .iter()
+        .filter(|position| (position.z - end.z).abs() < TOLERANCE)
+        .collect();
+    assert!(!start_ring.is_empty() && !end_ring.is_empty());
+    assert!(
+        start_ring
+            .iter()
+            .all(|position| position.y.abs() <= RIBBON_THICKNESS / 2.0 + TOLERANCE),
+        "the ribbon should start flat"
+    );
+    assert!(
+        end_ring
+            .iter()
+            .all(|position| position.x.abs() <= RIBBON_THICKNESS / 2.0 + TOLERANCE),
+        "a quarter turn of twist should stand the ribbon on edge at the end"
+    );
+}
+
+#[test]
+fn custom_profile_wound_clockwise_faces_outward_with_creased_corners() {
+    let half = 0.05;
+    let clockwise_square = vec![
+        Vec2::new(-half, -half),
+        Vec2::new(-half, half),
+        Vec2::new(half, half),
+        Vec2::new(half, -half),
+    ];
+    let end = Vec3::new(0.0, 0.0, 1.0);
+    let geometry = CableGeometry::from_segments(
+        vec![CableSegment::straight_line(Vec3::ZERO, end, 2)],
+        vec![Vec3::ZERO, end],
+    );
+    let mesh = generate_tube_mesh(
+        &geometry,
+        &uncapped_config(CrossSection::Custom(clockwise_square), Vec3::Y, 0.0),
+    );
+    let positions = vertex_attribute(&mesh, Mesh::ATTRIBUTE_POSITION);
+    let normals = vertex_attribute(&mesh, Mesh::ATTRIBUTE_NORMAL);
+
+    assert_eq!(
+        positions.len(),
+        16,
+        "each of the two rings should split its four corners into two vertices"
+    );
+    for (position, normal) in positions.iter().zip(&normals) {
+        let radial = Vec3::new(position.x, position.y, 0.0);
+        assert!(
+            radial.dot(*normal) > 0.0,
+            "normal {normal} at {position} should face away from the path"
+        );
+        assert!(
+            normal.x.abs() < TOLERANCE || normal.y.abs() < TOLERANCE,
+            "creased corners should keep flat side normals, got {normal}"
+        );
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// UV mapping
+// ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn uv_v_follows_world_arc_length_through_an_elbow() {
+    let corner = Vec3::new(0.0, 0.0, 2.0);
+    let geometry = geometry_through(vec![Vec3::ZERO, corner, Vec3::new(2.0, 0.0, 2.0)]);
+    let cable_mesh_config = uncapped_config(CrossSection::Circle, Vec3::Y, 0.0);
+    let bend_radius = cable_mesh_config.elbow_bend_radius();
+    let mesh = generate_tube_mesh(&geometry, &cable_mesh_config);
+    let positions = vertex_attribute(&mesh, Mesh::ATTRIBUTE_POSITION);
+    let uvs = uvs(&mesh);
+    assert_eq!(positions.len(), uvs.len());
+
+    // Before the elbow, V is the distance travelled along Z.
+    for (position, uv) in positions.iter().zip(&uvs) {
+        if position.z < corner.z - bend_radius * 2.0 {
+            assert!(
+                (uv[1] - position.z).abs() < TOLERANCE,
+                "V should equal the distance along the first run, got {} at {position}",
+                uv[1]
+            );
+        }
+    }
+
+    // After it, V grows one-for-one with X, offset by the filleted corner:
+    // a quarter turn of the bend radius replaces two set-backs of the corner.
+    let expected_offset = (FRAC_PI_2 - 2.0).mul_add(bend_radius, corner.z);
+    let mut checked = 0;
+    for (position, uv) in positions.iter().zip(&uvs) {
+        if position.x > bend_radius * 2.0 {
+            checked += 1;
+            assert!(
+                (uv[1] - position.x - expected_offset).abs() < 0.01,
+                "V should keep pace with X after the elbow, got {} at {position}",
+                uv[1]
+            );
+        }
+    }
+    assert!(checked > 0, "the second run should have vertices");
+}
+
+#[test]
+fn uv_v_continues_over_round_caps_at_the_tube_rate() {
+    let end = Vec3::new(1.0, 0.0, 0.0);
+    let geometry = CableGeometry::from_segments(
+        vec![CableSegment::straight_line(Vec3::ZERO, end, 5)],
+        vec![Vec3::ZERO, end],
+    );
+    let repeat_length = 0.5;
+    let cable_mesh_config = CableMeshConfig {
+        uv_config: UvConfig {
+            repeat_length,
+            ..UvConfig::default()
+        },
+        ..CableMeshConfig::default()
+    };
+    let radius = cable_mesh_config.tube_config.radius;
+    let mesh = generate_tube_mesh(&geometry, &cable_mesh_config);
+    let positions = vertex_attribute(&mesh, Mesh::ATTRIBUTE_POSITION);
+    let uvs = uvs(&mesh);
+
+    let pole_v = |pick: fn(f32, f32) -> bool| {
+        positions
+            .iter()
+            .zip(&uvs)
+            .reduce(|best, candidate| {
+                if pick(candidate.0.x, best.0.x) {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .map(|(_, uv)| uv[1])
+            .unwrap()
+    };
+    // Each pole sits a quarter great circle over the cap from its ring.
+    let cap_meridian = FRAC_PI_2 * radius;
+    let end_pole = pole_v(|candidate, best| candidate > best);
+    let start_pole = pole_v(|candidate, best| candidate < best);
+    assert!(
+        (end_pole - (end.x + cap_meridian) / repeat_length).abs() < TOLERANCE,
+        "end pole V should continue past the tube's end, got {end_pole}"
+    );
+    assert!(
+        (start_pole + cap_meridian / repeat_length).abs() < TOLERANCE,
+        "start pole V should run back before the tube's start, got {start_pole}"
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Level of detail
+// ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn path_density_places_rings_at_a_fraction_of_the_samples() {
+    let end = Vec3::new(0.0, 0.0, 1.0);
+    let geometry = CableGeometry::from_segments(
+        vec![CableSegment::straight_line(Vec3::ZERO, end, 9)],
+        vec![Vec3::ZERO, end],
+    );
+    let mut cable_mesh_config = uncapped_config(CrossSection::Circle, Vec3::Y, 0.0);
+    let sides = usize::try_from(cable_mesh_config.tube_config.sides).unwrap();
+    let full = vertex_attribute(
+        &generate_tube_mesh(&geometry, &cable_mesh_config),
+        Mesh::ATTRIBUTE_POSITION,
+    );
+    cable_mesh_config.tube_config.path_density = 0.5;
+    let halved = vertex_attribute(
+        &generate_tube_mesh(&geometry, &cable_mesh_config),
+        Mesh::ATTRIBUTE_POSITION,
+    );
+
+    assert_eq!(full.len(), 9 * sides);
+    assert_eq!(
+        halved.len(),
+        5 * sides,
+        "half density should keep every other sample, including both ends"
+    );
+    assert!(
+        halved
+            .iter()
+            .any(|position| (position.z - end.z).abs() < TOLERANCE),
+        "the last sample should always keep its ring"
+    );
+}
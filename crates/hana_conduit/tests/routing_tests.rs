@@ -6,25 +6,39 @@
 //! Comprehensive tests for the `hana_conduit` routing module.
 
 use bevy::math::Dir3;
+use bevy::math::Quat;
+use bevy::math::Ray3d;
 use bevy::math::Vec3;
 use bevy_kana::ToF32;
 use bevy_kana::ToUsize;
 use hana_conduit::AStarPlanner;
 use hana_conduit::Anchor;
 use hana_conduit::AnchorExit;
+use hana_conduit::CableGeometry;
 use hana_conduit::CableSegment;
+use hana_conduit::CableSimulation;
 use hana_conduit::CatenarySolver;
+use hana_conduit::CurveKind;
 use hana_conduit::CurveSolver;
 use hana_conduit::DirectPlanner;
+use hana_conduit::LayoutCable;
 use hana_conduit::LinearSolver;
 use hana_conduit::Obstacle;
+use hana_conduit::ObstacleIndex;
 use hana_conduit::OrthogonalPlanner;
 use hana_conduit::PathPlanner;
+use hana_conduit::PathStrategy;
+use hana_conduit::RouteOutcome;
 use hana_conduit::RouteRequest;
 use hana_conduit::RouteSolver;
 use hana_conduit::Router;
+use hana_conduit::SimulatedSolver;
 use hana_conduit::Solver;
+use hana_conduit::SplineSolver;
+use hana_conduit::SupportSurface;
+use hana_conduit::ViaPoint;
 use hana_conduit::evaluate;
+use hana_conduit::layout_cables;
 use hana_conduit::sample_3d;
 use hana_conduit::solve_parameter;
 
@@ -341,6 +355,57 @@ fn cable_segment_from_points_empty() {
     );
 }
 
+// ─────────────────────────────────────────────────────────────────────
+// `CableGeometry::cast_ray`
+// ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn cable_geometry_cast_ray_reports_arc_length_across_segments() {
+    let geometry = CableGeometry::from_segments(
+        vec![
+            CableSegment::straight_line(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0), 3),
+            CableSegment::straight_line(Vec3::new(2.0, 0.0, 0.0), Vec3::new(4.0, 0.0, 0.0), 3),
+        ],
+        vec![Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0)],
+    );
+    let ray = Ray3d::new(Vec3::new(3.0, 5.0, 0.0), Dir3::NEG_Y);
+
+    let hit = geometry.cast_ray(ray, 0.1).unwrap();
+
+    assert!((hit.distance - 4.9).abs() < TOLERANCE);
+    assert_vec3_approx(hit.position, Vec3::new(3.0, 0.1, 0.0), "hit position");
+    assert_vec3_approx(hit.point, Vec3::new(3.0, 0.0, 0.0), "nearest cable point");
+    assert!(
+        (hit.arc_length - 3.0).abs() < TOLERANCE,
+        "arc length should count the first segment, got {}",
+        hit.arc_length
+    );
+    assert_vec3_approx(hit.normal().unwrap(), Vec3::Y, "hit normal");
+}
+
+#[test]
+fn cable_geometry_cast_ray_hits_end_caps_and_misses_beside_the_tube() {
+    let geometry = CableGeometry::from_segments(
+        vec![CableSegment::straight_line(
+            Vec3::ZERO,
+            Vec3::new(2.0, 0.0, 0.0),
+            3,
+        )],
+        vec![Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0)],
+    );
+
+    let along_axis = Ray3d::new(Vec3::new(-5.0, 0.0, 0.0), Dir3::X);
+    let cap_hit = geometry.cast_ray(along_axis, 0.1).unwrap();
+    assert!(
+        (cap_hit.distance - 4.9).abs() < TOLERANCE,
+        "a ray along the axis should enter through the start cap"
+    );
+    assert!(cap_hit.arc_length.abs() < TOLERANCE);
+
+    let beside = Ray3d::new(Vec3::new(1.0, 5.0, 0.2), Dir3::NEG_Y);
+    assert!(geometry.cast_ray(beside, 0.1).is_none());
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // Solvers: `DirectPlanner`
 // ─────────────────────────────────────────────────────────────────────
@@ -409,12 +474,11 @@ fn linear_solver_produces_straight_line() {
 #[test]
 fn linear_solver_as_route_solver() {
     let solver = LinearSolver;
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        Vec3::new(3.0, 4.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 10,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 0.0, 0.0).into(),
+        Vec3::new(3.0, 4.0, 0.0).into(),
+        10,
+    );
 
     let geometry = solver.solve(&request);
     assert_eq!(geometry.segments.len(), 1);
@@ -436,12 +500,11 @@ fn linear_solver_as_route_solver() {
 #[test]
 fn router_composes_planner_and_curve_solver() {
     let router = Router::new(DirectPlanner, LinearSolver);
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        Vec3::new(5.0, 0.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 20,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 0.0, 0.0).into(),
+        Vec3::new(5.0, 0.0, 0.0).into(),
+        20,
+    );
 
     let geometry = router.solve(&request);
 
@@ -454,12 +517,11 @@ fn router_composes_planner_and_curve_solver() {
 #[test]
 fn router_with_catenary_solver() {
     let router = Router::new(DirectPlanner, CatenarySolver::new().with_slack(1.3));
-    let request = RouteRequest {
-        start:      Vec3::new(-3.0, 2.0, 0.0).into(),
-        end:        Vec3::new(3.0, 2.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 32,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(-3.0, 2.0, 0.0).into(),
+        Vec3::new(3.0, 2.0, 0.0).into(),
+        32,
+    );
 
     let geometry = router.solve(&request);
 
@@ -478,12 +540,7 @@ fn router_with_catenary_solver() {
 #[test]
 fn router_with_custom_resolution() {
     let router = Router::new(DirectPlanner, LinearSolver).with_resolution(64);
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        (Vec3::X * 5.0).into(),
-        obstacles:  &[],
-        resolution: 0, // use router's default
-    };
+    let request = RouteRequest::new(Vec3::new(0.0, 0.0, 0.0).into(), (Vec3::X * 5.0).into(), 0);
 
     let geometry = router.solve(&request);
     assert_eq!(
@@ -513,12 +570,11 @@ fn catenary_solver_implements_curve_solver() {
 #[test]
 fn catenary_solver_implements_route_solver() {
     let solver = CatenarySolver::new().with_slack(1.3);
-    let request = RouteRequest {
-        start:      Vec3::new(-3.0, 5.0, 0.0).into(),
-        end:        Vec3::new(3.0, 5.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 24,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(-3.0, 5.0, 0.0).into(),
+        Vec3::new(3.0, 5.0, 0.0).into(),
+        24,
+    );
 
     let geometry = solver.solve(&request);
 
@@ -552,6 +608,33 @@ fn catenary_solver_custom_gravity() {
     );
 }
 
+#[test]
+fn catenary_with_absolute_length_spends_it_across_leads_and_span() {
+    let solver = Solver::Catenary(CatenarySolver::new().with_length(5.0));
+    let start = Anchor {
+        position: Vec3::ZERO,
+        exit:     AnchorExit::Lead {
+            direction: Dir3::X,
+            length:    0.5,
+        },
+    };
+    let request = |end: Vec3| RouteRequest::new(start, end.into(), 32);
+
+    let hanging = solver.solve(&request(Vec3::new(4.0, 0.0, 0.0)));
+    let stretched = solver.solve(&request(Vec3::new(6.0, 0.0, 0.0)));
+
+    assert_eq!(solver.length(), Some(5.0));
+    assert!(
+        (hanging.total_length - 5.0).abs() < 0.05,
+        "lead plus sagging span should add up to the cable length, got {}",
+        hanging.total_length
+    );
+    assert!(
+        (stretched.total_length - stretched.waypoint_length()).abs() < 1e-3,
+        "endpoints beyond the cable's length pull it straight"
+    );
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // A* Pathfinding
 // ─────────────────────────────────────────────────────────────────────
@@ -693,6 +776,108 @@ fn astar_falls_back_to_direct_when_path_not_found() {
     assert_vec3_approx(waypoints[1], end, "fallback end");
 }
 
+#[test]
+fn astar_routes_long_span_around_wall_through_coarse_corridor() {
+    let planner = AStarPlanner::new().with_grid_size(0.25).with_margin(0.2);
+    let start = Vec3::ZERO;
+    let end = Vec3::new(20.0, 0.0, 0.0);
+    // Eighty fine cells apart, so the search runs coarse-to-fine.
+    let half_extents = Vec3::new(0.5, 3.0, 3.0);
+    let center = Vec3::new(10.0, 0.0, 0.0);
+    let wall = Obstacle::new(half_extents, center);
+
+    let waypoints = planner.plan(start, end, &[wall]);
+
+    assert!(
+        waypoints.len() > 2,
+        "should route around the wall, got {} waypoints",
+        waypoints.len()
+    );
+    assert_vec3_approx(waypoints[0], start, "hierarchical path start");
+    assert_vec3_approx(*waypoints.last().unwrap(), end, "hierarchical path end");
+    for pair in waypoints.windows(2) {
+        for step in 0..=20 {
+            let point = pair[0].lerp(pair[1], step.to_f32() / 20.0);
+            let offset = (point - center).abs();
+            assert!(
+                !offset.cmplt(half_extents).all(),
+                "route sample {point} passes through the wall"
+            );
+        }
+    }
+}
+
+#[test]
+fn routed_solve_with_shared_obstacle_index_matches_unindexed_solve() {
+    let obstacles = vec![
+        Obstacle::new(Vec3::splat(1.0), Vec3::new(3.0, 0.0, 0.0)),
+        Obstacle::sphere(0.75, Vec3::new(3.0, 2.5, 0.0)),
+    ];
+    let index = ObstacleIndex::from_obstacles(&obstacles);
+    let solver = Solver::Routed {
+        path_strategy: PathStrategy::AStar {
+            grid_size: 0.5,
+            margin:    0.2,
+        },
+        curve_kind:    CurveKind::Linear,
+        resolution:    0,
+    };
+
+    for end in [Vec3::new(6.0, 0.0, 0.0), Vec3::new(6.0, 0.5, 0.5)] {
+        let request =
+            RouteRequest::new(Vec3::ZERO.into(), end.into(), 0).with_obstacles(&obstacles);
+        assert_eq!(
+            solver
+                .solve(&request.clone().with_obstacle_index(&index))
+                .waypoints,
+            solver.solve(&request).waypoints,
+            "a shared index should plan the same route to {end}"
+        );
+    }
+}
+
+#[test]
+fn astar_reports_search_exhausted_with_explored_cells() {
+    let planner = AStarPlanner {
+        grid_size: 0.5,
+        margin:    0.2,
+        max_cells: 5,
+    };
+    let start = Vec3::ZERO;
+    let end = Vec3::new(6.0, 0.0, 0.0);
+    let obstacles = [Obstacle::new(Vec3::splat(1.0), Vec3::new(3.0, 0.0, 0.0))];
+
+    let (waypoints, outcome) =
+        planner.plan_with_index(start, end, &ObstacleIndex::from_obstacles(&obstacles));
+
+    assert_eq!(waypoints, vec![start, end]);
+    assert_eq!(outcome, RouteOutcome::SearchExhausted { explored: 5 });
+    assert!(outcome.is_fallback());
+}
+
+#[test]
+fn astar_reports_endpoint_pushed_out_of_obstacle() {
+    let planner = AStarPlanner::new().with_grid_size(0.5).with_margin(0.2);
+    let start = Vec3::ZERO;
+    let end = Vec3::new(4.0, 0.0, 0.0);
+    let obstacles = [Obstacle::new(Vec3::splat(0.5), Vec3::ZERO)];
+
+    let (waypoints, outcome) =
+        planner.plan_with_index(start, end, &ObstacleIndex::from_obstacles(&obstacles));
+
+    assert_vec3_approx(waypoints[0], start, "pushed-out path start");
+    // The search begins from a cell outside the obstacle inflated by the
+    // margin.
+    assert!(
+        matches!(
+            outcome,
+            RouteOutcome::EndpointPushedOut { endpoint, clear }
+                if endpoint.distance(start) < TOLERANCE && clear.abs().max_element() > 0.7
+        ),
+        "the start should be pushed out of the obstacle, got {outcome:?}"
+    );
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // Orthogonal routing
 // ─────────────────────────────────────────────────────────────────────
@@ -918,6 +1103,28 @@ fn orthogonal_endpoints_preserved() {
     assert_vec3_approx(*waypoints.last().unwrap(), end, "orthogonal path end");
 }
 
+#[test]
+fn orthogonal_reports_the_axis_order_it_chose() {
+    let planner = OrthogonalPlanner::new();
+    let (_, clear) = planner.plan_with_outcome(Vec3::ZERO, Vec3::new(2.0, 1.0, 3.0), &[]);
+    assert_eq!(
+        clear,
+        RouteOutcome::OrthogonalAxes {
+            axes: [Dir3::X, Dir3::Z, Dir3::Y],
+        }
+    );
+
+    // A box around the end blocks every axis order's last leg.
+    let end = Vec3::new(2.0, 1.0, 3.0);
+    let obstacles = [Obstacle::new(Vec3::splat(0.5), end)];
+    let (_, detour) = planner.plan_with_outcome(Vec3::ZERO, end, &obstacles);
+    assert_eq!(detour, RouteOutcome::OrthogonalDetour);
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Obstacle shapes
+// ─────────────────────────────────────────────────────────────────────
+
 // ─────────────────────────────────────────────────────────────────────
 // `CableGeometry` construction
 // ─────────────────────────────────────────────────────────────────────
@@ -928,12 +1135,11 @@ fn cable_geometry_all_points_iterates_across_segments() {
 
     // Build geometry with multiple segments via an orthogonal planner + linear solver
     let router = Router::new(OrthogonalPlanner::new(), solver);
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        Vec3::new(5.0, 3.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 10,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 0.0, 0.0).into(),
+        Vec3::new(5.0, 3.0, 0.0).into(),
+        10,
+    );
 
     let geometry = router.solve(&request);
 
@@ -950,12 +1156,11 @@ fn cable_geometry_all_points_iterates_across_segments() {
 fn cable_geometry_total_length_is_sum_of_segments() {
     let solver = LinearSolver;
     let router = Router::new(OrthogonalPlanner::new(), solver);
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        Vec3::new(4.0, 3.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 10,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 0.0, 0.0).into(),
+        Vec3::new(4.0, 3.0, 0.0).into(),
+        10,
+    );
 
     let geometry = router.solve(&request);
 
@@ -967,6 +1172,115 @@ fn cable_geometry_total_length_is_sum_of_segments() {
     );
 }
 
+#[test]
+fn astar_passes_beside_rotated_box_inside_its_aabb() {
+    let planner = AStarPlanner::new().with_grid_size(0.5).with_margin(0.1);
+
+    // A thin plank rotated 45 degrees about Y: its axis-aligned bounds cover
+    // the straight route, but the plank itself runs diagonally past it.
+    let obstacles = vec![Obstacle::oriented(
+        Vec3::new(3.0, 0.5, 0.1),
+        Vec3::new(3.0, 0.0, 0.0),
+        Quat::from_rotation_y(std::f32::consts::FRAC_PI_4),
+    )];
+    let start = Vec3::new(2.5, 0.0, 1.5);
+    let end = Vec3::new(4.5, 0.0, 1.5);
+
+    let waypoints = planner.plan(start, end, &obstacles);
+
+    assert_eq!(
+        waypoints.len(),
+        2,
+        "route clear of the rotated plank should stay direct"
+    );
+}
+
+#[test]
+fn planners_route_around_round_obstacles() {
+    let start = Vec3::new(0.0, 0.0, 0.0);
+    let end = Vec3::new(6.0, 0.0, 0.0);
+    let obstacles = vec![
+        Obstacle::sphere(1.0, Vec3::new(2.0, 0.0, 0.0)),
+        Obstacle::capsule(0.5, Vec3::new(4.5, -2.0, 0.0), Vec3::new(4.5, 2.0, 0.0)),
+    ];
+
+    for (label, waypoints) in [
+        (
+            "A*",
+            AStarPlanner::new()
+                .with_grid_size(0.5)
+                .with_margin(0.2)
+                .plan(start, end, &obstacles),
+        ),
+        (
+            "orthogonal",
+            OrthogonalPlanner::new().plan(start, end, &obstacles),
+        ),
+    ] {
+        assert!(
+            waypoints.len() > 2,
+            "{label} should divert around the sphere and capsule, got {} waypoints",
+            waypoints.len()
+        );
+        assert_vec3_approx(waypoints[0], start, label);
+        assert_vec3_approx(*waypoints.last().unwrap(), end, label);
+    }
+}
+
+#[test]
+fn convex_decomposition_leaves_the_gap_of_an_l_shape_open() {
+    // An L-shaped prism in the XY plane: a 4×1 foot along X and a 1×4 arm
+    // along Y, both 1 deep. Its single hull would fill the corner triangle.
+    let outline = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(4.0, 0.0, 0.0),
+        Vec3::new(4.0, 1.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(1.0, 4.0, 0.0),
+        Vec3::new(0.0, 4.0, 0.0),
+    ];
+    let depth = Vec3::new(0.0, 0.0, 1.0);
+    let mut triangles: Vec<[Vec3; 3]> = (1..outline.len() - 1)
+        .flat_map(|index| {
+            [
+                [outline[0], outline[index], outline[index + 1]],
+                [
+                    outline[0] + depth,
+                    outline[index + 1] + depth,
+                    outline[index] + depth,
+                ],
+            ]
+        })
+        .collect();
+    for index in 0..outline.len() {
+        let (a, b) = (outline[index], outline[(index + 1) % outline.len()]);
+        triangles.push([a, b, b + depth]);
+        triangles.push([a, b + depth, a + depth]);
+    }
+    // Inside the L's single hull, but outside both of its arms.
+    let gap = Vec3::new(2.0, 2.0, 0.5);
+    let above_gap = Vec3::new(2.0, 2.0, 3.0);
+    let planner = OrthogonalPlanner::new().with_margin(0.05);
+
+    let single_hull = Obstacle::convex_decomposition(&triangles, 1);
+    let decomposed = Obstacle::convex_decomposition(&triangles, 4);
+
+    assert_eq!(single_hull.len(), 1);
+    assert!(
+        decomposed.len() > 1,
+        "the L should split into several hulls"
+    );
+    assert_eq!(
+        planner.plan(gap, above_gap, &decomposed).len(),
+        2,
+        "the corner gap of the L should be free space once decomposed"
+    );
+    assert!(
+        planner.plan(gap, above_gap, &single_hull).len() > 2,
+        "a single hull closes the corner gap"
+    );
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // `Obstacle` and `Anchor` construction
 // ─────────────────────────────────────────────────────────────────────
@@ -1003,24 +1317,23 @@ fn anchor_constructors() {
 #[test]
 fn solver_routes_between_lead_tips() {
     let solver = Solver::Linear;
-    let request = RouteRequest {
-        start:      Anchor {
+    let request = RouteRequest::new(
+        Anchor {
             position: Vec3::new(0.0, 0.0, 0.0),
             exit:     AnchorExit::Lead {
                 direction: Dir3::Y,
                 length:    1.0,
             },
         },
-        end:        Anchor {
+        Anchor {
             position: Vec3::new(5.0, 0.0, 0.0),
             exit:     AnchorExit::Lead {
                 direction: Dir3::Y,
                 length:    1.0,
             },
         },
-        obstacles:  &[],
-        resolution: 10,
-    };
+        10,
+    );
 
     let geometry = solver.solve(&request);
 
@@ -1062,24 +1375,23 @@ fn solver_ignores_leads_when_tips_collapse() {
     // length leave no routable span; the solver falls back to the bare
     // anchor positions.
     let solver = Solver::Linear;
-    let request = RouteRequest {
-        start:      Anchor {
+    let request = RouteRequest::new(
+        Anchor {
             position: Vec3::new(0.0, 0.0, 0.0),
             exit:     AnchorExit::Lead {
                 direction: Dir3::X,
                 length:    0.5,
             },
         },
-        end:        Anchor {
+        Anchor {
             position: Vec3::new(1.0, 0.0, 0.0),
             exit:     AnchorExit::Lead {
                 direction: Dir3::NEG_X,
                 length:    0.5,
             },
         },
-        obstacles:  &[],
-        resolution: 10,
-    };
+        10,
+    );
 
     let geometry = solver.solve(&request);
 
@@ -1096,6 +1408,501 @@ fn solver_ignores_leads_when_tips_collapse() {
     );
 }
 
+// ─────────────────────────────────────────────────────────────────────
+// Cable simulation
+// ─────────────────────────────────────────────────────────────────────
+
+const SIMULATION_FRAME_SECONDS: f32 = 1.0 / 60.0;
+
+fn polyline_length(points: &[Vec3]) -> f32 {
+    points
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum()
+}
+
+#[test]
+fn simulated_solver_seeds_from_catenary() {
+    let solver = SimulatedSolver::new().with_catenary(CatenarySolver::new().with_slack(1.3));
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 2.0, 0.0).into(),
+        Vec3::new(2.0, 2.0, 0.0).into(),
+        16,
+    );
+
+    let catenary = solver.catenary.solve(&request);
+    let routed = Solver::Simulated(solver.clone()).solve(&request);
+    let simulation = CableSimulation::seed(&solver, &request);
+
+    assert_eq!(routed.segments[0].points, catenary.segments[0].points);
+    assert_eq!(simulation.points(), catenary.segments[0].points.as_slice());
+    assert!(
+        (simulation.length() - catenary.total_length).abs() < 1e-4,
+        "the chain's rest length is the catenary's length"
+    );
+}
+
+#[test]
+fn simulation_swings_after_yank_and_settles_at_fixed_length() {
+    let solver = SimulatedSolver::new().with_catenary(CatenarySolver::new().with_slack(1.3));
+    let start = Vec3::new(0.0, 2.0, 0.0);
+    let mut simulation = CableSimulation::seed(
+        &solver,
+        &RouteRequest::new(start.into(), Vec3::new(2.0, 2.0, 0.0).into(), 16),
+    );
+
+    let yanked_end = Vec3::new(2.4, 2.5, 0.0);
+    let yanked = RouteRequest::new(start.into(), yanked_end.into(), 16);
+    simulation.step(&solver, &yanked, SIMULATION_FRAME_SECONDS);
+
+    assert_vec3_approx(simulation.points()[0], start, "start stays pinned");
+    assert_vec3_approx(
+        *simulation.points().last().unwrap(),
+        yanked_end,
+        "end follows the yank",
+    );
+    assert!(
+        simulation.max_displacement() > 0.0,
+        "the interior should move with the yank"
+    );
+
+    for _ in 0..600 {
+        simulation.step(&solver, &yanked, SIMULATION_FRAME_SECONDS);
+    }
+
+    assert!(
+        simulation.max_displacement() < 1e-3,
+        "damping should bring the chain to rest"
+    );
+    let length = polyline_length(simulation.points());
+    assert!(
+        (length - simulation.length()).abs() < simulation.length() * 0.05,
+        "settled length {length} should stay near the rest length {}",
+        simulation.length()
+    );
+}
+
+#[test]
+fn simulation_drapes_over_obstacles() {
+    let solver = SimulatedSolver::new().with_catenary(CatenarySolver::new().with_slack(1.25));
+    let obstacles = vec![Obstacle::new(
+        Vec3::new(0.5, 0.5, 1.0),
+        Vec3::new(2.0, 0.0, 0.0),
+    )];
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 1.0, 0.0).into(),
+        Vec3::new(4.0, 1.0, 0.0).into(),
+        24,
+    )
+    .with_obstacles(&obstacles);
+    let inside_box =
+        |point: &Vec3| (point.x - 2.0).abs() < 0.5 && point.y.abs() < 0.5 && point.z.abs() < 1.0;
+    let mut simulation = CableSimulation::seed(&solver, &request);
+    assert!(
+        simulation.points().iter().any(inside_box),
+        "the catenary seed should sag through the box"
+    );
+
+    for _ in 0..300 {
+        simulation.step(&solver, &request, SIMULATION_FRAME_SECONDS);
+    }
+
+    assert!(
+        !simulation.points().iter().any(inside_box),
+        "no particle should rest inside the box"
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Spline curves
+// ─────────────────────────────────────────────────────────────────────
+
+fn orthogonal_spline_route(spline: SplineSolver, obstacles: &[Obstacle]) -> Vec<Vec3> {
+    let solver = Solver::Routed {
+        path_strategy: PathStrategy::Orthogonal,
+        curve_kind:    CurveKind::Spline(spline),
+        resolution:    16,
+    };
+    let request = RouteRequest::new(Vec3::ZERO.into(), Vec3::new(4.0, 3.0, 0.0).into(), 16)
+        .with_obstacles(obstacles);
+    solver.solve(&request).all_points().copied().collect()
+}
+
+/// Radius of the circle through three points, or infinity when they are
+/// collinear.
+fn circumradius(a: Vec3, b: Vec3, c: Vec3) -> f32 {
+    let twice_area = (b - a).cross(c - a).length();
+    if twice_area < 1e-9 {
+        return f32::INFINITY;
+    }
+    a.distance(b) * b.distance(c) * c.distance(a) / (2.0 * twice_area)
+}
+
+#[test]
+fn spline_rounds_corners_to_the_minimum_bend_radius() {
+    let min_bend_radius = 0.5;
+    let points = orthogonal_spline_route(
+        SplineSolver::new().with_min_bend_radius(min_bend_radius),
+        &[],
+    );
+    let corner = Vec3::new(4.0, 0.0, 0.0);
+
+    assert_vec3_approx(points[0], Vec3::ZERO, "spline start");
+    assert_vec3_approx(
+        *points.last().unwrap(),
+        Vec3::new(4.0, 3.0, 0.0),
+        "spline end",
+    );
+    assert!(
+        points.iter().all(|point| point.distance(corner) > 0.15),
+        "the spline should round the corner off instead of touching it"
+    );
+    for triple in points.windows(3) {
+        let radius = circumradius(triple[0], triple[1], triple[2]);
+        assert!(
+            radius > min_bend_radius * 0.9,
+            "spline bends through radius {radius} at {}",
+            triple[1]
+        );
+    }
+}
+
+#[test]
+fn spline_pulls_corner_in_where_it_would_cut_an_obstacle() {
+    // Clear of the orthogonal path by more than the planner's margin, but in
+    // the way of a wide corner arc.
+    let obstacle = Obstacle::new(Vec3::new(0.1, 0.1, 0.5), Vec3::new(3.6, 0.45, 0.0));
+    let spline = SplineSolver::new().with_min_bend_radius(2.0);
+
+    let unobstructed = orthogonal_spline_route(spline.clone(), &[]);
+    let inside_box =
+        |point: &&Vec3| (3.5..=3.7).contains(&point.x) && (0.35..=0.55).contains(&point.y);
+    assert!(
+        unobstructed.iter().any(|point| inside_box(&point)),
+        "the wide arc should cut through the box when it is not an obstacle"
+    );
+
+    let points = orthogonal_spline_route(spline, &[obstacle]);
+
+    assert!(
+        !points.iter().any(|point| inside_box(&point)),
+        "the spline should pull its corner in around the obstacle"
+    );
+    assert!(
+        points
+            .iter()
+            .all(|point| point.distance(Vec3::new(4.0, 0.0, 0.0)) > 0.05),
+        "the corner should stay rounded, just tighter"
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Via points
+// ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn router_plans_each_leg_through_via_points() {
+    let router = Router::new(OrthogonalPlanner::new(), LinearSolver);
+    let via = Vec3::new(2.0, 2.0, 0.0);
+    let vias = [ViaPoint::from(via)];
+    let request =
+        RouteRequest::new(Vec3::ZERO.into(), Vec3::new(4.0, 0.0, 0.0).into(), 8).with_vias(&vias);
+
+    let geometry = router.solve(&request);
+
+    assert!(
+        geometry.waypoints.contains(&via),
+        "route should pass through the via point, got {:?}",
+        geometry.waypoints
+    );
+    assert_vec3_approx(geometry.waypoints[0], Vec3::ZERO, "route start");
+    assert_vec3_approx(
+        *geometry.waypoints.last().unwrap(),
+        Vec3::new(4.0, 0.0, 0.0),
+        "route end",
+    );
+    for pair in geometry.waypoints.windows(2) {
+        let delta = pair[1] - pair[0];
+        let non_zero_axes = [delta.x, delta.y, delta.z]
+            .iter()
+            .filter(|v| v.abs() > TOLERANCE)
+            .count();
+        assert_eq!(
+            non_zero_axes, 1,
+            "every leg should be planned orthogonally, got {delta}"
+        );
+    }
+}
+
+#[test]
+fn via_tangent_runs_straight_through_the_via() {
+    let solver = Solver::Catenary(CatenarySolver::new().with_slack(1.3));
+    let via = Vec3::new(2.0, 1.0, 0.0);
+    let vias = [ViaPoint {
+        position: via,
+        tangent:  Some(Dir3::X),
+    }];
+    let request =
+        RouteRequest::new(Vec3::ZERO.into(), Vec3::new(4.0, 0.0, 0.0).into(), 16).with_vias(&vias);
+
+    let geometry = solver.solve(&request);
+
+    assert_eq!(
+        geometry.segments.len(),
+        3,
+        "leg, through-piece, leg: {:?}",
+        geometry.waypoints
+    );
+    let through = &geometry.segments[1];
+    for point in &through.points {
+        assert!(
+            (point.y - via.y).abs() < 1e-4 && point.z.abs() < 1e-4,
+            "through-piece should run straight along the tangent, got {point}"
+        );
+    }
+    assert!(
+        through.points.iter().any(|point| point.x < via.x)
+            && through.points.iter().any(|point| point.x > via.x),
+        "through-piece should straddle the via point"
+    );
+    for (index, pair) in geometry.segments.windows(2).enumerate() {
+        assert_vec3_approx(
+            *pair[0].points.last().unwrap(),
+            pair[1].points[0],
+            &format!("segments {index} and {} should meet", index + 1),
+        );
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Cable layout
+// ─────────────────────────────────────────────────────────────────────
+
+const LAYOUT_CABLE_RADIUS: f32 = 0.04;
+
+fn layout_cable(solver: &Solver, start: Vec3, end: Vec3, bundle: Option<u32>) -> LayoutCable<'_> {
+    LayoutCable {
+        solver,
+        request: RouteRequest::new(start.into(), end.into(), 8),
+        radius: LAYOUT_CABLE_RADIUS,
+        bundle,
+    }
+}
+
+#[test]
+fn layout_spreads_cables_sharing_a_corridor_into_lanes() {
+    let solver = Solver::Routed {
+        path_strategy: PathStrategy::Orthogonal,
+        curve_kind:    CurveKind::Linear,
+        resolution:    8,
+    };
+    let first_start = Vec3::ZERO;
+    let second_start = Vec3::new(0.0, 0.03, 0.0);
+    let cables = [
+        layout_cable(&solver, first_start, Vec3::new(4.0, 0.0, 0.0), None),
+        layout_cable(&solver, second_start, Vec3::new(4.0, 0.03, 0.0), None),
+    ];
+
+    let geometries = layout_cables(&cables);
+
+    assert_eq!(geometries.len(), 2);
+    assert_eq!(
+        geometries[0].waypoints.len(),
+        2,
+        "the first cable opens the corridor and keeps its route"
+    );
+    let second = &geometries[1];
+    assert_vec3_approx(second.waypoints[0], second_start, "second cable start");
+    assert_vec3_approx(
+        *second.waypoints.last().unwrap(),
+        Vec3::new(4.0, 0.03, 0.0),
+        "second cable end",
+    );
+    for point in second
+        .all_points()
+        .filter(|point| (0.5..3.5).contains(&point.x))
+    {
+        let from_first = Vec3::new(0.0, point.y, point.z).length();
+        assert!(
+            from_first >= 2.0f32.mul_add(LAYOUT_CABLE_RADIUS, -TOLERANCE),
+            "second cable at {point} runs {from_first} from the first, inside its tube"
+        );
+    }
+}
+
+#[test]
+fn layout_bundle_shares_a_spine_and_fans_out_to_endpoints() {
+    let solver = Solver::Linear;
+    let heights = [0.0, 0.3, 0.6];
+    let cables = heights.map(|y| {
+        layout_cable(
+            &solver,
+            Vec3::new(0.0, y, 0.0),
+            Vec3::new(4.0, y, 0.0),
+            Some(7),
+        )
+    });
+
+    let geometries = layout_cables(&cables);
+
+    assert_eq!(geometries.len(), heights.len());
+    let spine_start = Vec3::new(0.8, 0.3, 0.0);
+    let tube_width = 2.0 * LAYOUT_CABLE_RADIUS;
+    for (geometry, y) in geometries.iter().zip(heights) {
+        assert_eq!(
+            geometry.waypoints.len(),
+            4,
+            "each member runs start, spine start, spine end, end"
+        );
+        assert_vec3_approx(
+            geometry.waypoints[0],
+            Vec3::new(0.0, y, 0.0),
+            "member start",
+        );
+        assert_vec3_approx(geometry.waypoints[3], Vec3::new(4.0, y, 0.0), "member end");
+        assert!(
+            geometry.waypoints[1].distance(spine_start) < 2.0 * tube_width,
+            "member should join the shared spine, got {}",
+            geometry.waypoints[1]
+        );
+    }
+    for (index, geometry) in geometries.iter().enumerate() {
+        for other in &geometries[index + 1..] {
+            let gap = geometry.waypoints[1].distance(other.waypoints[1]);
+            assert!(
+                gap >= tube_width,
+                "bundle members should sit a tube apart on the spine, got {gap}"
+            );
+        }
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────
+// Support surfaces
+// ─────────────────────────────────────────────────────────────────────
+
+fn supported_catenary(slack: f32, supports: &[SupportSurface]) -> Vec<Vec3> {
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 1.0, 0.0).into(),
+        Vec3::new(4.0, 1.0, 0.0).into(),
+        64,
+    )
+    .with_supports(supports);
+    Solver::Catenary(CatenarySolver::new().with_slack(slack))
+        .solve(&request)
+        .all_points()
+        .copied()
+        .collect()
+}
+
+#[test]
+fn catenary_rests_on_a_floor_it_would_sag_through() {
+    let slack = 1.3;
+    let free = supported_catenary(slack, &[]);
+    assert!(
+        free.iter().any(|point| point.y < -0.1),
+        "the free catenary should sag through the floor"
+    );
+
+    let points = supported_catenary(slack, &[SupportSurface::plane(Vec3::ZERO)]);
+
+    assert_vec3_approx(points[0], Vec3::new(0.0, 1.0, 0.0), "resting start");
+    assert_vec3_approx(
+        *points.last().unwrap(),
+        Vec3::new(4.0, 1.0, 0.0),
+        "resting end",
+    );
+    assert!(
+        points.iter().all(|point| point.y > -TOLERANCE),
+        "no sample should sink through the floor"
+    );
+    assert!(
+        points
+            .iter()
+            .any(|point| point.y.abs() < TOLERANCE && (point.x - 2.0).abs() < 0.5),
+        "the middle of the cable should lie on the floor"
+    );
+    let length: f32 = points
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum();
+    assert!(
+        slack.mul_add(-4.0, length).abs() < 0.05,
+        "the resting cable keeps its length, got {length}"
+    );
+
+    // The cable leaves the floor level with it, not at a kink.
+    let lift_off = points
+        .windows(2)
+        .find(|pair| pair[0].y.abs() < 1e-4 && pair[1].y > 1e-4)
+        .unwrap();
+    let leaving = (lift_off[1] - lift_off[0]).normalize();
+    assert!(
+        leaving.y < 0.2,
+        "the cable should lift off tangent to the floor, left along {leaving}"
+    );
+}
+
+#[test]
+fn catenary_rests_on_the_highest_support_it_sags_through() {
+    let floor = SupportSurface::plane(Vec3::ZERO);
+    let table = SupportSurface::top_of(&Obstacle::new(
+        Vec3::new(3.0, 0.25, 1.0),
+        Vec3::new(2.0, 0.25, 0.0),
+    ));
+
+    let points = supported_catenary(1.2, &[floor, table]);
+
+    let lowest = points
+        .iter()
+        .map(|point| point.y)
+        .fold(f32::INFINITY, f32::min);
+    assert!(
+        (lowest - 0.5).abs() < TOLERANCE,
+        "the cable should lie on the table top at 0.5, lowest sample {lowest}"
+    );
+}
+
+#[test]
+fn bounded_support_holds_only_a_run_that_fits_on_it() {
+    // Too narrow for the run the cable would lie along.
+    let narrow_table = SupportSurface::top_of(&Obstacle::new(
+        Vec3::new(0.5, 0.25, 1.0),
+        Vec3::new(2.0, 0.25, 0.0),
+    ));
+
+    assert_eq!(
+        supported_catenary(1.2, &[narrow_table]),
+        supported_catenary(1.2, &[]),
+        "a run that would overhang the top should leave the free catenary"
+    );
+}
+
+#[test]
+fn simulated_cable_settles_on_a_floor() {
+    let solver = SimulatedSolver::new().with_catenary(CatenarySolver::new().with_slack(1.3));
+    let supports = [SupportSurface::plane(Vec3::ZERO)];
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 1.0, 0.0).into(),
+        Vec3::new(4.0, 1.0, 0.0).into(),
+        24,
+    )
+    .with_supports(&supports);
+    let mut simulation = CableSimulation::seed(&solver, &request);
+    assert!(
+        simulation.points().iter().all(|point| point.y > -TOLERANCE),
+        "the chain should be seeded resting on the floor"
+    );
+
+    for _ in 0..120 {
+        simulation.step(&solver, &request, SIMULATION_FRAME_SECONDS);
+        assert!(
+            simulation.points().iter().all(|point| point.y > -TOLERANCE),
+            "no particle should fall through the floor"
+        );
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────
 // Integration: full pipeline A* + CatenarySolver
 // ─────────────────────────────────────────────────────────────────────
@@ -1112,12 +1919,12 @@ fn full_pipeline_astar_catenary() {
         Vec3::new(3.0, 0.0, 0.0),
     )];
 
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        Vec3::new(6.0, 0.0, 0.0).into(),
-        obstacles:  &obstacles,
-        resolution: 16,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 0.0, 0.0).into(),
+        Vec3::new(6.0, 0.0, 0.0).into(),
+        16,
+    )
+    .with_obstacles(&obstacles);
 
     let geometry = router.solve(&request);
 
@@ -1140,12 +1947,11 @@ fn full_pipeline_astar_catenary() {
 fn full_pipeline_orthogonal_linear() {
     let router = Router::new(OrthogonalPlanner::new(), LinearSolver);
 
-    let request = RouteRequest {
-        start:      Vec3::new(0.0, 0.0, 0.0).into(),
-        end:        Vec3::new(5.0, 3.0, 0.0).into(),
-        obstacles:  &[],
-        resolution: 10,
-    };
+    let request = RouteRequest::new(
+        Vec3::new(0.0, 0.0, 0.0).into(),
+        Vec3::new(5.0, 3.0, 0.0).into(),
+        10,
+    );
 
     let geometry = router.solve(&request);
 
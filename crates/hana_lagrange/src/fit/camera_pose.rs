@@ -88,11 +88,34 @@ pub(super) fn apply_free_cam_pose(free_cam: &mut FreeCam, pose: FreeCamFitPose)
 }
 
 /// Parameters for an instant orbital snap.
-pub(super) struct SnapOrbit {
-    pub(super) focus:  WorldPosition,
-    pub(super) yaw:    Option<f32>,
-    pub(super) pitch:  Option<f32>,
-    pub(super) radius: f32,
+pub(crate) struct SnapOrbit {
+    pub(crate) focus:  WorldPosition,
+    pub(crate) yaw:    Option<f32>,
+    pub(crate) pitch:  Option<f32>,
+    pub(crate) radius: f32,
+}
+
+impl SnapOrbit {
+    /// Snaps `orbit_cam` to this orbital position without firing any events.
+    pub(crate) fn apply(self, orbit_cam: &mut OrbitCam) {
+        orbit_cam.pan.snap_to(Focus(*self.focus));
+        orbit_cam.zoom.snap_to(Radius(self.radius));
+        // Only the axes the caller provided are snapped; an unspecified axis keeps
+        // both its current and target angle.
+        let mut current = orbit_cam.orbit.current();
+        let mut target = orbit_cam.orbit.target();
+        if let Some(yaw) = self.yaw {
+            current.yaw = yaw;
+            target.yaw = yaw;
+        }
+        if let Some(pitch) = self.pitch {
+            current.pitch = pitch;
+            target.pitch = pitch;
+        }
+        orbit_cam.orbit.set_current(current);
+        orbit_cam.orbit.set_target(target);
+        orbit_cam.force_update();
+    }
 }
 
 /// Snaps the camera to an orbital position instantly and fires caller-provided
@@ -103,23 +126,7 @@ pub(super) fn snap_to_orbit(
     snap: SnapOrbit,
     emit_events: impl FnOnce(&mut Commands),
 ) {
-    orbit_cam.pan.snap_to(Focus(*snap.focus));
-    orbit_cam.zoom.snap_to(Radius(snap.radius));
-    // Only the axes the caller provided are snapped; an unspecified axis keeps
-    // both its current and target angle.
-    let mut current = orbit_cam.orbit.current();
-    let mut target = orbit_cam.orbit.target();
-    if let Some(yaw) = snap.yaw {
-        current.yaw = yaw;
-        target.yaw = yaw;
-    }
-    if let Some(pitch) = snap.pitch {
-        current.pitch = pitch;
-        target.pitch = pitch;
-    }
-    orbit_cam.orbit.set_current(current);
-    orbit_cam.orbit.set_target(target);
-    orbit_cam.force_update();
+    snap.apply(orbit_cam);
 
     emit_events(commands);
 }
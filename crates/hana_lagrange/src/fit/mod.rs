@@ -13,6 +13,7 @@ mod triggers;
 mod overlay;
 
 use bevy::prelude::*;
+pub(crate) use camera_pose::SnapOrbit;
 pub use geometry::FitAnchor;
 #[cfg(feature = "fit_overlay")]
 pub use overlay::FitOverlay;
@@ -25,6 +26,7 @@ pub use target::SetFitTarget;
 pub use triggers::AnimateToFit;
 pub use triggers::LookAt;
 pub use triggers::LookAtAndZoomToFit;
+pub(crate) use triggers::LookAtPlan;
 pub use triggers::ZoomBegin;
 pub use triggers::ZoomContext;
 pub use triggers::ZoomEnd;
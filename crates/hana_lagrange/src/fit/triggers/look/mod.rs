@@ -9,3 +9,4 @@ pub(crate) use look_at::on_orbit_cam_look_at;
 pub use look_at_and_zoom_to_fit::LookAtAndZoomToFit;
 pub(crate) use look_at_and_zoom_to_fit::on_free_cam_look_at_and_zoom_to_fit;
 pub(crate) use look_at_and_zoom_to_fit::on_orbit_cam_look_at_and_zoom_to_fit;
+pub(crate) use plan::LookAtPlan;
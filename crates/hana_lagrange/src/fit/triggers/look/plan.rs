@@ -11,16 +11,16 @@ use crate::operation::LookAngles;
 use crate::operation::Roll;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub(super) struct LookAtPlan {
-    pub(super) camera_position: Vec3,
-    pub(super) target_position: Vec3,
-    pub(super) yaw:             f32,
-    pub(super) pitch:           f32,
-    pub(super) radius:          f32,
+pub(crate) struct LookAtPlan {
+    pub(crate) camera_position: Vec3,
+    pub(crate) target_position: Vec3,
+    pub(crate) yaw:             f32,
+    pub(crate) pitch:           f32,
+    pub(crate) radius:          f32,
 }
 
 impl LookAtPlan {
-    pub(super) fn from_world_positions(camera_position: Vec3, target_position: Vec3) -> Self {
+    pub(crate) fn from_world_positions(camera_position: Vec3, target_position: Vec3) -> Self {
         let (yaw, pitch, radius) = animation::orbital_parameters_from_offset(Displacement(
             camera_position - target_position,
         ));
@@ -8,6 +8,7 @@ pub(crate) use animate::on_free_cam_animate_to_fit;
 pub(crate) use animate::on_orbit_cam_animate_to_fit;
 pub use look::LookAt;
 pub use look::LookAtAndZoomToFit;
+pub(crate) use look::LookAtPlan;
 pub(crate) use look::on_free_cam_look_at;
 pub(crate) use look::on_free_cam_look_at_and_zoom_to_fit;
 pub(crate) use look::on_orbit_cam_look_at;
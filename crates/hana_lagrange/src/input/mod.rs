@@ -286,6 +286,7 @@ pub use crate::orbit_cam::OrbitCamMouseDrag;
 pub use crate::orbit_cam::OrbitCamMouseWheelZoom;
 pub use crate::orbit_cam::OrbitCamOrbitActionBindings;
 pub use crate::orbit_cam::OrbitCamOrbitBinding;
+pub use crate::orbit_cam::OrbitCamOrbitPivot;
 pub use crate::orbit_cam::OrbitCamPanActionBindings;
 pub use crate::orbit_cam::OrbitCamPanBinding;
 pub use crate::orbit_cam::OrbitCamPinchZoom;
@@ -296,6 +297,7 @@ pub use crate::orbit_cam::OrbitCamSimpleMousePreset;
 pub use crate::orbit_cam::OrbitCamTouchBinding;
 pub use crate::orbit_cam::OrbitCamTouchBindingConfig;
 pub use crate::orbit_cam::OrbitCamTrackpadScroll;
+pub use crate::orbit_cam::OrbitCamZoomAnchor;
 pub use crate::orbit_cam::OrbitCamZoomBinding;
 pub use crate::orbit_cam::OrbitCamZoomCoarseActionBindings;
 pub use crate::orbit_cam::OrbitCamZoomSmoothActionBindings;
@@ -174,6 +174,7 @@ pub use input::OrbitCamMouseWheelZoom;
 pub use input::OrbitCamOrbitAction;
 pub use input::OrbitCamOrbitActionBindings;
 pub use input::OrbitCamOrbitBinding;
+pub use input::OrbitCamOrbitPivot;
 pub use input::OrbitCamPanAction;
 pub use input::OrbitCamPanActionBindings;
 pub use input::OrbitCamPanBinding;
@@ -185,6 +186,7 @@ pub use input::OrbitCamSimpleMousePreset;
 pub use input::OrbitCamTouchBinding;
 pub use input::OrbitCamTouchBindingConfig;
 pub use input::OrbitCamTrackpadScroll;
+pub use input::OrbitCamZoomAnchor;
 pub use input::OrbitCamZoomBinding;
 pub use input::OrbitCamZoomCoarseAction;
 pub use input::OrbitCamZoomCoarseActionBindings;
@@ -107,10 +107,15 @@ impl<V: Smoothable> Operation<V> {
 
     /// Advances one frame: bounds the target, then eases current toward it.
     pub fn update(&mut self, delta_secs: f32) {
+        self.update_with_damping(delta_secs, self.damping.value());
+    }
+
+    /// Advances one frame like [`Self::update`], easing under `damping`
+    /// instead of this operation's own, so it keeps pace with another
+    /// operation.
+    pub(crate) fn update_with_damping(&mut self, delta_secs: f32, damping: f32) {
         self.target = self.limit.constrain(self.target);
-        self.current = self
-            .current
-            .lerp_and_snap(self.target, self.damping.value(), delta_secs);
+        self.current = self.current.lerp_and_snap(self.target, damping, delta_secs);
     }
 }
 
@@ -3,6 +3,7 @@ use std::f32::consts::PI;
 use std::f32::consts::TAU;
 
 use bevy::prelude::*;
+use bevy_kana::Position as WorldPosition;
 
 use super::OrbitCam;
 use super::OrbitCamHomePose;
@@ -10,15 +11,20 @@ use super::OrbitCamInput;
 use super::OrbitCamUpdateRequest;
 use super::UpsideDownPolicy;
 use super::constants::SCROLL_ZOOM_FACTOR;
+use super::cursor_anchor::CursorAnchor;
 use super::drag_state::CameraOrientation;
 use super::drag_state::DragActivity;
 use super::drag_state::OrbitDragState;
+use super::drag_state::PanEasing;
 use super::orbit_transform;
 use crate::CameraBasis;
 use crate::CameraHomePending;
 use crate::Initialization;
+use crate::fit::LookAtPlan;
+use crate::fit::SnapOrbit;
 use crate::input::CameraInputSurfaceMetrics;
 use crate::input::ResolvedCameraInputRoute;
+use crate::operation::Focus;
 use crate::operation::Limit;
 use crate::operation::OrbitAngles;
 use crate::operation::Radius;
@@ -229,8 +235,9 @@ fn apply_pan_input(
     MotionStatus::Unchanged
 }
 
-/// Applies scroll/zoom input to the radius target. Returns `MotionStatus::Changed`
-/// if the camera moved.
+/// Applies scroll/zoom input to the radius target, moving the focus toward
+/// `zoom_anchor` when there is one. Returns `MotionStatus::Changed` if the camera
+/// moved.
 //
 // Multiplicative (exponential) zoom: one out-tick is the exact inverse of one
 // in-tick at any radius. Additive `radius *= (1 ± k)` would feel symmetric
@@ -240,27 +247,129 @@ fn apply_scroll_input(
     scroll_line: f32,
     scroll_pixel: f32,
     orbit_cam: &mut OrbitCam,
+    zoom_anchor: Option<Vec3>,
 ) -> MotionStatus {
     if (scroll_line + scroll_pixel).abs() > 0.0 {
         let line_factor = (-scroll_line * SCROLL_ZOOM_FACTOR).exp();
         let pixel_factor = (-scroll_pixel * SCROLL_ZOOM_FACTOR).exp();
 
+        let limit = orbit_cam.zoom.limit();
+        let previous_target = limit.constrain(orbit_cam.zoom.target());
+        let previous_current = orbit_cam.zoom.current();
+
         orbit_cam
             .zoom
             .set_target(orbit_cam.zoom.target() * (line_factor * pixel_factor));
 
         // Pixel-based scrolling is applied directly to the current value (already smooth)
-        let snapped = orbit_cam
-            .zoom
-            .limit()
-            .constrain(orbit_cam.zoom.current() * pixel_factor);
+        let snapped = limit.constrain(previous_current * pixel_factor);
         orbit_cam.zoom.set_current(snapped);
 
+        if let Some(anchor) = zoom_anchor {
+            let target_ratio = limit.constrain(orbit_cam.zoom.target()).0 / previous_target.0;
+            let current_ratio = snapped.0 / previous_current.0;
+            let target = orbit_cam.pan.target();
+            let current = orbit_cam.pan.current();
+            orbit_cam
+                .pan
+                .set_target(scale_focus_about(target, anchor, target_ratio));
+            orbit_cam
+                .pan
+                .set_current(scale_focus_about(current, anchor, current_ratio));
+        }
+
         return MotionStatus::Changed;
     }
     MotionStatus::Unchanged
 }
 
+/// Moves `focus` toward or away from `anchor` as the radius scales by `ratio`.
+///
+/// The camera sits `radius` behind the focus, so scaling both about the anchor
+/// slides the camera along its ray through the anchor, and the anchor holds
+/// its place on screen. For an orthographic camera, whose radius is the
+/// projection scale, the anchor's offset from the view centre shrinks with
+/// the scale, with the same result.
+fn scale_focus_about(focus: Focus, anchor: Vec3, ratio: f32) -> Focus {
+    if !ratio.is_finite() || ratio <= 0.0 {
+        return focus;
+    }
+    Focus(anchor + (focus.0 - anchor) * ratio)
+}
+
+/// Re-anchors the orbit on `pivot` the way `LookAt` re-anchors on its target,
+/// without moving the camera, so the orbit drag starting this frame rotates
+/// around it.
+///
+/// The pivot is first moved to its depth along the view axis so the view does
+/// not turn toward it. A perspective camera keeps its position exactly; an
+/// orthographic one, whose radius is the projection scale, keeps its scale and
+/// only slides along its view axis, which changes nothing on screen. The pivot
+/// is left alone when it lies behind the camera or the pan or zoom limits would
+/// move the camera to reach it.
+fn reanchor_orbit(
+    orbit_cam: &mut OrbitCam,
+    pivot: Vec3,
+    transform: &Transform,
+    projection: &Projection,
+) {
+    let forward = *transform.forward();
+    let depth = (pivot - transform.translation).dot(forward);
+    if depth <= f32::EPSILON {
+        return;
+    }
+    let plan = LookAtPlan::from_world_positions(
+        transform.translation,
+        transform.translation + forward * depth,
+    );
+    let focus = Focus(plan.target_position);
+    let radius = match projection {
+        Projection::Perspective(_) => Radius(plan.radius),
+        Projection::Orthographic(_) => orbit_cam.zoom.current(),
+        Projection::Custom(_) => return,
+    };
+    if orbit_cam.pan.limit().constrain(focus) != focus
+        || orbit_cam.zoom.limit().constrain(radius) != radius
+    {
+        return;
+    }
+    // The view already faces the new focus, so the angles stay as they are;
+    // the plan's world-space angles would rewrap the yaw and right an
+    // upside-down camera.
+    SnapOrbit {
+        focus:  WorldPosition(plan.target_position),
+        yaw:    None,
+        pitch:  None,
+        radius: radius.0,
+    }
+    .apply(orbit_cam);
+}
+
+/// Picks the damping the focus eases under this frame.
+///
+/// A zoom toward the cursor moves the focus in proportion to the radius, so the
+/// focus eases with the zoom until the zoom lands or other pan or zoom input
+/// takes over; otherwise a coarse zoom would let the anchor drift while the two
+/// catch up at different rates.
+fn next_pan_easing(
+    orbit_cam: &OrbitCam,
+    previous: PanEasing,
+    pan_motion: MotionStatus,
+    scroll_motion: MotionStatus,
+    zoom_anchored: bool,
+) -> PanEasing {
+    if scroll_motion.is_changed() && zoom_anchored {
+        PanEasing::WithZoom
+    } else if pan_motion.is_changed()
+        || scroll_motion.is_changed()
+        || orbit_cam.zoom.target() == orbit_cam.zoom.current()
+    {
+        PanEasing::Own
+    } else {
+        previous
+    }
+}
+
 /// Eases the operations one frame and writes the resulting camera transform.
 fn smooth_and_update_transform(
     orbit_cam: &mut OrbitCam,
@@ -268,10 +377,16 @@ fn smooth_and_update_transform(
     transform: &mut Transform,
     projection: &mut Projection,
     delta: f32,
+    pan_easing: PanEasing,
 ) {
     orbit_cam.orbit.update(delta);
     orbit_cam.zoom.update(delta);
-    orbit_cam.pan.update(delta);
+    match pan_easing {
+        PanEasing::Own => orbit_cam.pan.update(delta),
+        PanEasing::WithZoom => orbit_cam
+            .pan
+            .update_with_damping(delta, orbit_cam.zoom.damping()),
+    }
 
     let angles = orbit_cam.orbit.current();
     orbit_transform::update_orbit_transform(
@@ -293,6 +408,7 @@ pub(crate) fn orbit_cam(
         &mut OrbitCam,
         &mut OrbitDragState,
         &OrbitCamInput,
+        &CursorAnchor,
         Option<&CameraInputSurfaceMetrics>,
         Ref<CameraBasis>,
         &mut Transform,
@@ -309,6 +425,7 @@ pub(crate) fn orbit_cam(
         mut orbit_cam,
         mut drag_state,
         input,
+        cursor_anchor,
         explicit_metrics,
         basis,
         mut transform,
@@ -346,27 +463,45 @@ pub(crate) fn orbit_cam(
                 CameraOrientation::Normal
             };
             drag_state.orbit_drag = orbit_drag;
+            if orbit_drag == DragActivity::Active
+                && let Some(pivot) = cursor_anchor.pivot
+            {
+                reanchor_orbit(&mut orbit_cam, pivot, &transform, &projection);
+            }
         }
 
-        let motion = apply_orbit_input(
+        let orbit_motion = apply_orbit_input(
             input.orbit,
             &mut orbit_cam,
             *drag_state,
             metrics.input_surface_size,
-        )
-        .merge(apply_pan_input(
+        );
+        let pan_motion = apply_pan_input(
             input.pan,
             &mut orbit_cam,
             basis,
             metrics.camera_view_size,
             &transform,
             &projection,
-        ))
-        .merge(apply_scroll_input(
+        );
+        let scroll_motion = apply_scroll_input(
             input.scroll_line,
             input.scroll_pixel,
             &mut orbit_cam,
-        ));
+            cursor_anchor.zoom,
+        );
+        let motion = orbit_motion.merge(pan_motion).merge(scroll_motion);
+
+        let pan_easing = next_pan_easing(
+            &orbit_cam,
+            drag_state.pan_easing,
+            pan_motion,
+            scroll_motion,
+            cursor_anchor.zoom.is_some(),
+        );
+        if drag_state.pan_easing != pan_easing {
+            drag_state.pan_easing = pan_easing;
+        }
 
         // Enforce the upside-down policy on the orbit target; the operations' own
         // limits (angle, radius, region) are applied inside `Operation::update`.
@@ -397,6 +532,7 @@ pub(crate) fn orbit_cam(
                 &mut transform,
                 &mut projection,
                 delta,
+                pan_easing,
             );
         }
     }
@@ -555,4 +691,116 @@ mod tests {
         assert_eq!(metrics.camera_view_size, Some(Vec2::new(500.0, 600.0)));
         assert_eq!(metrics.input_surface_size, Some(Vec2::new(300.0, 400.0)));
     }
+
+    const ANCHOR_TOLERANCE: f32 = 1e-4;
+
+    fn current_transform(orbit_cam: &OrbitCam, projection: &Projection) -> Transform {
+        let angles = orbit_cam.orbit.current();
+        orbit_transform::transform_from_orbit(
+            angles.yaw,
+            angles.pitch,
+            orbit_cam.zoom.current().0,
+            orbit_cam.pan.current().0,
+            projection,
+            CameraBasis::Y_UP.axes(),
+        )
+    }
+
+    #[test]
+    fn zoom_toward_anchor_keeps_it_under_the_cursor() {
+        let start = start_pose();
+        let mut orbit_cam = OrbitCam::from_pose(start.pan, start.orbit, start.zoom);
+        let projection = Projection::Perspective(PerspectiveProjection::default());
+        let before = current_transform(&orbit_cam, &projection);
+        let anchor = before.translation + *before.forward() * 4.0 + *before.right() * 1.5;
+
+        apply_scroll_input(0.0, 2.0, &mut orbit_cam, Some(anchor));
+        let after = current_transform(&orbit_cam, &projection);
+
+        assert!(orbit_cam.zoom.current().0 < START_RADIUS);
+        assert_eq!(orbit_cam.pan.target(), orbit_cam.pan.current());
+        assert_eq!(after.rotation, before.rotation);
+        assert!(
+            (anchor - before.translation)
+                .normalize()
+                .abs_diff_eq((anchor - after.translation).normalize(), ANCHOR_TOLERANCE),
+            "the anchor should stay on the same view ray"
+        );
+    }
+
+    #[test]
+    fn coarse_zoom_toward_anchor_keeps_it_under_the_cursor_while_easing() {
+        let start = start_pose();
+        let mut orbit_cam = OrbitCam::from_pose(start.pan, start.orbit, start.zoom);
+        let mut projection = Projection::Perspective(PerspectiveProjection::default());
+        let mut transform = current_transform(&orbit_cam, &projection);
+        let anchor = transform.translation + *transform.forward() * 4.0 + *transform.right() * 1.5;
+        let anchor_direction = (anchor - transform.translation).normalize();
+
+        apply_scroll_input(2.0, 0.0, &mut orbit_cam, Some(anchor));
+        assert_eq!(
+            orbit_cam.zoom.current(),
+            Radius(START_RADIUS),
+            "line zoom should ease in rather than snap"
+        );
+
+        for _ in 0..10 {
+            smooth_and_update_transform(
+                &mut orbit_cam,
+                CameraBasis::Y_UP,
+                &mut transform,
+                &mut projection,
+                1.0 / 60.0,
+                PanEasing::WithZoom,
+            );
+            assert!(
+                anchor_direction.abs_diff_eq(
+                    (anchor - transform.translation).normalize(),
+                    ANCHOR_TOLERANCE
+                ),
+                "the anchor should stay on the same view ray while the zoom eases in"
+            );
+        }
+        assert!(orbit_cam.zoom.current().0 < START_RADIUS);
+    }
+
+    fn assert_reanchor_keeps_the_view(projection: &Projection) {
+        let start = start_pose();
+        let mut orbit_cam = OrbitCam::from_pose(start.pan, start.orbit, start.zoom);
+        let before = current_transform(&orbit_cam, projection);
+        let on_axis = before.translation + *before.forward() * 2.0;
+
+        reanchor_orbit(
+            &mut orbit_cam,
+            on_axis + *before.up() * 0.5,
+            &before,
+            projection,
+        );
+        let after = current_transform(&orbit_cam, projection);
+
+        assert!(
+            orbit_cam
+                .pan
+                .current()
+                .0
+                .abs_diff_eq(on_axis, ANCHOR_TOLERANCE)
+        );
+        assert_eq!(orbit_cam.pan.target(), orbit_cam.pan.current());
+        assert_eq!(orbit_cam.orbit.current(), start.orbit);
+        assert_eq!(after.rotation, before.rotation);
+        // Sliding along the view axis is invisible to an orthographic camera.
+        let shift = after.translation - before.translation;
+        assert!(
+            shift.cross(*before.forward()).length() < ANCHOR_TOLERANCE,
+            "the camera should not move across the view, moved {shift}"
+        );
+    }
+
+    #[test]
+    fn orbit_reanchor_keeps_the_view_for_both_projections() {
+        assert_reanchor_keeps_the_view(&Projection::Perspective(PerspectiveProjection::default()));
+        assert_reanchor_keeps_the_view(&Projection::Orthographic(
+            OrthographicProjection::default_3d(),
+        ));
+    }
 }
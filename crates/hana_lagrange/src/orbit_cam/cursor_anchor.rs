@@ -0,0 +1,149 @@
+//! World points under the cursor that an `OrbitCam` zooms toward or re-anchors
+//! its orbit to, resolved from the camera's installed bindings.
+
+use bevy::camera::RenderTarget;
+#[cfg(feature = "mesh_ray_cast")]
+use bevy::picking::mesh_picking::ray_cast::MeshRayCast;
+#[cfg(feature = "mesh_ray_cast")]
+use bevy::picking::mesh_picking::ray_cast::MeshRayCastSettings;
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use bevy::window::WindowRef;
+
+use super::OrbitCam;
+use super::OrbitCamInput;
+use super::OrbitCamKind;
+use super::OrbitCamOrbitPivot;
+use super::OrbitCamZoomAnchor;
+use super::drag_state::DragActivity;
+use super::drag_state::OrbitDragState;
+use crate::input::CameraInstalledBindings;
+
+/// Internal per-camera anchors for this frame's controller pass.
+///
+/// Each is `None` unless the bindings ask for it, the matching input is active,
+/// and the cursor lies inside the camera's viewport.
+#[derive(Component, Default, Copy, Clone, Debug, PartialEq)]
+pub(super) struct CursorAnchor {
+    /// Point zoom input moves the camera toward.
+    pub(super) zoom:  Option<Vec3>,
+    /// Point an orbit drag starting this frame re-anchors to.
+    pub(super) pivot: Option<Vec3>,
+}
+
+/// Query type for the camera-side components `resolve_cursor_anchors` reads.
+type CursorAnchorQuery<'w> = (
+    &'w OrbitCam,
+    &'w OrbitCamInput,
+    &'w OrbitDragState,
+    Option<&'w CameraInstalledBindings<OrbitCamKind>>,
+    &'w Camera,
+    &'w RenderTarget,
+    &'w GlobalTransform,
+    &'w Projection,
+    &'w mut CursorAnchor,
+);
+
+/// Finds the world point under the cursor for cameras whose bindings zoom
+/// toward it or orbit around it.
+///
+/// The point is the nearest mesh under the cursor with the `mesh_ray_cast`
+/// feature in an app with mesh assets, falling back to where the cursor ray crosses the plane through the
+/// focus facing the camera. Orthographic zoom always uses that plane: every
+/// point along the ray lies under the cursor, and staying in the focus plane
+/// keeps the camera from drifting along its view axis.
+pub(super) fn resolve_cursor_anchors(
+    mut cameras: Query<CursorAnchorQuery>,
+    windows: Query<&Window>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    #[cfg(feature = "mesh_ray_cast")] mut mesh_ray_cast: Option<MeshRayCast>,
+) {
+    for (
+        orbit_cam,
+        input,
+        drag_state,
+        bindings,
+        camera,
+        render_target,
+        camera_transform,
+        projection,
+        mut cursor_anchor,
+    ) in &mut cameras
+    {
+        let (wants_zoom, wants_pivot) = bindings.map_or((false, false), |bindings| {
+            (
+                input.has_zoom() && bindings.0.zoom_anchor() == OrbitCamZoomAnchor::Cursor,
+                input.has_orbit()
+                    && drag_state.orbit_drag == DragActivity::Idle
+                    && bindings.0.orbit_pivot() == OrbitCamOrbitPivot::PickedPoint,
+            )
+        });
+        let ray = if wants_zoom || wants_pivot {
+            cursor_ray(
+                camera,
+                render_target,
+                camera_transform,
+                &windows,
+                &primary_window,
+            )
+        } else {
+            None
+        };
+        let Some(ray) = ray else {
+            cursor_anchor.set_if_neq(CursorAnchor::default());
+            continue;
+        };
+
+        let focus_plane_point = ray.plane_intersection_point(
+            orbit_cam.pan.current().0,
+            InfinitePlane3d::new(camera_transform.forward()),
+        );
+        #[cfg(feature = "mesh_ray_cast")]
+        let picked_point = mesh_ray_cast
+            .as_mut()
+            .and_then(|mesh_ray_cast| {
+                mesh_ray_cast
+                    .cast_ray(ray, &MeshRayCastSettings::default())
+                    .first()
+                    .map(|(_, hit)| hit.point)
+            })
+            .or(focus_plane_point);
+        #[cfg(not(feature = "mesh_ray_cast"))]
+        let picked_point = focus_plane_point;
+
+        let zoom_point = match projection {
+            Projection::Orthographic(_) => focus_plane_point,
+            Projection::Perspective(_) | Projection::Custom(_) => picked_point,
+        };
+        cursor_anchor.set_if_neq(CursorAnchor {
+            zoom:  zoom_point.filter(|_| wants_zoom),
+            pivot: picked_point.filter(|_| wants_pivot),
+        });
+    }
+}
+
+/// World ray through the cursor, or `None` when the camera does not render to
+/// a window or the cursor is outside its viewport.
+fn cursor_ray(
+    camera: &Camera,
+    render_target: &RenderTarget,
+    camera_transform: &GlobalTransform,
+    windows: &Query<&Window>,
+    primary_window: &Query<Entity, With<PrimaryWindow>>,
+) -> Option<Ray3d> {
+    let RenderTarget::Window(window_ref) = render_target else {
+        return None;
+    };
+    let window = match window_ref {
+        WindowRef::Primary => primary_window.single().ok()?,
+        WindowRef::Entity(entity) => *entity,
+    };
+    let cursor = windows.get(window).ok()?.cursor_position()?;
+    let viewport = camera.logical_viewport_rect()?;
+    if !viewport.contains(cursor) {
+        return None;
+    }
+    camera
+        .viewport_to_world(camera_transform, cursor - viewport.min)
+        .ok()
+}
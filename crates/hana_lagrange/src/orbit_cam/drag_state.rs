@@ -1,10 +1,12 @@
 use bevy::prelude::*;
 
-/// Internal per-camera state used to keep orbit direction stable during a drag.
+/// Internal per-camera state used to keep orbit direction stable during a drag
+/// and to keep the focus easing in step with an anchored zoom.
 #[derive(Component, Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub(super) struct OrbitDragState {
     pub(super) orientation: CameraOrientation,
     pub(super) orbit_drag:  DragActivity,
+    pub(super) pan_easing:  PanEasing,
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -25,3 +27,15 @@ pub(super) enum CameraOrientation {
     Normal,
     UpsideDown,
 }
+
+/// Which damping the focus eases under.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) enum PanEasing {
+    /// The pan operation's own damping.
+    #[default]
+    Own,
+    /// The zoom operation's damping, while a zoom toward a cursor anchor is
+    /// still easing in; the focus then moves in proportion to the radius and
+    /// the anchor stays put on screen.
+    WithZoom,
+}
@@ -306,6 +306,39 @@ pub enum ZoomInversion {
     Inverted,
 }
 
+/// Where scroll-based and held zoom move the camera toward.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum OrbitCamZoomAnchor {
+    /// Zoom along the view axis toward the orbit focus.
+    #[default]
+    Focus,
+    /// Zoom toward the world point under the cursor, keeping it fixed on
+    /// screen. Falls back to [`Self::Focus`] while the cursor is outside the
+    /// camera's viewport.
+    ///
+    /// Only the `mesh_ray_cast` feature finds the mesh under the cursor;
+    /// without it the point is where the cursor ray crosses the plane through
+    /// the focus facing the camera, so zooming toward an object in front of
+    /// or behind the focus drifts it across the screen.
+    Cursor,
+}
+
+/// Which point an orbit drag rotates the camera around.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum OrbitCamOrbitPivot {
+    /// Orbit the current focus.
+    #[default]
+    Focus,
+    /// Re-anchor the focus to the world point under the cursor when the drag
+    /// begins, without moving the camera, then orbit it. Falls back to
+    /// [`Self::Focus`] while the cursor is outside the camera's viewport.
+    ///
+    /// Only the `mesh_ray_cast` feature picks the mesh under the cursor;
+    /// without it the pivot is where the cursor ray crosses the plane through
+    /// the focus facing the camera.
+    PickedPoint,
+}
+
 /// Axis used for button-drag zoom.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
 #[non_exhaustive]
@@ -15,10 +15,12 @@ use super::binding_kinds::OrbitCamBindingWithInputGain;
 use super::binding_kinds::OrbitCamButtonDragZoom;
 use super::binding_kinds::OrbitCamMouseDrag;
 use super::binding_kinds::OrbitCamMouseWheelZoom;
+use super::binding_kinds::OrbitCamOrbitPivot;
 use super::binding_kinds::OrbitCamPinchZoom;
 use super::binding_kinds::OrbitCamTouchBinding;
 use super::binding_kinds::OrbitCamTouchBindingConfig;
 use super::binding_kinds::OrbitCamTrackpadScroll;
+use super::binding_kinds::OrbitCamZoomAnchor;
 use super::binding_kinds::ZoomInversion;
 use super::validate;
 use crate::input::ActionBindingDescriptor;
@@ -44,6 +46,8 @@ pub(super) struct OrbitCamBindingsDescriptor {
     pub(super) touch:            Option<OrbitCamTouchBindingConfig>,
     pub(super) gamepad:          CameraInputGamepadSelectionPolicy,
     pub(super) zoom_inversion:   ZoomInversion,
+    pub(super) zoom_anchor:      OrbitCamZoomAnchor,
+    pub(super) orbit_pivot:      OrbitCamOrbitPivot,
     pub(super) button_drag_zoom: Option<OrbitCamBindingWithInputGain<OrbitCamButtonDragZoom>>,
     pub(super) slow_mode:        Option<CameraSlowMode>,
     pub(super) home:             Vec<ActionBindingDescriptor>,
@@ -145,6 +149,20 @@ impl OrbitCamBindingsBuilder {
         self
     }
 
+    /// Sets where zoom input moves the camera toward.
+    #[must_use]
+    pub const fn zoom_anchor(mut self, zoom_anchor: OrbitCamZoomAnchor) -> Self {
+        self.descriptor.zoom_anchor = zoom_anchor;
+        self
+    }
+
+    /// Sets which point orbit drags rotate the camera around.
+    #[must_use]
+    pub const fn orbit_pivot(mut self, orbit_pivot: OrbitCamOrbitPivot) -> Self {
+        self.descriptor.orbit_pivot = orbit_pivot;
+        self
+    }
+
     /// Sets the slow-mode policy.
     #[must_use]
     pub const fn slow_mode(mut self, slow_mode: CameraSlowMode) -> Self {
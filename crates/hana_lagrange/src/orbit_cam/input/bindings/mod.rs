@@ -6,7 +6,7 @@
 //! - [`preset`] — built-in [`OrbitCamPreset`] keymaps.
 //! - [`builder`] — [`OrbitCamBindingsBuilder`] and the dispatch enums it consumes.
 //! - [`binding_kinds`] — user-facing concrete binding kinds (mouse drag, trackpad, mouse wheel,
-//!   pinch, button drag, touch, gamepad policy, zoom inversion).
+//!   pinch, button drag, touch, gamepad policy, zoom inversion, zoom anchor, orbit pivot).
 //! - [`action_set`] — per-action binding-set newtypes written by the validator and read by the
 //!   adapter.
 //! - [`input_gain`] — the [`OrbitCamInputGain`] per-action gain layered over the shared
@@ -35,10 +35,12 @@ pub use binding_kinds::OrbitCamButtonDragZoom;
 pub use binding_kinds::OrbitCamButtonDragZoomAxis;
 pub use binding_kinds::OrbitCamMouseDrag;
 pub use binding_kinds::OrbitCamMouseWheelZoom;
+pub use binding_kinds::OrbitCamOrbitPivot;
 pub use binding_kinds::OrbitCamPinchZoom;
 pub use binding_kinds::OrbitCamTouchBinding;
 pub use binding_kinds::OrbitCamTouchBindingConfig;
 pub use binding_kinds::OrbitCamTrackpadScroll;
+pub use binding_kinds::OrbitCamZoomAnchor;
 pub use binding_kinds::ZoomInversion;
 pub use builder::OrbitCamBindingsBuilder;
 pub use builder::OrbitCamOrbitBinding;
@@ -83,6 +85,8 @@ pub struct OrbitCamBindings {
     pub(super) touch:            Option<OrbitCamTouchBindingConfig>,
     pub(super) gamepad:          CameraInputGamepadSelectionPolicy,
     pub(super) zoom_inversion:   ZoomInversion,
+    pub(super) zoom_anchor:      OrbitCamZoomAnchor,
+    pub(super) orbit_pivot:      OrbitCamOrbitPivot,
     pub(super) button_drag_zoom: Option<OrbitCamBindingWithInputGain<OrbitCamButtonDragZoom>>,
     pub(super) slow_mode:        Option<CameraSlowMode>,
     pub(super) home:             OrbitCamHomeActionBindings,
@@ -246,6 +250,14 @@ impl OrbitCamBindings {
     #[must_use]
     pub const fn zoom_inversion(&self) -> ZoomInversion { self.zoom_inversion }
 
+    /// Returns where zoom input moves the camera toward.
+    #[must_use]
+    pub const fn zoom_anchor(&self) -> OrbitCamZoomAnchor { self.zoom_anchor }
+
+    /// Returns which point orbit drags rotate the camera around.
+    #[must_use]
+    pub const fn orbit_pivot(&self) -> OrbitCamOrbitPivot { self.orbit_pivot }
+
     /// Returns button-drag zoom policy.
     #[must_use]
     pub const fn button_drag_zoom(
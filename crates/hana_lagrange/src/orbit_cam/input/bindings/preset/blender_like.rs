@@ -13,8 +13,10 @@ use crate::orbit_cam::input::bindings::OrbitCamBindingsBuilder;
 use crate::orbit_cam::input::bindings::OrbitCamInputGain;
 use crate::orbit_cam::input::bindings::OrbitCamMouseDrag;
 use crate::orbit_cam::input::bindings::OrbitCamMouseWheelZoom;
+use crate::orbit_cam::input::bindings::OrbitCamOrbitPivot;
 use crate::orbit_cam::input::bindings::OrbitCamPinchZoom;
 use crate::orbit_cam::input::bindings::OrbitCamTrackpadScroll;
+use crate::orbit_cam::input::bindings::OrbitCamZoomAnchor;
 
 /// Configures Blender-style pointer and smooth-scroll camera controls.
 #[derive(Clone, Copy, Debug, PartialEq, Reflect)]
@@ -27,6 +29,8 @@ pub struct OrbitCamBlenderLikePreset {
     slow_toggle_mod_keys:     ModKeys,
     slow_scale:               f32,
     home:                     [Option<Binding>; 2],
+    zoom_anchor:              OrbitCamZoomAnchor,
+    orbit_pivot:              OrbitCamOrbitPivot,
 }
 
 impl OrbitCamBlenderLikePreset {
@@ -92,6 +96,24 @@ impl OrbitCamBlenderLikePreset {
     #[must_use]
     pub const fn has_home(&self) -> bool { matches!(self.home, [Some(_), _] | [_, Some(_)]) }
 
+    /// Sets where zoom input moves the camera toward.
+    ///
+    /// Defaults to [`OrbitCamZoomAnchor::Focus`].
+    #[must_use]
+    pub const fn zoom_anchor(mut self, zoom_anchor: OrbitCamZoomAnchor) -> Self {
+        self.zoom_anchor = zoom_anchor;
+        self
+    }
+
+    /// Sets which point orbit drags rotate the camera around.
+    ///
+    /// Defaults to [`OrbitCamOrbitPivot::Focus`].
+    #[must_use]
+    pub const fn orbit_pivot(mut self, orbit_pivot: OrbitCamOrbitPivot) -> Self {
+        self.orbit_pivot = orbit_pivot;
+        self
+    }
+
     /// Sets source input gain for mouse-drag and line-wheel input.
     #[must_use]
     pub const fn mouse_input_gain(mut self, input_gain: OrbitCamInputGain) -> Self {
@@ -106,6 +128,15 @@ impl OrbitCamBlenderLikePreset {
         self
     }
 
+    pub(super) const fn anchor_into(
+        self,
+        builder: OrbitCamBindingsBuilder,
+    ) -> OrbitCamBindingsBuilder {
+        builder
+            .zoom_anchor(self.zoom_anchor)
+            .orbit_pivot(self.orbit_pivot)
+    }
+
     pub(super) fn build_into(
         self,
         builder: OrbitCamBindingsBuilder,
@@ -187,6 +218,8 @@ impl Default for OrbitCamBlenderLikePreset {
             slow_toggle_mod_keys:     ModKeys::ALT,
             slow_scale:               Self::DEFAULT_SLOW_SCALE,
             home:                     [None; 2],
+            zoom_anchor:              OrbitCamZoomAnchor::Focus,
+            orbit_pivot:              OrbitCamOrbitPivot::Focus,
         }
     }
 }
@@ -209,7 +242,8 @@ impl SmoothScrollInputGain for OrbitCamBlenderLikePreset {
 
 impl OrbitCamPresetConfig for OrbitCamBlenderLikePreset {
     fn build(self) -> Result<OrbitCamBindings, BindingsError> {
-        self.build_into(OrbitCamBindings::builder())?.build()
+        self.build_into(self.anchor_into(OrbitCamBindings::builder()))?
+            .build()
     }
 }
 
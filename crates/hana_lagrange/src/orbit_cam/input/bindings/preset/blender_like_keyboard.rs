@@ -10,6 +10,8 @@ use crate::orbit_cam::input::bindings::BindingsError;
 use crate::orbit_cam::input::bindings::OrbitCamBindings;
 use crate::orbit_cam::input::bindings::OrbitCamBindingsBuilder;
 use crate::orbit_cam::input::bindings::OrbitCamInputGain;
+use crate::orbit_cam::input::bindings::OrbitCamOrbitPivot;
+use crate::orbit_cam::input::bindings::OrbitCamZoomAnchor;
 
 /// Configures Blender-like pointer controls plus keyboard camera controls.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
@@ -57,6 +59,25 @@ impl OrbitCamBlenderLikeKeyboardPreset {
     #[must_use]
     pub const fn has_home(&self) -> bool { self.pointer.has_home() || self.keyboard.has_home() }
 
+    /// Sets where zoom input moves the camera toward, for keyboard zoom as
+    /// well as the pointer child's.
+    ///
+    /// Defaults to [`OrbitCamZoomAnchor::Focus`].
+    #[must_use]
+    pub const fn zoom_anchor(mut self, zoom_anchor: OrbitCamZoomAnchor) -> Self {
+        self.pointer = self.pointer.zoom_anchor(zoom_anchor);
+        self
+    }
+
+    /// Sets which point orbit drags rotate the camera around.
+    ///
+    /// Defaults to [`OrbitCamOrbitPivot::Focus`].
+    #[must_use]
+    pub const fn orbit_pivot(mut self, orbit_pivot: OrbitCamOrbitPivot) -> Self {
+        self.pointer = self.pointer.orbit_pivot(orbit_pivot);
+        self
+    }
+
     /// Sets source input gain for mouse-drag and line-wheel input.
     #[must_use]
     pub const fn mouse_input_gain(mut self, input_gain: OrbitCamInputGain) -> Self {
@@ -98,7 +119,8 @@ impl SmoothScrollInputGain for OrbitCamBlenderLikeKeyboardPreset {
 
 impl OrbitCamPresetConfig for OrbitCamBlenderLikeKeyboardPreset {
     fn build(self) -> Result<OrbitCamBindings, BindingsError> {
-        self.build_into(OrbitCamBindings::builder())?.build()
+        self.build_into(self.pointer.anchor_into(OrbitCamBindings::builder()))?
+            .build()
     }
 }
 
@@ -9,6 +9,8 @@ use super::OrbitCamSimpleMouseKeyboardPreset;
 use super::OrbitCamSimpleMousePreset;
 use crate::orbit_cam::input::bindings::BindingsError;
 use crate::orbit_cam::input::bindings::OrbitCamBindings;
+use crate::orbit_cam::input::bindings::OrbitCamOrbitPivot;
+use crate::orbit_cam::input::bindings::OrbitCamZoomAnchor;
 
 /// Setting-insensitive identity for a built-in orbit-camera input preset.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
@@ -137,6 +139,54 @@ impl OrbitCamPreset {
         }
     }
 
+    /// Sets where zoom input moves the camera toward.
+    ///
+    /// Every preset defaults to [`OrbitCamZoomAnchor::Focus`].
+    ///
+    /// Keyboard and gamepad presets have no cursor to zoom toward: on them this
+    /// is a no-op that discards `zoom_anchor`, and their bindings always zoom
+    /// toward the focus. Use [`Self::simple_mouse_keyboard`] or
+    /// [`Self::blender_like_keyboard`] for keyboard controls that zoom toward
+    /// the cursor.
+    #[must_use]
+    pub const fn zoom_anchor(self, zoom_anchor: OrbitCamZoomAnchor) -> Self {
+        match self {
+            Self::SimpleMouse(preset) => Self::SimpleMouse(preset.zoom_anchor(zoom_anchor)),
+            Self::BlenderLike(preset) => Self::BlenderLike(preset.zoom_anchor(zoom_anchor)),
+            Self::SimpleMouseKeyboard(preset) => {
+                Self::SimpleMouseKeyboard(preset.zoom_anchor(zoom_anchor))
+            },
+            Self::BlenderLikeKeyboard(preset) => {
+                Self::BlenderLikeKeyboard(preset.zoom_anchor(zoom_anchor))
+            },
+            Self::Keyboard(_) | Self::Gamepad(_) => self,
+        }
+    }
+
+    /// Sets which point orbit drags rotate the camera around.
+    ///
+    /// Every preset defaults to [`OrbitCamOrbitPivot::Focus`].
+    ///
+    /// Keyboard and gamepad presets have no cursor to pick with: on them this
+    /// is a no-op that discards `orbit_pivot`, and their bindings always orbit
+    /// the focus. Use [`Self::simple_mouse_keyboard`] or
+    /// [`Self::blender_like_keyboard`] for keyboard controls that orbit a
+    /// picked point.
+    #[must_use]
+    pub const fn orbit_pivot(self, orbit_pivot: OrbitCamOrbitPivot) -> Self {
+        match self {
+            Self::SimpleMouse(preset) => Self::SimpleMouse(preset.orbit_pivot(orbit_pivot)),
+            Self::BlenderLike(preset) => Self::BlenderLike(preset.orbit_pivot(orbit_pivot)),
+            Self::SimpleMouseKeyboard(preset) => {
+                Self::SimpleMouseKeyboard(preset.orbit_pivot(orbit_pivot))
+            },
+            Self::BlenderLikeKeyboard(preset) => {
+                Self::BlenderLikeKeyboard(preset.orbit_pivot(orbit_pivot))
+            },
+            Self::Keyboard(_) | Self::Gamepad(_) => self,
+        }
+    }
+
     /// Converts this preset into validated custom bindings.
     ///
     /// # Errors
@@ -247,4 +297,43 @@ mod tests {
         assert_preset_home_round_trip(OrbitCamPreset::gamepad())?;
         Ok(())
     }
+
+    fn assert_preset_anchors(
+        preset: OrbitCamPreset,
+        zoom_anchor: OrbitCamZoomAnchor,
+        orbit_pivot: OrbitCamOrbitPivot,
+    ) -> Result<(), BindingsError> {
+        let bindings = preset.to_bindings()?;
+        assert_eq!(bindings.zoom_anchor(), OrbitCamZoomAnchor::Focus);
+        assert_eq!(bindings.orbit_pivot(), OrbitCamOrbitPivot::Focus);
+
+        let bindings = preset
+            .zoom_anchor(OrbitCamZoomAnchor::Cursor)
+            .orbit_pivot(OrbitCamOrbitPivot::PickedPoint)
+            .to_bindings()?;
+
+        assert_eq!(bindings.zoom_anchor(), zoom_anchor);
+        assert_eq!(bindings.orbit_pivot(), orbit_pivot);
+        Ok(())
+    }
+
+    #[test]
+    fn enum_anchor_dispatch_reaches_only_pointer_presets() -> Result<(), BindingsError> {
+        for preset in [
+            OrbitCamPreset::simple_mouse(),
+            OrbitCamPreset::blender_like(),
+            OrbitCamPreset::simple_mouse_keyboard(),
+            OrbitCamPreset::blender_like_keyboard(),
+        ] {
+            assert_preset_anchors(
+                preset,
+                OrbitCamZoomAnchor::Cursor,
+                OrbitCamOrbitPivot::PickedPoint,
+            )?;
+        }
+        for preset in [OrbitCamPreset::keyboard(), OrbitCamPreset::gamepad()] {
+            assert_preset_anchors(preset, OrbitCamZoomAnchor::Focus, OrbitCamOrbitPivot::Focus)?;
+        }
+        Ok(())
+    }
 }
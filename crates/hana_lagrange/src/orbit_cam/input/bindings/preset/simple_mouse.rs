@@ -10,8 +10,10 @@ use crate::orbit_cam::input::bindings::OrbitCamBindingsBuilder;
 use crate::orbit_cam::input::bindings::OrbitCamInputGain;
 use crate::orbit_cam::input::bindings::OrbitCamMouseDrag;
 use crate::orbit_cam::input::bindings::OrbitCamMouseWheelZoom;
+use crate::orbit_cam::input::bindings::OrbitCamOrbitPivot;
 use crate::orbit_cam::input::bindings::OrbitCamPinchZoom;
 use crate::orbit_cam::input::bindings::OrbitCamTrackpadScroll;
+use crate::orbit_cam::input::bindings::OrbitCamZoomAnchor;
 
 /// Configures the default mouse-oriented orbit-camera preset.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
@@ -20,6 +22,8 @@ pub struct OrbitCamSimpleMousePreset {
     mouse_input_gain:         OrbitCamInputGain,
     smooth_scroll_input_gain: OrbitCamInputGain,
     home:                     [Option<Binding>; 2],
+    zoom_anchor:              OrbitCamZoomAnchor,
+    orbit_pivot:              OrbitCamOrbitPivot,
 }
 
 impl OrbitCamSimpleMousePreset {
@@ -66,6 +70,33 @@ impl OrbitCamSimpleMousePreset {
     #[must_use]
     pub const fn has_home(&self) -> bool { matches!(self.home, [Some(_), _] | [_, Some(_)]) }
 
+    /// Sets where zoom input moves the camera toward.
+    ///
+    /// Defaults to [`OrbitCamZoomAnchor::Focus`].
+    #[must_use]
+    pub const fn zoom_anchor(mut self, zoom_anchor: OrbitCamZoomAnchor) -> Self {
+        self.zoom_anchor = zoom_anchor;
+        self
+    }
+
+    /// Sets which point orbit drags rotate the camera around.
+    ///
+    /// Defaults to [`OrbitCamOrbitPivot::Focus`].
+    #[must_use]
+    pub const fn orbit_pivot(mut self, orbit_pivot: OrbitCamOrbitPivot) -> Self {
+        self.orbit_pivot = orbit_pivot;
+        self
+    }
+
+    pub(super) const fn anchor_into(
+        self,
+        builder: OrbitCamBindingsBuilder,
+    ) -> OrbitCamBindingsBuilder {
+        builder
+            .zoom_anchor(self.zoom_anchor)
+            .orbit_pivot(self.orbit_pivot)
+    }
+
     pub(super) fn build_into(
         self,
         builder: OrbitCamBindingsBuilder,
@@ -124,7 +155,8 @@ impl SmoothScrollInputGain for OrbitCamSimpleMousePreset {
 
 impl OrbitCamPresetConfig for OrbitCamSimpleMousePreset {
     fn build(self) -> Result<OrbitCamBindings, BindingsError> {
-        self.build_into(OrbitCamBindings::builder())?.build()
+        self.build_into(self.anchor_into(OrbitCamBindings::builder()))?
+            .build()
     }
 }
 
@@ -75,6 +75,8 @@ pub(super) fn validate_bindings(
         touch:            descriptor.touch,
         gamepad:          descriptor.gamepad,
         zoom_inversion:   descriptor.zoom_inversion,
+        zoom_anchor:      descriptor.zoom_anchor,
+        orbit_pivot:      descriptor.orbit_pivot,
         button_drag_zoom: descriptor.button_drag_zoom,
         slow_mode:        descriptor.slow_mode,
     })
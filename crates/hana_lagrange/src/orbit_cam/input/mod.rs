@@ -21,6 +21,7 @@ pub use bindings::OrbitCamMouseDrag;
 pub use bindings::OrbitCamMouseWheelZoom;
 pub use bindings::OrbitCamOrbitActionBindings;
 pub use bindings::OrbitCamOrbitBinding;
+pub use bindings::OrbitCamOrbitPivot;
 pub use bindings::OrbitCamPanActionBindings;
 pub use bindings::OrbitCamPanBinding;
 pub use bindings::OrbitCamPinchZoom;
@@ -31,6 +32,7 @@ pub use bindings::OrbitCamSimpleMousePreset;
 pub use bindings::OrbitCamTouchBinding;
 pub use bindings::OrbitCamTouchBindingConfig;
 pub use bindings::OrbitCamTrackpadScroll;
+pub use bindings::OrbitCamZoomAnchor;
 pub use bindings::OrbitCamZoomBinding;
 pub use bindings::OrbitCamZoomCoarseActionBindings;
 pub use bindings::OrbitCamZoomSmoothActionBindings;
@@ -2,6 +2,7 @@
 
 mod constants;
 mod controller;
+mod cursor_anchor;
 mod drag_state;
 mod input;
 mod intent;
@@ -17,6 +18,7 @@ use constants::DEFAULT_PAN_SMOOTHNESS;
 use constants::DEFAULT_ZOOM_LOWER_LIMIT;
 use constants::DEFAULT_ZOOM_SMOOTHNESS;
 use controller::orbit_cam;
+use cursor_anchor::CursorAnchor;
 use drag_state::OrbitDragState;
 pub use input::CameraInputGamepadSelectionPolicy;
 pub use input::GamepadInputGain;
@@ -38,6 +40,7 @@ pub use input::OrbitCamMouseDrag;
 pub use input::OrbitCamMouseWheelZoom;
 pub use input::OrbitCamOrbitActionBindings;
 pub use input::OrbitCamOrbitBinding;
+pub use input::OrbitCamOrbitPivot;
 pub use input::OrbitCamPanActionBindings;
 pub use input::OrbitCamPanBinding;
 pub use input::OrbitCamPinchZoom;
@@ -48,6 +51,7 @@ pub use input::OrbitCamSimpleMousePreset;
 pub use input::OrbitCamTouchBinding;
 pub use input::OrbitCamTouchBindingConfig;
 pub use input::OrbitCamTrackpadScroll;
+pub use input::OrbitCamZoomAnchor;
 pub use input::OrbitCamZoomBinding;
 pub use input::OrbitCamZoomCoarseActionBindings;
 pub use input::OrbitCamZoomSmoothActionBindings;
@@ -114,11 +118,14 @@ impl CameraKind for OrbitCamKind {
             .add_plugins(OrbitCamInputAdapterPlugin)
             .add_systems(
                 PostUpdate,
-                orbit_cam
-                    .in_set(OrbitCamSystemSet)
-                    .in_set(CameraControllerSystemSet)
-                    .before(TransformSystems::Propagate)
-                    .before(CameraUpdateSystems),
+                (
+                    cursor_anchor::resolve_cursor_anchors.before(orbit_cam),
+                    orbit_cam
+                        .in_set(CameraControllerSystemSet)
+                        .before(TransformSystems::Propagate)
+                        .before(CameraUpdateSystems),
+                )
+                    .in_set(OrbitCamSystemSet),
             );
         camera_home::add_home_systems::<Self>(app);
         camera_home::add_orbit_cam_home_reset_systems(app);
@@ -153,7 +160,7 @@ impl CameraHomeKind for OrbitCamKind {
     }
 }
 
-/// Schedule label for the private `OrbitCam` controller system.
+/// Schedule label for the private `OrbitCam` controller systems.
 ///
 /// Use this in `PostUpdate` to run systems before the controller reads
 /// `OrbitCam` and `OrbitCamInput`, or after it writes the camera `Transform`.
@@ -217,6 +224,7 @@ pub enum OrbitCamUpdateRequest {
     Camera3d,
     Transform,
     CameraBasis,
+    CursorAnchor,
     OrbitDragState,
     OrbitCamInput,
     OrbitCamInputContext,
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use bevy::prelude::World;
+use bevy::reflect::FromType;
+use bevy::reflect::TypeInfo;
+use bevy::reflect::Typed;
+use serde::de::DeserializeOwned;
+use serde_json_lenient::Value;
+
+use super::KeymapCommand;
+
+/// Typed binding arguments accepted by a [`KeymapCommand`].
+///
+/// A keymap binds an argument-taking command with a two-element array, such as
+/// `"ctrl-1": ["app::select_tab", { "index": 1 }]`. The second element is deserialized into
+/// [`Self::Arguments`] when the keymap loads, so a malformed argument is reported as a keymap
+/// diagnostic rather than when the key is pressed. A binding that omits the arguments still fires
+/// [`KeymapCommand::build`].
+///
+/// Register the type data next to [`ReflectKeymapCommand`](super::ReflectKeymapCommand):
+///
+/// ```ignore
+/// #[derive(Event, Reflect)]
+/// #[reflect(Event, KeymapCommand, KeymapCommandArguments)]
+/// struct SelectTab {
+///     index: usize,
+/// }
+///
+/// #[derive(Deserialize, Reflect)]
+/// struct SelectTabArguments {
+///     index: usize,
+/// }
+///
+/// impl KeymapCommandArguments for SelectTab {
+///     type Arguments = SelectTabArguments;
+///
+///     fn build_with(arguments: SelectTabArguments) -> Self {
+///         Self {
+///             index: arguments.index,
+///         }
+///     }
+/// }
+/// ```
+pub trait KeymapCommandArguments: KeymapCommand {
+    /// The reflected argument payload a binding may carry.
+    ///
+    /// The generated keymap schema describes this type from its reflected field names, so its
+    /// serde representation should not rename fields.
+    type Arguments: DeserializeOwned + Typed;
+
+    /// Constructs this event from a binding's validated arguments.
+    #[must_use]
+    fn build_with(arguments: Self::Arguments) -> Self;
+}
+
+/// Reflection type data retained for each [`KeymapCommandArguments`] event registration.
+#[derive(Clone, Debug)]
+pub struct ReflectKeymapCommandArguments {
+    /// Type path of the reflected argument payload.
+    pub type_path:        &'static str,
+    pub(crate) type_info: fn() -> &'static TypeInfo,
+    pub(crate) validate:  fn(&Value) -> Result<(), String>,
+    pub(crate) dispatch:  fn(&mut World, &Value),
+}
+
+impl<T> FromType<T> for ReflectKeymapCommandArguments
+where
+    T: KeymapCommandArguments,
+    for<'a> T::Trigger<'a>: Default,
+{
+    fn from_type() -> Self {
+        Self {
+            type_path: T::Arguments::type_info().type_path(),
+            type_info: T::Arguments::type_info,
+            validate:  validate_arguments::<T>,
+            dispatch:  dispatch_with_arguments::<T>,
+        }
+    }
+}
+
+fn validate_arguments<T: KeymapCommandArguments>(arguments: &Value) -> Result<(), String> {
+    serde_json_lenient::from_value::<T::Arguments>(arguments.clone())
+        .map(drop)
+        .map_err(|error| error.to_string())
+}
+
+fn dispatch_with_arguments<T>(world: &mut World, arguments: &Value)
+where
+    T: KeymapCommandArguments,
+    for<'a> T::Trigger<'a>: Default,
+{
+    if let Ok(arguments) = serde_json_lenient::from_value::<T::Arguments>(arguments.clone()) {
+        world.trigger(T::build_with(arguments));
+    }
+}
+
+/// The argument value authored after a command ID in a keymap binding.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct BindingArguments(Arc<Value>);
+
+impl BindingArguments {
+    pub(crate) fn new(value: Value) -> Self { Self(Arc::new(value)) }
+
+    pub(crate) fn value(&self) -> &Value { &self.0 }
+}
+
+/// How a compiled binding triggers its command event.
+#[derive(Clone)]
+pub(crate) enum CommandDispatch {
+    /// Triggers the event built by [`KeymapCommand::build`].
+    Build(fn(&mut World)),
+    /// Triggers the event built by [`KeymapCommandArguments::build_with`] from the binding's
+    /// validated arguments.
+    BuildWith(fn(&mut World, &Value), BindingArguments),
+}
+
+impl CommandDispatch {
+    /// Returns the binding arguments the event is built from, if any.
+    pub(crate) const fn arguments(&self) -> Option<&BindingArguments> {
+        match self {
+            Self::Build(_) => None,
+            Self::BuildWith(_, arguments) => Some(arguments),
+        }
+    }
+
+    pub(crate) fn run(&self, world: &mut World) {
+        match self {
+            Self::Build(dispatch) => dispatch(world),
+            Self::BuildWith(dispatch, arguments) => dispatch(world, arguments.value()),
+        }
+    }
+}
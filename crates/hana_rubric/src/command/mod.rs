@@ -1,4 +1,5 @@
 mod action;
+mod arguments;
 mod bind_action_system;
 mod capability;
 mod event;
@@ -7,6 +8,10 @@ mod keybindings;
 mod reflect_command;
 mod registry;
 
+pub(crate) use arguments::BindingArguments;
+pub(crate) use arguments::CommandDispatch;
+pub use arguments::KeymapCommandArguments;
+pub use arguments::ReflectKeymapCommandArguments;
 pub use capability::Capability;
 pub use capability::HoldPhase;
 pub use id::CommandId;
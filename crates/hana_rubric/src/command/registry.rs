@@ -12,11 +12,14 @@ use bevy_enhanced_input::prelude::ActionValue;
 use bevy_enhanced_input::prelude::CustomInput;
 use bevy_enhanced_input::prelude::CustomInputs;
 
+use super::BindingArguments;
 use super::Capability;
+use super::CommandDispatch;
 use super::CommandId;
 use super::HoldPhase;
 use super::KeymapCommand;
 use super::ReflectKeymapCommand;
+use super::ReflectKeymapCommandArguments;
 use crate::Diagnostic;
 use crate::DiagnosticKind;
 use crate::DiagnosticOrigin;
@@ -42,6 +45,8 @@ pub struct CommandInfo<'registry> {
     pub description: &'registry str,
     /// Declared keymap binding capability.
     pub capability:  Capability,
+    /// Type path of the typed binding arguments the command accepts, if any.
+    pub arguments:   Option<&'registry str>,
 }
 
 /// The result of resolving a command ID against the registry's declared metadata.
@@ -129,6 +134,7 @@ impl CommandRegistry {
                     description: declaration.command.description,
                     capability,
                     dispatch: declaration.command.dispatch,
+                    arguments: declaration.arguments,
                     invocation,
                 };
 
@@ -149,6 +155,7 @@ impl CommandRegistry {
             title:       entry.title,
             description: entry.description,
             capability:  entry.capability,
+            arguments:   entry.arguments_type_path(),
         })
     }
 
@@ -169,11 +176,21 @@ impl CommandRegistry {
                     title:       entry.title,
                     description: entry.description,
                     capability:  entry.capability,
+                    arguments:   entry.arguments_type_path(),
                 })
             },
         )
     }
 
+    pub(crate) fn arguments(
+        &self,
+        command_id: &CommandId,
+    ) -> Option<&ReflectKeymapCommandArguments> {
+        self.entries
+            .get(command_id)
+            .and_then(CommandEntry::arguments)
+    }
+
     /// Resolves whether `command_id` has a preallocated held custom input.
     #[must_use]
     pub fn held_command_lookup(&self, command_id: &CommandId) -> HeldCommandLookupOutcome {
@@ -194,15 +211,34 @@ impl CommandRegistry {
     /// A hold-to-act command carries a [`HoldPhase`] its caller must choose, so it is rejected
     /// before its event is constructed.
     pub fn invoke(&self, command_id: &CommandId, world: &mut World) -> CommandInvocationOutcome {
-        let Some(entry) = self.entries.get(command_id) else {
-            return CommandInvocationOutcome::UnknownCommand;
-        };
-        if matches!(entry.invocation, Invocation::Held(_)) {
-            return CommandInvocationOutcome::HeldCommandRequiresPhase;
+        self.invoke_with(command_id, None, world)
+    }
+
+    /// Reports what [`Self::invoke`] would do with `command_id`, without dispatching it.
+    pub(crate) fn invocation_outcome(&self, command_id: &CommandId) -> CommandInvocationOutcome {
+        match self.entries.get(command_id) {
+            None => CommandInvocationOutcome::UnknownCommand,
+            Some(entry) if matches!(entry.invocation, Invocation::Held(_)) => {
+                CommandInvocationOutcome::HeldCommandRequiresPhase
+            },
+            Some(_) => CommandInvocationOutcome::Invoked,
         }
+    }
 
-        (entry.dispatch)(world);
-        CommandInvocationOutcome::Invoked
+    /// Dispatches `command_id` the way a binding carrying `arguments` would.
+    pub(crate) fn invoke_with(
+        &self,
+        command_id: &CommandId,
+        arguments: Option<&BindingArguments>,
+        world: &mut World,
+    ) -> CommandInvocationOutcome {
+        let outcome = self.invocation_outcome(command_id);
+        if let (CommandInvocationOutcome::Invoked, Some(entry)) =
+            (outcome, self.entries.get(command_id))
+        {
+            entry.command_dispatch(arguments).run(world);
+        }
+        outcome
     }
 
     pub(crate) fn register_held_observers(&self, world: &mut World) {
@@ -256,11 +292,29 @@ pub(crate) struct CommandEntry {
     description: &'static str,
     capability:  Capability,
     dispatch:    fn(&mut World),
+    arguments:   Option<ReflectKeymapCommandArguments>,
     invocation:  Invocation,
 }
 
 impl CommandEntry {
-    pub(crate) const fn dispatch(&self) -> fn(&mut World) { self.dispatch }
+    /// Selects how a binding fires this command, building the event from `arguments` when the
+    /// binding carries them and the command declares an argument type.
+    pub(crate) fn command_dispatch(&self, arguments: Option<&BindingArguments>) -> CommandDispatch {
+        match (&self.arguments, arguments) {
+            (Some(command_arguments), Some(arguments)) => {
+                CommandDispatch::BuildWith(command_arguments.dispatch, arguments.clone())
+            },
+            _ => CommandDispatch::Build(self.dispatch),
+        }
+    }
+
+    pub(crate) const fn arguments(&self) -> Option<&ReflectKeymapCommandArguments> {
+        self.arguments.as_ref()
+    }
+
+    fn arguments_type_path(&self) -> Option<&'static str> {
+        self.arguments.as_ref().map(|arguments| arguments.type_path)
+    }
 
     pub(crate) const fn invocation(&self) -> Invocation { self.invocation }
 }
@@ -301,12 +355,14 @@ impl Invocation {
 struct CommandDeclaration {
     type_path:     &'static str,
     command:       ReflectKeymapCommand,
+    arguments:     Option<ReflectKeymapCommandArguments>,
     reflect_event: Option<ReflectEvent>,
 }
 
 struct ValidatedDeclaration {
     command_id: CommandId,
     command:    ReflectKeymapCommand,
+    arguments:  Option<ReflectKeymapCommandArguments>,
 }
 
 fn command_declarations(type_registry: &TypeRegistry) -> Vec<CommandDeclaration> {
@@ -315,6 +371,9 @@ fn command_declarations(type_registry: &TypeRegistry) -> Vec<CommandDeclaration>
         .map(|(registration, command)| CommandDeclaration {
             type_path:     registration.type_info().type_path(),
             command:       command.clone(),
+            arguments:     registration
+                .data::<ReflectKeymapCommandArguments>()
+                .cloned(),
             reflect_event: registration.data::<ReflectEvent>().cloned(),
         })
         .collect::<Vec<_>>();
@@ -402,6 +461,7 @@ fn validate_declarations(
             validated_declarations.push(ValidatedDeclaration {
                 command_id,
                 command: declaration.command,
+                arguments: declaration.arguments,
             });
         }
     }
@@ -18,6 +18,8 @@ pub enum DiagnosticKind {
     Keystroke,
     /// A binding refers to an unavailable command.
     Command,
+    /// A binding's arguments are not accepted by its command's argument type.
+    BindingArguments,
     /// A binding refers to an unavailable input context.
     Context,
     /// The keymap file could not be read or watched.
@@ -47,6 +49,16 @@ pub enum DiagnosticKind {
     /// The keymap plugin was assembled without an application name, embedded defaults, or a
     /// protected keystroke, so it has nothing to compile bindings from.
     UnconfiguredKeymapPlugin,
+    /// Two equally specific blocks in one keymap layer bind the same keystroke sequence, so the
+    /// later one silently replaces the earlier.
+    DuplicateBinding,
+    /// A binding is a strict prefix of a longer sequence in the same scope, so it fires only after
+    /// the sequence timeout.
+    PrefixConflict,
+    /// A user binding replaces a shipped default binding with a different command.
+    ShadowedDefault,
+    /// A binding is overridden in every condition input can route through, so it never fires.
+    UnreachableBinding,
 }
 
 /// What produced a [`Diagnostic`]. Only [`DiagnosticOrigin::KeymapFile`] names a keymap source
@@ -12,6 +12,8 @@ use std::path::PathBuf;
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 
+use super::config_file::read_config_file;
+use super::config_file::replace_config_file;
 use super::constants::COMPANION_FILE_MODE;
 use super::constants::TEMPORARY_FILE_ATTEMPTS;
 use super::paths::KeymapPaths;
@@ -52,6 +54,20 @@ pub(super) fn publish_companion_files(
     diagnostics
 }
 
+/// Reads the recorded keymap macros, reporting a missing file as `None`.
+pub(crate) fn read_macros(paths: &KeymapPaths) -> io::Result<Option<Vec<u8>>> {
+    read_config_file(paths.macros())
+}
+
+/// Replaces the recorded keymap macros in one rename.
+///
+/// Unlike the generated companions, the macros file is never made read-only: it holds the user's
+/// own recordings, which they may prune by hand, so it is replaced the way the user keymap is.
+pub(crate) fn replace_macros(paths: &KeymapPaths, contents: &[u8]) -> io::Result<()> {
+    fs::create_dir_all(paths.config_directory())?;
+    replace_config_file(paths.macros(), contents)
+}
+
 fn publish_companion_file(destination: &Path, contents: &[u8]) -> io::Result<()> {
     if existing_regular_file_matches(destination, contents)? {
         return Ok(());
@@ -88,19 +104,16 @@ fn existing_regular_file_matches(destination: &Path, contents: &[u8]) -> io::Res
     }
 }
 
-fn create_temporary_file(destination: &Path) -> io::Result<(File, PathBuf)> {
+pub(super) fn create_temporary_file(destination: &Path) -> io::Result<(File, PathBuf)> {
     let directory = destination.parent().ok_or_else(|| {
         io::Error::new(
             ErrorKind::InvalidInput,
-            "companion destination has no parent directory",
-        )
-    })?;
-    let file_name = destination.file_name().ok_or_else(|| {
-        io::Error::new(
-            ErrorKind::InvalidInput,
-            "companion destination has no file name",
+            "destination has no parent directory",
         )
     })?;
+    let file_name = destination
+        .file_name()
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "destination has no file name"))?;
 
     for _ in 0..TEMPORARY_FILE_ATTEMPTS {
         let temporary_file_id = NEXT_TEMPORARY_FILE_ID.fetch_add(1, Ordering::Relaxed);
@@ -124,11 +137,11 @@ fn create_temporary_file(destination: &Path) -> io::Result<(File, PathBuf)> {
 
     Err(io::Error::new(
         ErrorKind::AlreadyExists,
-        "could not create a unique companion temporary file",
+        "could not create a unique temporary file",
     ))
 }
 
-fn write_temporary_file(temporary_file: &mut File, contents: &[u8]) -> io::Result<()> {
+pub(super) fn write_temporary_file(temporary_file: &mut File, contents: &[u8]) -> io::Result<()> {
     temporary_file.write_all(contents)?;
     temporary_file.sync_all()
 }
@@ -146,11 +159,13 @@ fn set_companion_permissions(path: &Path) -> io::Result<()> {
     fs::set_permissions(path, permissions)
 }
 
-fn replace_file(temporary_path: &Path, destination: &Path) -> io::Result<()> {
+pub(super) fn replace_file(temporary_path: &Path, destination: &Path) -> io::Result<()> {
     fs::rename(temporary_path, destination)
 }
 
-fn remove_temporary_file(temporary_path: &Path) { let _ = fs::remove_file(temporary_path); }
+pub(super) fn remove_temporary_file(temporary_path: &Path) {
+    let _ = fs::remove_file(temporary_path);
+}
 
 fn companion_diagnostic(origin: DiagnosticOrigin, error: Error) -> Diagnostic {
     Diagnostic {
@@ -0,0 +1,123 @@
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::companion_files::create_temporary_file;
+use super::companion_files::remove_temporary_file;
+use super::companion_files::replace_file;
+use super::companion_files::write_temporary_file;
+
+/// Reads a configuration file, reporting a missing file as `None`.
+pub(crate) fn read_config_file(path: &Path) -> io::Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Replaces a configuration file's contents in one rename.
+///
+/// A symlinked file keeps its link: the rename lands on the file the link resolves to, in that
+/// file's directory, so the link and its target stay what the user made them. The replacement
+/// keeps the permissions of the file it replaces.
+pub(crate) fn replace_config_file(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let destination = resolved_destination(path)?;
+    let permissions = match fs::metadata(&destination) {
+        Ok(metadata) => Some(metadata.permissions()),
+        Err(error) if error.kind() == ErrorKind::NotFound => None,
+        Err(error) => return Err(error),
+    };
+
+    let (mut temporary_file, temporary_path) = create_temporary_file(&destination)?;
+    let result = write_temporary_file(&mut temporary_file, contents);
+    drop(temporary_file);
+
+    let result = result.and_then(|()| {
+        permissions.map_or(Ok(()), |permissions| {
+            fs::set_permissions(&temporary_path, permissions)
+        })
+    });
+    if let Err(error) = result.and_then(|()| replace_file(&temporary_path, &destination)) {
+        remove_temporary_file(&temporary_path);
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+fn resolved_destination(path: &Path) -> io::Result<PathBuf> {
+    match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_symlink() => fs::canonicalize(path),
+        Ok(_) => Ok(path.to_path_buf()),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(path.to_path_buf()),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    #[cfg(unix)]
+    use std::os::unix::fs::symlink;
+
+    use super::read_config_file;
+    use super::replace_config_file;
+    use crate::disk::paths::TestDirectory;
+
+    #[test]
+    fn replacement_writes_new_contents_and_missing_files_read_as_absent() -> Result<(), String> {
+        let temporary_directory =
+            TestDirectory::new("user-keymap-replace").map_err(|error| error.to_string())?;
+        let user_keymap = temporary_directory.path().join("keymap.jsonc");
+
+        assert_eq!(
+            read_config_file(&user_keymap).map_err(|error| error.to_string())?,
+            None
+        );
+        replace_config_file(&user_keymap, b"{ \"bindings\": [] }")
+            .map_err(|error| error.to_string())?;
+        replace_config_file(&user_keymap, b"// edited\n{ \"bindings\": [] }")
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            read_config_file(&user_keymap).map_err(|error| error.to_string())?,
+            Some(b"// edited\n{ \"bindings\": [] }".to_vec())
+        );
+        let leftover_files = fs::read_dir(temporary_directory.path())
+            .map_err(|error| error.to_string())?
+            .count();
+        assert_eq!(
+            leftover_files, 1,
+            "the temporary file is renamed into place"
+        );
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn replacement_writes_through_a_symlinked_keymap() -> Result<(), String> {
+        let temporary_directory =
+            TestDirectory::new("user-keymap-symlink").map_err(|error| error.to_string())?;
+        let dotfiles = temporary_directory.path().join("dotfiles");
+        fs::create_dir_all(&dotfiles).map_err(|error| error.to_string())?;
+        let target = dotfiles.join("keymap.jsonc");
+        let user_keymap = temporary_directory.path().join("keymap.jsonc");
+        fs::write(&target, b"{ \"bindings\": [] }").map_err(|error| error.to_string())?;
+        symlink(&target, &user_keymap).map_err(|error| error.to_string())?;
+
+        replace_config_file(&user_keymap, b"// edited\n{ \"bindings\": [] }")
+            .map_err(|error| error.to_string())?;
+
+        let link_metadata =
+            fs::symlink_metadata(&user_keymap).map_err(|error| error.to_string())?;
+        assert!(link_metadata.file_type().is_symlink());
+        assert_eq!(
+            fs::read(&target).map_err(|error| error.to_string())?,
+            b"// edited\n{ \"bindings\": [] }"
+        );
+        Ok(())
+    }
+}
@@ -5,10 +5,18 @@ use std::time::Duration;
 pub(crate) const MAX_RETAINED_DIAGNOSTICS: usize = 64;
 
 // filenames
+pub(super) const ACTIVE_PROFILE_FILE_NAME: &str = "active-profile";
 pub(super) const DEFAULT_KEYMAP_FILE_NAME: &str = "keymap.default.jsonc";
+pub(super) const MACROS_FILE_NAME: &str = "macros.jsonc";
 pub(super) const SCHEMA_FILE_NAME: &str = "keymap.schema.json";
+pub(super) const PROFILE_FILE_EXTENSION: &str = "jsonc";
+pub(super) const PROFILES_DIRECTORY_NAME: &str = "profiles";
 pub(super) const USER_KEYMAP_FILE_NAME: &str = "keymap.jsonc";
 
+// profiles
+pub(super) const DEFAULT_PROFILE_NAME: &str = "default";
+pub(super) const MAX_PROFILE_NAME_LENGTH: usize = 64;
+
 // permissions
 pub(super) const COMPANION_FILE_MODE: u32 = 0o444;
 
@@ -6,10 +6,16 @@
 //! first run.
 
 mod companion_files;
+mod config_file;
 mod constants;
 mod paths;
+mod profile_files;
 mod worker;
 
+pub(crate) use companion_files::read_macros;
+pub(crate) use companion_files::replace_macros;
+pub(crate) use config_file::read_config_file;
+pub(crate) use config_file::replace_config_file;
 pub(crate) use constants::MAX_RETAINED_DIAGNOSTICS;
 #[cfg(test)]
 pub(crate) use paths::ENVIRONMENT_LOCK;
@@ -21,9 +27,17 @@ pub use paths::KeymapPaths;
 pub(crate) use paths::TestDirectory;
 #[cfg(test)]
 pub(crate) use paths::XdgConfigHome;
+pub use profile_files::KeymapProfileName;
+pub use profile_files::KeymapProfileNameError;
+pub(crate) use profile_files::migrate_profiles;
+pub(crate) use profile_files::profile_names;
+pub(crate) use profile_files::read_profile;
+pub(crate) use profile_files::write_active_profile;
+pub(crate) use profile_files::write_profile;
 pub(crate) use worker::DiskDelivery;
 pub(crate) use worker::DiskWorkerChannels;
 pub(crate) use worker::DiskWorkerMessage;
+pub(crate) use worker::USER_KEYMAP_STUB;
 pub(crate) use worker::start_disk_worker;
 
 pub(super) fn take_worker_message(
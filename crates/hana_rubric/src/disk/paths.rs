@@ -18,7 +18,12 @@ use std::sync::atomic::Ordering;
 use bevy::prelude::Reflect;
 use bevy::prelude::Resource;
 
+use super::KeymapProfileName;
+use super::constants::ACTIVE_PROFILE_FILE_NAME;
 use super::constants::DEFAULT_KEYMAP_FILE_NAME;
+use super::constants::MACROS_FILE_NAME;
+use super::constants::PROFILE_FILE_EXTENSION;
+use super::constants::PROFILES_DIRECTORY_NAME;
 use super::constants::SCHEMA_FILE_NAME;
 use super::constants::USER_KEYMAP_FILE_NAME;
 use super::constants::XDG_CONFIG_HOME;
@@ -135,13 +140,16 @@ impl KeymapPathFailure {
     }
 }
 
-/// Resolved locations for an application's user keymap and generated companions.
+/// Resolved locations for an application's user keymap, generated companions, and profiles.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct KeymapPaths {
-    config_directory: PathBuf,
-    default_keymap:   PathBuf,
-    schema:           PathBuf,
-    user_keymap:      PathBuf,
+    config_directory:   PathBuf,
+    default_keymap:     PathBuf,
+    schema:             PathBuf,
+    user_keymap:        PathBuf,
+    macros:             PathBuf,
+    profiles_directory: PathBuf,
+    active_profile:     PathBuf,
 }
 
 impl KeymapPaths {
@@ -161,11 +169,35 @@ impl KeymapPaths {
     #[must_use]
     pub fn user_keymap(&self) -> &Path { &self.user_keymap }
 
+    /// Returns the JSONC file that stores recorded keymap macros.
+    #[must_use]
+    pub fn macros(&self) -> &Path { &self.macros }
+
+    /// Returns the directory that stores every named keymap profile.
+    #[must_use]
+    pub fn profiles_directory(&self) -> &Path { &self.profiles_directory }
+
+    /// Returns the file that names the active keymap profile.
+    #[must_use]
+    pub fn active_profile(&self) -> &Path { &self.active_profile }
+
+    /// Returns the stored JSONC path of the named keymap profile.
+    #[must_use]
+    pub fn profile(&self, profile: &KeymapProfileName) -> PathBuf {
+        self.profiles_directory
+            .join(profile.as_str())
+            .with_extension(PROFILE_FILE_EXTENSION)
+    }
+
     fn from_config_directory(config_directory: PathBuf) -> Self {
+        let profiles_directory = config_directory.join(PROFILES_DIRECTORY_NAME);
         Self {
             default_keymap: config_directory.join(DEFAULT_KEYMAP_FILE_NAME),
             schema: config_directory.join(SCHEMA_FILE_NAME),
             user_keymap: config_directory.join(USER_KEYMAP_FILE_NAME),
+            macros: config_directory.join(MACROS_FILE_NAME),
+            active_profile: profiles_directory.join(ACTIVE_PROFILE_FILE_NAME),
+            profiles_directory,
             config_directory,
         }
     }
@@ -282,6 +314,7 @@ mod tests {
     use super::KeymapPathFailure;
     use super::TestDirectory;
     use super::XdgConfigHome;
+    #[cfg(target_os = "macos")]
     use super::configuration_root;
 
     const TEST_APP_NAME: &str = "hana-rubric-paths-test";
@@ -0,0 +1,278 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+use super::KeymapPaths;
+use super::config_file::read_config_file;
+use super::config_file::replace_config_file;
+use super::constants::DEFAULT_PROFILE_NAME;
+use super::constants::MAX_PROFILE_NAME_LENGTH;
+use super::constants::PROFILE_FILE_EXTENSION;
+use super::worker::USER_KEYMAP_STUB;
+
+/// The name of a keymap profile, which is also the stem of its stored file.
+///
+/// Names are 1 to 64 lowercase ASCII letters, digits, `-`, or `_`, such as `default`,
+/// `blender-ish`, or `laptop`. Every name is therefore one file name that no supported platform
+/// folds into another.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KeymapProfileName(String);
+
+impl KeymapProfileName {
+    /// Returns whether `value` is a valid keymap profile name.
+    #[must_use]
+    pub fn is_valid(value: &str) -> bool {
+        (1..=MAX_PROFILE_NAME_LENGTH).contains(&value.len())
+            && value.bytes().all(|byte| {
+                byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-' || byte == b'_'
+            })
+    }
+
+    /// The profile an existing single-file keymap migrates into.
+    #[must_use]
+    pub fn default_profile() -> Self { Self(DEFAULT_PROFILE_NAME.to_owned()) }
+
+    /// Borrows the validated profile name text.
+    #[must_use]
+    pub fn as_str(&self) -> &str { &self.0 }
+}
+
+impl AsRef<str> for KeymapProfileName {
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl Display for KeymapProfileName {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Error returned when text does not satisfy [`KeymapProfileName::is_valid`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeymapProfileNameError;
+
+impl Display for KeymapProfileNameError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(
+            "keymap profile names require 1 to 64 lowercase ASCII letters, digits, `-`, or `_`",
+        )
+    }
+}
+
+impl Error for KeymapProfileNameError {}
+
+impl TryFrom<&str> for KeymapProfileName {
+    type Error = KeymapProfileNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(Self(value.to_owned()))
+        } else {
+            Err(KeymapProfileNameError)
+        }
+    }
+}
+
+impl FromStr for KeymapProfileName {
+    type Err = KeymapProfileNameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> { Self::try_from(value) }
+}
+
+/// Moves a single-file keymap into the profile layout and returns the active profile.
+///
+/// The user keymap stays where it is: it is always the active profile's working copy, the file
+/// the disk watcher reloads and keymap edits write. Migration copies it into the `default`
+/// profile, never replacing a stored profile, and names `default` active. The active-profile file
+/// is written last, so an interrupted migration runs again from the untouched user keymap.
+pub(crate) fn migrate_profiles(paths: &KeymapPaths) -> io::Result<KeymapProfileName> {
+    if let Some(active) = read_active_profile(paths)? {
+        return Ok(active);
+    }
+
+    let default_profile = KeymapProfileName::default_profile();
+    if read_profile(paths, &default_profile)?.is_none() {
+        let contents =
+            read_config_file(paths.user_keymap())?.unwrap_or_else(|| USER_KEYMAP_STUB.to_vec());
+        write_profile(paths, &default_profile, &contents)?;
+    }
+    write_active_profile(paths, &default_profile)?;
+
+    Ok(default_profile)
+}
+
+/// Reads the active profile's name, reporting a layout that predates profiles as `None`.
+pub(crate) fn read_active_profile(paths: &KeymapPaths) -> io::Result<Option<KeymapProfileName>> {
+    let Some(contents) = read_config_file(paths.active_profile())? else {
+        return Ok(None);
+    };
+
+    String::from_utf8(contents)
+        .ok()
+        .and_then(|contents| KeymapProfileName::try_from(contents.trim()).ok())
+        .map(Some)
+        .ok_or_else(|| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "{} does not name a keymap profile",
+                    paths.active_profile().display()
+                ),
+            )
+        })
+}
+
+/// Names `profile` as the active profile.
+pub(crate) fn write_active_profile(
+    paths: &KeymapPaths,
+    profile: &KeymapProfileName,
+) -> io::Result<()> {
+    fs::create_dir_all(paths.profiles_directory())?;
+    replace_config_file(paths.active_profile(), format!("{profile}\n").as_bytes())
+}
+
+/// Lists the stored profiles by name, ignoring files that are not profiles.
+pub(crate) fn profile_names(paths: &KeymapPaths) -> io::Result<Vec<KeymapProfileName>> {
+    let entries = match fs::read_dir(paths.profiles_directory()) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some(PROFILE_FILE_EXTENSION)
+        {
+            continue;
+        }
+        if let Some(name) = path
+            .file_stem()
+            .and_then(|file_stem| file_stem.to_str())
+            .and_then(|file_stem| KeymapProfileName::try_from(file_stem).ok())
+        {
+            names.push(name);
+        }
+    }
+    names.sort();
+
+    Ok(names)
+}
+
+/// Reads a stored profile, reporting a missing one as `None`.
+pub(crate) fn read_profile(
+    paths: &KeymapPaths,
+    profile: &KeymapProfileName,
+) -> io::Result<Option<Vec<u8>>> {
+    read_config_file(&paths.profile(profile))
+}
+
+/// Replaces a stored profile's contents in one rename.
+pub(crate) fn write_profile(
+    paths: &KeymapPaths,
+    profile: &KeymapProfileName,
+    contents: &[u8],
+) -> io::Result<()> {
+    fs::create_dir_all(paths.profiles_directory())?;
+    replace_config_file(&paths.profile(profile), contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::KeymapProfileName;
+    use super::migrate_profiles;
+    use super::profile_names;
+    use super::read_active_profile;
+    use super::read_profile;
+    use super::write_profile;
+    use crate::disk::paths::ENVIRONMENT_LOCK;
+    use crate::disk::paths::KeymapPathAvailability;
+    use crate::disk::paths::TestDirectory;
+    use crate::disk::paths::XdgConfigHome;
+
+    const TEST_APP_NAME: &str = "hana-rubric-profile-files-test";
+    const SINGLE_FILE_KEYMAP: &str = "// my keymap\n{ \"bindings\": [] }\n";
+
+    #[test]
+    fn profile_names_are_portable_file_names() {
+        for name in ["default", "blender-ish", "laptop_2"] {
+            assert!(
+                KeymapProfileName::is_valid(name),
+                "{name} is a profile name"
+            );
+        }
+        for name in [
+            "",
+            "Laptop",
+            "../default",
+            "with space",
+            "default.jsonc",
+            &"a".repeat(65),
+        ] {
+            assert!(
+                !KeymapProfileName::is_valid(name),
+                "{name} is not a profile name"
+            );
+        }
+    }
+
+    #[test]
+    fn migration_copies_a_single_file_keymap_into_the_default_profile() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("profile-migration").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let paths = KeymapPathAvailability::for_app_name(TEST_APP_NAME)
+            .into_resolved()
+            .map_err(|keymap_path_failure| format!("{keymap_path_failure:?}"))?;
+        fs::create_dir_all(paths.config_directory()).map_err(|error| error.to_string())?;
+        fs::write(paths.user_keymap(), SINGLE_FILE_KEYMAP).map_err(|error| error.to_string())?;
+
+        let active = migrate_profiles(&paths).map_err(|error| error.to_string())?;
+
+        let default_profile = KeymapProfileName::default_profile();
+        assert_eq!(active, default_profile);
+        assert_eq!(
+            read_active_profile(&paths).map_err(|error| error.to_string())?,
+            Some(default_profile.clone())
+        );
+        assert_eq!(
+            read_profile(&paths, &default_profile).map_err(|error| error.to_string())?,
+            Some(SINGLE_FILE_KEYMAP.as_bytes().to_vec())
+        );
+        assert_eq!(
+            fs::read_to_string(paths.user_keymap()).map_err(|error| error.to_string())?,
+            SINGLE_FILE_KEYMAP,
+            "the user keymap stays in place as the active profile's working copy"
+        );
+
+        let laptop = KeymapProfileName::try_from("laptop").map_err(|error| error.to_string())?;
+        write_profile(&paths, &laptop, b"{ \"bindings\": [] }")
+            .map_err(|error| error.to_string())?;
+        fs::write(paths.user_keymap(), "{ \"bindings\": [] }")
+            .map_err(|error| error.to_string())?;
+        assert_eq!(
+            migrate_profiles(&paths).map_err(|error| error.to_string())?,
+            default_profile,
+            "a migrated layout is left alone"
+        );
+        assert_eq!(
+            read_profile(&paths, &default_profile).map_err(|error| error.to_string())?,
+            Some(SINGLE_FILE_KEYMAP.as_bytes().to_vec())
+        );
+        assert_eq!(
+            profile_names(&paths).map_err(|error| error.to_string())?,
+            vec![default_profile, laptop]
+        );
+        Ok(())
+    }
+}
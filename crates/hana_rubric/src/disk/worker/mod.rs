@@ -5,6 +5,7 @@ mod watch;
 pub(crate) use channels::DiskDelivery;
 pub(crate) use channels::DiskWorkerChannels;
 pub(crate) use channels::DiskWorkerMessage;
+pub(crate) use runtime::USER_KEYMAP_STUB;
 pub(crate) use runtime::start_disk_worker;
 
 pub(super) fn take_worker_message(
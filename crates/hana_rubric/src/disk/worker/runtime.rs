@@ -37,7 +37,7 @@ use crate::disk::constants::RETRY_INTERVAL;
 use crate::disk::paths::KeymapPaths;
 use crate::keymap::UserKeymapContents;
 
-pub(super) const USER_KEYMAP_STUB: &[u8] = br#"{
+pub(crate) const USER_KEYMAP_STUB: &[u8] = br#"{
   "$schema": "./keymap.schema.json",
   "bindings": []
 }"#;
@@ -0,0 +1,700 @@
+//! A deterministic input-script harness for testing keymaps.
+//!
+//! [`InputScriptHarness`] builds a headless application with [`KeymapPlugin`] on
+//! [`MinimalPlugins`], plays a timed script of presses, releases, and context changes through the
+//! real input path, and reports what the keymap did. Time is virtual: every frame runs at the
+//! exact script time it stands for, so sequence timeouts resolve at the same instant on every run.
+
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+
+use bevy::app::App;
+use bevy::ecs::entity::Entity;
+use bevy::input::ButtonState;
+use bevy::input::InputPlugin;
+use bevy::input::keyboard::Key;
+use bevy::input::keyboard::KeyCode;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::keyboard::NativeKey;
+use bevy::input::mouse::MouseButton;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::prelude::MinimalPlugins;
+use bevy::prelude::Resource;
+use bevy::time::TimeUpdateStrategy;
+use bevy_enhanced_input::prelude::ActionValue;
+use bevy_enhanced_input::prelude::CustomInput;
+use bevy_enhanced_input::prelude::CustomInputs;
+use bevy_enhanced_input::prelude::EnhancedInputPlugin;
+
+use crate::Capability;
+use crate::CommandId;
+use crate::CommandRegistry;
+use crate::Diagnostic;
+use crate::DiagnosticOrigin;
+use crate::HeldCommandLookupOutcome;
+use crate::HoldPhase;
+use crate::KeymapContext;
+use crate::KeymapLoadFailures;
+use crate::KeymapPathFailure;
+use crate::KeymapPlugin;
+use crate::Keystroke;
+use crate::ModifierFamily;
+use crate::PrimaryTrigger;
+use crate::keymap::KeymapRuntime;
+use crate::keymap::RoutingTrace;
+use crate::keymap::RoutingTraceEvent;
+
+/// Runs timed input scripts against one keymap and application context enum.
+///
+/// A script is a list of steps separated by `;` or newlines, each starting with its time in
+/// milliseconds:
+///
+/// ```text
+/// t=0 press ctrl-k; t=50 press g; t=900 release all
+/// ```
+///
+/// `press <keystroke>` presses the keystroke's modifiers and then its trigger, and
+/// `release <keystroke>` releases them in the opposite order. `release all` releases everything
+/// still held, `reset` calls [`reset_physical_input`](crate::reset_physical_input) as a focus
+/// change would, and `context <name>` switches the context resource to the variant spelled
+/// `name`. Steps that share a time are delivered in the same frame. Gamepad buttons cannot be
+/// scripted.
+pub struct InputScriptHarness<C> {
+    keymap:  &'static str,
+    context: C,
+    setup:   Option<fn(&mut App)>,
+}
+
+impl<C> InputScriptHarness<C>
+where
+    C: KeymapContext + Resource,
+{
+    /// Starts a harness for the default keymap `keymap`, with `context` active at time zero.
+    #[must_use]
+    pub const fn new(keymap: &'static str, context: C) -> Self {
+        Self {
+            keymap,
+            context,
+            setup: None,
+        }
+    }
+
+    /// Runs `setup` on the application before the keymap plugin is added, to register commands
+    /// or observers the script exercises.
+    #[must_use]
+    pub const fn with_setup(mut self, setup: fn(&mut App)) -> Self {
+        self.setup = Some(setup);
+        self
+    }
+
+    /// Plays `script` in a fresh application and reports what the keymap did.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InputScriptError`] when a step cannot be read, before any input is delivered.
+    pub fn run(&self, script: &str) -> Result<InputScriptReport, InputScriptError> {
+        let steps = parse_script::<C>(script)?;
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+            .insert_resource(self.context)
+            .init_resource::<RoutingTrace>();
+        if let Some(setup) = self.setup {
+            setup(&mut app);
+        }
+        app.add_plugins(
+            KeymapPlugin::new()
+                .with_defaults(self.keymap)
+                .for_context::<C>(),
+        );
+        app.finish();
+        app.cleanup();
+
+        let mut script_run = ScriptRun {
+            started:      Instant::now(),
+            held_inputs:  held_inputs(&app),
+            active_holds: Vec::new(),
+            held_buttons: Vec::new(),
+            events:       Vec::new(),
+        };
+        let mut steps = steps.into_iter().peekable();
+        for at in frame_times(steps.clone()) {
+            script_run.set_clock(&mut app, at);
+            while let Some(step) = steps.next_if(|step| step.at == at) {
+                script_run.apply(&mut app, step.action);
+            }
+            app.update();
+            script_run.collect(&mut app, at);
+        }
+
+        Ok(InputScriptReport {
+            events:      script_run.events,
+            diagnostics: diagnostics(&app),
+        })
+    }
+}
+
+/// Everything a script run observed, in the order it happened.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InputScriptReport {
+    /// Fired commands, hold phases, and sequence timeouts in order.
+    ///
+    /// Within one frame, the commands routing fired and the sequences that timed out come first,
+    /// followed by the hold phases the frame began or ended.
+    pub events:      Vec<InputScriptEvent>,
+    /// Diagnostics the keymap reported while loading.
+    ///
+    /// The harness runs without a configuration directory, so the advisory saying so is left out.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl InputScriptReport {
+    /// Returns the one-shot commands that fired, in order.
+    #[must_use]
+    pub fn fired_commands(&self) -> Vec<&str> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                InputScriptEvent::Fired { command, .. } => Some(command.as_str()),
+                InputScriptEvent::Hold { .. } | InputScriptEvent::SequenceTimeout { .. } => None,
+            })
+            .collect()
+    }
+}
+
+/// One thing the keymap did during a script run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputScriptEvent {
+    /// Dispatch triggered a command's event.
+    Fired {
+        /// The script time of the frame it fired in.
+        at:      Duration,
+        /// The command that fired.
+        command: CommandId,
+    },
+    /// A held command began or ended.
+    Hold {
+        /// The script time of the frame the phase changed in.
+        at:      Duration,
+        /// The held command.
+        command: CommandId,
+        /// Whether the hold began or ended.
+        phase:   HoldPhase,
+    },
+    /// A pending sequence timed out.
+    SequenceTimeout {
+        /// The script time of the frame it timed out in.
+        at:       Duration,
+        /// Whether the pending keystrokes completed a shorter binding, which fires next, rather
+        /// than being discarded.
+        resolved: bool,
+    },
+}
+
+/// Why an input script could not be run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputScriptError {
+    /// The one-based step `step` cannot be read.
+    Malformed {
+        /// The one-based position of the step in the script.
+        step:    usize,
+        /// What is wrong with it.
+        message: String,
+    },
+    /// The one-based step `step` names a context the context enum does not declare.
+    UnknownContext {
+        /// The one-based position of the step in the script.
+        step:    usize,
+        /// The context name the step spelled.
+        context: String,
+    },
+}
+
+impl Display for InputScriptError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { step, message } => {
+                write!(
+                    formatter,
+                    "Input script step {step} cannot be read: {message}"
+                )
+            },
+            Self::UnknownContext { step, context } => {
+                write!(
+                    formatter,
+                    "Input script step {step} names the unknown context `{context}`."
+                )
+            },
+        }
+    }
+}
+
+impl Error for InputScriptError {}
+
+#[derive(Clone)]
+struct ScriptStep<C> {
+    at:     Duration,
+    action: ScriptAction<C>,
+}
+
+#[derive(Clone)]
+enum ScriptAction<C> {
+    Press(Vec<ScriptButton>),
+    Release(Vec<ScriptButton>),
+    ReleaseAll,
+    Reset,
+    Context(C),
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum ScriptButton {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+struct ScriptRun {
+    started:      Instant,
+    held_inputs:  Vec<(CommandId, CustomInput)>,
+    active_holds: Vec<CustomInput>,
+    held_buttons: Vec<ScriptButton>,
+    events:       Vec<InputScriptEvent>,
+}
+
+impl ScriptRun {
+    fn set_clock(&self, app: &mut App, at: Duration) {
+        let now = self.started + at;
+        app.insert_resource(TimeUpdateStrategy::ManualInstant(now));
+        if let Some(mut keymap_runtime) = app.world_mut().get_resource_mut::<KeymapRuntime>() {
+            keymap_runtime.set_virtual_clock(now);
+        }
+    }
+
+    fn apply<C: Resource>(&mut self, app: &mut App, action: ScriptAction<C>) {
+        match action {
+            ScriptAction::Press(buttons) => {
+                for button in buttons {
+                    if !self.held_buttons.contains(&button) {
+                        self.held_buttons.push(button);
+                        write_button(app, button, ButtonState::Pressed);
+                    }
+                }
+            },
+            ScriptAction::Release(buttons) => {
+                for button in buttons.into_iter().rev() {
+                    self.release(app, button);
+                }
+            },
+            ScriptAction::ReleaseAll => {
+                while let Some(button) = self.held_buttons.last().copied() {
+                    self.release(app, button);
+                }
+            },
+            ScriptAction::Reset => crate::reset_physical_input(app.world_mut()),
+            ScriptAction::Context(context) => {
+                app.insert_resource(context);
+            },
+        }
+    }
+
+    fn release(&mut self, app: &mut App, button: ScriptButton) {
+        if let Some(index) = self.held_buttons.iter().position(|held| *held == button) {
+            self.held_buttons.remove(index);
+            write_button(app, button, ButtonState::Released);
+        }
+    }
+
+    fn collect(&mut self, app: &mut App, at: Duration) {
+        self.events
+            .extend(
+                app.world_mut()
+                    .resource_mut::<RoutingTrace>()
+                    .drain()
+                    .map(|routing_event| match routing_event {
+                        RoutingTraceEvent::Dispatched(command) => {
+                            InputScriptEvent::Fired { at, command }
+                        },
+                        RoutingTraceEvent::SequenceTimeout { resolved } => {
+                            InputScriptEvent::SequenceTimeout { at, resolved }
+                        },
+                    }),
+            );
+
+        let custom_inputs = app.world().resource::<CustomInputs>();
+        for (command, custom_input) in &self.held_inputs {
+            let is_active = custom_inputs
+                .get(custom_input)
+                .is_some_and(|action_value| *action_value == ActionValue::Bool(true));
+            let was_active = self.active_holds.contains(custom_input);
+            let phase = match (was_active, is_active) {
+                (false, true) => HoldPhase::Begin,
+                (true, false) => HoldPhase::End,
+                (false, false) | (true, true) => continue,
+            };
+            if is_active {
+                self.active_holds.push(*custom_input);
+            } else {
+                self.active_holds.retain(|active| active != custom_input);
+            }
+            self.events.push(InputScriptEvent::Hold {
+                at,
+                command: command.clone(),
+                phase,
+            });
+        }
+    }
+}
+
+fn write_button(app: &mut App, button: ScriptButton, state: ButtonState) {
+    match button {
+        ScriptButton::Key(key_code) => {
+            app.world_mut().write_message(KeyboardInput {
+                key_code,
+                logical_key: Key::Unidentified(NativeKey::Unidentified),
+                state,
+                text: None,
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+        },
+        ScriptButton::Mouse(button) => {
+            app.world_mut().write_message(MouseButtonInput {
+                button,
+                state,
+                window: Entity::PLACEHOLDER,
+            });
+        },
+    }
+}
+
+/// Every held command and the custom input its binding drives.
+fn held_inputs(app: &App) -> Vec<(CommandId, CustomInput)> {
+    let Some(command_registry) = app.world().get_resource::<CommandRegistry>() else {
+        return Vec::new();
+    };
+    command_registry
+        .iter()
+        .filter(|command_info| command_info.capability == Capability::Held)
+        .filter_map(
+            |command_info| match command_registry.held_command_lookup(command_info.id) {
+                HeldCommandLookupOutcome::RegisteredHeldInput(custom_input) => {
+                    Some((command_info.id.clone(), custom_input))
+                },
+                HeldCommandLookupOutcome::KnownNonHeld
+                | HeldCommandLookupOutcome::UnknownCommand => None,
+            },
+        )
+        .collect()
+}
+
+fn diagnostics(app: &App) -> Vec<Diagnostic> {
+    app.world()
+        .get_resource::<KeymapLoadFailures>()
+        .map(|keymap_load_failures| {
+            keymap_load_failures
+                .all_diagnostics()
+                .filter(|diagnostic| {
+                    diagnostic.origin
+                        != DiagnosticOrigin::PathsUnavailable(
+                            KeymapPathFailure::AppNameNotConfigured,
+                        )
+                })
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The script times a frame runs at: every step's, and every press's sequence timeout, so a
+/// pending sequence resolves at the instant the real runtime would resolve it.
+fn frame_times<C>(steps: impl Iterator<Item = ScriptStep<C>>) -> BTreeSet<Duration> {
+    let mut frame_times = BTreeSet::from([Duration::ZERO]);
+    for step in steps {
+        frame_times.insert(step.at);
+        if matches!(step.action, ScriptAction::Press(_)) {
+            frame_times.insert(step.at + KeymapRuntime::sequence_timeout());
+        }
+    }
+    frame_times
+}
+
+fn parse_script<C: KeymapContext>(script: &str) -> Result<Vec<ScriptStep<C>>, InputScriptError> {
+    let mut steps = Vec::new();
+    for (index, text) in script
+        .split([';', '\n'])
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .enumerate()
+    {
+        let step = index + 1;
+        let malformed = |message: String| InputScriptError::Malformed { step, message };
+        let mut words = text.split_whitespace();
+        let at = words
+            .next()
+            .and_then(|word| word.strip_prefix("t="))
+            .and_then(|milliseconds| milliseconds.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .ok_or_else(|| malformed(format!("`{text}` does not start with `t=<milliseconds>`")))?;
+        if steps
+            .last()
+            .is_some_and(|previous: &ScriptStep<C>| previous.at > at)
+        {
+            return Err(malformed(String::from(
+                "steps must be listed in time order",
+            )));
+        }
+
+        let action = match (words.next(), words.next(), words.next()) {
+            (Some("press"), Some(keystroke), None) => {
+                ScriptAction::Press(buttons(keystroke).map_err(malformed)?)
+            },
+            (Some("release"), Some("all"), None) => ScriptAction::ReleaseAll,
+            (Some("release"), Some(keystroke), None) => {
+                ScriptAction::Release(buttons(keystroke).map_err(malformed)?)
+            },
+            (Some("reset"), None, None) => ScriptAction::Reset,
+            (Some("context"), Some(name), None) => ScriptAction::Context(
+                C::iter()
+                    .find(|context| context.as_ref() == name)
+                    .ok_or_else(|| InputScriptError::UnknownContext {
+                        step,
+                        context: name.to_owned(),
+                    })?,
+            ),
+            _ => {
+                return Err(malformed(format!(
+                    "`{text}` is not `press <keystroke>`, `release <keystroke>`, `release all`, \
+                     `reset`, or `context <name>`"
+                )));
+            },
+        };
+        steps.push(ScriptStep { at, action });
+    }
+    Ok(steps)
+}
+
+/// The physical buttons `keystroke` presses, modifiers first.
+fn buttons(keystroke: &str) -> Result<Vec<ScriptButton>, String> {
+    let keystroke = Keystroke::from_str(keystroke).map_err(|error| error.to_string())?;
+    let modifiers = keystroke.modifiers();
+    let mut buttons = [
+        (modifiers.has_platform(), KeyCode::SuperLeft),
+        (modifiers.has_control(), KeyCode::ControlLeft),
+        (modifiers.has_alt(), KeyCode::AltLeft),
+        (modifiers.has_shift(), KeyCode::ShiftLeft),
+    ]
+    .into_iter()
+    .filter_map(|(is_held, key_code)| is_held.then_some(ScriptButton::Key(key_code)))
+    .collect::<Vec<_>>();
+
+    buttons.push(match keystroke.primary_trigger() {
+        PrimaryTrigger::OrdinaryKey(ordinary_key) => ScriptButton::Key(ordinary_key.key_code()),
+        PrimaryTrigger::ModifierFamily(modifier_family) => {
+            ScriptButton::Key(modifier_key_code(modifier_family))
+        },
+        PrimaryTrigger::MouseButton(mouse_button) => {
+            ScriptButton::Mouse(mouse_button.mouse_button())
+        },
+        PrimaryTrigger::GamepadButton(_) => {
+            return Err(format!("gamepad button `{keystroke}` cannot be scripted"));
+        },
+    });
+    Ok(buttons)
+}
+
+/// The left-hand physical key of `modifier_family`, which the script presses for a bare modifier.
+const fn modifier_key_code(modifier_family: ModifierFamily) -> KeyCode {
+    match modifier_family {
+        ModifierFamily::Control => KeyCode::ControlLeft,
+        ModifierFamily::Alt => KeyCode::AltLeft,
+        ModifierFamily::Shift => KeyCode::ShiftLeft,
+        ModifierFamily::Platform => KeyCode::SuperLeft,
+    }
+}
+
+#[cfg(test)]
+#[allow(
+    dead_code,
+    reason = "command declarations generate action types the harness never reads"
+)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::app::App;
+    use bevy::prelude::AppTypeRegistry;
+    use bevy::prelude::Event;
+    use bevy::prelude::Reflect;
+    use bevy::prelude::ReflectEvent;
+    use bevy::prelude::Resource;
+    use bevy_enhanced_input::prelude::InputAction;
+    use strum::AsRefStr;
+    use strum::EnumIter;
+    use strum::EnumMessage;
+
+    use super::InputScriptError;
+    use super::InputScriptEvent;
+    use super::InputScriptHarness;
+    use crate::CommandId;
+    use crate::HoldPhase;
+    use crate::ReflectKeymapCommand;
+
+    const SCRIPT_KEYMAP: &str = r#"{
+        "bindings": [
+            { "bindings": {
+                "ctrl-k g": "script::chord",
+                "space": "script::held"
+            }},
+            { "context": "editing", "bindings": {
+                "g": "script::short",
+                "g h": "script::long"
+            }}
+        ]
+    }"#;
+
+    crate::command! {
+        action:      ScriptChordAction,
+        event:       ScriptChord,
+        id:          "script::chord",
+        title:       "Script Chord",
+        description: "Fires after a two-stroke scripted chord.",
+    }
+
+    crate::command! {
+        action:      ScriptShortAction,
+        event:       ScriptShort,
+        id:          "script::short",
+        title:       "Script Short",
+        description: "Fires when a scripted sequence times out on its first stroke.",
+    }
+
+    crate::command! {
+        action:      ScriptLongAction,
+        event:       ScriptLong,
+        id:          "script::long",
+        title:       "Script Long",
+        description: "Fires when a scripted sequence completes both strokes.",
+    }
+
+    crate::command! {
+        held,
+        action:      ScriptHeldAction,
+        event:       ScriptHeld,
+        id:          "script::held",
+        title:       "Script Held",
+        description: "Holds while the scripted key is down.",
+    }
+
+    #[derive(AsRefStr, Clone, Copy, EnumIter, EnumMessage, Eq, PartialEq, Resource)]
+    #[strum(serialize_all = "snake_case")]
+    enum ScriptContext {
+        #[strum(message = "Routes while the script is browsing.")]
+        Browsing,
+        #[strum(message = "Routes while the script is editing.")]
+        Editing,
+    }
+
+    fn register_script_commands(app: &mut App) {
+        app.world_mut().insert_resource(AppTypeRegistry::default());
+        let app_type_registry = app.world().resource::<AppTypeRegistry>().clone();
+        let mut type_registry = app_type_registry.write();
+        type_registry.register::<ScriptChord>();
+        type_registry.register::<ScriptShort>();
+        type_registry.register::<ScriptLong>();
+        type_registry.register::<ScriptHeld>();
+    }
+
+    fn harness() -> InputScriptHarness<ScriptContext> {
+        InputScriptHarness::new(SCRIPT_KEYMAP, ScriptContext::Browsing)
+            .with_setup(register_script_commands)
+    }
+
+    fn command(id: &str) -> Result<CommandId, String> {
+        id.parse::<CommandId>().map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn chords_fire_and_held_keys_report_both_phases() -> Result<(), String> {
+        let report = harness()
+            .run(
+                "t=0 press ctrl-k
+                 t=20 release ctrl-k
+                 t=50 press g
+                 t=100 release all
+                 t=200 press space
+                 t=900 release all",
+            )
+            .map_err(|error| error.to_string())?;
+
+        assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+        assert_eq!(
+            report.events,
+            vec![
+                InputScriptEvent::Fired {
+                    at:      Duration::from_millis(50),
+                    command: command("script::chord")?,
+                },
+                InputScriptEvent::Hold {
+                    at:      Duration::from_millis(200),
+                    command: command("script::held")?,
+                    phase:   HoldPhase::Begin,
+                },
+                InputScriptEvent::Hold {
+                    at:      Duration::from_millis(900),
+                    command: command("script::held")?,
+                    phase:   HoldPhase::End,
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn deferred_sequences_resolve_at_the_virtual_timeout() -> Result<(), String> {
+        let report = harness()
+            .run("t=0 context editing; t=100 press g; t=150 release g")
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            report.events,
+            vec![
+                InputScriptEvent::SequenceTimeout {
+                    at:       Duration::from_millis(1100),
+                    resolved: true,
+                },
+                InputScriptEvent::Fired {
+                    at:      Duration::from_millis(1100),
+                    command: command("script::short")?,
+                },
+            ]
+        );
+
+        let completed = harness()
+            .run("t=0 context editing; t=100 press g; t=150 release g; t=400 press h")
+            .map_err(|error| error.to_string())?;
+        assert_eq!(completed.fired_commands(), vec!["script::long"]);
+        Ok(())
+    }
+
+    #[test]
+    fn unreadable_steps_report_their_position() {
+        assert_eq!(
+            harness().run("t=0 press g; t=10 context flying"),
+            Err(InputScriptError::UnknownContext {
+                step:    2,
+                context: String::from("flying"),
+            })
+        );
+        assert!(matches!(
+            harness().run("t=50 press g; t=10 release g"),
+            Err(InputScriptError::Malformed { step: 2, .. })
+        ));
+        assert!(matches!(
+            harness().run("press g"),
+            Err(InputScriptError::Malformed { step: 1, .. })
+        ));
+    }
+}
@@ -7,16 +7,18 @@ use std::time::Duration;
 use std::time::Instant;
 
 use bevy::prelude::Resource;
-use bevy::prelude::World;
 use bevy_enhanced_input::prelude::CustomInput;
 
 use super::MergedKeymap;
+use super::merged::BindingScope;
 use crate::CommandId;
 use crate::CommandRegistry;
 use crate::KeystrokeSequence;
 use crate::ModifierFamily;
 use crate::PrimaryTrigger;
 use crate::SequenceMatcher;
+use crate::command::BindingArguments;
+use crate::command::CommandDispatch;
 use crate::command::CommandEntry;
 use crate::command::Invocation;
 use crate::condition::ConditionHandle;
@@ -26,7 +28,7 @@ use crate::condition::ConditionHandle;
 pub(crate) struct Generation(pub(crate) usize);
 
 /// An opaque index into [`CompiledKeymap`]'s resolved command table.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct CommandHandle(usize);
 
 #[cfg(test)]
@@ -48,6 +50,7 @@ pub(crate) struct CompiledKeymap {
     pub(super) global:      SequenceMatcher<CommandHandle>,
     pub(super) commands:    Vec<(CommandId, CommandEntry)>,
     pub(super) matchers:    HashMap<ConditionHandle, SequenceMatcher<CommandHandle>>,
+    bound_arguments:        HashMap<CommandHandle, BindingArguments>,
     modifier_held_bindings: ContextualModifierHeldBindings,
 }
 
@@ -59,7 +62,7 @@ impl CompiledKeymap {
         merged_keymap: &MergedKeymap,
         command_registry: &CommandRegistry,
     ) -> Self {
-        let command_entries = command_registry
+        let mut command_entries = command_registry
             .iter_entries()
             .map(|(command_id, command_entry)| (command_id.clone(), command_entry.clone()))
             .collect::<Vec<_>>();
@@ -73,20 +76,35 @@ impl CompiledKeymap {
             &command_handles,
             &command_entries,
         );
-        let global = SequenceMatcher::new(merged_keymap.global().iter().filter_map(
-            |(keystroke_sequence, command_id)| {
-                matcher_entry(keystroke_sequence, command_id, &command_handles)
-            },
+        let mut bound_arguments = HashMap::new();
+        let mut matcher_entries =
+            |binding_scope: BindingScope, bindings: &[(KeystrokeSequence, CommandId)]| {
+                bindings
+                    .iter()
+                    .filter_map(|(keystroke_sequence, command_id)| {
+                        matcher_entry(keystroke_sequence, command_id, &command_handles)
+                    })
+                    .map(|(keystroke_sequence, command_handle)| {
+                        let command_handle = argument_handle(
+                            command_handle,
+                            merged_keymap.arguments(binding_scope, &keystroke_sequence),
+                            &mut command_entries,
+                            &mut bound_arguments,
+                        );
+                        (keystroke_sequence, command_handle)
+                    })
+                    .collect::<Vec<_>>()
+            };
+        let global = SequenceMatcher::new(matcher_entries(
+            BindingScope::Global,
+            merged_keymap.global(),
         ));
         let matchers = merged_keymap
             .conditions
             .iter()
             .map(|(condition_handle, bindings)| {
-                let sequences = bindings
-                    .iter()
-                    .filter_map(|(keystroke_sequence, command_id)| {
-                        matcher_entry(keystroke_sequence, command_id, &command_handles)
-                    });
+                let sequences =
+                    matcher_entries(BindingScope::Condition(*condition_handle), bindings);
 
                 (*condition_handle, SequenceMatcher::new(sequences))
             })
@@ -96,6 +114,7 @@ impl CompiledKeymap {
             global,
             commands: command_entries,
             matchers,
+            bound_arguments,
             modifier_held_bindings,
         }
     }
@@ -123,10 +142,12 @@ impl CompiledKeymap {
             .map(|(_, command_entry)| command_entry.invocation())
     }
 
-    pub(crate) fn dispatch(&self, command_handle: CommandHandle) -> Option<fn(&mut World)> {
+    pub(crate) fn dispatch(&self, command_handle: CommandHandle) -> Option<CommandDispatch> {
         self.commands
             .get(command_handle.0)
-            .map(|(_, command_entry)| command_entry.dispatch())
+            .map(|(_, command_entry)| {
+                command_entry.command_dispatch(self.bound_arguments.get(&command_handle))
+            })
     }
 
     #[cfg(test)]
@@ -145,17 +166,17 @@ impl CompiledKeymap {
 
 /// Pairs a binding with its command handle when a [`SequenceMatcher`] is the path that routes it.
 ///
-/// Only ordinary-key strokes reach a matcher: `route_ordinary_key_presses` is the sole caller of
-/// `match_keystroke` and it only ever builds [`PrimaryTrigger::OrdinaryKey`] keystrokes. A
-/// modifier-family binding routes through [`ContextualModifierHeldBindings`] instead, so leaving
-/// it out here keeps the two paths disjoint rather than seeding the matcher with entries no
-/// keystroke can reach.
+/// Only pressed-button strokes reach a matcher: `route_ordinary_key_presses` and
+/// `route_button_presses` are the callers of `match_keystroke`, and they only ever build ordinary
+/// key, mouse button, and gamepad button keystrokes. A modifier-family binding routes through
+/// [`ContextualModifierHeldBindings`] instead, so leaving it out here keeps the two paths disjoint
+/// rather than seeding the matcher with entries no keystroke can reach.
 fn matcher_entry(
     keystroke_sequence: &KeystrokeSequence,
     command_id: &crate::CommandId,
     command_handles: &HashMap<crate::CommandId, CommandHandle>,
 ) -> Option<(KeystrokeSequence, CommandHandle)> {
-    if !routes_ordinary_keys_only(keystroke_sequence) {
+    if !routes_pressed_buttons_only(keystroke_sequence) {
         return None;
     }
 
@@ -165,10 +186,33 @@ fn matcher_entry(
         .map(|command_handle| (keystroke_sequence.clone(), command_handle))
 }
 
-fn routes_ordinary_keys_only(keystroke_sequence: &KeystrokeSequence) -> bool {
-    keystroke_sequence
-        .iter()
-        .all(|keystroke| matches!(keystroke.primary_trigger(), PrimaryTrigger::OrdinaryKey(_)))
+/// Issues a dedicated command handle for a binding that carries typed arguments, so two keys bound
+/// to one command with different arguments dispatch different events.
+fn argument_handle(
+    command_handle: CommandHandle,
+    arguments: Option<&BindingArguments>,
+    command_entries: &mut Vec<(CommandId, CommandEntry)>,
+    bound_arguments: &mut HashMap<CommandHandle, BindingArguments>,
+) -> CommandHandle {
+    let Some(arguments) = arguments else {
+        return command_handle;
+    };
+    let Some(command) = command_entries.get(command_handle.0).cloned() else {
+        return command_handle;
+    };
+    let argument_handle = CommandHandle(command_entries.len());
+    command_entries.push(command);
+    bound_arguments.insert(argument_handle, arguments.clone());
+    argument_handle
+}
+
+fn routes_pressed_buttons_only(keystroke_sequence: &KeystrokeSequence) -> bool {
+    keystroke_sequence.iter().all(|keystroke| {
+        !matches!(
+            keystroke.primary_trigger(),
+            PrimaryTrigger::ModifierFamily(_)
+        )
+    })
 }
 
 #[derive(Clone, Copy, Default)]
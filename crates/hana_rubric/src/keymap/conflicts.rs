@@ -0,0 +1,31 @@
+//! Whole-keymap reports of bindings that load cleanly but conflict with one another.
+
+use bevy::ecs::world::World;
+
+use super::reload::CommittedUserKeymap;
+use super::reload::ReloadConfiguration;
+use crate::CommandRegistry;
+use crate::Diagnostic;
+use crate::condition::ConditionRegistry;
+
+/// Reports the conflicts in the sources the live generation was compiled from.
+pub(crate) fn keymap_conflicts(world: &World) -> Vec<Diagnostic> {
+    match (
+        world.get_resource::<ReloadConfiguration>(),
+        world.get_resource::<CommittedUserKeymap>(),
+        world.get_resource::<CommandRegistry>(),
+        world.get_resource::<ConditionRegistry>(),
+    ) {
+        (
+            Some(reload_configuration),
+            Some(committed_user_keymap),
+            Some(command_registry),
+            Some(condition_registry),
+        ) => reload_configuration.conflicts(
+            &committed_user_keymap.0,
+            command_registry,
+            condition_registry,
+        ),
+        _ => Vec::new(),
+    }
+}
@@ -3,3 +3,11 @@ use std::time::Duration;
 // sequence matching
 /// Maximum elapsed time between keystrokes in one keymap sequence.
 pub(super) const SEQUENCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+// history
+/// Maximum applied keymap transactions retained for undo in one session.
+pub(super) const MAX_UNDO_TRANSACTIONS: usize = 64;
+
+// macros
+/// Maximum length of a recorded keymap macro's name.
+pub(super) const MAX_MACRO_NAME_LENGTH: usize = 64;
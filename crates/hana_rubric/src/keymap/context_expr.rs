@@ -0,0 +1,484 @@
+//! Boolean keymap context expressions and their resolved condition trees.
+
+use std::cmp::Reverse;
+use std::fmt;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use crate::ConditionName;
+use crate::condition::ConditionHandle;
+use crate::condition::ConditionLookup;
+use crate::condition::ConditionRegistry;
+
+/// A keymap context expression.
+///
+/// A bare condition name is the simplest expression; `&&`, `||`, `!`, and parentheses combine
+/// names. Each name keeps its byte range within the authored context text so a name the
+/// application never registered can be reported where it was written.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum ContextExpr {
+    /// A single application-defined condition name.
+    Name(ConditionName, Range<usize>),
+    /// Holds while the operand does not.
+    Not(Box<Self>),
+    /// Holds while both operands hold.
+    And(Box<Self>, Box<Self>),
+    /// Holds while either operand holds.
+    Or(Box<Self>, Box<Self>),
+}
+
+impl ContextExpr {
+    /// Parses authored context text such as `editing && !modal`.
+    ///
+    /// `!` binds tighter than `&&`, which binds tighter than `||`.
+    pub(super) fn parse(text: &str) -> Result<Self, ContextSyntaxError> {
+        let mut parser = ContextParser {
+            text,
+            tokens: tokenize(text)?,
+            position: 0,
+        };
+        let expression = parser.parse_or()?;
+
+        match parser.tokens.get(parser.position) {
+            None => Ok(expression),
+            Some(token) if token.kind == TokenKind::CloseParen => Err(ContextSyntaxError {
+                range:   token.range.clone(),
+                message: "Unmatched `)` in keymap context expression.".to_owned(),
+            }),
+            Some(token) => Err(ContextSyntaxError {
+                range:   token.range.clone(),
+                message: format!(
+                    "Expected `&&` or `||` before `{}` in keymap context expression.",
+                    &text[token.range.clone()]
+                ),
+            }),
+        }
+    }
+
+    /// Resolves every name against the condition registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns every name the registry does not recognize, with its range in the context text.
+    pub(super) fn resolve(
+        &self,
+        condition_registry: &ConditionRegistry,
+    ) -> Result<ConditionTree, Vec<(ConditionName, Range<usize>)>> {
+        let mut unregistered = Vec::new();
+        let condition_tree = self.resolve_into(condition_registry, &mut unregistered);
+
+        match condition_tree {
+            Some(condition_tree) if unregistered.is_empty() => Ok(condition_tree),
+            _ => Err(unregistered),
+        }
+    }
+
+    fn resolve_into(
+        &self,
+        condition_registry: &ConditionRegistry,
+        unregistered: &mut Vec<(ConditionName, Range<usize>)>,
+    ) -> Option<ConditionTree> {
+        match self {
+            Self::Name(condition_name, range) => {
+                if let ConditionLookup::Registered { handle, .. } =
+                    condition_registry.lookup(condition_name.as_str())
+                {
+                    Some(ConditionTree::Condition(handle))
+                } else {
+                    unregistered.push((condition_name.clone(), range.clone()));
+                    None
+                }
+            },
+            Self::Not(operand) => operand
+                .resolve_into(condition_registry, unregistered)
+                .map(|operand| ConditionTree::Not(Box::new(operand))),
+            Self::And(left, right) => {
+                let left = left.resolve_into(condition_registry, unregistered);
+                let right = right.resolve_into(condition_registry, unregistered);
+                Some(ConditionTree::And(Box::new(left?), Box::new(right?)))
+            },
+            Self::Or(left, right) => {
+                let left = left.resolve_into(condition_registry, unregistered);
+                let right = right.resolve_into(condition_registry, unregistered);
+                Some(ConditionTree::Or(Box::new(left?), Box::new(right?)))
+            },
+        }
+    }
+
+    const fn precedence(&self) -> u8 {
+        match self {
+            Self::Or(..) => 0,
+            Self::And(..) => 1,
+            Self::Not(_) | Self::Name(..) => 2,
+        }
+    }
+
+    fn fmt_operand(&self, operand: &Self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        if operand.precedence() < self.precedence() {
+            write!(formatter, "({operand})")
+        } else {
+            write!(formatter, "{operand}")
+        }
+    }
+}
+
+impl fmt::Display for ContextExpr {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Name(condition_name, _) => formatter.write_str(condition_name.as_str()),
+            Self::Not(operand) => {
+                formatter.write_str("!")?;
+                self.fmt_operand(operand, formatter)
+            },
+            Self::And(left, right) => {
+                self.fmt_operand(left, formatter)?;
+                formatter.write_str(" && ")?;
+                self.fmt_operand(right, formatter)
+            },
+            Self::Or(left, right) => {
+                self.fmt_operand(left, formatter)?;
+                formatter.write_str(" || ")?;
+                self.fmt_operand(right, formatter)
+            },
+        }
+    }
+}
+
+/// A context expression that could not be parsed, located within the authored context text.
+#[derive(Debug)]
+pub(super) struct ContextSyntaxError {
+    pub(super) range:   Range<usize>,
+    pub(super) message: String,
+}
+
+/// A context expression whose names all resolved to registered conditions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum ConditionTree {
+    Condition(ConditionHandle),
+    Not(Box<Self>),
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+}
+
+impl ConditionTree {
+    /// Whether the expression holds while `active` is the application's one active condition.
+    pub(super) fn holds_for(&self, active: ConditionHandle) -> bool {
+        match self {
+            Self::Condition(condition_handle) => *condition_handle == active,
+            Self::Not(operand) => !operand.holds_for(active),
+            Self::And(left, right) => left.holds_for(active) && right.holds_for(active),
+            Self::Or(left, right) => left.holds_for(active) || right.holds_for(active),
+        }
+    }
+
+    /// The registered conditions under which the expression holds, in registration order.
+    pub(super) fn matching_conditions(
+        &self,
+        condition_registry: &ConditionRegistry,
+    ) -> Vec<ConditionHandle> {
+        condition_registry
+            .iter()
+            .filter_map(|condition_info| {
+                match condition_registry.lookup(condition_info.name.as_str()) {
+                    ConditionLookup::Registered { handle, .. } => Some(handle),
+                    ConditionLookup::UnregisteredName => None,
+                }
+            })
+            .filter(|condition_handle| self.holds_for(*condition_handle))
+            .collect()
+    }
+}
+
+/// How narrowly a block's context selects conditions.
+///
+/// An expression that holds under fewer registered conditions outranks a broader one at the same
+/// keystroke within one keymap layer, whichever block comes first. Blocks of equal specificity
+/// keep the later-block-wins rule.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub(super) struct Specificity(Reverse<usize>);
+
+impl Specificity {
+    /// The specificity of a block without a context, which only competes with other global
+    /// blocks.
+    pub(super) const UNCONDITIONAL: Self = Self(Reverse(usize::MAX));
+
+    pub(super) const fn from_matching_conditions(matching_conditions: usize) -> Self {
+        Self(Reverse(matching_conditions))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum TokenKind {
+    Name,
+    Not,
+    And,
+    Or,
+    OpenParen,
+    CloseParen,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind:  TokenKind,
+    range: Range<usize>,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, ContextSyntaxError> {
+    let mut tokens = Vec::new();
+    let mut characters = text.char_indices().peekable();
+
+    while let Some((start, character)) = characters.next() {
+        let kind = match character {
+            character if character.is_whitespace() => continue,
+            '!' => TokenKind::Not,
+            '(' => TokenKind::OpenParen,
+            ')' => TokenKind::CloseParen,
+            '&' | '|' => {
+                if characters.next_if(|(_, next)| *next == character).is_none() {
+                    return Err(ContextSyntaxError {
+                        range:   start..start + 1,
+                        message: format!(
+                            "Keymap context operator `{character}` must be written `{character}{character}`."
+                        ),
+                    });
+                }
+                tokens.push(Token {
+                    kind:  if character == '&' {
+                        TokenKind::And
+                    } else {
+                        TokenKind::Or
+                    },
+                    range: start..start + 2,
+                });
+                continue;
+            },
+            _ => {
+                let mut end = start + character.len_utf8();
+                while let Some((offset, next)) =
+                    characters.next_if(|(_, next)| is_name_character(*next))
+                {
+                    end = offset + next.len_utf8();
+                }
+                tokens.push(Token {
+                    kind:  TokenKind::Name,
+                    range: start..end,
+                });
+                continue;
+            },
+        };
+
+        tokens.push(Token {
+            kind,
+            range: start..start + 1,
+        });
+    }
+
+    Ok(tokens)
+}
+
+const fn is_name_character(character: char) -> bool {
+    !character.is_whitespace() && !matches!(character, '!' | '&' | '|' | '(' | ')')
+}
+
+struct ContextParser<'text> {
+    text:     &'text str,
+    tokens:   Vec<Token>,
+    position: usize,
+}
+
+impl ContextParser<'_> {
+    fn parse_or(&mut self) -> Result<ContextExpr, ContextSyntaxError> {
+        let mut expression = self.parse_and()?;
+        while self.consume(TokenKind::Or) {
+            let right = self.parse_and()?;
+            expression = ContextExpr::Or(Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_and(&mut self) -> Result<ContextExpr, ContextSyntaxError> {
+        let mut expression = self.parse_unary()?;
+        while self.consume(TokenKind::And) {
+            let right = self.parse_unary()?;
+            expression = ContextExpr::And(Box::new(expression), Box::new(right));
+        }
+        Ok(expression)
+    }
+
+    fn parse_unary(&mut self) -> Result<ContextExpr, ContextSyntaxError> {
+        if self.consume(TokenKind::Not) {
+            return Ok(ContextExpr::Not(Box::new(self.parse_unary()?)));
+        }
+
+        let Some(token) = self.tokens.get(self.position).cloned() else {
+            let end = self.text.len();
+            return Err(ContextSyntaxError {
+                range:   end..end,
+                message: "Expected a context name at the end of the keymap context expression."
+                    .to_owned(),
+            });
+        };
+        self.position += 1;
+
+        match token.kind {
+            TokenKind::Name => Ok(ContextExpr::Name(
+                ConditionName::new(&self.text[token.range.clone()]),
+                token.range,
+            )),
+            TokenKind::OpenParen => {
+                let expression = self.parse_or()?;
+                if self.consume(TokenKind::CloseParen) {
+                    Ok(expression)
+                } else {
+                    Err(ContextSyntaxError {
+                        range:   token.range,
+                        message: "Unclosed `(` in keymap context expression.".to_owned(),
+                    })
+                }
+            },
+            TokenKind::Not | TokenKind::And | TokenKind::Or | TokenKind::CloseParen => {
+                Err(ContextSyntaxError {
+                    message: format!(
+                        "Expected a context name before `{}` in keymap context expression.",
+                        &self.text[token.range.clone()]
+                    ),
+                    range:   token.range,
+                })
+            },
+        }
+    }
+
+    fn consume(&mut self, kind: TokenKind) -> bool {
+        let matches = self
+            .tokens
+            .get(self.position)
+            .is_some_and(|token| token.kind == kind);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::AsRefStr;
+    use strum::EnumIter;
+    use strum::EnumMessage;
+
+    use super::ContextExpr;
+    use super::Specificity;
+    use crate::condition::ConditionLookup;
+    use crate::condition::ConditionRegistry;
+
+    #[derive(AsRefStr, Clone, Copy, Debug, EnumIter, EnumMessage, Eq, PartialEq)]
+    #[strum(serialize_all = "snake_case")]
+    enum TestContext {
+        #[strum(message = "While text is being edited")]
+        Editing,
+        #[strum(message = "While a modal dialog is open")]
+        Modal,
+        #[strum(message = "While the browser is focused")]
+        Browsing,
+    }
+
+    fn condition_registry() -> Result<ConditionRegistry, String> {
+        let mut condition_registry = ConditionRegistry::default();
+        condition_registry
+            .register::<TestContext>()
+            .map_err(|diagnostics| format!("condition registry errors: {diagnostics:?}"))?;
+        Ok(condition_registry)
+    }
+
+    fn matching_names(text: &str) -> Result<Vec<String>, String> {
+        let condition_registry = condition_registry()?;
+        let condition_tree = ContextExpr::parse(text)
+            .map_err(|error| format!("context syntax error: {error:?}"))?
+            .resolve(&condition_registry)
+            .map_err(|unregistered| format!("unregistered names: {unregistered:?}"))?;
+        let handles = condition_tree.matching_conditions(&condition_registry);
+
+        Ok(condition_registry
+            .iter()
+            .filter(|condition_info| {
+                matches!(
+                    condition_registry.lookup(condition_info.name.as_str()),
+                    ConditionLookup::Registered { handle, .. } if handles.contains(&handle)
+                )
+            })
+            .map(|condition_info| condition_info.name.as_str().to_owned())
+            .collect())
+    }
+
+    #[test]
+    fn operators_follow_not_and_or_precedence() -> Result<(), String> {
+        let expression = ContextExpr::parse("browsing || editing && !modal")
+            .map_err(|error| format!("context syntax error: {error:?}"))?;
+
+        assert_eq!(expression.to_string(), "browsing || editing && !modal");
+        assert!(matches!(expression, ContextExpr::Or(..)));
+        assert_eq!(
+            ContextExpr::parse("(browsing || editing) && !modal")
+                .map_err(|error| format!("context syntax error: {error:?}"))?
+                .to_string(),
+            "(browsing || editing) && !modal"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn expressions_hold_for_the_conditions_they_describe() -> Result<(), String> {
+        assert_eq!(matching_names("editing")?, ["editing"]);
+        assert_eq!(matching_names("editing && !modal")?, ["editing"]);
+        assert_eq!(
+            matching_names("browsing || editing")?,
+            ["editing", "browsing"]
+        );
+        assert_eq!(matching_names("!modal")?, ["editing", "browsing"]);
+        assert!(matching_names("editing && modal")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn syntax_errors_name_the_offending_range() {
+        let cases = [
+            ("editing &", 8..9),
+            ("editing && ", 11..11),
+            ("(editing", 0..1),
+            ("editing)", 7..8),
+            ("editing modal", 8..13),
+            ("|| editing", 0..2),
+        ];
+
+        for (text, range) in cases {
+            let error = ContextExpr::parse(text).err();
+            assert_eq!(error.map(|error| error.range), Some(range), "{text}");
+        }
+    }
+
+    #[test]
+    fn unregistered_names_are_all_reported_with_their_ranges() -> Result<(), String> {
+        let condition_registry = condition_registry()?;
+        let unregistered = ContextExpr::parse("edting || !modle")
+            .map_err(|error| format!("context syntax error: {error:?}"))?
+            .resolve(&condition_registry)
+            .err()
+            .unwrap_or_default();
+
+        assert_eq!(
+            unregistered
+                .iter()
+                .map(|(name, range)| (name.as_str(), range.clone()))
+                .collect::<Vec<_>>(),
+            [("edting", 0..6), ("modle", 11..16)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn narrower_expressions_are_more_specific() {
+        assert!(
+            Specificity::from_matching_conditions(1) > Specificity::from_matching_conditions(2)
+        );
+    }
+}
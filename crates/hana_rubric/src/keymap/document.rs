@@ -11,14 +11,15 @@ use serde::de::Visitor;
 use serde_json_lenient::Error;
 use serde_json_lenient::Value;
 
+use super::context_expr::ContextExpr;
 use crate::CommandId;
-use crate::ConditionName;
 use crate::Diagnostic;
 use crate::DiagnosticKind;
 use crate::DiagnosticOrigin;
 use crate::DiagnosticSeverity;
 use crate::KeystrokeSequence;
 use crate::KeystrokeSequenceParseError;
+use crate::command::BindingArguments;
 
 pub(crate) const RECOGNIZED_ROOT_MEMBERS: [&str; 2] = ["$schema", "bindings"];
 
@@ -73,6 +74,7 @@ impl KeymapDocument {
         let SourceIndex {
             blocks: indexed_blocks,
             unrecognized_root_members,
+            ..
         } = source_index;
         let mut diagnostics = unrecognized_root_members
             .into_iter()
@@ -200,6 +202,7 @@ fn parse_block(
         context: indexed_context,
         bindings: indexed_bindings,
         unrecognized_members,
+        ..
     } = indexed_block;
     let (context, context_source) = parse_context(
         diagnostic_origin,
@@ -209,12 +212,9 @@ fn parse_block(
         condition_names,
         diagnostics,
     );
-    let context_text =
-        context
-            .as_ref()
-            .map_or_else(String::new, |context_expr| match context_expr {
-                ContextExpr::Name(condition_name) => condition_name.as_str().to_owned(),
-            });
+    let context_text = context
+        .as_ref()
+        .map_or_else(String::new, ContextExpr::to_string);
     let bindings = parse_bindings(
         diagnostic_origin,
         source,
@@ -281,10 +281,24 @@ fn parse_context(
         ));
     }
 
-    (
-        Some(ContextExpr::Name(ConditionName::new(context))),
-        context_source,
-    )
+    match ContextExpr::parse(&context) {
+        Ok(context_expr) => (Some(context_expr), context_source),
+        Err(error) => {
+            let error_source = context_source
+                .as_ref()
+                .map(|context_source| context_source.narrowed(&error.range));
+            diagnostics.push(context_diagnostic(
+                diagnostic_origin,
+                error_source.as_ref(),
+                block_index,
+                context,
+                DiagnosticKind::Syntax,
+                DiagnosticSeverity::Failure,
+                error.message,
+            ));
+            (None, context_source)
+        },
+    }
 }
 
 fn parse_bindings(
@@ -396,18 +410,27 @@ pub(super) struct Binding {
 pub(super) enum BindingEdit {
     /// Binds a keystroke sequence to a command.
     Bind(CommandId),
+    /// Binds a keystroke sequence to a command and the typed arguments authored after it.
+    BindWithArguments {
+        command_id: CommandId,
+        arguments:  BindingArguments,
+    },
     /// Removes a binding inherited from an earlier block.
     Unbind,
 }
 
-/// A keymap context expression.
-///
-/// A bare condition name remains valid when the keymap grammar later adds boolean operators, so
-/// this enum preserves the expression boundary instead of storing a [`ConditionName`] directly.
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub(super) enum ContextExpr {
-    /// A single application-defined condition name.
-    Name(ConditionName),
+impl BindingEdit {
+    /// Splits a binding into its command and authored arguments, or `None` for an unbind.
+    pub(super) fn into_command(self) -> Option<(CommandId, Option<BindingArguments>)> {
+        match self {
+            Self::Bind(command_id) => Some((command_id, None)),
+            Self::BindWithArguments {
+                command_id,
+                arguments,
+            } => Some((command_id, Some(arguments))),
+            Self::Unbind => None,
+        }
+    }
 }
 
 /// The source location for one parsed context expression.
@@ -428,6 +451,22 @@ impl ContextSource {
             block_index,
         }
     }
+
+    /// The location of `range`, a byte range within the context text.
+    ///
+    /// Context strings sit on one line, so the column advances by the bytes skipped. An escape
+    /// sequence earlier in the string shifts the result, and the range is clamped to the string.
+    pub(super) fn narrowed(&self, range: &Range<usize>) -> Self {
+        let start = (self.byte_range.start + range.start).min(self.byte_range.end);
+        let end = (self.byte_range.start + range.end).clamp(start, self.byte_range.end);
+
+        Self {
+            byte_range:  start..end,
+            line:        self.line,
+            column:      self.column + (start - self.byte_range.start),
+            block_index: self.block_index,
+        }
+    }
 }
 
 /// The retained source locations for one authored binding key and command value.
@@ -457,6 +496,17 @@ impl BindingSource {
         }
     }
 
+    /// One-based line of the authored binding key.
+    pub(super) const fn line(&self) -> usize { self.key.line }
+
+    /// One-based column of the authored binding key.
+    pub(super) const fn column(&self) -> usize { self.key.column }
+
+    /// Whether `other` was parsed from the same binding key as `self`.
+    pub(super) fn is_same_binding(&self, other: &Self) -> bool {
+        self.key.byte_range == other.key.byte_range
+    }
+
     pub(super) fn diagnostic(
         &self,
         diagnostic_origin: &DiagnosticOrigin,
@@ -570,34 +620,62 @@ fn parse_binding_edit(
     match wire_value {
         Value::String(command_id) => match CommandId::try_from(command_id.as_str()) {
             Ok(command_id) => BindingEditResult::Edit(BindingEdit::Bind(command_id)),
-            Err(_) => BindingEditResult::Diagnostic(binding_source.command_diagnostic(
+            Err(_) => BindingEditResult::Diagnostic(invalid_command_id(
                 diagnostic_origin,
-                command_id.clone(),
-                DiagnosticKind::Command,
-                DiagnosticSeverity::Failure,
-                format!(
-                    "Command ID `{command_id}` requires one :: separator and snake-case segments."
-                ),
+                binding_source,
+                &command_id,
             )),
         },
         Value::Null => BindingEditResult::Edit(BindingEdit::Unbind),
-        Value::Array(_) => BindingEditResult::Diagnostic(binding_source.diagnostic(
-            diagnostic_origin,
-            String::new(),
-            DiagnosticKind::Syntax,
-            DiagnosticSeverity::Failure,
-            "Binding arguments are not yet supported.".to_owned(),
-        )),
+        Value::Array(elements) => {
+            let [Value::String(command_id), arguments] = <[Value; 2]>::try_from(elements)
+                .unwrap_or([Value::Null, Value::Null])
+            else {
+                return BindingEditResult::Diagnostic(binding_source.diagnostic(
+                    diagnostic_origin,
+                    String::new(),
+                    DiagnosticKind::Syntax,
+                    DiagnosticSeverity::Failure,
+                    "Binding arguments must be written as a command ID string followed by one arguments value.".to_owned(),
+                ));
+            };
+            match CommandId::try_from(command_id.as_str()) {
+                Ok(command_id) => BindingEditResult::Edit(BindingEdit::BindWithArguments {
+                    command_id,
+                    arguments: BindingArguments::new(arguments),
+                }),
+                Err(_) => BindingEditResult::Diagnostic(invalid_command_id(
+                    diagnostic_origin,
+                    binding_source,
+                    &command_id,
+                )),
+            }
+        },
         _ => BindingEditResult::Diagnostic(binding_source.diagnostic(
             diagnostic_origin,
             String::new(),
             DiagnosticKind::Syntax,
             DiagnosticSeverity::Failure,
-            "Binding values must be command ID strings or null.".to_owned(),
+            "Binding values must be command ID strings, command ID and arguments arrays, or null."
+                .to_owned(),
         )),
     }
 }
 
+fn invalid_command_id(
+    diagnostic_origin: &DiagnosticOrigin,
+    binding_source: &BindingSource,
+    command_id: &str,
+) -> Diagnostic {
+    binding_source.command_diagnostic(
+        diagnostic_origin,
+        command_id.to_owned(),
+        DiagnosticKind::Command,
+        DiagnosticSeverity::Failure,
+        format!("Command ID `{command_id}` requires one :: separator and snake-case segments."),
+    )
+}
+
 fn context_diagnostic(
     diagnostic_origin: &DiagnosticOrigin,
     context_source: Option<&ContextSource>,
@@ -803,7 +881,92 @@ impl<'de> Deserialize<'de> for WireBindings {
     }
 }
 
+/// The byte ranges an in-place edit of a keymap document splices around.
+///
+/// Unlike [`KeymapDocument`], the layout keeps every block and binding the scanner found, valid or
+/// not, so an edit can leave everything it does not touch exactly as authored.
+pub(super) struct SourceLayout {
+    /// The `bindings` array, from `[` through `]`, when the root has one.
+    pub(super) bindings_array: Option<Range<usize>>,
+    pub(super) blocks:         Vec<BlockLayout>,
+}
+
+impl SourceLayout {
+    /// Scans `source` for the structure an edit needs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the scanner's message, with its byte offset, when `source` is not a keymap envelope.
+    pub(super) fn parse(source: &str) -> Result<Self, String> {
+        let source_index = SourceIndex::parse(source)
+            .map_err(|error| format!("{} (at byte {})", error.message, error.offset))?;
+
+        Ok(Self {
+            bindings_array: source_index.bindings_array,
+            blocks:         source_index
+                .blocks
+                .into_iter()
+                .map(|block| BlockLayout {
+                    context:         match (block.context, block.context_text) {
+                        (None, _) => BlockContext::Global,
+                        (Some(_), Some(text)) => BlockContext::Authored(text),
+                        (Some(_), None) => BlockContext::Unreadable,
+                    },
+                    object:          block.object,
+                    comma:           block.comma,
+                    bindings_object: block.bindings_object,
+                    bindings:        block
+                        .bindings
+                        .into_iter()
+                        .map(|binding| BindingLayout {
+                            member: binding.key.byte_range.start - 1..binding.value_token.end,
+                            key:    binding.key_text,
+                            value:  binding.value_token,
+                            comma:  binding.comma,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        })
+    }
+}
+
+/// One keymap block's byte ranges.
+pub(super) struct BlockLayout {
+    pub(super) context:         BlockContext,
+    /// The block object, from `{` through `}`.
+    pub(super) object:          Range<usize>,
+    /// The offset of the `,` that follows the block, if any.
+    pub(super) comma:           Option<usize>,
+    /// The `bindings` object, from `{` through `}`, when the block has one.
+    pub(super) bindings_object: Option<Range<usize>>,
+    pub(super) bindings:        Vec<BindingLayout>,
+}
+
+/// The context a block was authored under, as far as an edit needs to tell blocks apart.
+pub(super) enum BlockContext {
+    /// The block has no `context` member.
+    Global,
+    /// The block's `context` member is this string.
+    Authored(String),
+    /// The block's `context` member is not a string, so no edit targets it.
+    Unreadable,
+}
+
+/// One binding member's byte ranges.
+pub(super) struct BindingLayout {
+    /// The decoded binding key.
+    pub(super) key:    String,
+    /// The member, from the key's opening quote through the end of its value.
+    pub(super) member: Range<usize>,
+    /// The value token.
+    pub(super) value:  Range<usize>,
+    /// The offset of the `,` that follows the member, if any.
+    pub(super) comma:  Option<usize>,
+}
+
 struct SourceIndex {
+    bindings_array:            Option<Range<usize>>,
     blocks:                    Vec<IndexedBlock>,
     unrecognized_root_members: Vec<IndexedRootMember>,
 }
@@ -815,11 +978,13 @@ impl SourceIndex {
         scanner.expect_byte(b'{', "Keymap documents must start with an object.")?;
 
         let mut blocks = None;
+        let mut bindings_array = None;
         scanner.skip_trivia()?;
 
         if scanner.consume_byte(b'}') {
             return Ok(Self {
-                blocks:                    Vec::new(),
+                bindings_array,
+                blocks: Vec::new(),
                 unrecognized_root_members: Vec::new(),
             });
         }
@@ -833,7 +998,9 @@ impl SourceIndex {
 
             let member_name = scanner.string_value(&key)?;
             if member_name == "bindings" && scanner.peek_byte() == Some(b'[') {
+                let start = scanner.offset;
                 blocks = Some(scanner.parse_blocks()?);
+                bindings_array = Some(start..scanner.offset);
             } else if RECOGNIZED_ROOT_MEMBERS.contains(&member_name.as_str()) {
                 scanner.skip_value()?;
             } else {
@@ -861,6 +1028,7 @@ impl SourceIndex {
         }
 
         Ok(Self {
+            bindings_array,
             blocks: blocks.unwrap_or_default(),
             unrecognized_root_members,
         })
@@ -873,14 +1041,21 @@ struct IndexedRootMember {
 }
 
 struct IndexedBlock {
+    object:               Range<usize>,
+    comma:                Option<usize>,
     context:              Option<SourceLocation>,
+    context_text:         Option<String>,
+    bindings_object:      Option<Range<usize>>,
     bindings:             Vec<IndexedBinding>,
     unrecognized_members: Vec<IndexedBlockMember>,
 }
 
 struct IndexedBinding {
-    key:   SourceLocation,
-    value: SourceLocation,
+    key:         SourceLocation,
+    key_text:    String,
+    value:       SourceLocation,
+    value_token: Range<usize>,
+    comma:       Option<usize>,
 }
 
 struct IndexedBlockMember {
@@ -929,8 +1104,12 @@ impl<'source> JsoncScanner<'source> {
         }
 
         loop {
-            blocks.push(self.parse_block()?);
+            let mut block = self.parse_block()?;
             self.skip_trivia()?;
+            if self.peek_byte() == Some(b',') {
+                block.comma = Some(self.offset);
+            }
+            blocks.push(block);
             if self.consume_byte(b']') {
                 break;
             }
@@ -945,15 +1124,23 @@ impl<'source> JsoncScanner<'source> {
     }
 
     fn parse_block(&mut self) -> Result<IndexedBlock, SourceIndexError> {
+        self.skip_trivia()?;
+        let start = self.offset;
         self.expect_byte(b'{', "Expected a keymap block object.")?;
         self.skip_trivia()?;
         let mut context = None;
+        let mut context_text = None;
+        let mut bindings_object = None;
         let mut bindings = None;
         let mut unrecognized_members = Vec::new();
 
         if self.consume_byte(b'}') {
             return Ok(IndexedBlock {
+                object: start..self.offset,
+                comma: None,
                 context,
+                context_text,
+                bindings_object,
                 bindings: Vec::new(),
                 unrecognized_members,
             });
@@ -967,9 +1154,15 @@ impl<'source> JsoncScanner<'source> {
 
             let member_name = self.string_value(&key)?;
             match member_name.as_str() {
-                "context" => context = Some(self.parse_context_location()?),
+                "context" => {
+                    let (location, text) = self.parse_context_location()?;
+                    context = Some(location);
+                    context_text = text;
+                },
                 "bindings" if self.peek_byte() == Some(b'{') => {
+                    let bindings_start = self.offset;
                     bindings = Some(self.parse_binding_locations()?);
+                    bindings_object = Some(bindings_start..self.offset);
                 },
                 _ => {
                     unrecognized_members.push(IndexedBlockMember {
@@ -992,7 +1185,11 @@ impl<'source> JsoncScanner<'source> {
         }
 
         Ok(IndexedBlock {
+            object: start..self.offset,
+            comma: None,
             context,
+            context_text,
+            bindings_object,
             bindings: bindings.unwrap_or_default(),
             unrecognized_members,
         })
@@ -1009,21 +1206,27 @@ impl<'source> JsoncScanner<'source> {
 
         loop {
             let key = self.parse_string()?;
+            let key_text = self.string_value(&key)?;
             self.skip_trivia()?;
             self.expect_byte(b':', "Expected `:` after a binding key.")?;
             self.skip_trivia()?;
+            let value_start = self.offset;
             let value = if self.peek_byte() == Some(b'"') {
                 SourceLocation::from_token(self.source, self.parse_string()?)
             } else {
-                let start = self.offset;
                 self.skip_value()?;
-                SourceLocation::from_range(self.source, start..self.offset)
+                SourceLocation::from_range(self.source, value_start..self.offset)
             };
+            let value_token = value_start..self.offset;
+            self.skip_trivia()?;
+            let comma = (self.peek_byte() == Some(b',')).then_some(self.offset);
             bindings.push(IndexedBinding {
                 key: SourceLocation::from_token(self.source, key),
+                key_text,
                 value,
+                value_token,
+                comma,
             });
-            self.skip_trivia()?;
             if self.consume_byte(b'}') {
                 break;
             }
@@ -1037,17 +1240,21 @@ impl<'source> JsoncScanner<'source> {
         Ok(bindings)
     }
 
-    fn parse_context_location(&mut self) -> Result<SourceLocation, SourceIndexError> {
+    fn parse_context_location(
+        &mut self,
+    ) -> Result<(SourceLocation, Option<String>), SourceIndexError> {
         if self.peek_byte() == Some(b'\"') {
-            return Ok(SourceLocation::from_token(
-                self.source,
-                self.parse_string()?,
-            ));
+            let token = self.parse_string()?;
+            let text = self.string_value(&token)?;
+            return Ok((SourceLocation::from_token(self.source, token), Some(text)));
         }
 
         let start = self.offset;
         self.skip_value()?;
-        Ok(SourceLocation::from_range(self.source, start..self.offset))
+        Ok((
+            SourceLocation::from_range(self.source, start..self.offset),
+            None,
+        ))
     }
 
     fn skip_value(&mut self) -> Result<(), SourceIndexError> {
@@ -1256,12 +1463,16 @@ struct SourceIndexError {
 mod tests {
     use std::path::PathBuf;
 
+    use serde_json_lenient::Value;
+
     use super::BindingEdit;
+    use super::BlockContext;
     use super::ContextExpr;
     use super::DiagnosticKind;
     use super::DiagnosticOrigin;
     use super::KeymapDocument;
     use super::SourceIndex;
+    use super::SourceLayout;
 
     const SOURCE_PATH: &str = "keymap.jsonc";
 
@@ -1305,7 +1516,7 @@ mod tests {
         ));
         assert!(matches!(
             document.blocks[1].context,
-            Some(ContextExpr::Name(ref condition_name)) if condition_name.as_str() == "dimension_lock"
+            Some(ContextExpr::Name(ref condition_name, _)) if condition_name.as_str() == "dimension_lock"
         ));
         assert!(matches!(
             document.blocks[1].bindings[0].edit,
@@ -1387,28 +1598,29 @@ mod tests {
     }
 
     #[test]
-    fn binding_arguments_are_rejected_until_they_are_supported() {
+    fn binding_arguments_follow_the_command_id() {
         let source = r#"{
             "bindings": [{ "bindings": {
-                "space": ["transport::toggle_playback", {}],
+                "ctrl-1": ["app::select_tab", { "index": 1 }],
                 "enter": "editor::copy"
             } }]
         }"#;
 
-        let (document, diagnostics) = KeymapDocument::parse(&keymap_file(), source)
-            .expect("binding argument diagnostics retain sibling bindings");
+        let (document, diagnostics) =
+            KeymapDocument::parse(&keymap_file(), source).expect("binding arguments parse");
 
-        assert_eq!(diagnostics.len(), 1);
-        assert_eq!(diagnostics[0].kind, DiagnosticKind::Syntax);
-        assert_eq!(
-            diagnostics[0].message,
-            "Binding arguments are not yet supported."
-        );
-        assert_eq!(document.blocks[0].bindings.len(), 1);
+        assert!(diagnostics.is_empty());
+        assert!(matches!(
+            document.blocks[0].bindings[0].edit,
+            BindingEdit::BindWithArguments { ref command_id, ref arguments }
+                if command_id.as_str() == "app::select_tab"
+                    && arguments.value().get("index").and_then(Value::as_u64) == Some(1)
+        ));
+        assert_eq!(document.blocks[0].bindings.len(), 2);
     }
 
     #[test]
-    fn numeric_binding_arguments_followed_by_comments_are_rejected() {
+    fn numeric_binding_arguments_followed_by_comments_are_parsed() {
         let source = r#"{
             "bindings": [{ "bindings": {
                 "space": ["transport::toggle_playback", 1/* explanation */],
@@ -1416,16 +1628,82 @@ mod tests {
             } }]
         }"#;
 
+        let (document, diagnostics) =
+            KeymapDocument::parse(&keymap_file(), source).expect("binding arguments parse");
+
+        assert!(diagnostics.is_empty());
+        assert!(matches!(
+            document.blocks[0].bindings[0].edit,
+            BindingEdit::BindWithArguments { ref arguments, .. }
+                if arguments.value().as_u64() == Some(1)
+        ));
+        assert_eq!(document.blocks[0].bindings.len(), 2);
+    }
+
+    #[test]
+    fn malformed_binding_argument_arrays_are_rejected() {
+        let source = r#"{
+            "bindings": [{ "bindings": {
+                "space": ["transport::toggle_playback"],
+                "tab": [1, {}],
+                "enter": "editor::copy"
+            } }]
+        }"#;
+
         let (document, diagnostics) = KeymapDocument::parse(&keymap_file(), source)
             .expect("binding argument diagnostics retain sibling bindings");
 
+        assert_eq!(diagnostics.len(), 2);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.kind == DiagnosticKind::Syntax)
+        );
+        assert_eq!(document.blocks[0].bindings.len(), 1);
+    }
+
+    #[test]
+    fn boolean_context_expressions_parse_into_operator_trees() {
+        let source = r#"{
+            "bindings": [{
+                "context": "editing && !modal",
+                "bindings": { "space": "transport::toggle_playback" }
+            }]
+        }"#;
+
+        let (document, diagnostics) =
+            KeymapDocument::parse(&keymap_file(), source).expect("valid context expression");
+
+        assert!(diagnostics.is_empty());
+        assert!(matches!(
+            document.blocks[0].context,
+            Some(ContextExpr::And(ref left, ref right))
+                if matches!(**left, ContextExpr::Name(ref name, _) if name.as_str() == "editing")
+                    && matches!(**right, ContextExpr::Not(_))
+        ));
+        assert_eq!(
+            document.blocks[0].bindings[0].source.context,
+            "editing && !modal"
+        );
+    }
+
+    #[test]
+    fn malformed_context_expressions_are_located_within_the_context_string() {
+        let source =
+            r#"{"bindings":[{"context":"editing &| modal","bindings":{"a":"editor::copy"}}]}"#;
+        let context_start = source.find("editing").expect("test source names a context");
+
+        let (document, diagnostics) = KeymapDocument::parse(&keymap_file(), source)
+            .expect("context syntax diagnostics retain the document");
+
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(diagnostics[0].kind, DiagnosticKind::Syntax);
         assert_eq!(
-            diagnostics[0].message,
-            "Binding arguments are not yet supported."
+            diagnostics[0].byte_range,
+            context_start + 8..context_start + 9
         );
-        assert_eq!(document.blocks[0].bindings.len(), 1);
+        assert_eq!(diagnostics[0].column, context_start + 9);
+        assert!(document.blocks[0].context.is_none());
     }
 
     #[test]
@@ -1637,4 +1915,38 @@ mod tests {
 
         assert_binding_key_slice(source, 0, "named");
     }
+
+    #[test]
+    fn source_layout_spans_members_and_records_their_commas() -> Result<(), String> {
+        let source = r#"{ "bindings": [ { "context": "editing", "bindings": { "ctrl-c": "editor::copy" /* note */, "ctrl-v": null } }, ] }"#;
+
+        let source_layout = SourceLayout::parse(source)?;
+
+        let block = &source_layout.blocks[0];
+        assert!(matches!(&block.context, BlockContext::Authored(context) if context == "editing"));
+        assert!(
+            block
+                .comma
+                .is_some_and(|comma| &source[comma..=comma] == ",")
+        );
+        let bindings = block
+            .bindings
+            .iter()
+            .map(|binding| {
+                (
+                    binding.key.as_str(),
+                    &source[binding.member.clone()],
+                    binding.comma.is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bindings,
+            vec![
+                ("ctrl-c", r#""ctrl-c": "editor::copy""#, true),
+                ("ctrl-v", r#""ctrl-v": null"#, false),
+            ]
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,471 @@
+//! Validated, atomic writes of keymap transactions to the user keymap file.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::str;
+
+use bevy::ecs::world::World;
+
+use super::KeymapEditError;
+use super::KeymapHistory;
+use super::KeymapRevision;
+use super::KeymapTransaction;
+use crate::CommandRegistry;
+use crate::Diagnostic;
+use crate::DiagnosticOrigin;
+use crate::DiagnosticSeverity;
+use crate::KeymapPathAvailability;
+use crate::KeymapPathFailure;
+use crate::condition::ConditionRegistry;
+use crate::disk::USER_KEYMAP_STUB;
+use crate::disk::read_config_file;
+use crate::disk::replace_config_file;
+use crate::keymap::ReloadConfiguration;
+use crate::keymap::merged::UserKeymap;
+
+/// Validates `transaction` against the live registries and writes it over the user keymap.
+///
+/// The disk watcher then reloads the written file like any other save.
+pub(crate) fn apply_transaction(
+    world: &mut World,
+    transaction: &KeymapTransaction,
+) -> Result<KeymapRevision, KeymapEditError> {
+    let EditedUserKeymap {
+        user_keymap,
+        found,
+        before,
+        after,
+    } = edit_user_keymap(world, transaction)?;
+    if after == before {
+        return Ok(found);
+    }
+
+    let introduced = introduced_failures(world, &user_keymap, &before, &after);
+    if !introduced.is_empty() {
+        return Err(KeymapEditError::Rejected(introduced));
+    }
+
+    replace_config_file(&user_keymap, after.as_bytes()).map_err(disk_error)?;
+    let revision = KeymapRevision::of(Some(after.as_bytes()));
+    world
+        .resource_mut::<KeymapHistory>()
+        .record(before.into_bytes(), after.into_bytes());
+
+    Ok(revision)
+}
+
+/// Reports the conflicts the keymap would have with `transaction` applied, writing nothing.
+///
+/// The edited keymap is validated the same way [`apply_transaction`] validates it, so an edit
+/// this previews cleanly is one that applying would accept.
+pub(crate) fn preview_transaction(
+    world: &World,
+    transaction: &KeymapTransaction,
+) -> Result<Vec<Diagnostic>, KeymapEditError> {
+    let EditedUserKeymap {
+        user_keymap,
+        before,
+        after,
+        ..
+    } = edit_user_keymap(world, transaction)?;
+    let introduced = introduced_failures(world, &user_keymap, &before, &after);
+    if !introduced.is_empty() {
+        return Err(KeymapEditError::Rejected(introduced));
+    }
+
+    Ok(world.resource::<ReloadConfiguration>().conflicts(
+        &UserKeymap::Layered {
+            origin:   DiagnosticOrigin::KeymapFile(user_keymap),
+            contents: after,
+        },
+        world.resource::<CommandRegistry>(),
+        world.resource::<ConditionRegistry>(),
+    ))
+}
+
+/// Restores the user keymap from before the latest applied transaction.
+pub(crate) fn undo_transaction(world: &mut World) -> Result<KeymapRevision, KeymapEditError> {
+    let user_keymap = user_keymap_path(world)?;
+    world.resource_mut::<KeymapHistory>().undo(&user_keymap)
+}
+
+/// Reapplies the latest undone transaction to the user keymap.
+pub(crate) fn redo_transaction(world: &mut World) -> Result<KeymapRevision, KeymapEditError> {
+    let user_keymap = user_keymap_path(world)?;
+    world.resource_mut::<KeymapHistory>().redo(&user_keymap)
+}
+
+/// The user keymap file read at the transaction's base revision, before and after its edits.
+struct EditedUserKeymap {
+    user_keymap: PathBuf,
+    found:       KeymapRevision,
+    before:      String,
+    after:       String,
+}
+
+/// Applies `transaction` to the user keymap in memory, refusing a file that changed since the
+/// transaction was prepared.
+fn edit_user_keymap(
+    world: &World,
+    transaction: &KeymapTransaction,
+) -> Result<EditedUserKeymap, KeymapEditError> {
+    let user_keymap = user_keymap_path(world)?;
+    let current = read_config_file(&user_keymap).map_err(disk_error)?;
+    let found = KeymapRevision::of(current.as_deref());
+    if found != transaction.base() {
+        return Err(KeymapEditError::Stale {
+            expected: transaction.base(),
+            found,
+        });
+    }
+
+    let before = str::from_utf8(current.as_deref().unwrap_or(USER_KEYMAP_STUB))
+        .map_err(|error| {
+            KeymapEditError::Malformed(format!("the user keymap is not valid UTF-8: {error}"))
+        })?
+        .to_owned();
+    let after = transaction.apply(
+        before.clone(),
+        world.resource::<ReloadConfiguration>().published_defaults(),
+    )?;
+
+    Ok(EditedUserKeymap {
+        user_keymap,
+        found,
+        before,
+        after,
+    })
+}
+
+fn user_keymap_path(world: &World) -> Result<PathBuf, KeymapEditError> {
+    world
+        .get_resource::<KeymapPathAvailability>()
+        .map_or(
+            Err(KeymapPathFailure::AppNameNotConfigured),
+            |availability| {
+                availability
+                    .resolved()
+                    .map(|paths| paths.user_keymap().to_path_buf())
+            },
+        )
+        .map_err(KeymapEditError::Unavailable)
+}
+
+/// The user-keymap failures `after` merges with that `before` did not already have.
+///
+/// Failures an edit did not cause are left for the user to fix, so a keymap that already carried
+/// an unknown command can still be edited elsewhere.
+fn introduced_failures(
+    world: &World,
+    user_keymap: &Path,
+    before: &str,
+    after: &str,
+) -> Vec<Diagnostic> {
+    let origin = DiagnosticOrigin::KeymapFile(user_keymap.to_path_buf());
+    let existing = merge_failures(world, &origin, before);
+
+    merge_failures(world, &origin, after)
+        .into_iter()
+        .filter(|failure| {
+            !existing
+                .iter()
+                .any(|existing| same_failure(existing, failure))
+        })
+        .collect()
+}
+
+/// The failures `contents` reports at `origin` when layered over the published defaults.
+pub(crate) fn merge_failures(
+    world: &World,
+    origin: &DiagnosticOrigin,
+    contents: &str,
+) -> Vec<Diagnostic> {
+    let user_keymap = UserKeymap::Layered {
+        origin:   origin.clone(),
+        contents: contents.to_owned(),
+    };
+    let diagnostics = match world.resource::<ReloadConfiguration>().merge(
+        &user_keymap,
+        world.resource::<CommandRegistry>(),
+        world.resource::<ConditionRegistry>(),
+    ) {
+        Ok((_, diagnostics)) | Err(diagnostics) => diagnostics,
+    };
+
+    diagnostics
+        .into_iter()
+        .filter(|diagnostic| {
+            diagnostic.origin == *origin && diagnostic.severity == DiagnosticSeverity::Failure
+        })
+        .collect()
+}
+
+/// Whether two failures report the same problem, wherever an edit moved its bytes to.
+fn same_failure(left: &Diagnostic, right: &Diagnostic) -> bool {
+    left.kind == right.kind
+        && left.message == right.message
+        && left.context == right.context
+        && left.original_keystroke == right.original_keystroke
+        && left.command_id == right.command_id
+}
+
+fn disk_error(error: std::io::Error) -> KeymapEditError { KeymapEditError::Disk(error.to_string()) }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    use bevy::ecs::world::World;
+    use bevy::prelude::Event;
+    use bevy::prelude::Reflect;
+    use bevy::prelude::ReflectEvent;
+    use bevy::reflect::TypeRegistry;
+    use bevy_enhanced_input::prelude::CustomInputs;
+
+    use super::apply_transaction;
+    use super::preview_transaction;
+    use super::redo_transaction;
+    use super::undo_transaction;
+    use crate::Capability;
+    use crate::CommandId;
+    use crate::CommandRegistry;
+    use crate::DiagnosticKind;
+    use crate::DiagnosticOrigin;
+    use crate::HoldPhase;
+    use crate::KeymapCommand;
+    use crate::KeymapPathAvailability;
+    use crate::KeystrokeSequence;
+    use crate::ReflectKeymapCommand;
+    use crate::condition::ConditionRegistry;
+    use crate::disk::ENVIRONMENT_LOCK;
+    use crate::disk::TestDirectory;
+    use crate::disk::XdgConfigHome;
+    use crate::keymap::ReloadConfiguration;
+    use crate::keymap::edit::KeymapEdit;
+    use crate::keymap::edit::KeymapEditError;
+    use crate::keymap::edit::KeymapHistory;
+    use crate::keymap::edit::KeymapRevision;
+    use crate::keymap::edit::KeymapTransaction;
+
+    const TEST_APP_NAME: &str = "hana-rubric-keymap-edit-test";
+    const DEFAULTS: &str = r#"{ "bindings": [ { "bindings": { "ctrl-1": "edit::first" } } ] }"#;
+    const USER_KEYMAP: &str = r#"// my keymap
+{
+  "$schema": "./keymap.schema.json",
+  "bindings": [
+    {
+      "bindings": {
+        "ctrl-9": "edit::first", // a second way in
+      },
+    },
+  ],
+}
+"#;
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand)]
+    struct EditFirst;
+
+    impl KeymapCommand for EditFirst {
+        const ID: &'static str = "edit::first";
+        const TITLE: &'static str = "Edit First";
+        const DESCRIPTION: &'static str = "First command used by keymap edit tests.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand)]
+    struct EditSecond;
+
+    impl KeymapCommand for EditSecond {
+        const ID: &'static str = "edit::second";
+        const TITLE: &'static str = "Edit Second";
+        const DESCRIPTION: &'static str = "Second command used by keymap edit tests.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    /// A world holding the resources keymap edits read, with `USER_KEYMAP` written to disk.
+    fn edit_world() -> Result<(World, PathBuf), String> {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<EditFirst>();
+        type_registry.register::<EditSecond>();
+        let command_registry = CommandRegistry::build(&type_registry, &mut CustomInputs::default())
+            .map_err(|diagnostics| format!("edit registry diagnostics: {diagnostics:?}"))?;
+        let keymap_path_availability = KeymapPathAvailability::for_app_name(TEST_APP_NAME);
+        let user_keymap = keymap_path_availability
+            .resolved()
+            .map_err(|keymap_path_failure| format!("{keymap_path_failure:?}"))?
+            .user_keymap()
+            .to_path_buf();
+        if let Some(config_directory) = user_keymap.parent() {
+            fs::create_dir_all(config_directory).map_err(|error| error.to_string())?;
+        }
+        fs::write(&user_keymap, USER_KEYMAP).map_err(|error| error.to_string())?;
+
+        let mut world = World::new();
+        world.insert_resource(command_registry);
+        world.insert_resource(ConditionRegistry::default());
+        world.insert_resource(keymap_path_availability);
+        world.init_resource::<KeymapHistory>();
+        world.insert_resource(ReloadConfiguration::new(
+            DiagnosticOrigin::EmbeddedDefaults,
+            DEFAULTS.to_owned(),
+            Vec::new(),
+            false,
+        ));
+
+        Ok((world, user_keymap))
+    }
+
+    fn add(command: &str, keystroke: &str) -> Result<KeymapEdit, String> {
+        Ok(KeymapEdit::Add {
+            command:   CommandId::try_from(command).map_err(|error| error.to_string())?,
+            context:   None,
+            keystroke: KeystrokeSequence::from_str(keystroke).map_err(|error| error.to_string())?,
+        })
+    }
+
+    fn read(user_keymap: &Path) -> Result<String, String> {
+        fs::read_to_string(user_keymap).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn applied_transactions_preserve_comments_and_undo_and_redo() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-edit-history").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, user_keymap) = edit_world()?;
+        let transaction = KeymapTransaction::new(KeymapRevision::of(Some(USER_KEYMAP.as_bytes())))
+            .with_edit(add("edit::second", "ctrl-2")?);
+
+        let written =
+            apply_transaction(&mut world, &transaction).map_err(|error| error.to_string())?;
+
+        let edited = read(&user_keymap)?;
+        assert_eq!(
+            edited,
+            USER_KEYMAP.replace(
+                "// a second way in\n",
+                "// a second way in\n        \"ctrl-2\": \"edit::second\",\n"
+            )
+        );
+        assert_eq!(world.resource::<KeymapHistory>().revision(), Some(written));
+        assert!(matches!(
+            apply_transaction(&mut world, &transaction),
+            Err(KeymapEditError::Stale { .. })
+        ));
+
+        undo_transaction(&mut world).map_err(|error| error.to_string())?;
+        assert_eq!(read(&user_keymap)?, USER_KEYMAP);
+        redo_transaction(&mut world).map_err(|error| error.to_string())?;
+        assert_eq!(read(&user_keymap)?, edited);
+        assert_eq!(
+            redo_transaction(&mut world),
+            Err(KeymapEditError::NothingToRedo)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn edits_that_introduce_failures_are_rejected_without_writing() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-edit-rejected").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, user_keymap) = edit_world()?;
+        let transaction = KeymapTransaction::new(KeymapRevision::of(Some(USER_KEYMAP.as_bytes())))
+            .with_edit(add("edit::missing", "ctrl-3")?);
+
+        let result = apply_transaction(&mut world, &transaction);
+
+        assert!(matches!(result, Err(KeymapEditError::Rejected(failures)) if !failures.is_empty()));
+        assert_eq!(read(&user_keymap)?, USER_KEYMAP);
+        assert!(!world.resource::<KeymapHistory>().can_undo());
+        Ok(())
+    }
+
+    #[test]
+    fn previews_report_the_conflicts_an_edit_would_introduce_without_writing() -> Result<(), String>
+    {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-edit-preview").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (world, user_keymap) = edit_world()?;
+        let base = KeymapRevision::of(Some(USER_KEYMAP.as_bytes()));
+
+        let conflicts = preview_transaction(
+            &world,
+            &KeymapTransaction::new(base).with_edit(add("edit::second", "ctrl-1")?),
+        )
+        .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(|conflict| conflict.kind)
+                .collect::<Vec<_>>(),
+            [DiagnosticKind::ShadowedDefault]
+        );
+        assert!(matches!(
+            preview_transaction(
+                &world,
+                &KeymapTransaction::new(base).with_edit(add("edit::missing", "ctrl-3")?),
+            ),
+            Err(KeymapEditError::Rejected(failures)) if !failures.is_empty()
+        ));
+        assert_eq!(read(&user_keymap)?, USER_KEYMAP);
+        assert!(!world.resource::<KeymapHistory>().can_undo());
+        Ok(())
+    }
+
+    #[test]
+    fn an_external_save_clears_the_history_instead_of_being_undone() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-edit-external").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, user_keymap) = edit_world()?;
+        let transaction = KeymapTransaction::new(KeymapRevision::of(Some(USER_KEYMAP.as_bytes())))
+            .with_edit(add("edit::second", "ctrl-2")?);
+        let written =
+            apply_transaction(&mut world, &transaction).map_err(|error| error.to_string())?;
+
+        world.resource_mut::<KeymapHistory>().observe(written);
+        assert!(world.resource::<KeymapHistory>().can_undo());
+
+        let external = "{ \"bindings\": [] }";
+        fs::write(&user_keymap, external).map_err(|error| error.to_string())?;
+        world
+            .resource_mut::<KeymapHistory>()
+            .observe(KeymapRevision::of(Some(external.as_bytes())));
+
+        assert!(!world.resource::<KeymapHistory>().can_undo());
+        assert_eq!(
+            undo_transaction(&mut world),
+            Err(KeymapEditError::NothingToUndo)
+        );
+        assert_eq!(read(&user_keymap)?, external);
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+//! Undo and redo over the user-keymap transactions applied this session.
+
+use std::collections::VecDeque;
+use std::path::Path;
+
+use bevy::prelude::Resource;
+
+use super::KeymapEditError;
+use super::KeymapRevision;
+use crate::disk::read_config_file;
+use crate::disk::replace_config_file;
+use crate::keymap::constants::MAX_UNDO_TRANSACTIONS;
+
+/// The user-keymap revision this session last saw, and the transactions it can undo and redo.
+///
+/// The disk watcher reports every revision it reads here. A revision no recorded transaction
+/// produced is an edit made outside the application, and it clears both histories: undoing across
+/// it would overwrite the user's manual change.
+#[derive(Default, Resource)]
+pub struct KeymapHistory {
+    revision: Option<KeymapRevision>,
+    undo:     VecDeque<AppliedTransaction>,
+    redo:     Vec<AppliedTransaction>,
+}
+
+/// The file contents on either side of one applied transaction.
+struct AppliedTransaction {
+    before: Vec<u8>,
+    after:  Vec<u8>,
+}
+
+impl KeymapHistory {
+    /// Returns the latest user keymap revision, read by the disk watcher or written by an edit.
+    ///
+    /// Start a [`KeymapTransaction`](super::KeymapTransaction) from this revision. `None` means
+    /// the disk watcher has not delivered the user keymap yet.
+    #[must_use]
+    pub const fn revision(&self) -> Option<KeymapRevision> { self.revision }
+
+    /// Returns whether an applied transaction can be undone.
+    #[must_use]
+    pub fn can_undo(&self) -> bool { !self.undo.is_empty() }
+
+    /// Returns whether an undone transaction can be redone.
+    #[must_use]
+    pub const fn can_redo(&self) -> bool { !self.redo.is_empty() }
+
+    /// Records a revision the disk watcher read.
+    pub(crate) fn observe(&mut self, revision: KeymapRevision) {
+        if !self.produced(revision) {
+            self.undo.clear();
+            self.redo.clear();
+        }
+        self.revision = Some(revision);
+    }
+
+    /// Records a transaction that replaced `before` with `after`, discarding the redo history.
+    pub(super) fn record(&mut self, before: Vec<u8>, after: Vec<u8>) {
+        self.revision = Some(KeymapRevision::of(Some(&after)));
+        self.redo.clear();
+        self.undo.push_back(AppliedTransaction { before, after });
+        if self.undo.len() > MAX_UNDO_TRANSACTIONS {
+            self.undo.pop_front();
+        }
+    }
+
+    /// Restores the contents from before the latest applied transaction.
+    pub(super) fn undo(&mut self, user_keymap: &Path) -> Result<KeymapRevision, KeymapEditError> {
+        let applied = self.undo.pop_back().ok_or(KeymapEditError::NothingToUndo)?;
+
+        match restore(user_keymap, &applied.after, &applied.before) {
+            Ok(revision) => {
+                self.revision = Some(revision);
+                self.redo.push(applied);
+                Ok(revision)
+            },
+            Err(error) => {
+                self.undo.push_back(applied);
+                Err(error)
+            },
+        }
+    }
+
+    /// Reapplies the latest undone transaction.
+    pub(super) fn redo(&mut self, user_keymap: &Path) -> Result<KeymapRevision, KeymapEditError> {
+        let applied = self.redo.pop().ok_or(KeymapEditError::NothingToRedo)?;
+
+        match restore(user_keymap, &applied.before, &applied.after) {
+            Ok(revision) => {
+                self.revision = Some(revision);
+                self.undo.push_back(applied);
+                Ok(revision)
+            },
+            Err(error) => {
+                self.redo.push(applied);
+                Err(error)
+            },
+        }
+    }
+
+    fn produced(&self, revision: KeymapRevision) -> bool {
+        self.undo
+            .iter()
+            .chain(&self.redo)
+            .flat_map(|applied| [&applied.before, &applied.after])
+            .any(|contents| KeymapRevision::of(Some(contents)) == revision)
+    }
+}
+
+/// Replaces `expected` with `replacement`, unless the file no longer holds `expected`.
+fn restore(
+    user_keymap: &Path,
+    expected: &[u8],
+    replacement: &[u8],
+) -> Result<KeymapRevision, KeymapEditError> {
+    let current =
+        read_config_file(user_keymap).map_err(|error| KeymapEditError::Disk(error.to_string()))?;
+    let expected = KeymapRevision::of(Some(expected));
+    let found = KeymapRevision::of(current.as_deref());
+    if found != expected {
+        return Err(KeymapEditError::Stale { expected, found });
+    }
+
+    replace_config_file(user_keymap, replacement)
+        .map_err(|error| KeymapEditError::Disk(error.to_string()))?;
+    Ok(KeymapRevision::of(Some(replacement)))
+}
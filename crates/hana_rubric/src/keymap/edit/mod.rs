@@ -0,0 +1,17 @@
+//! Comment-preserving, undoable edits to the user keymap file.
+
+mod commit;
+mod history;
+mod source;
+mod transaction;
+
+pub(crate) use commit::apply_transaction;
+pub(crate) use commit::merge_failures;
+pub(crate) use commit::preview_transaction;
+pub(crate) use commit::redo_transaction;
+pub(crate) use commit::undo_transaction;
+pub use history::KeymapHistory;
+pub use transaction::KeymapEdit;
+pub use transaction::KeymapEditError;
+pub use transaction::KeymapRevision;
+pub use transaction::KeymapTransaction;
@@ -0,0 +1,520 @@
+//! In-place splices of a keymap document that leave every untouched byte as authored.
+
+use std::fmt::Write;
+use std::ops::Range;
+use std::str::FromStr;
+
+use serde_json_lenient::Value;
+
+use super::KeymapEditError;
+use crate::CommandId;
+use crate::KeystrokeSequence;
+use crate::keymap::context_expr::ContextExpr;
+use crate::keymap::document::BindingLayout;
+use crate::keymap::document::BlockContext;
+use crate::keymap::document::BlockLayout;
+use crate::keymap::document::SourceLayout;
+
+/// The indentation one nesting level adds when a splice opens an empty object or array.
+const INDENT: &str = "  ";
+
+/// What one authored binding value does, as far as an edit needs to know.
+#[derive(Debug, Eq, PartialEq)]
+pub(super) enum AuthoredValue {
+    /// The binding runs this command, with or without arguments.
+    Command(CommandId),
+    /// The binding is a `null` tombstone.
+    Tombstone,
+    /// The value names no valid command, so no edit matches it.
+    Unreadable,
+}
+
+impl AuthoredValue {
+    fn from_value_text(value_text: &str) -> Self {
+        let command_text = match serde_json_lenient::from_str::<Value>(value_text) {
+            Ok(Value::Null) => return Self::Tombstone,
+            Ok(Value::String(command_text)) => command_text,
+            Ok(Value::Array(values)) => match values.into_iter().next() {
+                Some(Value::String(command_text)) => command_text,
+                _ => return Self::Unreadable,
+            },
+            _ => return Self::Unreadable,
+        };
+
+        CommandId::try_from(command_text.as_str()).map_or(Self::Unreadable, Self::Command)
+    }
+}
+
+/// A keymap document being edited one splice at a time.
+///
+/// Every operation rescans the text it is about to change, so a splice never works from ranges an
+/// earlier splice moved.
+pub(super) struct EditableSource {
+    text: String,
+}
+
+impl EditableSource {
+    pub(super) const fn new(text: String) -> Self { Self { text } }
+
+    pub(super) fn into_text(self) -> String { self.text }
+
+    /// Every readable binding authored in a block under `context`, in source order.
+    pub(super) fn bindings(
+        &self,
+        context: Option<&str>,
+    ) -> Result<Vec<(KeystrokeSequence, AuthoredValue)>, KeymapEditError> {
+        let source_layout = self.layout()?;
+
+        Ok(matching_blocks(&source_layout, context)
+            .flat_map(|block| &block.bindings)
+            .filter_map(|binding| {
+                let keystroke_sequence = KeystrokeSequence::from_str(&binding.key).ok()?;
+                let value = AuthoredValue::from_value_text(&self.text[binding.value.clone()]);
+                Some((keystroke_sequence, value))
+            })
+            .collect())
+    }
+
+    /// Points `keystroke_sequence` at `value_text` under `context`.
+    ///
+    /// The last binding already authored for the sequence under `context` has its value replaced,
+    /// because that is the one the merge reads. Otherwise the binding is appended to the last
+    /// block authored under `context`, or to a new block at the end of the `bindings` array.
+    pub(super) fn set(
+        &mut self,
+        context: Option<&str>,
+        keystroke_sequence: &KeystrokeSequence,
+        value_text: &str,
+    ) -> Result<(), KeymapEditError> {
+        let source_layout = self.layout()?;
+        let blocks = matching_blocks(&source_layout, context).collect::<Vec<_>>();
+
+        if let Some(binding) = blocks
+            .iter()
+            .flat_map(|block| &block.bindings)
+            .rev()
+            .find(|binding| binds(binding, keystroke_sequence))
+        {
+            self.splice(binding.value.clone(), value_text);
+            return Ok(());
+        }
+
+        let member = format!(
+            "{}: {value_text}",
+            json_string(&keystroke_sequence.to_string())
+        );
+        if let Some((block, bindings_object)) = blocks.iter().rev().find_map(|block| {
+            block
+                .bindings_object
+                .as_ref()
+                .map(|bindings_object| (block, bindings_object))
+        }) {
+            let last_binding = block
+                .bindings
+                .last()
+                .map(|binding| (binding.member.clone(), binding.comma));
+            self.insert_item(bindings_object, last_binding, &|_| member.clone());
+            return Ok(());
+        }
+
+        let Some(bindings_array) = &source_layout.bindings_array else {
+            return Err(KeymapEditError::Malformed(
+                "The user keymap has no `bindings` array to add a block to.".to_owned(),
+            ));
+        };
+        let last_block = source_layout
+            .blocks
+            .last()
+            .map(|block| (block.object.clone(), block.comma));
+        self.insert_item(bindings_array, last_block, &|indent| {
+            block_text(context, &member, indent)
+        });
+        Ok(())
+    }
+
+    /// Removes every binding under `context` whose sequence and value satisfy `should_remove`.
+    ///
+    /// A binding that sat alone on its line takes the line with it; comments and every other
+    /// binding stay where they were.
+    pub(super) fn remove(
+        &mut self,
+        context: Option<&str>,
+        should_remove: impl Fn(&KeystrokeSequence, &AuthoredValue) -> bool,
+    ) -> Result<usize, KeymapEditError> {
+        let mut removed = 0;
+
+        loop {
+            let source_layout = self.layout()?;
+            let Some(binding) = matching_blocks(&source_layout, context)
+                .flat_map(|block| &block.bindings)
+                .find(|binding| {
+                    KeystrokeSequence::from_str(&binding.key).is_ok_and(|keystroke_sequence| {
+                        should_remove(
+                            &keystroke_sequence,
+                            &AuthoredValue::from_value_text(&self.text[binding.value.clone()]),
+                        )
+                    })
+                })
+            else {
+                return Ok(removed);
+            };
+
+            self.remove_member(binding);
+            removed += 1;
+        }
+    }
+
+    fn layout(&self) -> Result<SourceLayout, KeymapEditError> {
+        SourceLayout::parse(&self.text).map_err(KeymapEditError::Malformed)
+    }
+
+    fn remove_member(&mut self, binding: &BindingLayout) {
+        let mut removed = binding.member.clone();
+        let adjacent_comma = binding
+            .comma
+            .filter(|&comma| self.text[removed.end..comma].trim().is_empty());
+
+        match (binding.comma, adjacent_comma) {
+            (_, Some(comma)) => {
+                removed.end = comma + 1;
+                removed.end += self.text[removed.end..].len()
+                    - self.text[removed.end..]
+                        .trim_start_matches([' ', '\t'])
+                        .len();
+            },
+            (Some(comma), None) => self.splice(comma..comma + 1, ""),
+            (None, None) => {},
+        }
+
+        let removed = whole_line(&self.text, removed);
+        self.splice(removed, "");
+    }
+
+    /// Appends one item after `last_item`, or into the empty `container`.
+    ///
+    /// `item` renders the new item for the indentation its line starts with, or for `None` when
+    /// the container sits on one line. A container that keeps a trailing comma after its last item
+    /// keeps one after the new item too.
+    fn insert_item(
+        &mut self,
+        container: &Range<usize>,
+        last_item: Option<(Range<usize>, Option<usize>)>,
+        item: &dyn Fn(Option<&str>) -> String,
+    ) {
+        let is_single_line = !self.text[container.clone()].contains('\n');
+
+        match last_item {
+            Some((_, Some(comma))) if is_single_line => {
+                self.splice(comma + 1..comma + 1, &format!(" {},", item(None)));
+            },
+            Some((last_item, None)) if is_single_line => {
+                let end = last_item.end;
+                self.splice(end..end, &format!(", {}", item(None)));
+            },
+            Some((last_item, comma)) => {
+                let indent = line_indent(&self.text, last_item.start).to_owned();
+                let item = item(Some(&indent));
+                if let Some(comma) = comma {
+                    let line_end = line_end_after(&self.text, comma + 1);
+                    self.splice(line_end..line_end, &format!("\n{indent}{item},"));
+                } else {
+                    let end = last_item.end;
+                    let line_end = line_end_after(&self.text, end);
+                    self.splice(line_end..line_end, &format!("\n{indent}{item}"));
+                    self.splice(end..end, ",");
+                }
+            },
+            None => {
+                let container_indent = line_indent(&self.text, container.start).to_owned();
+                let indent = format!("{container_indent}{INDENT}");
+                let item = item(Some(&indent));
+                let interior = container.start + 1..container.end - 1;
+                if self.text[interior.clone()].trim().is_empty() {
+                    self.splice(interior, &format!("\n{indent}{item}\n{container_indent}"));
+                } else {
+                    self.splice(interior.start..interior.start, &format!("\n{indent}{item}"));
+                }
+            },
+        }
+    }
+
+    fn splice(&mut self, range: Range<usize>, replacement: &str) {
+        self.text.replace_range(range, replacement);
+    }
+}
+
+fn matching_blocks<'layout>(
+    source_layout: &'layout SourceLayout,
+    context: Option<&str>,
+) -> impl Iterator<Item = &'layout BlockLayout> {
+    let context = context.map(canonical_context);
+
+    source_layout
+        .blocks
+        .iter()
+        .filter(move |block| match (&block.context, &context) {
+            (BlockContext::Global, None) => true,
+            (BlockContext::Authored(authored), Some(context)) => {
+                canonical_context(authored) == *context
+            },
+            _ => false,
+        })
+}
+
+/// The normalized text of a context expression, so spacing and redundant parentheses do not
+/// make two spellings of one expression address different blocks.
+fn canonical_context(text: &str) -> String {
+    ContextExpr::parse(text).map_or_else(|_| text.trim().to_owned(), |context| context.to_string())
+}
+
+fn binds(binding: &BindingLayout, keystroke_sequence: &KeystrokeSequence) -> bool {
+    KeystrokeSequence::from_str(&binding.key).is_ok_and(|authored| authored == *keystroke_sequence)
+}
+
+fn block_text(context: Option<&str>, member: &str, indent: Option<&str>) -> String {
+    let context_member = context.map(|context| format!("\"context\": {}", json_string(context)));
+
+    match indent {
+        None => match context_member {
+            Some(context_member) => format!("{{ {context_member}, \"bindings\": {{ {member} }} }}"),
+            None => format!("{{ \"bindings\": {{ {member} }} }}"),
+        },
+        Some(indent) => {
+            let mut text = String::from("{\n");
+            if let Some(context_member) = context_member {
+                let _ = writeln!(text, "{indent}{INDENT}{context_member},");
+            }
+            let _ = write!(
+                text,
+                "{indent}{INDENT}\"bindings\": {{\n{indent}{INDENT}{INDENT}{member}\n{indent}{INDENT}}}\n{indent}}}"
+            );
+            text
+        },
+    }
+}
+
+/// Widens `range` to its whole line, newline included, when nothing else shares the line.
+fn whole_line(text: &str, range: Range<usize>) -> Range<usize> {
+    let line_start = text[..range.start]
+        .rfind('\n')
+        .map_or(0, |newline| newline + 1);
+    let line_end = text[range.end..]
+        .find('\n')
+        .map_or(text.len(), |newline| range.end + newline);
+
+    if text[line_start..range.start].trim().is_empty()
+        && text[range.end..line_end].trim().is_empty()
+    {
+        line_start..(line_end + 1).min(text.len())
+    } else {
+        range
+    }
+}
+
+/// The end of the line `offset` sits on when only whitespace or a line comment follows it, so an
+/// inserted line lands below that comment rather than taking it along.
+fn line_end_after(text: &str, offset: usize) -> usize {
+    let line_end = text[offset..]
+        .find('\n')
+        .map_or(text.len(), |newline| offset + newline);
+    let rest = text[offset..line_end].trim();
+
+    if rest.is_empty() || rest.starts_with("//") {
+        line_end
+    } else {
+        offset
+    }
+}
+
+fn line_indent(text: &str, offset: usize) -> &str {
+    let line_start = text[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+    let line = &text[line_start..offset];
+
+    &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            character if character.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(character));
+            },
+            character => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::AuthoredValue;
+    use super::EditableSource;
+    use crate::CommandId;
+    use crate::KeystrokeSequence;
+
+    fn sequence(text: &str) -> Result<KeystrokeSequence, String> {
+        KeystrokeSequence::from_str(text).map_err(|error| error.to_string())
+    }
+
+    fn command(text: &str) -> Result<CommandId, String> {
+        CommandId::try_from(text).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn setting_a_new_binding_appends_it_and_keeps_comments() -> Result<(), String> {
+        let mut source = EditableSource::new(
+            r#"{
+  // my camera keys
+  "bindings": [
+    {
+      "bindings": {
+        "ctrl-h": "camera::home", // home sweet home
+      },
+    },
+  ],
+}"#
+            .to_owned(),
+        );
+
+        source
+            .set(None, &sequence("ctrl-r")?, "\"camera::reset\"")
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            source.into_text(),
+            r#"{
+  // my camera keys
+  "bindings": [
+    {
+      "bindings": {
+        "ctrl-h": "camera::home", // home sweet home
+        "ctrl-r": "camera::reset",
+      },
+    },
+  ],
+}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn setting_an_authored_binding_replaces_only_its_value() -> Result<(), String> {
+        let mut source = EditableSource::new(
+            r#"{ "bindings": [ { "context": "editing&&!modal", "bindings": { "ctrl-h": /* old */ "camera::home" } } ] }"#
+                .to_owned(),
+        );
+
+        source
+            .set(Some("editing && !modal"), &sequence("ctrl-h")?, "null")
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            source.into_text(),
+            r#"{ "bindings": [ { "context": "editing&&!modal", "bindings": { "ctrl-h": /* old */ null } } ] }"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn setting_a_binding_in_a_new_context_appends_a_block() -> Result<(), String> {
+        let mut source = EditableSource::new(
+            "{\n  \"$schema\": \"./keymap.schema.json\",\n  \"bindings\": []\n}".to_owned(),
+        );
+
+        source
+            .set(
+                Some("palette_open"),
+                &sequence("escape")?,
+                "\"palette::close\"",
+            )
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            source.into_text(),
+            r#"{
+  "$schema": "./keymap.schema.json",
+  "bindings": [
+    {
+      "context": "palette_open",
+      "bindings": {
+        "escape": "palette::close"
+      }
+    }
+  ]
+}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn removing_bindings_drops_their_lines_and_keeps_neighbours() -> Result<(), String> {
+        let mut source = EditableSource::new(
+            r#"{
+  "bindings": [
+    {
+      "bindings": {
+        // keep this note
+        "ctrl-h": "camera::home",
+        "ctrl-r": "camera::reset",
+        "ctrl-x": null
+      }
+    }
+  ]
+}"#
+            .to_owned(),
+        );
+        let camera_reset = command("camera::reset")?;
+
+        let removed = source
+            .remove(None, |_, value| {
+                *value == AuthoredValue::Command(camera_reset.clone())
+                    || *value == AuthoredValue::Tombstone
+            })
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(removed, 2);
+        assert_eq!(
+            source.into_text(),
+            r#"{
+  "bindings": [
+    {
+      "bindings": {
+        // keep this note
+        "ctrl-h": "camera::home",
+      }
+    }
+  ]
+}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bindings_report_commands_arguments_and_tombstones() -> Result<(), String> {
+        let source = EditableSource::new(
+            r#"{ "bindings": [ { "bindings": { "ctrl-1": ["view::zoom", 2], "ctrl-2": null, "bad key": "camera::home" } } ] }"#
+                .to_owned(),
+        );
+
+        let bindings = source.bindings(None).map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            bindings,
+            vec![
+                (
+                    sequence("ctrl-1")?,
+                    AuthoredValue::Command(command("view::zoom")?)
+                ),
+                (sequence("ctrl-2")?, AuthoredValue::Tombstone),
+            ]
+        );
+        Ok(())
+    }
+}
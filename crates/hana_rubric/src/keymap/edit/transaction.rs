@@ -0,0 +1,420 @@
+//! Typed user-keymap edits and the transactions that group them against one file revision.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use super::source::AuthoredValue;
+use super::source::EditableSource;
+use crate::CommandId;
+use crate::Diagnostic;
+use crate::KeymapCommand;
+use crate::KeymapMacroName;
+use crate::KeymapPathFailure;
+use crate::KeystrokeSequence;
+use crate::PlayKeymapMacro;
+
+/// One version of the user keymap file's bytes, as read or written during this session.
+///
+/// Revisions compare file contents, so an edit that restores earlier bytes restores the earlier
+/// revision too. A missing file has a revision of its own. Revisions are not stable across
+/// processes and are not meant to be stored.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeymapRevision(u64);
+
+impl KeymapRevision {
+    /// The revision of `contents`, or of a missing file when `contents` is `None`.
+    pub(crate) fn of(contents: Option<&[u8]>) -> Self {
+        let mut hasher = DefaultHasher::new();
+        contents.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+/// One structured change to the user keymap, addressed by command and context.
+///
+/// `context` is the context text of the blocks the edit reads and writes, or `None` for blocks
+/// without a `context` member. Expressions compare after normalization, so
+/// `editing&&!modal` addresses a block authored as `editing && !modal`. Edits only ever touch
+/// the user keymap; the shipped defaults are read to decide when a tombstone is needed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeymapEdit {
+    /// Binds `keystroke` to `command` under `context`, replacing whatever the user keymap bound
+    /// to that keystroke there.
+    Add {
+        /// The command the keystroke runs.
+        command:   CommandId,
+        /// The context text of the block the binding belongs to.
+        context:   Option<String>,
+        /// The keystroke sequence that runs the command.
+        keystroke: KeystrokeSequence,
+    },
+    /// Moves `command` from `previous` to `keystroke` under `context`.
+    ///
+    /// The user's own binding at `previous` is removed, and a shipped default binding there is
+    /// tombstoned, so the command runs only from `keystroke` afterwards.
+    Replace {
+        /// The command being moved.
+        command:   CommandId,
+        /// The context text of the block the binding belongs to.
+        context:   Option<String>,
+        /// The keystroke sequence the command runs from now.
+        previous:  KeystrokeSequence,
+        /// The keystroke sequence the command runs from afterwards.
+        keystroke: KeystrokeSequence,
+    },
+    /// Tombstones `keystroke` under `context` so `command` no longer runs from it.
+    Unbind {
+        /// The command bound to the keystroke.
+        command:   CommandId,
+        /// The context text of the block the binding belongs to.
+        context:   Option<String>,
+        /// The keystroke sequence to tombstone.
+        keystroke: KeystrokeSequence,
+    },
+    /// Removes every user binding of `command` under `context`, and every user tombstone over a
+    /// shipped binding of it, so the command runs from its shipped defaults again.
+    Reset {
+        /// The command to restore.
+        command: CommandId,
+        /// The context text of the blocks to restore it in.
+        context: Option<String>,
+    },
+    /// Binds `keystroke` to play the recorded macro `name` under `context`, replacing whatever
+    /// the user keymap bound to that keystroke there.
+    BindMacro {
+        /// The recorded macro the keystroke plays.
+        name:      KeymapMacroName,
+        /// The context text of the block the binding belongs to.
+        context:   Option<String>,
+        /// The keystroke sequence that plays the macro.
+        keystroke: KeystrokeSequence,
+    },
+}
+
+impl KeymapEdit {
+    fn apply(
+        &self,
+        user: &mut EditableSource,
+        defaults: &EditableSource,
+    ) -> Result<(), KeymapEditError> {
+        match self {
+            Self::Add {
+                command,
+                context,
+                keystroke,
+            } => user.set(context.as_deref(), keystroke, &command_value(command)),
+            Self::Replace {
+                command,
+                context,
+                previous,
+                keystroke,
+            } => {
+                unbind(user, defaults, command, context.as_deref(), previous)?;
+                user.set(context.as_deref(), keystroke, &command_value(command))
+            },
+            Self::Unbind {
+                command,
+                context,
+                keystroke,
+            } => unbind(user, defaults, command, context.as_deref(), keystroke),
+            Self::Reset { command, context } => {
+                let shipped = shipped_keystrokes(defaults, command, context.as_deref())?;
+                user.remove(context.as_deref(), |keystroke, value| match value {
+                    AuthoredValue::Command(bound) => bound == command,
+                    AuthoredValue::Tombstone => shipped.contains(keystroke),
+                    AuthoredValue::Unreadable => false,
+                })?;
+                Ok(())
+            },
+            Self::BindMacro {
+                name,
+                context,
+                keystroke,
+            } => user.set(context.as_deref(), keystroke, &macro_value(name)),
+        }
+    }
+}
+
+/// Removes the user's binding of `command` at `keystroke`, then tombstones a shipped one.
+fn unbind(
+    user: &mut EditableSource,
+    defaults: &EditableSource,
+    command: &CommandId,
+    context: Option<&str>,
+    keystroke: &KeystrokeSequence,
+) -> Result<(), KeymapEditError> {
+    let removed = user.remove(context, |bound_keystroke, value| {
+        bound_keystroke == keystroke
+            && matches!(value, AuthoredValue::Command(bound) if bound == command)
+    })?;
+    let is_shipped = shipped_keystrokes(defaults, command, context)?.contains(keystroke);
+
+    if is_shipped {
+        user.set(context, keystroke, "null")
+    } else if removed > 0 {
+        Ok(())
+    } else {
+        Err(KeymapEditError::NotBound {
+            command:   command.clone(),
+            keystroke: keystroke.clone(),
+        })
+    }
+}
+
+fn shipped_keystrokes(
+    defaults: &EditableSource,
+    command: &CommandId,
+    context: Option<&str>,
+) -> Result<Vec<KeystrokeSequence>, KeymapEditError> {
+    let mut shipped = Vec::new();
+    for (keystroke, value) in defaults.bindings(context)? {
+        match value {
+            AuthoredValue::Command(bound) if bound == *command => shipped.push(keystroke),
+            AuthoredValue::Command(_) | AuthoredValue::Tombstone => {
+                shipped.retain(|shipped_keystroke| *shipped_keystroke != keystroke);
+            },
+            AuthoredValue::Unreadable => {},
+        }
+    }
+
+    Ok(shipped)
+}
+
+fn command_value(command: &CommandId) -> String { format!("\"{command}\"") }
+
+fn macro_value(name: &KeymapMacroName) -> String {
+    format!("[\"{}\", {{ \"name\": \"{name}\" }}]", PlayKeymapMacro::ID)
+}
+
+/// An ordered group of [`KeymapEdit`]s applied to one revision of the user keymap.
+///
+/// The transaction is written only if the user keymap still has the `base` revision when it is
+/// applied, so an edit prepared against an older file never overwrites a newer one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeymapTransaction {
+    base:  KeymapRevision,
+    edits: Vec<KeymapEdit>,
+}
+
+impl KeymapTransaction {
+    /// Starts an empty transaction against the user keymap revision `base`.
+    #[must_use]
+    pub const fn new(base: KeymapRevision) -> Self {
+        Self {
+            base,
+            edits: Vec::new(),
+        }
+    }
+
+    /// Appends `edit`, which applies after every edit added before it.
+    #[must_use]
+    pub fn with_edit(mut self, edit: KeymapEdit) -> Self {
+        self.edits.push(edit);
+        self
+    }
+
+    /// Returns the revision the transaction was prepared against.
+    #[must_use]
+    pub const fn base(&self) -> KeymapRevision { self.base }
+
+    /// Returns the edits in the order they apply.
+    #[must_use]
+    pub fn edits(&self) -> &[KeymapEdit] { &self.edits }
+
+    /// Applies every edit to the user keymap `source`, reading `defaults` for shipped bindings.
+    pub(super) fn apply(&self, source: String, defaults: &str) -> Result<String, KeymapEditError> {
+        let defaults = EditableSource::new(defaults.to_owned());
+        let mut user = EditableSource::new(source);
+        for edit in &self.edits {
+            edit.apply(&mut user, &defaults)?;
+        }
+
+        Ok(user.into_text())
+    }
+}
+
+/// Why a keymap transaction, undo, or redo wrote nothing.
+///
+/// Every error leaves the user keymap file and the live keymap as they were.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeymapEditError {
+    /// No configuration directory resolved, so there is no user keymap file to edit.
+    Unavailable(KeymapPathFailure),
+    /// The user keymap changed after the edit was prepared, so the newer file was left alone.
+    Stale {
+        /// The revision the edit was prepared against.
+        expected: KeymapRevision,
+        /// The revision on disk.
+        found:    KeymapRevision,
+    },
+    /// The edit names a binding that neither the user keymap nor the shipped defaults author.
+    NotBound {
+        /// The command the edit expected to find.
+        command:   CommandId,
+        /// The keystroke sequence the edit expected to find it at.
+        keystroke: KeystrokeSequence,
+    },
+    /// The user keymap is not a keymap document an edit can splice into.
+    Malformed(String),
+    /// The edited keymap would introduce these failures, so it was not written.
+    Rejected(Vec<Diagnostic>),
+    /// Reading or replacing the user keymap file failed.
+    Disk(String),
+    /// No applied transaction is left to undo.
+    NothingToUndo,
+    /// No undone transaction is left to redo.
+    NothingToRedo,
+}
+
+impl Display for KeymapEditError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable(keymap_path_failure) => {
+                formatter.write_str(keymap_path_failure.reason())
+            },
+            Self::Stale { .. } => formatter.write_str(
+                "The user keymap changed on disk after this edit was prepared; review the newer \
+                 file before editing again.",
+            ),
+            Self::NotBound { command, keystroke } => write!(
+                formatter,
+                "`{command}` is not bound to `{keystroke}` in the keymap."
+            ),
+            Self::Malformed(message) => {
+                write!(formatter, "The user keymap cannot be edited: {message}")
+            },
+            Self::Rejected(diagnostics) => {
+                formatter.write_str("The edit was rejected:")?;
+                for diagnostic in diagnostics {
+                    write!(formatter, " {}", diagnostic.message)?;
+                }
+                Ok(())
+            },
+            Self::Disk(message) => {
+                write!(formatter, "Could not write the user keymap: {message}")
+            },
+            Self::NothingToUndo => formatter.write_str("There is no keymap edit to undo."),
+            Self::NothingToRedo => formatter.write_str("There is no keymap edit to redo."),
+        }
+    }
+}
+
+impl Error for KeymapEditError {}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::KeymapEdit;
+    use super::KeymapEditError;
+    use super::KeymapRevision;
+    use super::KeymapTransaction;
+    use crate::CommandId;
+    use crate::KeystrokeSequence;
+
+    const DEFAULTS: &str = r#"{
+  "bindings": [
+    { "bindings": { "ctrl-h": "camera::home", "ctrl-r": "camera::reset" } },
+  ],
+}"#;
+
+    fn sequence(text: &str) -> Result<KeystrokeSequence, String> {
+        KeystrokeSequence::from_str(text).map_err(|error| error.to_string())
+    }
+
+    fn command(text: &str) -> Result<CommandId, String> {
+        CommandId::try_from(text).map_err(|error| error.to_string())
+    }
+
+    fn transaction() -> KeymapTransaction { KeymapTransaction::new(KeymapRevision::of(None)) }
+
+    #[test]
+    fn replacing_a_shipped_binding_tombstones_the_previous_keystroke() -> Result<(), String> {
+        let edited = transaction()
+            .with_edit(KeymapEdit::Replace {
+                command:   command("camera::home")?,
+                context:   None,
+                previous:  sequence("ctrl-h")?,
+                keystroke: sequence("alt-h")?,
+            })
+            .apply(r#"{ "bindings": [] }"#.to_owned(), DEFAULTS)
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            edited,
+            r#"{ "bindings": [
+  {
+    "bindings": {
+      "ctrl-h": null,
+      "alt-h": "camera::home"
+    }
+  }
+] }"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reset_removes_user_bindings_and_tombstones_over_shipped_ones() -> Result<(), String> {
+        let edited = transaction()
+            .with_edit(KeymapEdit::Reset {
+                command: command("camera::home")?,
+                context: None,
+            })
+            .apply(
+                r#"{
+  "bindings": [
+    {
+      "bindings": {
+        "ctrl-h": null,
+        "alt-h": "camera::home",
+        "ctrl-r": null
+      }
+    }
+  ]
+}"#
+                .to_owned(),
+                DEFAULTS,
+            )
+            .map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            edited,
+            r#"{
+  "bindings": [
+    {
+      "bindings": {
+        "ctrl-r": null
+      }
+    }
+  ]
+}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unbinding_a_keystroke_that_runs_another_command_is_rejected() -> Result<(), String> {
+        let result = transaction()
+            .with_edit(KeymapEdit::Unbind {
+                command:   command("camera::home")?,
+                context:   None,
+                keystroke: sequence("ctrl-r")?,
+            })
+            .apply(r#"{ "bindings": [] }"#.to_owned(), DEFAULTS);
+
+        assert_eq!(
+            result,
+            Err(KeymapEditError::NotBound {
+                command:   command("camera::home")?,
+                keystroke: sequence("ctrl-r")?,
+            })
+        );
+        Ok(())
+    }
+}
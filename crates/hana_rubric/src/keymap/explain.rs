@@ -0,0 +1,529 @@
+//! Explanations of which authored binding a keystroke sequence dispatches.
+
+use std::sync::OnceLock;
+
+use bevy::ecs::world::World;
+use bevy::prelude::Resource;
+
+use super::merged::BindingIndex;
+use super::merged::BindingScope;
+use super::reload::CommittedUserKeymap;
+use super::reload::ReloadConfiguration;
+use crate::ActiveCondition;
+use crate::ActiveConditionState;
+use crate::CommandId;
+use crate::CommandRegistry;
+use crate::ConditionName;
+use crate::DiagnosticOrigin;
+use crate::KeymapContext;
+use crate::KeystrokeSequence;
+use crate::condition::ConditionLookup;
+use crate::condition::ConditionRegistry;
+
+/// Every binding the keymap sources author for one keystroke sequence, and what each one does
+/// under the active condition.
+///
+/// Candidates are listed in source order, shipped defaults first and the user keymap after them.
+/// At most one candidate [`BindingOutcome::Fires`] or [`BindingOutcome::Unbinds`]. A sequence no
+/// source binds has no candidates.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BindingExplanation {
+    /// The keystroke sequence that was explained.
+    pub keystroke_sequence: KeystrokeSequence,
+    /// The condition routing input when the explanation was taken, or `None` for global routing.
+    pub active_condition:   Option<ConditionName>,
+    /// Every authored edit at [`Self::keystroke_sequence`], defaults first.
+    pub candidates:         Vec<BindingCandidate>,
+}
+
+impl BindingExplanation {
+    /// Returns the binding dispatch fires for the sequence, if any.
+    #[must_use]
+    pub fn fired(&self) -> Option<&BindingCandidate> {
+        self.candidates
+            .iter()
+            .find(|candidate| candidate.outcome == BindingOutcome::Fires)
+    }
+}
+
+/// One authored binding or unbind for an explained keystroke sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BindingCandidate {
+    /// The keymap layer that authored the edit.
+    pub layer:   BindingLayer,
+    /// The keymap source that authored the edit.
+    pub origin:  DiagnosticOrigin,
+    /// One-based source line of the binding key.
+    pub line:    usize,
+    /// One-based source column of the binding key.
+    pub column:  usize,
+    /// The block's authored context expression, or `None` for a global block.
+    pub context: Option<String>,
+    /// The command the edit binds, or `None` for an unbind.
+    pub command: Option<CommandId>,
+    /// What the edit contributes under the active condition.
+    pub outcome: BindingOutcome,
+}
+
+/// The keymap layer a [`BindingCandidate`] was authored in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BindingLayer {
+    /// The application's shipped default keymap.
+    ShippedDefault,
+    /// The user keymap layered over the defaults.
+    User,
+}
+
+/// What a [`BindingCandidate`] contributes to dispatch under the active condition.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BindingOutcome {
+    /// Dispatch fires this binding's command.
+    Fires,
+    /// This unbind is the edit dispatch routes to, so the sequence fires nothing.
+    Unbinds,
+    /// Another binding in scope takes precedence: a user binding over a default, a condition's
+    /// binding over a global one, or a more specific or later block over this one.
+    Shadowed,
+    /// An unbind in scope removes this binding.
+    Tombstoned,
+    /// The block's context does not hold under the active condition, or no context has been
+    /// reported yet.
+    Inactive,
+    /// Validation refused the edit; its diagnostic is in
+    /// [`KeymapLoadFailures`](crate::KeymapLoadFailures).
+    Rejected,
+    /// Nothing routes at the sequence in scope, because validation dropped every layer's edit
+    /// there, so this edit has no effect.
+    Unbound,
+}
+
+/// The live generation's [`BindingIndex`], built by the first explanation after each commit.
+///
+/// Every commit replaces the cache, so the index always describes the sources the live generation
+/// was compiled from.
+#[derive(Default, Resource)]
+pub(crate) struct ExplanationCache(OnceLock<Option<BindingIndex>>);
+
+/// Explains `keystroke_sequence` under the active condition.
+pub(crate) fn explain_keystroke_sequence(
+    world: &World,
+    keystroke_sequence: &KeystrokeSequence,
+) -> BindingExplanation {
+    let routing = match world
+        .get_resource::<ActiveCondition>()
+        .map(ActiveCondition::state)
+    {
+        Some(ActiveConditionState::ResolvedCondition { handle, name })
+            if handle.is_registry_issued() =>
+        {
+            ExplainedRouting::Routing(BindingScope::Condition(*handle), Some(name.clone()))
+        },
+        Some(
+            ActiveConditionState::AwaitingContext | ActiveConditionState::ResolvedCondition { .. },
+        ) => ExplainedRouting::NotRouting,
+        Some(ActiveConditionState::GlobalRouting) | None => {
+            ExplainedRouting::Routing(BindingScope::Global, None)
+        },
+    };
+
+    explain_with_routing(world, keystroke_sequence, routing)
+}
+
+/// Explains `keystroke_sequence` as if `context` were the active condition, or as if no context
+/// source were registered when it is `None`.
+pub(crate) fn explain_keystroke_sequence_in_context<C: KeymapContext>(
+    world: &World,
+    keystroke_sequence: &KeystrokeSequence,
+    context: Option<C>,
+) -> BindingExplanation {
+    let routing = match (context, world.get_resource::<ConditionRegistry>()) {
+        (None, _) => ExplainedRouting::Routing(BindingScope::Global, None),
+        (Some(context), Some(condition_registry)) => {
+            match condition_registry.lookup(context.as_ref()) {
+                ConditionLookup::Registered { handle, name } => {
+                    ExplainedRouting::Routing(BindingScope::Condition(handle), Some(name.clone()))
+                },
+                ConditionLookup::UnregisteredName => ExplainedRouting::NotRouting,
+            }
+        },
+        (Some(_), None) => ExplainedRouting::NotRouting,
+    };
+
+    explain_with_routing(world, keystroke_sequence, routing)
+}
+
+/// How input routes while a keystroke sequence is explained.
+enum ExplainedRouting {
+    /// Input routes through this scope, reported as this condition.
+    Routing(BindingScope, Option<ConditionName>),
+    /// No binding routes: no context has been reported yet, or the context is not registered.
+    NotRouting,
+}
+
+fn explain_with_routing(
+    world: &World,
+    keystroke_sequence: &KeystrokeSequence,
+    routing: ExplainedRouting,
+) -> BindingExplanation {
+    let (binding_scope, active_condition, is_routing) = match routing {
+        ExplainedRouting::Routing(binding_scope, active_condition) => {
+            (binding_scope, active_condition, true)
+        },
+        ExplainedRouting::NotRouting => (BindingScope::Global, None, false),
+    };
+
+    let mut candidates = binding_index(world).map_or_else(Vec::new, |binding_index| {
+        binding_index.explain(keystroke_sequence, binding_scope)
+    });
+    if !is_routing {
+        for candidate in &mut candidates {
+            if matches!(
+                candidate.outcome,
+                BindingOutcome::Fires | BindingOutcome::Unbinds
+            ) {
+                candidate.outcome = BindingOutcome::Inactive;
+            }
+        }
+    }
+
+    BindingExplanation {
+        keystroke_sequence: keystroke_sequence.clone(),
+        active_condition,
+        candidates,
+    }
+}
+
+/// The cached index of the live generation's sources, built on first use.
+fn binding_index(world: &World) -> Option<&BindingIndex> {
+    world
+        .get_resource::<ExplanationCache>()?
+        .0
+        .get_or_init(|| {
+            match (
+                world.get_resource::<ReloadConfiguration>(),
+                world.get_resource::<CommittedUserKeymap>(),
+                world.get_resource::<CommandRegistry>(),
+                world.get_resource::<ConditionRegistry>(),
+            ) {
+                (
+                    Some(reload_configuration),
+                    Some(committed_user_keymap),
+                    Some(command_registry),
+                    Some(condition_registry),
+                ) => reload_configuration.index(
+                    &committed_user_keymap.0,
+                    command_registry,
+                    condition_registry,
+                ),
+                _ => None,
+            }
+        })
+        .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use bevy::ecs::world::World;
+    use bevy::prelude::Event;
+    use bevy::prelude::Reflect;
+    use bevy::prelude::ReflectEvent;
+    use bevy::reflect::TypeRegistry;
+    use bevy_enhanced_input::prelude::CustomInputs;
+    use strum::AsRefStr;
+    use strum::EnumIter;
+    use strum::EnumMessage;
+
+    use super::BindingLayer;
+    use super::BindingOutcome;
+    use super::explain_keystroke_sequence;
+    use super::explain_keystroke_sequence_in_context;
+    use crate::ActiveCondition;
+    use crate::Capability;
+    use crate::CommandId;
+    use crate::CommandRegistry;
+    use crate::ConditionName;
+    use crate::DiagnosticOrigin;
+    use crate::HoldPhase;
+    use crate::KeymapCommand;
+    use crate::KeymapLoadFailures;
+    use crate::KeystrokeSequence;
+    use crate::ReflectKeymapCommand;
+    use crate::condition::ConditionLookup;
+    use crate::condition::ConditionRegistry;
+    use crate::keymap::reload::ReloadConfiguration;
+    use crate::keymap::reload::commit_user_keymap;
+
+    const DEFAULTS_PATH: &str = "explain-defaults.jsonc";
+    const USER_KEYMAP_FIXTURE: &str = "explain-keymap.jsonc";
+    const DEFAULTS: &str = r#"{ "bindings": [
+  { "bindings": { "ctrl-h": "explain::home", "g h": "explain::home" } },
+  { "context": "dimension_lock", "bindings": { "ctrl-h": "explain::reset" } },
+] }"#;
+    const USER_KEYMAP: &str =
+        r#"{ "bindings": [ { "bindings": { "ctrl-h": "explain::reset" } } ] }"#;
+
+    #[derive(AsRefStr, Clone, Copy, Debug, EnumIter, EnumMessage, Eq, PartialEq)]
+    #[strum(serialize_all = "snake_case")]
+    enum ExplainContext {
+        #[strum(message = "While a dimension lock is active")]
+        DimensionLock,
+    }
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand)]
+    struct ExplainHome;
+
+    impl KeymapCommand for ExplainHome {
+        const ID: &'static str = "explain::home";
+        const TITLE: &'static str = "Explain Home";
+        const DESCRIPTION: &'static str = "First command used by explanation tests.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand)]
+    struct ExplainReset;
+
+    impl KeymapCommand for ExplainReset {
+        const ID: &'static str = "explain::reset";
+        const TITLE: &'static str = "Explain Reset";
+        const DESCRIPTION: &'static str = "Second command used by explanation tests.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    /// A world with `USER_KEYMAP` committed over `DEFAULTS`, still awaiting its first context.
+    fn explained_world() -> Result<World, String> {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<ExplainHome>();
+        type_registry.register::<ExplainReset>();
+        let mut custom_inputs = CustomInputs::default();
+        let command_registry = CommandRegistry::build(&type_registry, &mut custom_inputs)
+            .map_err(|diagnostics| format!("explain registry diagnostics: {diagnostics:?}"))?;
+        let mut condition_registry = ConditionRegistry::default();
+        condition_registry
+            .register::<ExplainContext>()
+            .map_err(|diagnostics| format!("condition registry errors: {diagnostics:?}"))?;
+
+        let mut world = World::new();
+        world.insert_resource(command_registry);
+        world.insert_resource(condition_registry);
+        world.init_resource::<ActiveCondition>();
+        world.init_resource::<KeymapLoadFailures>();
+        world.insert_resource(ReloadConfiguration::new(
+            DiagnosticOrigin::KeymapFile(PathBuf::from(DEFAULTS_PATH)),
+            DEFAULTS.to_owned(),
+            Vec::new(),
+            false,
+        ));
+        commit(&mut world, USER_KEYMAP)?;
+        Ok(world)
+    }
+
+    fn commit(world: &mut World, user_keymap: &str) -> Result<(), String> {
+        if commit_user_keymap(
+            world,
+            PathBuf::from(USER_KEYMAP_FIXTURE),
+            user_keymap.as_bytes().to_vec(),
+        ) {
+            Ok(())
+        } else {
+            Err(format!(
+                "the explained keymap did not commit: {user_keymap}"
+            ))
+        }
+    }
+
+    fn sequence(keystroke_sequence: &str) -> Result<KeystrokeSequence, String> {
+        keystroke_sequence
+            .parse::<KeystrokeSequence>()
+            .map_err(|error| format!("invalid test keystroke: {error}"))
+    }
+
+    fn outcomes(
+        world: &World,
+        keystroke_sequence: &str,
+    ) -> Result<Vec<(BindingLayer, BindingOutcome)>, String> {
+        Ok(
+            explain_keystroke_sequence(world, &sequence(keystroke_sequence)?)
+                .candidates
+                .into_iter()
+                .map(|candidate| (candidate.layer, candidate.outcome))
+                .collect(),
+        )
+    }
+
+    fn resolve_dimension_lock(world: &mut World) -> Result<(), String> {
+        let ConditionLookup::Registered { handle, name } = world
+            .resource::<ConditionRegistry>()
+            .lookup("dimension_lock")
+        else {
+            return Err(String::from("dimension_lock condition was not registered"));
+        };
+        let name = name.clone();
+        world
+            .resource_mut::<ActiveCondition>()
+            .resolve(handle, &name);
+        Ok(())
+    }
+
+    #[test]
+    fn global_routing_reports_the_user_binding_over_the_shadowed_default() -> Result<(), String> {
+        let mut world = explained_world()?;
+        world.resource_mut::<ActiveCondition>().enable_global();
+
+        let explanation = explain_keystroke_sequence(&world, &sequence("ctrl-h")?);
+
+        assert_eq!(explanation.active_condition, None);
+        assert_eq!(
+            outcomes(&world, "ctrl-h")?,
+            [
+                (BindingLayer::ShippedDefault, BindingOutcome::Shadowed),
+                (BindingLayer::ShippedDefault, BindingOutcome::Inactive),
+                (BindingLayer::User, BindingOutcome::Fires),
+            ]
+        );
+        assert_eq!(
+            explanation
+                .fired()
+                .and_then(|candidate| candidate.command.as_ref())
+                .map(CommandId::as_str),
+            Some("explain::reset")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_sequence_no_source_binds_has_no_candidates() -> Result<(), String> {
+        let mut world = explained_world()?;
+        world.resource_mut::<ActiveCondition>().enable_global();
+
+        let explanation = explain_keystroke_sequence(&world, &sequence("ctrl-u")?);
+
+        assert!(explanation.candidates.is_empty());
+        assert!(explanation.fired().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn a_pending_prefix_explains_nothing_until_its_sequence_completes() -> Result<(), String> {
+        let mut world = explained_world()?;
+        world.resource_mut::<ActiveCondition>().enable_global();
+
+        assert!(outcomes(&world, "g")?.is_empty());
+        assert_eq!(
+            outcomes(&world, "g h")?,
+            [(BindingLayer::ShippedDefault, BindingOutcome::Fires)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_resolved_condition_fires_its_own_binding_over_global_ones() -> Result<(), String> {
+        let mut world = explained_world()?;
+        resolve_dimension_lock(&mut world)?;
+
+        let explanation = explain_keystroke_sequence(&world, &sequence("ctrl-h")?);
+
+        assert_eq!(
+            explanation
+                .active_condition
+                .as_ref()
+                .map(ConditionName::as_str),
+            Some("dimension_lock")
+        );
+        assert_eq!(
+            outcomes(&world, "ctrl-h")?,
+            [
+                (BindingLayer::ShippedDefault, BindingOutcome::Shadowed),
+                (BindingLayer::ShippedDefault, BindingOutcome::Fires),
+                (BindingLayer::User, BindingOutcome::Shadowed),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn awaiting_context_downgrades_the_routed_binding_to_inactive() -> Result<(), String> {
+        let world = explained_world()?;
+
+        let explanation = explain_keystroke_sequence(&world, &sequence("ctrl-h")?);
+
+        assert_eq!(explanation.active_condition, None);
+        assert!(explanation.fired().is_none());
+        assert_eq!(
+            outcomes(&world, "ctrl-h")?,
+            [
+                (BindingLayer::ShippedDefault, BindingOutcome::Shadowed),
+                (BindingLayer::ShippedDefault, BindingOutcome::Inactive),
+                (BindingLayer::User, BindingOutcome::Inactive),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn an_explicit_context_explains_regardless_of_the_active_condition() -> Result<(), String> {
+        let world = explained_world()?;
+        let keystroke_sequence = sequence("ctrl-h")?;
+        let outcomes_in = |context| {
+            explain_keystroke_sequence_in_context(&world, &keystroke_sequence, context)
+                .candidates
+                .into_iter()
+                .map(|candidate| candidate.outcome)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            outcomes_in(Some(ExplainContext::DimensionLock)),
+            [
+                BindingOutcome::Shadowed,
+                BindingOutcome::Fires,
+                BindingOutcome::Shadowed,
+            ]
+        );
+        assert_eq!(
+            outcomes_in(None),
+            [
+                BindingOutcome::Shadowed,
+                BindingOutcome::Inactive,
+                BindingOutcome::Fires,
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_commit_replaces_the_cached_explanation() -> Result<(), String> {
+        let mut world = explained_world()?;
+        world.resource_mut::<ActiveCondition>().enable_global();
+        assert_eq!(outcomes(&world, "ctrl-h")?.len(), 3);
+
+        commit(
+            &mut world,
+            r#"{ "bindings": [ { "bindings": { "ctrl-j": "explain::reset" } } ] }"#,
+        )?;
+
+        assert_eq!(
+            outcomes(&world, "ctrl-h")?,
+            [
+                (BindingLayer::ShippedDefault, BindingOutcome::Fires),
+                (BindingLayer::ShippedDefault, BindingOutcome::Inactive),
+            ]
+        );
+        assert_eq!(
+            outcomes(&world, "ctrl-j")?,
+            [(BindingLayer::User, BindingOutcome::Fires)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,879 @@
+//! Recorded command macros: capture from dispatch, storage, binding, and replay.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::str;
+use std::str::FromStr;
+
+use bevy::ecs::world::World;
+use bevy::prelude::Commands;
+use bevy::prelude::Event;
+use bevy::prelude::On;
+use bevy::prelude::Reflect;
+use bevy::prelude::ReflectEvent;
+use bevy::prelude::Resource;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json_lenient::Value;
+
+use super::constants::MAX_MACRO_NAME_LENGTH;
+use super::edit::KeymapEdit;
+use super::edit::KeymapEditError;
+use super::edit::KeymapRevision;
+use super::edit::KeymapTransaction;
+use super::edit::apply_transaction;
+use super::routing::KeystrokeRouting;
+use crate::ActiveCondition;
+use crate::ActiveConditionState;
+use crate::Capability;
+use crate::CommandId;
+use crate::CommandInvocationOutcome;
+use crate::CommandRegistry;
+use crate::HoldPhase;
+use crate::KeymapCommand;
+use crate::KeymapCommandArguments;
+use crate::KeymapPathAvailability;
+use crate::KeymapPathFailure;
+use crate::KeymapPaths;
+use crate::KeystrokeSequence;
+use crate::ReflectKeymapCommand;
+use crate::ReflectKeymapCommandArguments;
+use crate::command::BindingArguments;
+use crate::disk::read_config_file;
+use crate::disk::read_macros;
+use crate::disk::replace_macros;
+
+/// The header written above the recorded macros.
+const MACROS_HEADER: &str = concat!(
+    "// Keymap macros. Each one lists the commands it replays, in order.\n",
+    "// Bind one with [\"keymap::play_macro\", { \"name\": \"<macro>\" }].\n",
+);
+
+/// The name of a recorded keymap macro.
+///
+/// Names are 1 to 64 lowercase ASCII letters, digits, `-`, or `_`, such as `indent-block` or
+/// `save_all`, the same rules [`KeymapProfileName`](crate::KeymapProfileName) follows.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct KeymapMacroName(String);
+
+impl KeymapMacroName {
+    /// Returns whether `value` is a valid keymap macro name.
+    #[must_use]
+    pub fn is_valid(value: &str) -> bool {
+        (1..=MAX_MACRO_NAME_LENGTH).contains(&value.len())
+            && value.bytes().all(|byte| {
+                byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-' || byte == b'_'
+            })
+    }
+
+    /// Borrows the validated macro name text.
+    #[must_use]
+    pub fn as_str(&self) -> &str { &self.0 }
+}
+
+impl AsRef<str> for KeymapMacroName {
+    fn as_ref(&self) -> &str { self.as_str() }
+}
+
+impl Display for KeymapMacroName {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Error returned when text does not satisfy [`KeymapMacroName::is_valid`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeymapMacroNameError;
+
+impl Display for KeymapMacroNameError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(
+            "keymap macro names require 1 to 64 lowercase ASCII letters, digits, `-`, or `_`",
+        )
+    }
+}
+
+impl Error for KeymapMacroNameError {}
+
+impl TryFrom<&str> for KeymapMacroName {
+    type Error = KeymapMacroNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(Self(value.to_owned()))
+        } else {
+            Err(KeymapMacroNameError)
+        }
+    }
+}
+
+impl FromStr for KeymapMacroName {
+    type Err = KeymapMacroNameError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> { Self::try_from(value) }
+}
+
+/// One command a macro replays, with the binding arguments it was dispatched with.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeymapMacroStep {
+    command:   CommandId,
+    arguments: Option<BindingArguments>,
+}
+
+impl KeymapMacroStep {
+    /// Returns the command this step invokes.
+    #[must_use]
+    pub const fn command(&self) -> &CommandId { &self.command }
+}
+
+/// Plays the recorded macro named `name` when triggered.
+///
+/// Bind it with `["keymap::play_macro", { "name": "<macro>" }]`, which is what
+/// [`finish_keymap_macro_recording`](crate::finish_keymap_macro_recording) writes. The replay is
+/// recorded in [`KeymapMacros::last_replay`], and a macro that cannot be read in
+/// [`KeymapMacros::last_failure`].
+#[derive(Clone, Debug, Default, Event, Reflect)]
+#[reflect(Event, KeymapCommand, KeymapCommandArguments)]
+pub struct PlayKeymapMacro {
+    /// The name of the macro to play.
+    pub name: String,
+}
+
+impl KeymapCommand for PlayKeymapMacro {
+    const ID: &'static str = "keymap::play_macro";
+    const TITLE: &'static str = "Play Keymap Macro";
+    const DESCRIPTION: &'static str = "Replays the commands of a recorded keymap macro.";
+    const CAPABILITY: Capability = Capability::OneShot;
+
+    fn build() -> Self { Self::default() }
+
+    fn hold_phase(&self) -> Option<HoldPhase> { None }
+}
+
+/// The binding arguments of [`PlayKeymapMacro`].
+#[derive(Deserialize, Reflect)]
+pub struct PlayKeymapMacroArguments {
+    /// The name of the macro to play.
+    pub name: String,
+}
+
+impl KeymapCommandArguments for PlayKeymapMacro {
+    type Arguments = PlayKeymapMacroArguments;
+
+    fn build_with(arguments: PlayKeymapMacroArguments) -> Self {
+        Self {
+            name: arguments.name,
+        }
+    }
+}
+
+/// The macro being recorded and the outcome of the latest replay.
+///
+/// While a recording is in progress, every command keymap dispatch fires is appended to it, with
+/// the arguments its binding carried. Held commands and [`PlayKeymapMacro`] are never recorded.
+#[derive(Debug, Default, Resource)]
+pub struct KeymapMacros {
+    recording:    Option<Vec<KeymapMacroStep>>,
+    last_replay:  Option<KeymapMacroReplay>,
+    last_failure: Option<KeymapMacroError>,
+}
+
+impl KeymapMacros {
+    /// Returns whether dispatched commands are being recorded.
+    #[must_use]
+    pub const fn is_recording(&self) -> bool { self.recording.is_some() }
+
+    /// Returns the commands recorded so far, or an empty slice when nothing is being recorded.
+    #[must_use]
+    pub fn recorded_steps(&self) -> &[KeymapMacroStep] {
+        self.recording.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the latest replay a triggered [`PlayKeymapMacro`] ran.
+    #[must_use]
+    pub const fn last_replay(&self) -> Option<&KeymapMacroReplay> { self.last_replay.as_ref() }
+
+    /// Returns why the latest triggered [`PlayKeymapMacro`] could not be replayed at all.
+    ///
+    /// A replay that reads its macro clears it.
+    #[must_use]
+    pub const fn last_failure(&self) -> Option<&KeymapMacroError> { self.last_failure.as_ref() }
+
+    pub(crate) fn record(&mut self, command: CommandId, arguments: Option<BindingArguments>) {
+        if let Some(recording) = &mut self.recording {
+            recording.push(KeymapMacroStep { command, arguments });
+        }
+    }
+}
+
+/// What one replay of a macro did with each of its steps.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeymapMacroReplay {
+    /// The macro that was replayed.
+    pub name:  KeymapMacroName,
+    /// One report per macro step, in order.
+    pub steps: Vec<KeymapMacroStepReport>,
+}
+
+impl KeymapMacroReplay {
+    /// Returns whether every step was invoked.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.steps
+            .iter()
+            .all(|step| step.outcome == KeymapMacroStepOutcome::Invoked)
+    }
+}
+
+/// What a replay did with one macro step.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeymapMacroStepReport {
+    /// The command the step names.
+    pub command: CommandId,
+    /// What the replay did with it.
+    pub outcome: KeymapMacroStepOutcome,
+}
+
+/// The outcome of one macro step.
+///
+/// A replay checks every step before it runs the first, so a macro that names a command the
+/// current context cannot run runs nothing. A step that becomes unavailable partway through a
+/// replay stops it there.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeymapMacroStepOutcome {
+    /// The command's event was triggered.
+    Invoked,
+    /// The command registry refused the command.
+    Refused(CommandInvocationOutcome),
+    /// The command is registered but cannot run in the current context: no context has been
+    /// reported yet, text entry owns the keyboard, or the step plays another macro.
+    Unavailable,
+    /// The replay stopped before this step.
+    NotRun,
+}
+
+/// Why a macro was not recorded, bound, or replayed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeymapMacroError {
+    /// No configuration directory resolved, so there is no macros file to read or write.
+    Unavailable(KeymapPathFailure),
+    /// A recording is already in progress.
+    AlreadyRecording,
+    /// No recording is in progress.
+    NotRecording,
+    /// The recording captured no commands, so there is nothing to store.
+    EmptyRecording,
+    /// No recorded macro has this name.
+    NotFound(String),
+    /// The macros file cannot be read.
+    Malformed(String),
+    /// Binding the macro to its keystroke failed.
+    Edit(KeymapEditError),
+    /// Reading or writing the macros file failed.
+    Disk(String),
+}
+
+impl Display for KeymapMacroError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable(keymap_path_failure) => {
+                formatter.write_str(keymap_path_failure.reason())
+            },
+            Self::AlreadyRecording => formatter.write_str("A keymap macro is already recording."),
+            Self::NotRecording => formatter.write_str("No keymap macro is recording."),
+            Self::EmptyRecording => formatter.write_str("The keymap macro recorded no commands."),
+            Self::NotFound(name) => write!(formatter, "There is no keymap macro `{name}`."),
+            Self::Malformed(message) => {
+                write!(formatter, "The keymap macros cannot be read: {message}")
+            },
+            Self::Edit(error) => write!(formatter, "The keymap macro was not bound: {error}"),
+            Self::Disk(message) => {
+                write!(formatter, "Could not access the keymap macros: {message}")
+            },
+        }
+    }
+}
+
+impl Error for KeymapMacroError {}
+
+/// The stored form of the macros file.
+#[derive(Default, Deserialize, Serialize)]
+struct StoredMacros {
+    #[serde(default)]
+    macros: BTreeMap<String, Vec<StoredStep>>,
+}
+
+/// One stored step: a bare command ID, or a command ID and its binding arguments.
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum StoredStep {
+    Command(String),
+    WithArguments(String, Value),
+}
+
+impl From<&KeymapMacroStep> for StoredStep {
+    fn from(step: &KeymapMacroStep) -> Self {
+        step.arguments.as_ref().map_or_else(
+            || Self::Command(step.command.to_string()),
+            |arguments| Self::WithArguments(step.command.to_string(), arguments.value().clone()),
+        )
+    }
+}
+
+impl TryFrom<StoredStep> for KeymapMacroStep {
+    type Error = KeymapMacroError;
+
+    fn try_from(stored_step: StoredStep) -> Result<Self, Self::Error> {
+        let (command, arguments) = match stored_step {
+            StoredStep::Command(command) => (command, None),
+            StoredStep::WithArguments(command, arguments) => {
+                (command, Some(BindingArguments::new(arguments)))
+            },
+        };
+        let command = CommandId::try_from(command)
+            .map_err(|error| KeymapMacroError::Malformed(error.to_string()))?;
+        Ok(Self { command, arguments })
+    }
+}
+
+/// Starts recording the commands keymap dispatch fires.
+pub(crate) fn start_recording(world: &mut World) -> Result<(), KeymapMacroError> {
+    let mut keymap_macros = world.get_resource_or_init::<KeymapMacros>();
+    if keymap_macros.is_recording() {
+        return Err(KeymapMacroError::AlreadyRecording);
+    }
+    keymap_macros.recording = Some(Vec::new());
+    Ok(())
+}
+
+/// Discards the recording in progress.
+pub(crate) fn cancel_recording(world: &mut World) -> Result<(), KeymapMacroError> {
+    world
+        .get_resource_or_init::<KeymapMacros>()
+        .recording
+        .take()
+        .map(drop)
+        .ok_or(KeymapMacroError::NotRecording)
+}
+
+/// Stores the recording as `name` and binds `keystroke` to play it under `context`.
+///
+/// The macros file is written first so the binding never names a macro that is not stored. When
+/// the binding is refused, the macros file is restored and the recording stays in progress, so
+/// the caller can retry with another keystroke.
+///
+/// Only the binding joins the edit history. Undoing it unbinds the keystroke but keeps the macro
+/// stored, so redo, or a hand-written binding, can play it again; the macros file is a library
+/// of recordings that history never deletes from.
+pub(crate) fn finish_recording(
+    world: &mut World,
+    name: &KeymapMacroName,
+    context: Option<String>,
+    keystroke: KeystrokeSequence,
+) -> Result<KeymapRevision, KeymapMacroError> {
+    let paths = keymap_paths(world)?;
+    let steps = world
+        .get_resource::<KeymapMacros>()
+        .and_then(|keymap_macros| keymap_macros.recording.as_ref())
+        .ok_or(KeymapMacroError::NotRecording)?;
+    if steps.is_empty() {
+        return Err(KeymapMacroError::EmptyRecording);
+    }
+    let stored_steps = steps.iter().map(StoredStep::from).collect();
+
+    let previous = read_macros(&paths).map_err(disk_error)?;
+    let mut stored_macros = previous
+        .as_deref()
+        .map_or_else(|| Ok(StoredMacros::default()), parse_macros)?;
+    stored_macros.macros.insert(name.to_string(), stored_steps);
+    replace_macros(&paths, &render_macros(&stored_macros)?).map_err(disk_error)?;
+
+    let base = KeymapRevision::of(
+        read_config_file(paths.user_keymap())
+            .map_err(disk_error)?
+            .as_deref(),
+    );
+    let transaction = KeymapTransaction::new(base).with_edit(KeymapEdit::BindMacro {
+        name: name.clone(),
+        context,
+        keystroke,
+    });
+    match apply_transaction(world, &transaction) {
+        Ok(revision) => {
+            world.resource_mut::<KeymapMacros>().recording = None;
+            Ok(revision)
+        },
+        Err(error) => {
+            previous
+                .map_or_else(
+                    || remove_macros(&paths),
+                    |contents| replace_macros(&paths, &contents),
+                )
+                .map_err(disk_error)?;
+            Err(KeymapMacroError::Edit(error))
+        },
+    }
+}
+
+/// Replays the macro `name` through the command registry, reporting every step.
+pub(crate) fn play_macro(
+    world: &mut World,
+    name: &KeymapMacroName,
+) -> Result<KeymapMacroReplay, KeymapMacroError> {
+    let steps = stored_macro(world, name)?;
+    let mut reports = steps
+        .iter()
+        .map(|step| KeymapMacroStepReport {
+            command: step.command.clone(),
+            outcome: KeymapMacroStepOutcome::NotRun,
+        })
+        .collect::<Vec<_>>();
+
+    if let Some((index, outcome)) = steps
+        .iter()
+        .enumerate()
+        .find_map(|(index, step)| refusal(world, step).map(|outcome| (index, outcome)))
+    {
+        reports[index].outcome = outcome;
+        return Ok(KeymapMacroReplay {
+            name:  name.clone(),
+            steps: reports,
+        });
+    }
+
+    for (step, report) in steps.iter().zip(&mut reports) {
+        report.outcome = refusal(world, step).unwrap_or_else(|| {
+            match world.resource_scope::<CommandRegistry, _>(|world, command_registry| {
+                command_registry.invoke_with(&step.command, step.arguments.as_ref(), world)
+            }) {
+                CommandInvocationOutcome::Invoked => KeymapMacroStepOutcome::Invoked,
+                outcome => KeymapMacroStepOutcome::Refused(outcome),
+            }
+        });
+        if report.outcome != KeymapMacroStepOutcome::Invoked {
+            break;
+        }
+    }
+
+    Ok(KeymapMacroReplay {
+        name:  name.clone(),
+        steps: reports,
+    })
+}
+
+/// Plays a macro for a triggered [`PlayKeymapMacro`].
+pub(crate) fn play_on_request(play: On<PlayKeymapMacro>, mut commands: Commands) {
+    let name = play.event().name.clone();
+    commands.queue(move |world: &mut World| {
+        let result = KeymapMacroName::try_from(name.as_str())
+            .map_err(|_| KeymapMacroError::NotFound(name.clone()))
+            .and_then(|name| play_macro(world, &name));
+        if let Err(error) = &result {
+            bevy::log::warn!("{error}");
+        }
+        let mut keymap_macros = world.get_resource_or_init::<KeymapMacros>();
+        match result {
+            Ok(replay) => {
+                keymap_macros.last_replay = Some(replay);
+                keymap_macros.last_failure = None;
+            },
+            Err(error) => keymap_macros.last_failure = Some(error),
+        }
+    });
+}
+
+/// Returns why `step` cannot run right now, or `None` when it can.
+fn refusal(world: &World, step: &KeymapMacroStep) -> Option<KeymapMacroStepOutcome> {
+    let outcome = world.get_resource::<CommandRegistry>().map_or(
+        CommandInvocationOutcome::UnknownCommand,
+        |command_registry| command_registry.invocation_outcome(&step.command),
+    );
+    if outcome != CommandInvocationOutcome::Invoked {
+        return Some(KeymapMacroStepOutcome::Refused(outcome));
+    }
+
+    let is_routing = match world
+        .get_resource::<ActiveCondition>()
+        .map(ActiveCondition::state)
+    {
+        Some(ActiveConditionState::ResolvedCondition { handle, .. }) => handle.is_registry_issued(),
+        Some(ActiveConditionState::AwaitingContext) => false,
+        Some(ActiveConditionState::GlobalRouting) | None => true,
+    };
+    let is_available = is_routing
+        && step.command.as_str() != PlayKeymapMacro::ID
+        && world
+            .get_resource::<KeystrokeRouting>()
+            .is_none_or(|keystroke_routing| keystroke_routing.routes(&step.command));
+
+    (!is_available).then_some(KeymapMacroStepOutcome::Unavailable)
+}
+
+fn stored_macro(
+    world: &World,
+    name: &KeymapMacroName,
+) -> Result<Vec<KeymapMacroStep>, KeymapMacroError> {
+    let paths = keymap_paths(world)?;
+    let contents = read_macros(&paths)
+        .map_err(disk_error)?
+        .ok_or_else(|| KeymapMacroError::NotFound(name.to_string()))?;
+    parse_macros(&contents)?
+        .macros
+        .remove(name.as_str())
+        .ok_or_else(|| KeymapMacroError::NotFound(name.to_string()))?
+        .into_iter()
+        .map(KeymapMacroStep::try_from)
+        .collect()
+}
+
+fn parse_macros(contents: &[u8]) -> Result<StoredMacros, KeymapMacroError> {
+    let source = str::from_utf8(contents).map_err(|error| {
+        KeymapMacroError::Malformed(format!("the macros file is not valid UTF-8: {error}"))
+    })?;
+    serde_json_lenient::from_str(source)
+        .map_err(|error| KeymapMacroError::Malformed(error.to_string()))
+}
+
+fn render_macros(stored_macros: &StoredMacros) -> Result<Vec<u8>, KeymapMacroError> {
+    let body = serde_json_lenient::to_string_pretty(stored_macros)
+        .map_err(|error| KeymapMacroError::Malformed(error.to_string()))?;
+    Ok(format!("{MACROS_HEADER}{body}\n").into_bytes())
+}
+
+fn remove_macros(paths: &KeymapPaths) -> io::Result<()> {
+    match fs::remove_file(paths.macros()) {
+        Err(error) if error.kind() != ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+fn keymap_paths(world: &World) -> Result<KeymapPaths, KeymapMacroError> {
+    world
+        .get_resource::<KeymapPathAvailability>()
+        .map_or(
+            Err(KeymapPathFailure::AppNameNotConfigured),
+            |availability| availability.resolved().cloned(),
+        )
+        .map_err(KeymapMacroError::Unavailable)
+}
+
+fn disk_error(error: io::Error) -> KeymapMacroError { KeymapMacroError::Disk(error.to_string()) }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::str::FromStr;
+
+    use bevy::ecs::world::World;
+    use bevy::prelude::Event;
+    use bevy::prelude::On;
+    use bevy::prelude::Reflect;
+    use bevy::prelude::ReflectEvent;
+    use bevy::prelude::ResMut;
+    use bevy::prelude::Resource;
+    use bevy::reflect::TypeRegistry;
+    use bevy_enhanced_input::prelude::CustomInputs;
+    use serde::Deserialize;
+    use serde_json_lenient::Value;
+
+    use super::KeymapMacroError;
+    use super::KeymapMacroName;
+    use super::KeymapMacroStepOutcome;
+    use super::KeymapMacros;
+    use super::PlayKeymapMacro;
+    use super::finish_recording;
+    use super::play_macro;
+    use super::start_recording;
+    use crate::Capability;
+    use crate::CommandId;
+    use crate::CommandInvocationOutcome;
+    use crate::CommandRegistry;
+    use crate::DiagnosticOrigin;
+    use crate::HoldPhase;
+    use crate::KeymapCommand;
+    use crate::KeymapCommandArguments;
+    use crate::KeymapPathAvailability;
+    use crate::KeymapPaths;
+    use crate::KeystrokeSequence;
+    use crate::ReflectKeymapCommand;
+    use crate::ReflectKeymapCommandArguments;
+    use crate::command::BindingArguments;
+    use crate::condition::ConditionRegistry;
+    use crate::disk::ENVIRONMENT_LOCK;
+    use crate::disk::TestDirectory;
+    use crate::disk::XdgConfigHome;
+    use crate::keymap::KeyboardOwner;
+    use crate::keymap::KeymapHistory;
+    use crate::keymap::KeystrokeRouting;
+    use crate::keymap::ReloadConfiguration;
+    use crate::keymap::edit::redo_transaction;
+    use crate::keymap::edit::undo_transaction;
+
+    const TEST_APP_NAME: &str = "hana-rubric-keymap-macros-test";
+    const DEFAULTS: &str = r#"{ "bindings": [ { "bindings": { "ctrl-1": "macro::first" } } ] }"#;
+    const USER_KEYMAP: &str = "// my keymap\n{ \"bindings\": [] }\n";
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand)]
+    struct MacroFirst;
+
+    impl KeymapCommand for MacroFirst {
+        const ID: &'static str = "macro::first";
+        const TITLE: &'static str = "Macro First";
+        const DESCRIPTION: &'static str = "First command used by keymap macro tests.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand, KeymapCommandArguments)]
+    struct MacroSelect {
+        index: usize,
+    }
+
+    #[derive(Deserialize, Reflect)]
+    struct MacroSelectArguments {
+        index: usize,
+    }
+
+    impl KeymapCommand for MacroSelect {
+        const ID: &'static str = "macro::select";
+        const TITLE: &'static str = "Macro Select";
+        const DESCRIPTION: &'static str = "Selects the index named by the binding's arguments.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self::default() }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    impl KeymapCommandArguments for MacroSelect {
+        type Arguments = MacroSelectArguments;
+
+        fn build_with(arguments: MacroSelectArguments) -> Self {
+            Self {
+                index: arguments.index,
+            }
+        }
+    }
+
+    #[derive(Debug, Default, Eq, PartialEq, Resource)]
+    struct Invoked(Vec<String>);
+
+    /// A world holding the resources macros read, with `USER_KEYMAP` written to disk.
+    fn macro_world() -> Result<(World, KeymapPaths), String> {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<MacroFirst>();
+        type_registry.register::<MacroSelect>();
+        type_registry.register::<PlayKeymapMacro>();
+        let command_registry = CommandRegistry::build(&type_registry, &mut CustomInputs::default())
+            .map_err(|diagnostics| format!("macro registry diagnostics: {diagnostics:?}"))?;
+        let keymap_path_availability = KeymapPathAvailability::for_app_name(TEST_APP_NAME);
+        let paths = keymap_path_availability
+            .resolved()
+            .map_err(|keymap_path_failure| format!("{keymap_path_failure:?}"))?
+            .clone();
+        fs::create_dir_all(paths.config_directory()).map_err(|error| error.to_string())?;
+        fs::write(paths.user_keymap(), USER_KEYMAP).map_err(|error| error.to_string())?;
+
+        let mut world = World::new();
+        world.insert_resource(command_registry);
+        world.insert_resource(ConditionRegistry::default());
+        world.insert_resource(keymap_path_availability);
+        world.init_resource::<Invoked>();
+        world.init_resource::<KeymapHistory>();
+        world.init_resource::<KeymapMacros>();
+        world.init_resource::<KeystrokeRouting>();
+        world.insert_resource(ReloadConfiguration::new(
+            DiagnosticOrigin::EmbeddedDefaults,
+            DEFAULTS.to_owned(),
+            Vec::new(),
+            false,
+        ));
+        world.add_observer(|_: On<MacroFirst>, mut invoked: ResMut<Invoked>| {
+            invoked.0.push(String::from("first"));
+        });
+        world.add_observer(|select: On<MacroSelect>, mut invoked: ResMut<Invoked>| {
+            invoked.0.push(format!("select {}", select.event().index));
+        });
+
+        Ok((world, paths))
+    }
+
+    fn record(world: &mut World, command: &str, arguments: Option<&str>) -> Result<(), String> {
+        let command = CommandId::try_from(command).map_err(|error| error.to_string())?;
+        let arguments = arguments
+            .map(serde_json_lenient::from_str::<Value>)
+            .transpose()
+            .map_err(|error| error.to_string())?
+            .map(BindingArguments::new);
+        world
+            .resource_mut::<KeymapMacros>()
+            .record(command, arguments);
+        Ok(())
+    }
+
+    fn name(text: &str) -> Result<KeymapMacroName, String> {
+        KeymapMacroName::try_from(text).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn finishing_stores_and_binds_the_recording_and_replay_invokes_every_step() -> Result<(), String>
+    {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-macro-finish").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, paths) = macro_world()?;
+        let indent = name("indent")?;
+
+        start_recording(&mut world).map_err(|error| error.to_string())?;
+        assert_eq!(
+            start_recording(&mut world),
+            Err(KeymapMacroError::AlreadyRecording)
+        );
+        record(&mut world, MacroFirst::ID, None)?;
+        record(&mut world, MacroSelect::ID, Some(r#"{ "index": 3 }"#))?;
+        finish_recording(
+            &mut world,
+            &indent,
+            None,
+            KeystrokeSequence::from_str("ctrl-m").map_err(|error| error.to_string())?,
+        )
+        .map_err(|error| error.to_string())?;
+
+        assert!(!world.resource::<KeymapMacros>().is_recording());
+        let macros = fs::read_to_string(paths.macros()).map_err(|error| error.to_string())?;
+        assert!(macros.starts_with("// Keymap macros."), "{macros}");
+        let user_keymap =
+            fs::read_to_string(paths.user_keymap()).map_err(|error| error.to_string())?;
+        assert!(
+            user_keymap.contains(r#""ctrl-m": ["keymap::play_macro", { "name": "indent" }]"#),
+            "{user_keymap}"
+        );
+
+        let replay = play_macro(&mut world, &indent).map_err(|error| error.to_string())?;
+        assert!(replay.is_complete(), "{replay:?}");
+        assert_eq!(world.resource::<Invoked>().0, ["first", "select 3"]);
+        assert_eq!(
+            play_macro(&mut world, &name("missing")?),
+            Err(KeymapMacroError::NotFound(String::from("missing")))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn replay_runs_nothing_when_a_step_is_unknown_or_unavailable() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-macro-abort").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, paths) = macro_world()?;
+        fs::write(
+            paths.macros(),
+            r#"// hand-edited
+{
+  "macros": {
+    "stale": ["macro::first", "macro::removed"],
+    "typing": ["macro::first", ["macro::select", { "index": 1 }]],
+  },
+}
+"#,
+        )
+        .map_err(|error| error.to_string())?;
+
+        let stale = play_macro(&mut world, &name("stale")?).map_err(|error| error.to_string())?;
+        assert_eq!(
+            stale
+                .steps
+                .iter()
+                .map(|step| step.outcome)
+                .collect::<Vec<_>>(),
+            [
+                KeymapMacroStepOutcome::NotRun,
+                KeymapMacroStepOutcome::Refused(CommandInvocationOutcome::UnknownCommand),
+            ]
+        );
+
+        world.insert_resource(KeystrokeRouting::text_entry(
+            KeyboardOwner::of::<Invoked>(),
+            [CommandId::declared::<MacroFirst>()],
+        ));
+        let typing = play_macro(&mut world, &name("typing")?).map_err(|error| error.to_string())?;
+        assert_eq!(
+            typing
+                .steps
+                .iter()
+                .map(|step| step.outcome)
+                .collect::<Vec<_>>(),
+            [
+                KeymapMacroStepOutcome::NotRun,
+                KeymapMacroStepOutcome::Unavailable,
+            ]
+        );
+        assert!(
+            world.resource::<Invoked>().0.is_empty(),
+            "an aborted replay runs none of its steps"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn undoing_a_macro_binding_keeps_the_macro_stored() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-macro-undo").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, paths) = macro_world()?;
+        let indent = name("indent")?;
+
+        start_recording(&mut world).map_err(|error| error.to_string())?;
+        record(&mut world, MacroFirst::ID, None)?;
+        finish_recording(
+            &mut world,
+            &indent,
+            None,
+            KeystrokeSequence::from_str("ctrl-m").map_err(|error| error.to_string())?,
+        )
+        .map_err(|error| error.to_string())?;
+        let macros = fs::read(paths.macros()).map_err(|error| error.to_string())?;
+
+        undo_transaction(&mut world).map_err(|error| error.to_string())?;
+
+        assert_eq!(
+            fs::read_to_string(paths.user_keymap()).map_err(|error| error.to_string())?,
+            USER_KEYMAP
+        );
+        assert_eq!(
+            fs::read(paths.macros()).map_err(|error| error.to_string())?,
+            macros,
+            "undo leaves the stored macro in place"
+        );
+        let replay = play_macro(&mut world, &indent).map_err(|error| error.to_string())?;
+        assert!(replay.is_complete(), "{replay:?}");
+
+        redo_transaction(&mut world).map_err(|error| error.to_string())?;
+        let user_keymap =
+            fs::read_to_string(paths.user_keymap()).map_err(|error| error.to_string())?;
+        assert!(
+            user_keymap.contains(r#""ctrl-m": ["keymap::play_macro", { "name": "indent" }]"#),
+            "{user_keymap}"
+        );
+        Ok(())
+    }
+}
@@ -6,10 +6,15 @@ use std::collections::hash_map::Entry;
 use super::CompiledKeymap;
 use super::Generation;
 use super::KeymapDocument;
+use super::context_expr::ConditionTree;
+use super::context_expr::ContextExpr;
+use super::context_expr::Specificity;
 use super::document::BindingEdit;
 use super::document::BindingSource;
-use super::document::ContextExpr;
 use super::document::ContextSource;
+use super::explain::BindingCandidate;
+use super::explain::BindingLayer;
+use super::explain::BindingOutcome;
 use crate::Capability;
 use crate::CommandId;
 use crate::CommandLookup;
@@ -21,6 +26,7 @@ use crate::DiagnosticSeverity;
 use crate::Keystroke;
 use crate::KeystrokeSequence;
 use crate::PrimaryTrigger;
+use crate::command::BindingArguments;
 use crate::condition::ConditionHandle;
 use crate::condition::ConditionLookup;
 use crate::condition::ConditionRegistry;
@@ -33,7 +39,7 @@ pub(super) const RECOGNIZED_BLOCK_MEMBERS: [&str; 2] = ["bindings", "context"];
 /// ahead of every conditioned one, which is what makes [`MergedKeymap::bindings`] report a
 /// command's global keystroke when the same command is also bound inside a condition.
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
-enum BindingScope {
+pub(super) enum BindingScope {
     Global,
     Condition(ConditionHandle),
 }
@@ -41,17 +47,34 @@ enum BindingScope {
 /// The exact valid edits that remain after defaults and user keymaps are layered.
 #[derive(Default)]
 pub(crate) struct ResolvedEdits {
-    entries: HashMap<BindingScope, HashMap<KeystrokeSequence, LayeredEdit>>,
+    entries:       HashMap<BindingScope, HashMap<KeystrokeSequence, LayeredEdit>>,
+    specificities: HashMap<(BindingScope, KeystrokeSequence, BindingSourceLayer), Specificity>,
 }
 
 impl ResolvedEdits {
+    /// Layers one edit at a keystroke identity unless the same layer already holds an edit there
+    /// from a more specific context expression.
     fn apply(
         &mut self,
         binding_scope: BindingScope,
         keystroke_sequence: KeystrokeSequence,
         source_layer: BindingSourceLayer,
+        specificity: Specificity,
         resolved_edit: ResolvedEdit,
     ) {
+        match self
+            .specificities
+            .entry((binding_scope, keystroke_sequence.clone(), source_layer))
+        {
+            Entry::Occupied(occupied) if *occupied.get() > specificity => return,
+            Entry::Occupied(mut occupied) => {
+                occupied.insert(specificity);
+            },
+            Entry::Vacant(vacant) => {
+                vacant.insert(specificity);
+            },
+        }
+
         match self
             .entries
             .entry(binding_scope)
@@ -90,6 +113,62 @@ impl ResolvedEdits {
         }
     }
 
+    /// The edit `keystroke_sequence` routes to while `binding_scope` is active, falling back to
+    /// the global edit when the condition authors none.
+    fn routed(
+        &self,
+        binding_scope: BindingScope,
+        keystroke_sequence: &KeystrokeSequence,
+    ) -> Option<&LayeredEdit> {
+        let condition_edit = match binding_scope {
+            BindingScope::Global => None,
+            BindingScope::Condition(condition_handle) => self
+                .for_condition(condition_handle)
+                .and_then(|edits| edits.get(keystroke_sequence)),
+        };
+
+        condition_edit.or_else(|| {
+            self.global()
+                .and_then(|edits| edits.get(keystroke_sequence))
+        })
+    }
+
+    /// The edit layered at exactly `binding_scope`, without falling back to the global edit.
+    fn at(
+        &self,
+        binding_scope: BindingScope,
+        keystroke_sequence: &KeystrokeSequence,
+    ) -> Option<&LayeredEdit> {
+        self.entries
+            .get(&binding_scope)
+            .and_then(|edits| edits.get(keystroke_sequence))
+    }
+
+    /// Every keystroke sequence `binding_scope` routes to a binding, global fallbacks included.
+    fn routed_bindings(
+        &self,
+        binding_scope: BindingScope,
+    ) -> impl Iterator<Item = (&KeystrokeSequence, &ResolvedBinding)> {
+        let condition_edits = match binding_scope {
+            BindingScope::Global => None,
+            BindingScope::Condition(condition_handle) => self.for_condition(condition_handle),
+        };
+
+        self.global()
+            .into_iter()
+            .flatten()
+            .filter(move |(keystroke_sequence, _)| {
+                condition_edits.is_none_or(|edits| !edits.contains_key(*keystroke_sequence))
+            })
+            .chain(condition_edits.into_iter().flatten())
+            .filter_map(
+                |(keystroke_sequence, layered_edit)| match layered_edit.live() {
+                    ResolvedEdit::Bind(binding) => Some((keystroke_sequence, binding)),
+                    ResolvedEdit::Tombstone(_) => None,
+                },
+            )
+    }
+
     fn global(&self) -> Option<&HashMap<KeystrokeSequence, LayeredEdit>> {
         self.entries.get(&BindingScope::Global)
     }
@@ -159,6 +238,19 @@ impl LayeredEdit {
         }
     }
 
+    /// The layer that authored [`Self::live`].
+    const fn live_layer(&self) -> BindingSourceLayer {
+        match self {
+            Self::ShippedDefault(_) => BindingSourceLayer::ShippedDefault,
+            Self::User(_) | Self::UserOverShippedDefault { .. } => BindingSourceLayer::User,
+        }
+    }
+
+    /// Whether [`Self::live`] is the edit `source_layer` authored at `binding_source`.
+    fn is_live(&self, source_layer: BindingSourceLayer, binding_source: &BindingSource) -> bool {
+        self.live_layer() == source_layer && self.live().source().is_same_binding(binding_source)
+    }
+
     /// Drops `source_layer`'s edit, leaving whatever the layer underneath still binds.
     fn reject_layer(&mut self, source_layer: BindingSourceLayer) -> BindingRetention {
         match (source_layer, &mut *self) {
@@ -192,12 +284,22 @@ enum BindingRetention {
 #[derive(Clone)]
 enum ResolvedEdit {
     Bind(ResolvedBinding),
-    Tombstone,
+    Tombstone(BindingSource),
+}
+
+impl ResolvedEdit {
+    const fn source(&self) -> &BindingSource {
+        match self {
+            Self::Bind(binding) => &binding.source,
+            Self::Tombstone(source) => source,
+        }
+    }
 }
 
 #[derive(Clone)]
 struct ResolvedBinding {
     command_id:        CommandId,
+    arguments:         Option<BindingArguments>,
     source:            BindingSource,
     source_layer:      BindingSourceLayer,
     diagnostic_origin: DiagnosticOrigin,
@@ -210,6 +312,43 @@ struct ScopedBinding {
     binding_scope:      BindingScope,
 }
 
+/// One valid authored edit with the scopes its block resolved to, in source order.
+struct AuthoredEdit {
+    keystroke_sequence: KeystrokeSequence,
+    resolved_edit:      ResolvedEdit,
+    source_layer:       BindingSourceLayer,
+    diagnostic_origin:  DiagnosticOrigin,
+    binding_scopes:     Vec<BindingScope>,
+    specificity:        Specificity,
+}
+
+impl AuthoredEdit {
+    /// The authored command, or `null` for an unbind, as conflict messages quote it.
+    fn describe(&self) -> String {
+        match &self.resolved_edit {
+            ResolvedEdit::Bind(binding) => format!("`{}`", binding.command_id),
+            ResolvedEdit::Tombstone(_) => String::from("`null`"),
+        }
+    }
+
+    fn command_text(&self) -> String {
+        match &self.resolved_edit {
+            ResolvedEdit::Bind(binding) => binding.command_id.to_string(),
+            ResolvedEdit::Tombstone(_) => String::new(),
+        }
+    }
+
+    fn diagnostic(&self, kind: DiagnosticKind, message: String) -> Diagnostic {
+        self.resolved_edit.source().diagnostic(
+            &self.diagnostic_origin,
+            self.command_text(),
+            kind,
+            DiagnosticSeverity::Advisory,
+            message,
+        )
+    }
+}
+
 /// One held-prefix collision participant to drop, named down to the layer that authored it so
 /// the layer beneath survives.
 struct RejectedBinding {
@@ -229,12 +368,21 @@ impl From<&ScopedBinding> for RejectedBinding {
 }
 
 /// The keymap layer that authored a resolved binding.
-#[derive(Clone, Copy, Eq, PartialEq)]
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
 enum BindingSourceLayer {
     ShippedDefault,
     User,
 }
 
+impl From<BindingSourceLayer> for BindingLayer {
+    fn from(source_layer: BindingSourceLayer) -> Self {
+        match source_layer {
+            BindingSourceLayer::ShippedDefault => Self::ShippedDefault,
+            BindingSourceLayer::User => Self::User,
+        }
+    }
+}
+
 /// Whether the two participants in a held-prefix collision were authored by the same keymap layer.
 ///
 /// A valid hold takes precedence over a longer sequence from the *other* layer, whichever layer the
@@ -258,15 +406,86 @@ impl HeldPrefixConflict {
 
 /// Which scope a keymap block's `context` member resolved to, or that it named no usable one.
 enum ContextResolution {
-    Resolved(BindingScope),
+    Resolved {
+        binding_scopes: Vec<BindingScope>,
+        specificity:    Specificity,
+    },
     Invalid,
 }
 
+/// Every edit the committed sources author, indexed by keystroke sequence, and the routing the
+/// merge resolved from them, so an explanation reads neither source again.
+pub(crate) struct BindingIndex {
+    resolved_edits:  ResolvedEdits,
+    explained_edits: HashMap<KeystrokeSequence, Vec<ExplainedEdit>>,
+}
+
+impl BindingIndex {
+    /// Lists every edit the sources author at `keystroke_sequence`, defaults first, with what
+    /// each contributes while `binding_scope` routes input.
+    pub(super) fn explain(
+        &self,
+        keystroke_sequence: &KeystrokeSequence,
+        binding_scope: BindingScope,
+    ) -> Vec<BindingCandidate> {
+        let routed = self
+            .resolved_edits
+            .routed(binding_scope, keystroke_sequence);
+
+        self.explained_edits
+            .get(keystroke_sequence)
+            .into_iter()
+            .flatten()
+            .map(|explained_edit| {
+                let outcome = match &explained_edit.binding_scopes {
+                    None => BindingOutcome::Rejected,
+                    Some(binding_scopes)
+                        if !binding_scopes.iter().any(|candidate_scope| {
+                            *candidate_scope == binding_scope
+                                || *candidate_scope == BindingScope::Global
+                        }) =>
+                    {
+                        BindingOutcome::Inactive
+                    },
+                    Some(_) => MergedKeymap::routed_outcome(
+                        routed,
+                        explained_edit.source_layer,
+                        &explained_edit.source,
+                    ),
+                };
+
+                BindingCandidate {
+                    layer: explained_edit.source_layer.into(),
+                    origin: explained_edit.origin.clone(),
+                    line: explained_edit.source.line(),
+                    column: explained_edit.source.column(),
+                    context: Some(explained_edit.source.context.clone())
+                        .filter(|context| !context.is_empty()),
+                    command: explained_edit.command.clone(),
+                    outcome,
+                }
+            })
+            .collect()
+    }
+}
+
+/// One authored binding or unbind and what validation made of it.
+struct ExplainedEdit {
+    source_layer:   BindingSourceLayer,
+    origin:         DiagnosticOrigin,
+    source:         BindingSource,
+    command:        Option<CommandId>,
+    /// The scopes the block's context resolves to, or `None` when validation refused the block's
+    /// context or the edit itself.
+    binding_scopes: Option<Vec<BindingScope>>,
+}
+
 /// The user keymap layered over the embedded defaults, or its absence.
 ///
 /// [`UserKeymap::DefaultsOnly`] covers every situation that leaves nothing to layer — a
 /// defaults-only startup commit, a missing user keymap file, and a user keymap file that is not
 /// UTF-8 — because the merge treats all three the same way.
+#[derive(Clone)]
 pub(crate) enum UserKeymap {
     Layered {
         origin:   DiagnosticOrigin,
@@ -288,6 +507,7 @@ pub(crate) enum UserKeymapDocument<'document> {
 pub(crate) struct MergedKeymap {
     global:                Vec<(KeystrokeSequence, CommandId)>,
     pub(super) conditions: HashMap<ConditionHandle, Vec<(KeystrokeSequence, CommandId)>>,
+    arguments:             HashMap<(BindingScope, KeystrokeSequence), BindingArguments>,
 }
 
 impl MergedKeymap {
@@ -309,6 +529,35 @@ impl MergedKeymap {
         condition_registry: &ConditionRegistry,
         protected_keystrokes: &[Keystroke],
     ) -> Result<(Self, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let (defaults, user, mut diagnostics) =
+            Self::parse_sources(defaults_diagnostic_origin, defaults_source, user_keymap)?;
+        let user_keymap_document = user.as_ref().map_or(
+            UserKeymapDocument::DefaultsOnly,
+            UserKeymapDocument::Layered,
+        );
+        let (merged_keymap, merge_diagnostics) = Self::from_documents(
+            &defaults,
+            user_keymap_document,
+            command_registry,
+            condition_registry,
+            protected_keystrokes,
+        );
+        diagnostics.extend(merge_diagnostics);
+
+        Ok((merged_keymap, diagnostics))
+    }
+
+    /// Parses the defaults and an optional user keymap, keeping each document's diagnostics.
+    ///
+    /// # Errors
+    ///
+    /// Returns every diagnostic collected before a document-level parse failure, followed by the
+    /// failing document's own diagnostics.
+    fn parse_sources(
+        defaults_diagnostic_origin: &DiagnosticOrigin,
+        defaults_source: &str,
+        user_keymap: &UserKeymap,
+    ) -> Result<(KeymapDocument, Option<KeymapDocument>, Vec<Diagnostic>), Vec<Diagnostic>> {
         let (defaults, mut diagnostics) =
             KeymapDocument::parse(defaults_diagnostic_origin, defaults_source)?;
         let user = match user_keymap {
@@ -325,39 +574,454 @@ impl MergedKeymap {
             },
             UserKeymap::DefaultsOnly => None,
         };
+
+        Ok((defaults, user, diagnostics))
+    }
+
+    /// Validates and layers already parsed defaults followed by an optional user keymap.
+    #[must_use]
+    pub(crate) fn from_documents(
+        defaults: &KeymapDocument,
+        user_keymap_document: UserKeymapDocument<'_>,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+        protected_keystrokes: &[Keystroke],
+    ) -> (Self, Vec<Diagnostic>) {
+        let mut diagnostics = Vec::new();
+        let resolved_edits = Self::resolve_edits(
+            defaults,
+            user_keymap_document,
+            command_registry,
+            condition_registry,
+            protected_keystrokes,
+            &mut diagnostics,
+        );
+
+        (
+            Self::from_resolved_edits(resolved_edits, condition_registry),
+            diagnostics,
+        )
+    }
+
+    /// Indexes every edit the two sources author by keystroke sequence, with the routing the merge
+    /// resolves from them.
+    ///
+    /// Returns `None` when a source no longer parses, because no generation was compiled from it.
+    pub(super) fn index_sources(
+        defaults_diagnostic_origin: &DiagnosticOrigin,
+        defaults_source: &str,
+        user_keymap: &UserKeymap,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+        protected_keystrokes: &[Keystroke],
+    ) -> Option<BindingIndex> {
+        let (defaults, user, _) =
+            Self::parse_sources(defaults_diagnostic_origin, defaults_source, user_keymap).ok()?;
         let user_keymap_document = user.as_ref().map_or(
             UserKeymapDocument::DefaultsOnly,
             UserKeymapDocument::Layered,
         );
-        let (merged_keymap, merge_diagnostics) = Self::from_documents(
+        let mut diagnostics = Vec::new();
+        let resolved_edits = Self::resolve_edits(
             &defaults,
             user_keymap_document,
             command_registry,
             condition_registry,
             protected_keystrokes,
+            &mut diagnostics,
         );
-        diagnostics.extend(merge_diagnostics);
+        let documents = std::iter::once((&defaults, BindingSourceLayer::ShippedDefault))
+            .chain(user.iter().map(|user| (user, BindingSourceLayer::User)));
+
+        let mut explained_edits = HashMap::<KeystrokeSequence, Vec<ExplainedEdit>>::new();
+        for (document, source_layer) in documents {
+            for block in &document.blocks {
+                let context_resolution = Self::resolve_context(
+                    block.context.as_ref(),
+                    block.context_source.as_ref(),
+                    document,
+                    condition_registry,
+                    &mut diagnostics,
+                );
+                for binding in &block.bindings {
+                    let resolved_edit = Self::resolve_binding(
+                        binding.edit.clone(),
+                        &binding.keystroke_sequence,
+                        &binding.source,
+                        source_layer,
+                        &document.diagnostic_origin,
+                        command_registry,
+                        protected_keystrokes,
+                        &mut diagnostics,
+                    );
+                    let binding_scopes = match (&context_resolution, resolved_edit) {
+                        (ContextResolution::Invalid, _) | (_, None) => None,
+                        (ContextResolution::Resolved { binding_scopes, .. }, Some(_)) => {
+                            Some(binding_scopes.clone())
+                        },
+                    };
 
-        Ok((merged_keymap, diagnostics))
+                    explained_edits
+                        .entry(binding.keystroke_sequence.clone())
+                        .or_default()
+                        .push(ExplainedEdit {
+                            source_layer,
+                            origin: document.diagnostic_origin.clone(),
+                            source: binding.source.clone(),
+                            command: match &binding.edit {
+                                BindingEdit::Bind(command_id)
+                                | BindingEdit::BindWithArguments { command_id, .. } => {
+                                    Some(command_id.clone())
+                                },
+                                BindingEdit::Unbind => None,
+                            },
+                            binding_scopes,
+                        });
+                }
+            }
+        }
+
+        Some(BindingIndex {
+            resolved_edits,
+            explained_edits,
+        })
     }
 
-    /// Validates and layers already parsed defaults followed by an optional user keymap.
-    #[must_use]
-    pub(crate) fn from_documents(
+    /// What an in-scope, valid edit contributes given the edit its keystroke routes to.
+    fn routed_outcome(
+        routed: Option<&LayeredEdit>,
+        source_layer: BindingSourceLayer,
+        binding_source: &BindingSource,
+    ) -> BindingOutcome {
+        let Some(routed) = routed else {
+            return BindingOutcome::Unbound;
+        };
+        let is_routed = routed.is_live(source_layer, binding_source);
+
+        match (routed.live(), is_routed) {
+            (ResolvedEdit::Bind(_), true) => BindingOutcome::Fires,
+            (ResolvedEdit::Tombstone(_), true) => BindingOutcome::Unbinds,
+            (ResolvedEdit::Bind(_), false) => BindingOutcome::Shadowed,
+            (ResolvedEdit::Tombstone(_), false) => BindingOutcome::Tombstoned,
+        }
+    }
+
+    /// Analyzes the whole layered keymap for bindings that merge cleanly but do not behave the way
+    /// their author likely intended.
+    ///
+    /// Reports, as advisories in source order, equally specific blocks in one layer that bind the
+    /// same sequence, bindings that are a strict prefix of a longer routed sequence and so wait
+    /// for the sequence timeout, user bindings that replace a shipped default's command, and
+    /// bindings every routable condition overrides. A source that does not parse reports its
+    /// parse diagnostics instead.
+    pub(super) fn conflicts_from_sources(
+        defaults_diagnostic_origin: &DiagnosticOrigin,
+        defaults_source: &str,
+        user_keymap: &UserKeymap,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+        protected_keystrokes: &[Keystroke],
+    ) -> Vec<Diagnostic> {
+        let (defaults, user) =
+            match Self::parse_sources(defaults_diagnostic_origin, defaults_source, user_keymap) {
+                Ok((defaults, user, _)) => (defaults, user),
+                Err(diagnostics) => return diagnostics,
+            };
+        let user_keymap_document = user.as_ref().map_or(
+            UserKeymapDocument::DefaultsOnly,
+            UserKeymapDocument::Layered,
+        );
+        let mut merge_diagnostics = Vec::new();
+        let resolved_edits = Self::resolve_edits(
+            &defaults,
+            user_keymap_document,
+            command_registry,
+            condition_registry,
+            protected_keystrokes,
+            &mut merge_diagnostics,
+        );
+        let authored_edits = Self::authored_edits(
+            &defaults,
+            user.as_ref(),
+            command_registry,
+            condition_registry,
+            protected_keystrokes,
+        );
+        let routing_scopes = Self::routing_scopes(condition_registry);
+
+        let mut conflicts = Vec::new();
+        for (index, authored_edit) in authored_edits.iter().enumerate() {
+            conflicts.extend(Self::duplicate_conflict(
+                authored_edit,
+                &authored_edits[index + 1..],
+            ));
+            let ResolvedEdit::Bind(binding) = &authored_edit.resolved_edit else {
+                continue;
+            };
+            conflicts.extend(Self::shadowed_default_conflict(
+                authored_edit,
+                binding,
+                &resolved_edits,
+            ));
+
+            let routed_scopes = routing_scopes
+                .iter()
+                .copied()
+                .filter(|binding_scope| {
+                    resolved_edits
+                        .routed(*binding_scope, &authored_edit.keystroke_sequence)
+                        .is_some_and(|routed| {
+                            routed.is_live(authored_edit.source_layer, &binding.source)
+                        })
+                })
+                .collect::<Vec<_>>();
+            if routed_scopes.is_empty() {
+                conflicts.extend(Self::unreachable_conflict(
+                    authored_edit,
+                    binding,
+                    &resolved_edits,
+                ));
+            } else {
+                conflicts.extend(Self::prefix_conflict(
+                    authored_edit,
+                    binding,
+                    &routed_scopes,
+                    &resolved_edits,
+                ));
+            }
+        }
+
+        conflicts
+    }
+
+    /// Every valid edit both sources author, defaults first and each in source order.
+    fn authored_edits(
         defaults: &KeymapDocument,
-        user_keymap_document: UserKeymapDocument<'_>,
+        user: Option<&KeymapDocument>,
         command_registry: &CommandRegistry,
         condition_registry: &ConditionRegistry,
         protected_keystrokes: &[Keystroke],
-    ) -> (Self, Vec<Diagnostic>) {
+    ) -> Vec<AuthoredEdit> {
+        let documents = std::iter::once((defaults, BindingSourceLayer::ShippedDefault))
+            .chain(user.map(|user| (user, BindingSourceLayer::User)));
         let mut diagnostics = Vec::new();
+
+        let mut authored_edits = Vec::new();
+        for (document, source_layer) in documents {
+            for block in &document.blocks {
+                let ContextResolution::Resolved {
+                    binding_scopes,
+                    specificity,
+                } = Self::resolve_context(
+                    block.context.as_ref(),
+                    block.context_source.as_ref(),
+                    document,
+                    condition_registry,
+                    &mut diagnostics,
+                )
+                else {
+                    continue;
+                };
+
+                for binding in &block.bindings {
+                    let Some(resolved_edit) = Self::resolve_binding(
+                        binding.edit.clone(),
+                        &binding.keystroke_sequence,
+                        &binding.source,
+                        source_layer,
+                        &document.diagnostic_origin,
+                        command_registry,
+                        protected_keystrokes,
+                        &mut diagnostics,
+                    ) else {
+                        continue;
+                    };
+
+                    authored_edits.push(AuthoredEdit {
+                        keystroke_sequence: binding.keystroke_sequence.clone(),
+                        resolved_edit,
+                        source_layer,
+                        diagnostic_origin: document.diagnostic_origin.clone(),
+                        binding_scopes: binding_scopes.clone(),
+                        specificity,
+                    });
+                }
+            }
+        }
+
+        authored_edits
+    }
+
+    /// The scopes input can route through: every registered condition once the application
+    /// reports contexts, or the global scope when it registers none.
+    fn routing_scopes(condition_registry: &ConditionRegistry) -> Vec<BindingScope> {
+        let condition_scopes = condition_registry
+            .iter()
+            .filter_map(|condition_info| {
+                match condition_registry.lookup(condition_info.name.as_str()) {
+                    ConditionLookup::Registered { handle, .. } => {
+                        Some(BindingScope::Condition(handle))
+                    },
+                    ConditionLookup::UnregisteredName => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if condition_scopes.is_empty() {
+            vec![BindingScope::Global]
+        } else {
+            condition_scopes
+        }
+    }
+
+    /// Reports `authored_edit` when a later, equally specific block in the same layer edits the
+    /// same sequence in a shared scope and so silently replaces it.
+    ///
+    /// Repeated keys inside one block are already reported when the document is parsed.
+    fn duplicate_conflict(
+        authored_edit: &AuthoredEdit,
+        later_edits: &[AuthoredEdit],
+    ) -> Option<Diagnostic> {
+        let replacement = later_edits.iter().find(|later_edit| {
+            later_edit.source_layer == authored_edit.source_layer
+                && later_edit.keystroke_sequence == authored_edit.keystroke_sequence
+                && later_edit.specificity == authored_edit.specificity
+                && later_edit.resolved_edit.source().block_index
+                    != authored_edit.resolved_edit.source().block_index
+                && later_edit
+                    .binding_scopes
+                    .iter()
+                    .any(|binding_scope| authored_edit.binding_scopes.contains(binding_scope))
+        })?;
+        let keystroke_sequence = &authored_edit.keystroke_sequence;
+        let line = replacement.resolved_edit.source().line();
+        let message = if replacement.describe() == authored_edit.describe() {
+            format!(
+                "Keystroke `{keystroke_sequence}` is bound to {} again at line {line} by an equally specific block.",
+                authored_edit.describe()
+            )
+        } else {
+            format!(
+                "Keystroke `{keystroke_sequence}` is rebound to {} at line {line} by an equally specific block, which replaces {} here.",
+                replacement.describe(),
+                authored_edit.describe()
+            )
+        };
+
+        Some(authored_edit.diagnostic(DiagnosticKind::DuplicateBinding, message))
+    }
+
+    /// Reports a user binding that replaces a shipped default bound to a different command.
+    fn shadowed_default_conflict(
+        authored_edit: &AuthoredEdit,
+        binding: &ResolvedBinding,
+        resolved_edits: &ResolvedEdits,
+    ) -> Option<Diagnostic> {
+        if authored_edit.source_layer != BindingSourceLayer::User {
+            return None;
+        }
+        let shipped_default = authored_edit
+            .binding_scopes
+            .iter()
+            .find_map(|binding_scope| {
+                match resolved_edits.at(*binding_scope, &authored_edit.keystroke_sequence) {
+                    Some(LayeredEdit::UserOverShippedDefault {
+                        user: ResolvedEdit::Bind(user),
+                        shipped_default: ResolvedEdit::Bind(shipped_default),
+                    }) if user.source.is_same_binding(&binding.source)
+                        && shipped_default.command_id != binding.command_id =>
+                    {
+                        Some(shipped_default)
+                    },
+                    _ => None,
+                }
+            })?;
+
+        Some(authored_edit.diagnostic(
+            DiagnosticKind::ShadowedDefault,
+            format!(
+                "Keystroke `{}` replaces the shipped default binding to `{}` (line {} of {}) with `{}`.",
+                authored_edit.keystroke_sequence,
+                shipped_default.command_id,
+                shipped_default.source.line(),
+                shipped_default.diagnostic_origin,
+                binding.command_id,
+            ),
+        ))
+    }
+
+    /// Reports a binding that survives layering in its own scope but that every routable
+    /// condition rebinds or unbinds.
+    fn unreachable_conflict(
+        authored_edit: &AuthoredEdit,
+        binding: &ResolvedBinding,
+        resolved_edits: &ResolvedEdits,
+    ) -> Option<Diagnostic> {
+        let is_layered_live = authored_edit.binding_scopes.iter().any(|binding_scope| {
+            resolved_edits
+                .at(*binding_scope, &authored_edit.keystroke_sequence)
+                .is_some_and(|layered_edit| {
+                    layered_edit.is_live(authored_edit.source_layer, &binding.source)
+                })
+        });
+        if !is_layered_live {
+            return None;
+        }
+
+        Some(authored_edit.diagnostic(
+            DiagnosticKind::UnreachableBinding,
+            format!(
+                "Keystroke `{}` never fires `{}`: every context rebinds or unbinds it.",
+                authored_edit.keystroke_sequence, binding.command_id
+            ),
+        ))
+    }
+
+    /// Reports a routed binding that is a strict prefix of a longer routed sequence, which makes
+    /// the matcher defer it until the sequence timeout.
+    fn prefix_conflict(
+        authored_edit: &AuthoredEdit,
+        binding: &ResolvedBinding,
+        routed_scopes: &[BindingScope],
+        resolved_edits: &ResolvedEdits,
+    ) -> Option<Diagnostic> {
+        let keystroke_sequence = &authored_edit.keystroke_sequence;
+        let (longer_sequence, longer_binding) = routed_scopes
+            .iter()
+            .flat_map(|binding_scope| resolved_edits.routed_bindings(*binding_scope))
+            .filter(|(candidate, _)| {
+                candidate.len() > keystroke_sequence.len()
+                    && candidate
+                        .as_slice()
+                        .starts_with(keystroke_sequence.as_slice())
+            })
+            .min_by_key(|(candidate, _)| (candidate.len(), candidate.to_string()))?;
+
+        Some(authored_edit.diagnostic(
+            DiagnosticKind::PrefixConflict,
+            format!(
+                "Keystroke `{keystroke_sequence}` fires `{}` only after the sequence timeout, because `{longer_sequence}` (`{}`) starts with it.",
+                binding.command_id, longer_binding.command_id
+            ),
+        ))
+    }
+
+    /// Layers every valid edit from both sources, with held-prefix collisions already rejected.
+    fn resolve_edits(
+        defaults: &KeymapDocument,
+        user_keymap_document: UserKeymapDocument<'_>,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+        protected_keystrokes: &[Keystroke],
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> ResolvedEdits {
         let mut resolved_edits = ResolvedEdits::default();
 
         Self::apply_document(
             defaults,
             BindingSourceLayer::ShippedDefault,
             &mut resolved_edits,
-            &mut diagnostics,
+            diagnostics,
             command_registry,
             condition_registry,
             protected_keystrokes,
@@ -367,7 +1031,7 @@ impl MergedKeymap {
                 user,
                 BindingSourceLayer::User,
                 &mut resolved_edits,
-                &mut diagnostics,
+                diagnostics,
                 command_registry,
                 condition_registry,
                 protected_keystrokes,
@@ -377,13 +1041,10 @@ impl MergedKeymap {
             &mut resolved_edits,
             command_registry,
             condition_registry,
-            &mut diagnostics,
+            diagnostics,
         );
 
-        (
-            Self::from_resolved_edits(resolved_edits, condition_registry),
-            diagnostics,
-        )
+        resolved_edits
     }
 
     /// Constructs the matchers and command table for one replacement generation.
@@ -428,14 +1089,17 @@ impl MergedKeymap {
                 }
             }));
 
-            let binding_scope = match Self::resolve_context(
+            let (binding_scopes, specificity) = match Self::resolve_context(
                 block.context.as_ref(),
                 block.context_source.as_ref(),
                 document,
                 condition_registry,
                 diagnostics,
             ) {
-                ContextResolution::Resolved(binding_scope) => binding_scope,
+                ContextResolution::Resolved {
+                    binding_scopes,
+                    specificity,
+                } => (binding_scopes, specificity),
                 ContextResolution::Invalid => continue,
             };
 
@@ -453,12 +1117,15 @@ impl MergedKeymap {
                     continue;
                 };
 
-                resolved_edits.apply(
-                    binding_scope,
-                    binding.keystroke_sequence.clone(),
-                    source_layer,
-                    resolved_edit,
-                );
+                for binding_scope in &binding_scopes {
+                    resolved_edits.apply(
+                        *binding_scope,
+                        binding.keystroke_sequence.clone(),
+                        source_layer,
+                        specificity,
+                        resolved_edit.clone(),
+                    );
+                }
             }
         }
     }
@@ -471,7 +1138,10 @@ impl MergedKeymap {
         diagnostics: &mut Vec<Diagnostic>,
     ) -> ContextResolution {
         match (context, context_source) {
-            (None, None) => ContextResolution::Resolved(BindingScope::Global),
+            (None, None) => ContextResolution::Resolved {
+                binding_scopes: vec![BindingScope::Global],
+                specificity:    Specificity::UNCONDITIONAL,
+            },
             (None, Some(context_source)) => {
                 diagnostics.push(Diagnostic {
                     origin: document.diagnostic_origin.clone(),
@@ -485,69 +1155,78 @@ impl MergedKeymap {
                     kind:               DiagnosticKind::Context,
                     severity:           DiagnosticSeverity::Advisory,
                     message: String::from(
-                        "A keymap block `context` value must be a string naming a registered condition.",
+                        "A keymap block `context` value must be a string holding a context expression over registered conditions.",
                     ),
                     suggestions:        Vec::new(),
                 });
 
                 ContextResolution::Invalid
             },
-            (Some(ContextExpr::Name(condition_name)), Some(context_source)) => {
-                if let ConditionLookup::Registered { handle, .. } =
-                    condition_registry.lookup(condition_name.as_str())
-                {
-                    ContextResolution::Resolved(BindingScope::Condition(handle))
-                } else {
-                    let names = condition_registry
-                        .iter()
-                        .map(|condition_info| condition_info.name.as_str())
-                        .collect::<Vec<_>>();
-                    let message = if names.is_empty() {
-                        format!(
-                            "Keymap context `{}` is not registered by the application.",
-                            condition_name.as_str()
-                        )
-                    } else {
-                        format!(
-                            "Keymap context `{}` is not registered. Registered contexts: {}.",
-                            condition_name.as_str(),
-                            names.join(", ")
-                        )
-                    };
-
-                    diagnostics.push(Diagnostic {
-                        origin: document.diagnostic_origin.clone(),
-                        byte_range: context_source.byte_range.clone(),
-                        line: context_source.line,
-                        column: context_source.column,
-                        block_index: context_source.block_index,
-                        context: condition_name.as_str().to_owned(),
-                        original_keystroke: String::new(),
-                        command_id: String::new(),
-                        kind: DiagnosticKind::Context,
-                        severity: DiagnosticSeverity::Failure,
-                        message,
-                        suggestions: names.into_iter().map(str::to_owned).collect(),
-                    });
-
-                    ContextResolution::Invalid
+            (Some(context_expr), Some(context_source)) => {
+                match context_expr.resolve(condition_registry) {
+                    Ok(condition_tree) => Self::expand_context(
+                        context_expr,
+                        context_source,
+                        &condition_tree,
+                        document,
+                        condition_registry,
+                        diagnostics,
+                    ),
+                    Err(unregistered) => {
+                        let names = condition_registry
+                            .iter()
+                            .map(|condition_info| condition_info.name.as_str())
+                            .collect::<Vec<_>>();
+
+                        for (condition_name, range) in unregistered {
+                            let message = if names.is_empty() {
+                                format!(
+                                    "Keymap context `{}` is not registered by the application.",
+                                    condition_name.as_str()
+                                )
+                            } else {
+                                format!(
+                                    "Keymap context `{}` is not registered. Registered contexts: {}.",
+                                    condition_name.as_str(),
+                                    names.join(", ")
+                                )
+                            };
+                            let name_source = context_source.narrowed(&range);
+
+                            diagnostics.push(Diagnostic {
+                                origin: document.diagnostic_origin.clone(),
+                                byte_range: name_source.byte_range,
+                                line: name_source.line,
+                                column: name_source.column,
+                                block_index: name_source.block_index,
+                                context: context_expr.to_string(),
+                                original_keystroke: String::new(),
+                                command_id: String::new(),
+                                kind: DiagnosticKind::Context,
+                                severity: DiagnosticSeverity::Failure,
+                                message,
+                                suggestions: names.iter().copied().map(str::to_owned).collect(),
+                            });
+                        }
+
+                        ContextResolution::Invalid
+                    },
                 }
             },
-            (Some(ContextExpr::Name(condition_name)), None) => {
+            (Some(context_expr), None) => {
                 diagnostics.push(Diagnostic {
                     origin:             document.diagnostic_origin.clone(),
                     byte_range:         0..0,
                     line:               0,
                     column:             0,
                     block_index:        0,
-                    context:            condition_name.as_str().to_owned(),
+                    context:            context_expr.to_string(),
                     original_keystroke: String::new(),
                     command_id:         String::new(),
                     kind:               DiagnosticKind::Context,
                     severity:           DiagnosticSeverity::Failure,
                     message:            format!(
-                        "Keymap context `{}` has no source location.",
-                        condition_name.as_str()
+                        "Keymap context `{context_expr}` has no source location."
                     ),
                     suggestions:        Vec::new(),
                 });
@@ -557,6 +1236,47 @@ impl MergedKeymap {
         }
     }
 
+    /// Expands a resolved context expression into the binding scope of every condition it
+    /// holds under, reporting an expression that holds under none.
+    fn expand_context(
+        context_expr: &ContextExpr,
+        context_source: &ContextSource,
+        condition_tree: &ConditionTree,
+        document: &KeymapDocument,
+        condition_registry: &ConditionRegistry,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> ContextResolution {
+        let condition_handles = condition_tree.matching_conditions(condition_registry);
+        if condition_handles.is_empty() {
+            diagnostics.push(Diagnostic {
+                origin:             document.diagnostic_origin.clone(),
+                byte_range:         context_source.byte_range.clone(),
+                line:               context_source.line,
+                column:             context_source.column,
+                block_index:        context_source.block_index,
+                context:            context_expr.to_string(),
+                original_keystroke: String::new(),
+                command_id:         String::new(),
+                kind:               DiagnosticKind::Context,
+                severity:           DiagnosticSeverity::Advisory,
+                message:            format!(
+                    "Keymap context `{context_expr}` never holds, because the application activates one condition at a time."
+                ),
+                suggestions:        Vec::new(),
+            });
+
+            return ContextResolution::Invalid;
+        }
+
+        ContextResolution::Resolved {
+            specificity:    Specificity::from_matching_conditions(condition_handles.len()),
+            binding_scopes: condition_handles
+                .into_iter()
+                .map(BindingScope::Condition)
+                .collect(),
+        }
+    }
+
     fn resolve_binding(
         edit: BindingEdit,
         keystroke_sequence: &KeystrokeSequence,
@@ -586,93 +1306,145 @@ impl MergedKeymap {
             return None;
         }
 
-        match edit {
-            BindingEdit::Unbind => Some(ResolvedEdit::Tombstone),
-            BindingEdit::Bind(command_id) => {
-                let CommandLookup::Found(command_info) = command_registry.lookup(&command_id)
-                else {
-                    let suggestions = Self::closest_command_ids(&command_id, command_registry);
-                    let message = suggestions.first().map_or_else(
-                        || format!("Command `{command_id}` is not registered."),
-                        |suggestion| {
-                            format!(
-                                "Command `{command_id}` is not registered. Did you mean `{suggestion}`?"
-                            )
-                        },
-                    );
-                    let mut diagnostic = binding_source.command_diagnostic(
-                        diagnostic_origin,
-                        command_id.to_string(),
-                        DiagnosticKind::Command,
-                        DiagnosticSeverity::Failure,
-                        message,
-                    );
-                    diagnostic.suggestions = suggestions;
-                    diagnostics.push(diagnostic);
+        let Some((command_id, arguments)) = edit.into_command() else {
+            return Some(ResolvedEdit::Tombstone(binding_source.clone()));
+        };
+        let CommandLookup::Found(command_info) = command_registry.lookup(&command_id) else {
+            diagnostics.push(Self::unregistered_command_diagnostic(
+                &command_id,
+                binding_source,
+                diagnostic_origin,
+                command_registry,
+            ));
 
-                    return None;
-                };
-                let capability = command_info.capability;
+            return None;
+        };
+        let capability = command_info.capability;
 
-                if let Some((index, modifier_family)) = keystroke_sequence
-                    .iter()
-                    .enumerate()
-                    .find_map(|(index, keystroke)| match keystroke.primary_trigger() {
-                        PrimaryTrigger::ModifierFamily(modifier_family) => {
-                            Some((index, modifier_family))
-                        },
-                        PrimaryTrigger::OrdinaryKey(_) => None,
-                    })
-                    && (capability != Capability::Held || keystroke_sequence.len() != 1)
-                {
-                    diagnostics.push(binding_source.diagnostic(
-                        diagnostic_origin,
-                        command_id.to_string(),
-                        DiagnosticKind::BareModifierRequiresHeldCommand,
-                        DiagnosticSeverity::Failure,
-                        format!(
-                            "Bare modifier keystroke {} `{modifier_family}` can only be the sole keystroke bound to a hold-to-act command.",
-                            index + 1
-                        ),
-                    ));
+        if let Some((index, modifier_family)) =
+            keystroke_sequence
+                .iter()
+                .enumerate()
+                .find_map(|(index, keystroke)| match keystroke.primary_trigger() {
+                    PrimaryTrigger::ModifierFamily(modifier_family) => {
+                        Some((index, modifier_family))
+                    },
+                    PrimaryTrigger::OrdinaryKey(_)
+                    | PrimaryTrigger::MouseButton(_)
+                    | PrimaryTrigger::GamepadButton(_) => None,
+                })
+            && (capability != Capability::Held || keystroke_sequence.len() != 1)
+        {
+            diagnostics.push(binding_source.diagnostic(
+                diagnostic_origin,
+                command_id.to_string(),
+                DiagnosticKind::BareModifierRequiresHeldCommand,
+                DiagnosticSeverity::Failure,
+                format!(
+                    "Bare modifier keystroke {} `{modifier_family}` can only be the sole keystroke bound to a hold-to-act command.",
+                    index + 1
+                ),
+            ));
 
-                    return None;
-                }
+            return None;
+        }
 
-                if capability == Capability::Held && keystroke_sequence.len() > 1 {
-                    diagnostics.push(binding_source.diagnostic(
-                        diagnostic_origin,
-                        command_id.to_string(),
-                        DiagnosticKind::HeldCommandInSequence,
-                        DiagnosticSeverity::Failure,
-                        format!(
-                            "Hold-to-act command `{command_id}` must use exactly one keystroke."
-                        ),
-                    ));
+        if capability == Capability::Held && keystroke_sequence.len() > 1 {
+            diagnostics.push(binding_source.diagnostic(
+                diagnostic_origin,
+                command_id.to_string(),
+                DiagnosticKind::HeldCommandInSequence,
+                DiagnosticSeverity::Failure,
+                format!("Hold-to-act command `{command_id}` must use exactly one keystroke."),
+            ));
 
-                    return None;
-                }
+            return None;
+        }
 
-                if capability == Capability::Unremappable {
-                    diagnostics.push(binding_source.diagnostic(
-                        diagnostic_origin,
-                        command_id.to_string(),
-                        DiagnosticKind::UnremappableCommand,
-                        DiagnosticSeverity::Failure,
-                        format!("Command `{command_id}` is reserved for recovery."),
-                    ));
+        if capability == Capability::Unremappable {
+            diagnostics.push(binding_source.diagnostic(
+                diagnostic_origin,
+                command_id.to_string(),
+                DiagnosticKind::UnremappableCommand,
+                DiagnosticSeverity::Failure,
+                format!("Command `{command_id}` is reserved for recovery."),
+            ));
 
-                    return None;
-                }
+            return None;
+        }
 
-                Some(ResolvedEdit::Bind(ResolvedBinding {
-                    command_id,
-                    source: binding_source.clone(),
-                    source_layer,
-                    diagnostic_origin: diagnostic_origin.clone(),
-                }))
+        if let Some(arguments) = &arguments
+            && let Err(message) =
+                Self::validate_arguments(&command_id, capability, arguments, command_registry)
+        {
+            diagnostics.push(binding_source.command_diagnostic(
+                diagnostic_origin,
+                command_id.to_string(),
+                DiagnosticKind::BindingArguments,
+                DiagnosticSeverity::Failure,
+                message,
+            ));
+
+            return None;
+        }
+
+        Some(ResolvedEdit::Bind(ResolvedBinding {
+            command_id,
+            arguments,
+            source: binding_source.clone(),
+            source_layer,
+            diagnostic_origin: diagnostic_origin.clone(),
+        }))
+    }
+
+    /// Reports a binding to an unregistered command, suggesting the closest registered IDs.
+    fn unregistered_command_diagnostic(
+        command_id: &CommandId,
+        binding_source: &BindingSource,
+        diagnostic_origin: &DiagnosticOrigin,
+        command_registry: &CommandRegistry,
+    ) -> Diagnostic {
+        let suggestions = Self::closest_command_ids(command_id, command_registry);
+        let message = suggestions.first().map_or_else(
+            || format!("Command `{command_id}` is not registered."),
+            |suggestion| {
+                format!("Command `{command_id}` is not registered. Did you mean `{suggestion}`?")
             },
+        );
+        let mut diagnostic = binding_source.command_diagnostic(
+            diagnostic_origin,
+            command_id.to_string(),
+            DiagnosticKind::Command,
+            DiagnosticSeverity::Failure,
+            message,
+        );
+        diagnostic.suggestions = suggestions;
+        diagnostic
+    }
+
+    fn validate_arguments(
+        command_id: &CommandId,
+        capability: Capability,
+        arguments: &BindingArguments,
+        command_registry: &CommandRegistry,
+    ) -> Result<(), String> {
+        if capability == Capability::Held {
+            return Err(format!(
+                "Hold-to-act command `{command_id}` does not accept binding arguments."
+            ));
         }
+        let Some(command_arguments) = command_registry.arguments(command_id) else {
+            return Err(format!(
+                "Command `{command_id}` does not accept binding arguments."
+            ));
+        };
+
+        (command_arguments.validate)(arguments.value()).map_err(|error| {
+            format!(
+                "Arguments for command `{command_id}` do not match `{}`: {error}.",
+                command_arguments.type_path
+            )
+        })
     }
 
     fn reject_held_prefixes(
@@ -748,7 +1520,7 @@ impl MergedKeymap {
                             binding: binding.clone(),
                             binding_scope,
                         }),
-                        ResolvedEdit::Tombstone => None,
+                        ResolvedEdit::Tombstone(_) => None,
                     },
                 )
                 .collect()
@@ -828,7 +1600,17 @@ impl MergedKeymap {
         condition_registry: &ConditionRegistry,
     ) -> Self {
         let global = Self::live_bindings(resolved_edits.global());
+        let global_arguments = Self::live_arguments(resolved_edits.global());
         let mut conditions = HashMap::new();
+        let mut arguments = global_arguments
+            .iter()
+            .map(|(keystroke_sequence, binding_arguments)| {
+                (
+                    (BindingScope::Global, keystroke_sequence.clone()),
+                    binding_arguments.clone(),
+                )
+            })
+            .collect::<HashMap<_, _>>();
 
         for condition_info in condition_registry.iter() {
             let ConditionLookup::Registered {
@@ -839,14 +1621,20 @@ impl MergedKeymap {
                 continue;
             };
             let mut bindings = global.clone();
+            let mut condition_arguments = global_arguments.clone();
 
             if let Some(condition_edits) = resolved_edits.for_condition(condition_handle) {
                 for (keystroke_sequence, layered_edit) in condition_edits {
+                    condition_arguments.remove(keystroke_sequence);
                     match layered_edit.live() {
                         ResolvedEdit::Bind(binding) => {
                             bindings.insert(keystroke_sequence.clone(), binding.command_id.clone());
+                            if let Some(binding_arguments) = &binding.arguments {
+                                condition_arguments
+                                    .insert(keystroke_sequence.clone(), binding_arguments.clone());
+                            }
                         },
-                        ResolvedEdit::Tombstone => {
+                        ResolvedEdit::Tombstone(_) => {
                             bindings.remove(keystroke_sequence);
                         },
                     }
@@ -854,26 +1642,57 @@ impl MergedKeymap {
             }
 
             conditions.insert(condition_handle, bindings.into_iter().collect());
+            arguments.extend(condition_arguments.into_iter().map(
+                |(keystroke_sequence, binding_arguments)| {
+                    (
+                        (
+                            BindingScope::Condition(condition_handle),
+                            keystroke_sequence,
+                        ),
+                        binding_arguments,
+                    )
+                },
+            ));
         }
 
         Self {
             global: global.into_iter().collect(),
             conditions,
+            arguments,
         }
     }
 
     fn live_bindings(
         edits: Option<&HashMap<KeystrokeSequence, LayeredEdit>>,
-    ) -> HashMap<KeystrokeSequence, CommandId> {
+    ) -> HashMap<KeystrokeSequence, CommandId> {
+        edits.map_or_else(HashMap::new, |edits| {
+            edits
+                .iter()
+                .filter_map(
+                    |(keystroke_sequence, layered_edit)| match layered_edit.live() {
+                        ResolvedEdit::Bind(binding) => {
+                            Some((keystroke_sequence.clone(), binding.command_id.clone()))
+                        },
+                        ResolvedEdit::Tombstone(_) => None,
+                    },
+                )
+                .collect()
+        })
+    }
+
+    fn live_arguments(
+        edits: Option<&HashMap<KeystrokeSequence, LayeredEdit>>,
+    ) -> HashMap<KeystrokeSequence, BindingArguments> {
         edits.map_or_else(HashMap::new, |edits| {
             edits
                 .iter()
                 .filter_map(
                     |(keystroke_sequence, layered_edit)| match layered_edit.live() {
-                        ResolvedEdit::Bind(binding) => {
-                            Some((keystroke_sequence.clone(), binding.command_id.clone()))
-                        },
-                        ResolvedEdit::Tombstone => None,
+                        ResolvedEdit::Bind(binding) => binding
+                            .arguments
+                            .clone()
+                            .map(|arguments| (keystroke_sequence.clone(), arguments)),
+                        ResolvedEdit::Tombstone(_) => None,
                     },
                 )
                 .collect()
@@ -914,6 +1733,17 @@ impl MergedKeymap {
 
     pub(super) fn global(&self) -> &[(KeystrokeSequence, CommandId)] { &self.global }
 
+    /// The typed arguments authored for the binding at `keystroke_sequence` in `binding_scope`,
+    /// including arguments a condition inherits from an unshadowed global binding.
+    pub(super) fn arguments(
+        &self,
+        binding_scope: BindingScope,
+        keystroke_sequence: &KeystrokeSequence,
+    ) -> Option<&BindingArguments> {
+        self.arguments
+            .get(&(binding_scope, keystroke_sequence.clone()))
+    }
+
     /// Every resolved binding, global bindings first and then each condition's
     /// bindings in the order the condition registry issued its handles.
     ///
@@ -997,10 +1827,14 @@ mod tests {
     use bevy::prelude::ReflectEvent;
     use bevy::reflect::TypeRegistry;
     use bevy_enhanced_input::prelude::CustomInputs;
+    use serde::Deserialize;
     use strum::AsRefStr;
     use strum::EnumIter;
     use strum::EnumMessage;
 
+    use super::BindingLayer;
+    use super::BindingOutcome;
+    use super::BindingScope;
     use super::Generation;
     use super::MergedKeymap;
     use super::UserKeymap;
@@ -1015,10 +1849,12 @@ mod tests {
     use crate::HoldPhase;
     use crate::KeymapBindings;
     use crate::KeymapCommand;
+    use crate::KeymapCommandArguments;
     use crate::Keystroke;
     use crate::KeystrokeSequence;
     use crate::MatchOutcome;
     use crate::ReflectKeymapCommand;
+    use crate::ReflectKeymapCommandArguments;
     use crate::TimeoutOutcome;
     use crate::condition::ConditionHandle;
     use crate::condition::ConditionLookup;
@@ -1110,12 +1946,45 @@ mod tests {
         fn hold_phase(&self) -> Option<HoldPhase> { None }
     }
 
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand, KeymapCommandArguments)]
+    struct ViewZoom {
+        level: u8,
+    }
+
+    #[derive(Deserialize, Reflect)]
+    struct ViewZoomArguments {
+        level: u8,
+    }
+
+    impl KeymapCommand for ViewZoom {
+        const ID: &'static str = "view::zoom";
+        const TITLE: &'static str = "View Zoom";
+        const DESCRIPTION: &'static str = "Zooms the view to a numbered level.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self::default() }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    impl KeymapCommandArguments for ViewZoom {
+        type Arguments = ViewZoomArguments;
+
+        fn build_with(arguments: ViewZoomArguments) -> Self {
+            Self {
+                level: arguments.level,
+            }
+        }
+    }
+
     fn command_registry() -> Result<CommandRegistry, String> {
         let mut type_registry = TypeRegistry::default();
         type_registry.register::<CameraHold>();
         type_registry.register::<CameraHome>();
         type_registry.register::<CameraReset>();
         type_registry.register::<RecoveryOpen>();
+        type_registry.register::<ViewZoom>();
         let mut custom_inputs = CustomInputs::default();
 
         CommandRegistry::build(&type_registry, &mut custom_inputs)
@@ -1237,6 +2106,69 @@ mod tests {
             .ok_or_else(|| String::from("camera::home was not reported by any binding"))
     }
 
+    #[test]
+    fn binding_arguments_are_validated_against_the_command_argument_type() -> Result<(), String> {
+        let defaults = r#"{
+            "bindings": [{ "bindings": {
+                "z": ["view::zoom", { "level": 2 }],
+                "x": ["view::zoom", { "level": "high" }],
+                "h": ["camera::home", {}],
+                "c": ["camera::hold", {}]
+            } }]
+        }"#;
+
+        let (merged_keymap, diagnostics, _, _) = merged_keymap(defaults, None, &[])?;
+
+        assert_eq!(diagnostics.len(), 3);
+        assert!(
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.kind == DiagnosticKind::BindingArguments)
+        );
+        assert_eq!(
+            command_for_sequence(merged_keymap.global(), "z")?,
+            Some("view::zoom")
+        );
+        assert_eq!(command_for_sequence(merged_keymap.global(), "x")?, None);
+        assert_eq!(command_for_sequence(merged_keymap.global(), "h")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn conditions_inherit_global_binding_arguments_until_shadowed() -> Result<(), String> {
+        let defaults = r#"{
+            "bindings": [
+                { "bindings": {
+                    "z": ["view::zoom", { "level": 2 }],
+                    "y": ["view::zoom", { "level": 3 }]
+                } },
+                { "context": "dimension_lock", "bindings": { "y": "view::zoom" } }
+            ]
+        }"#;
+
+        let (merged_keymap, diagnostics, _, condition_registry) =
+            merged_keymap(defaults, None, &[])?;
+        let condition_scope = BindingScope::Condition(dimension_lock_handle(&condition_registry)?);
+
+        assert!(diagnostics.is_empty());
+        assert!(
+            merged_keymap
+                .arguments(condition_scope, &keystroke_sequence("z")?)
+                .is_some()
+        );
+        assert!(
+            merged_keymap
+                .arguments(condition_scope, &keystroke_sequence("y")?)
+                .is_none()
+        );
+        assert!(
+            merged_keymap
+                .arguments(BindingScope::Global, &keystroke_sequence("y")?)
+                .is_some()
+        );
+        Ok(())
+    }
+
     #[test]
     fn a_command_bound_only_under_conditions_reports_the_first_registered_condition()
     -> Result<(), String> {
@@ -1283,6 +2215,151 @@ mod tests {
         Ok(())
     }
 
+    fn merged_with_palette_condition(
+        defaults: &str,
+    ) -> Result<(MergedKeymap, Vec<Diagnostic>, ConditionRegistry), String> {
+        let mut condition_registry = condition_registry()?;
+        condition_registry
+            .register::<TestPaletteContext>()
+            .map_err(|diagnostics| format!("condition registry errors: {diagnostics:?}"))?;
+        let command_registry = command_registry()?;
+        let (merged_keymap, diagnostics) = MergedKeymap::from_sources(
+            &defaults_keymap_file(),
+            defaults,
+            &UserKeymap::DefaultsOnly,
+            &command_registry,
+            &condition_registry,
+            &[],
+        )
+        .map_err(|diagnostics| format!("keymap parse errors: {diagnostics:?}"))?;
+
+        Ok((merged_keymap, diagnostics, condition_registry))
+    }
+
+    fn palette_open_handle(
+        condition_registry: &ConditionRegistry,
+    ) -> Result<ConditionHandle, String> {
+        match condition_registry.lookup("palette_open") {
+            ConditionLookup::Registered { handle, .. } => Ok(handle),
+            ConditionLookup::UnregisteredName => {
+                Err(String::from("palette_open condition was not registered"))
+            },
+        }
+    }
+
+    #[test]
+    fn context_expressions_apply_to_every_condition_they_hold_for() -> Result<(), String> {
+        let defaults = r#"{
+            "bindings": [
+                { "context": "!palette_open", "bindings": { "space": "camera::home" } },
+                { "context": "dimension_lock || palette_open", "bindings": { "r": "camera::reset" } }
+            ]
+        }"#;
+        let (merged_keymap, diagnostics, condition_registry) =
+            merged_with_palette_condition(defaults)?;
+        let dimension_lock =
+            condition_bindings(&merged_keymap, dimension_lock_handle(&condition_registry)?)?;
+        let palette_open =
+            condition_bindings(&merged_keymap, palette_open_handle(&condition_registry)?)?;
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(command_for_sequence(merged_keymap.global(), "space")?, None);
+        assert_eq!(command_for_sequence(merged_keymap.global(), "r")?, None);
+        assert_eq!(
+            command_for_sequence(dimension_lock, "space")?,
+            Some("camera::home")
+        );
+        assert_eq!(command_for_sequence(palette_open, "space")?, None);
+        assert_eq!(
+            command_for_sequence(dimension_lock, "r")?,
+            Some("camera::reset")
+        );
+        assert_eq!(
+            command_for_sequence(palette_open, "r")?,
+            Some("camera::reset")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn more_specific_context_expressions_win_regardless_of_block_order() -> Result<(), String> {
+        let defaults = r#"{
+            "bindings": [
+                { "context": "dimension_lock", "bindings": { "space": "camera::home" } },
+                { "context": "dimension_lock || palette_open", "bindings": {
+                    "space": "camera::reset",
+                    "enter": "camera::reset"
+                } },
+                { "context": "palette_open", "bindings": { "enter": "camera::home" } }
+            ]
+        }"#;
+        let (merged_keymap, _, condition_registry) = merged_with_palette_condition(defaults)?;
+        let dimension_lock =
+            condition_bindings(&merged_keymap, dimension_lock_handle(&condition_registry)?)?;
+        let palette_open =
+            condition_bindings(&merged_keymap, palette_open_handle(&condition_registry)?)?;
+
+        assert_eq!(
+            command_for_sequence(dimension_lock, "space")?,
+            Some("camera::home")
+        );
+        assert_eq!(
+            command_for_sequence(palette_open, "space")?,
+            Some("camera::reset")
+        );
+        assert_eq!(
+            command_for_sequence(palette_open, "enter")?,
+            Some("camera::home")
+        );
+        assert_eq!(
+            command_for_sequence(dimension_lock, "enter")?,
+            Some("camera::reset")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn context_expressions_that_never_hold_are_advisories() -> Result<(), String> {
+        let defaults = r#"{
+            "bindings": [{
+                "context": "dimension_lock && palette_open",
+                "bindings": { "space": "camera::home" }
+            }]
+        }"#;
+        let (merged_keymap, diagnostics, _) = merged_with_palette_condition(defaults)?;
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::Context);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Advisory);
+        assert!(diagnostics[0].message.contains("never holds"));
+        assert_eq!(merged_keymap.bindings().count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn unregistered_names_inside_context_expressions_are_located() -> Result<(), String> {
+        let defaults = r#"{
+            "bindings": [{
+                "context": "dimension_lock && !unknown_context",
+                "bindings": { "space": "camera::home" }
+            }]
+        }"#;
+        let (_, diagnostics, _, _) = merged_keymap(defaults, None, &[])?;
+        let name_start = defaults
+            .find("unknown_context")
+            .ok_or_else(|| String::from("test keymap is missing the unknown name"))?;
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::Context);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Failure);
+        assert_eq!(
+            diagnostics[0].byte_range,
+            name_start..name_start + "unknown_context".len()
+        );
+        assert_eq!(diagnostics[0].context, "dimension_lock && !unknown_context");
+        Ok(())
+    }
+
     /// [`BindingScope::Global`] sorting ahead of [`BindingScope::Condition`] is what makes
     /// [`KeymapBindings`] report the global keystroke; reversing the two variants' declaration
     /// order reverses the derived [`Ord`] and fails this assertion.
@@ -2046,4 +3123,245 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn explanations_report_what_each_authored_edit_contributes() -> Result<(), String> {
+        let defaults = r#"{ "bindings": [
+  { "bindings": { "ctrl-h": "camera::home" } },
+  { "context": "dimension_lock", "bindings": { "ctrl-h": "camera::reset" } },
+] }"#;
+        let user = r#"{ "bindings": [
+  { "bindings": { "ctrl-h": "camera::reset" } },
+  { "context": "dimension_lock", "bindings": { "ctrl-h": null } },
+  { "bindings": { "ctrl-h": "camera::missing" } },
+] }"#;
+        let command_registry = command_registry()?;
+        let condition_registry = condition_registry()?;
+        let dimension_lock = dimension_lock_handle(&condition_registry)?;
+        let keystroke_sequence = KeystrokeSequence::from_str("ctrl-h")
+            .map_err(|error| format!("invalid test keystroke: {error}"))?;
+        let binding_index = MergedKeymap::index_sources(
+            &defaults_keymap_file(),
+            defaults,
+            &UserKeymap::Layered {
+                origin:   user_keymap_file(),
+                contents: user.to_owned(),
+            },
+            &command_registry,
+            &condition_registry,
+            &[],
+        )
+        .ok_or("the explained sources parse")?;
+        let explain = |binding_scope| {
+            binding_index
+                .explain(&keystroke_sequence, binding_scope)
+                .into_iter()
+                .map(|candidate| (candidate.layer, candidate.line, candidate.outcome))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            explain(BindingScope::Global),
+            [
+                (BindingLayer::ShippedDefault, 2, BindingOutcome::Shadowed),
+                (BindingLayer::ShippedDefault, 3, BindingOutcome::Inactive),
+                (BindingLayer::User, 2, BindingOutcome::Fires),
+                (BindingLayer::User, 3, BindingOutcome::Inactive),
+                (BindingLayer::User, 4, BindingOutcome::Rejected),
+            ]
+        );
+        assert_eq!(
+            explain(BindingScope::Condition(dimension_lock)),
+            [
+                (BindingLayer::ShippedDefault, 2, BindingOutcome::Tombstoned),
+                (BindingLayer::ShippedDefault, 3, BindingOutcome::Tombstoned),
+                (BindingLayer::User, 2, BindingOutcome::Tombstoned),
+                (BindingLayer::User, 3, BindingOutcome::Unbinds),
+                (BindingLayer::User, 4, BindingOutcome::Rejected),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn conflicts_report_duplicates_prefixes_shadowed_defaults_and_unreachable_bindings()
+    -> Result<(), String> {
+        let defaults = r#"{ "bindings": [
+  { "bindings": {
+    "g": "camera::home",
+    "g r": "camera::reset",
+    "ctrl-h": "camera::home",
+  } },
+  { "context": "dimension_lock", "bindings": { "ctrl-h": "camera::reset" } },
+] }"#;
+        let user = r#"{ "bindings": [
+  { "bindings": { "z": "camera::home" } },
+  { "bindings": { "z": "camera::reset" } },
+  { "bindings": { "g r": "camera::home" } },
+] }"#;
+        let command_registry = command_registry()?;
+        let condition_registry = condition_registry()?;
+
+        let conflicts = MergedKeymap::conflicts_from_sources(
+            &defaults_keymap_file(),
+            defaults,
+            &UserKeymap::Layered {
+                origin:   user_keymap_file(),
+                contents: user.to_owned(),
+            },
+            &command_registry,
+            &condition_registry,
+            &[],
+        );
+
+        assert!(
+            conflicts
+                .iter()
+                .all(|conflict| conflict.severity == DiagnosticSeverity::Advisory),
+            "conflicts never stop a keymap from loading: {conflicts:?}"
+        );
+        assert_eq!(
+            conflicts
+                .iter()
+                .map(|conflict| (conflict.origin.clone(), conflict.line, conflict.kind))
+                .collect::<Vec<_>>(),
+            [
+                (defaults_keymap_file(), 3, DiagnosticKind::PrefixConflict),
+                (
+                    defaults_keymap_file(),
+                    5,
+                    DiagnosticKind::UnreachableBinding
+                ),
+                (user_keymap_file(), 2, DiagnosticKind::DuplicateBinding),
+                (user_keymap_file(), 4, DiagnosticKind::ShadowedDefault),
+            ]
+        );
+        Ok(())
+    }
+
+    /// The conflicts `user` layered over `defaults` reports, as origin, line, and kind.
+    fn conflict_rows(
+        defaults: &str,
+        user: Option<&str>,
+        condition_registry: &ConditionRegistry,
+    ) -> Result<Vec<(DiagnosticOrigin, usize, DiagnosticKind)>, String> {
+        let user_keymap = user.map_or(UserKeymap::DefaultsOnly, |user| UserKeymap::Layered {
+            origin:   user_keymap_file(),
+            contents: user.to_owned(),
+        });
+
+        Ok(MergedKeymap::conflicts_from_sources(
+            &defaults_keymap_file(),
+            defaults,
+            &user_keymap,
+            &command_registry()?,
+            condition_registry,
+            &[],
+        )
+        .into_iter()
+        .map(|conflict| (conflict.origin, conflict.line, conflict.kind))
+        .collect())
+    }
+
+    fn dimension_lock_and_palette_conditions() -> Result<ConditionRegistry, String> {
+        let mut condition_registry = condition_registry()?;
+        condition_registry
+            .register::<TestPaletteContext>()
+            .map_err(|diagnostics| format!("condition registry errors: {diagnostics:?}"))?;
+
+        Ok(condition_registry)
+    }
+
+    #[test]
+    fn equally_specific_blocks_binding_one_sequence_report_a_duplicate() -> Result<(), String> {
+        let defaults = r#"{ "bindings": [] }"#;
+        let user = r#"{ "bindings": [
+  { "bindings": { "z": "camera::home" } },
+  { "bindings": { "z": "camera::reset" } },
+] }"#;
+
+        assert_eq!(
+            conflict_rows(defaults, Some(user), &ConditionRegistry::default())?,
+            [(user_keymap_file(), 2, DiagnosticKind::DuplicateBinding)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn one_sequence_bound_in_disjoint_contexts_is_not_a_duplicate() -> Result<(), String> {
+        let defaults = r#"{ "bindings": [
+  { "context": "dimension_lock", "bindings": { "z": "camera::home" } },
+  { "context": "palette_open", "bindings": { "z": "camera::reset" } },
+] }"#;
+
+        assert!(
+            conflict_rows(defaults, None, &dimension_lock_and_palette_conditions()?)?.is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_routed_prefix_of_a_longer_sequence_reports_a_prefix_conflict() -> Result<(), String> {
+        let defaults = r#"{ "bindings": [
+  { "bindings": { "g": "camera::home", "g r": "camera::reset" } },
+] }"#;
+
+        assert_eq!(
+            conflict_rows(defaults, None, &ConditionRegistry::default())?,
+            [(defaults_keymap_file(), 2, DiagnosticKind::PrefixConflict)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_prefix_whose_longer_sequence_routes_in_another_context_is_not_a_conflict()
+    -> Result<(), String> {
+        let defaults = r#"{ "bindings": [
+  { "context": "dimension_lock", "bindings": { "g": "camera::home" } },
+  { "context": "palette_open", "bindings": { "g r": "camera::reset" } },
+] }"#;
+
+        assert!(
+            conflict_rows(defaults, None, &dimension_lock_and_palette_conditions()?)?.is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_user_binding_to_another_command_reports_a_shadowed_default() -> Result<(), String> {
+        let defaults = r#"{ "bindings": [ { "bindings": { "ctrl-h": "camera::home" } } ] }"#;
+        let rebinding = r#"{ "bindings": [ { "bindings": { "ctrl-h": "camera::reset" } } ] }"#;
+        let restating = r#"{ "bindings": [ { "bindings": { "ctrl-h": "camera::home" } } ] }"#;
+
+        assert_eq!(
+            conflict_rows(defaults, Some(rebinding), &ConditionRegistry::default())?,
+            [(user_keymap_file(), 1, DiagnosticKind::ShadowedDefault)]
+        );
+        assert!(
+            conflict_rows(defaults, Some(restating), &ConditionRegistry::default())?.is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_global_binding_every_condition_rebinds_is_unreachable() -> Result<(), String> {
+        let defaults = r#"{ "bindings": [
+  { "bindings": { "ctrl-h": "camera::home" } },
+  { "context": "dimension_lock", "bindings": { "ctrl-h": "camera::reset" } },
+] }"#;
+
+        assert_eq!(
+            conflict_rows(defaults, None, &condition_registry()?)?,
+            [(
+                defaults_keymap_file(),
+                2,
+                DiagnosticKind::UnreachableBinding
+            )]
+        );
+        assert!(
+            conflict_rows(defaults, None, &dimension_lock_and_palette_conditions()?)?.is_empty(),
+            "the global binding still fires while the palette is open"
+        );
+        Ok(())
+    }
 }
@@ -5,9 +5,15 @@ use bevy_enhanced_input::prelude::CustomInputs;
 
 mod bindings;
 mod compiled;
+mod conflicts;
 mod constants;
+mod context_expr;
 mod document;
+mod edit;
+mod explain;
+mod macros;
 mod merged;
+mod profiles;
 mod reload;
 mod routing;
 mod runtime;
@@ -20,8 +26,47 @@ pub(super) use compiled::CommandHandle;
 pub(crate) use compiled::CompiledKeymap;
 pub(crate) use compiled::Generation;
 pub(super) use compiled::ModifierFamilyHeldBinding;
+pub(crate) use conflicts::keymap_conflicts;
 pub(crate) use document::KeymapDocument;
+pub use edit::KeymapEdit;
+pub use edit::KeymapEditError;
+pub use edit::KeymapHistory;
+pub use edit::KeymapRevision;
+pub use edit::KeymapTransaction;
+pub(crate) use edit::apply_transaction;
+pub(crate) use edit::preview_transaction;
+pub(crate) use edit::redo_transaction;
+pub(crate) use edit::undo_transaction;
+pub use explain::BindingCandidate;
+pub use explain::BindingExplanation;
+pub use explain::BindingLayer;
+pub use explain::BindingOutcome;
+pub(crate) use explain::explain_keystroke_sequence;
+pub(crate) use explain::explain_keystroke_sequence_in_context;
+pub use macros::KeymapMacroError;
+pub use macros::KeymapMacroName;
+pub use macros::KeymapMacroNameError;
+pub use macros::KeymapMacroReplay;
+pub use macros::KeymapMacroStep;
+pub use macros::KeymapMacroStepOutcome;
+pub use macros::KeymapMacroStepReport;
+pub use macros::KeymapMacros;
+pub use macros::PlayKeymapMacro;
+pub use macros::PlayKeymapMacroArguments;
+pub(crate) use macros::cancel_recording;
+pub(crate) use macros::finish_recording;
+pub(crate) use macros::play_macro;
+pub(crate) use macros::play_on_request;
+pub(crate) use macros::start_recording;
 pub(crate) use merged::MergedKeymap;
+pub use profiles::KeymapProfileError;
+pub use profiles::KeymapProfiles;
+pub use profiles::SwitchKeymapProfile;
+pub(crate) use profiles::export_profile;
+pub(crate) use profiles::import_profile;
+pub(crate) use profiles::load_startup_profiles;
+pub(crate) use profiles::switch_on_request;
+pub(crate) use profiles::switch_profile;
 pub(crate) use reload::PendingReload;
 pub(crate) use reload::ReloadConfiguration;
 pub(crate) use reload::ReloadRequest;
@@ -32,8 +77,14 @@ pub use routing::KeyboardClaim;
 pub use routing::KeyboardOwner;
 pub use routing::KeyboardRelease;
 pub use routing::KeystrokeRouting;
+pub(super) use runtime::GamepadButtons;
 pub(super) use runtime::KeymapRuntime;
+#[cfg(any(test, feature = "input_script"))]
+pub(crate) use runtime::RoutingTrace;
+#[cfg(any(test, feature = "input_script"))]
+pub(crate) use runtime::RoutingTraceEvent;
 pub(super) use runtime::cancel_pending_sequences;
+pub(super) use runtime::mirror_gamepad_buttons;
 pub(super) use runtime::reset_physical_input;
 pub(super) use runtime::route_input;
 pub(crate) use schema::reference_default_bytes;
@@ -0,0 +1,496 @@
+//! Named keymap profiles: switching, import, and export.
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str;
+
+use bevy::ecs::world::World;
+use bevy::prelude::Commands;
+use bevy::prelude::Event;
+use bevy::prelude::On;
+use bevy::prelude::Resource;
+
+use super::edit::merge_failures;
+use super::reload::commit_user_keymap;
+use crate::Diagnostic;
+use crate::DiagnosticKind;
+use crate::DiagnosticOrigin;
+use crate::DiagnosticSeverity;
+use crate::KeymapLoadFailures;
+use crate::KeymapPathAvailability;
+use crate::KeymapPathFailure;
+use crate::KeymapPaths;
+use crate::disk::KeymapProfileName;
+use crate::disk::USER_KEYMAP_STUB;
+use crate::disk::migrate_profiles;
+use crate::disk::profile_names;
+use crate::disk::read_config_file;
+use crate::disk::read_profile;
+use crate::disk::replace_config_file;
+use crate::disk::write_active_profile;
+use crate::disk::write_profile;
+use crate::keymap_plugin::startup_diagnostic;
+
+/// The stored keymap profiles and the one layered over the shipped defaults.
+///
+/// The active profile is edited in the user keymap file, which the disk watcher reloads. Its
+/// stored copy is refreshed from that file whenever another profile is switched in. Trigger
+/// [`SwitchKeymapProfile`] or call [`switch_keymap_profile`](crate::switch_keymap_profile) to
+/// change profiles.
+#[derive(Debug, Default, Resource)]
+pub struct KeymapProfiles {
+    active:       Option<KeymapProfileName>,
+    names:        Vec<KeymapProfileName>,
+    last_failure: Option<KeymapProfileError>,
+}
+
+impl KeymapProfiles {
+    /// Returns the active profile, or `None` when the application has no profiles directory.
+    #[must_use]
+    pub const fn active(&self) -> Option<&KeymapProfileName> { self.active.as_ref() }
+
+    /// Returns every stored profile, sorted by name.
+    #[must_use]
+    pub fn names(&self) -> &[KeymapProfileName] { &self.names }
+
+    /// Returns why the latest [`SwitchKeymapProfile`] left the active profile in place.
+    ///
+    /// A switch that succeeds clears it.
+    #[must_use]
+    pub const fn last_failure(&self) -> Option<&KeymapProfileError> { self.last_failure.as_ref() }
+
+    fn refresh(&mut self, paths: &KeymapPaths, active: KeymapProfileName) -> io::Result<()> {
+        self.names = profile_names(paths)?;
+        self.active = Some(active);
+        Ok(())
+    }
+}
+
+/// Switches the active keymap profile when triggered.
+///
+/// A refused switch is recorded in [`KeymapProfiles::last_failure`].
+#[derive(Clone, Debug, Event)]
+pub struct SwitchKeymapProfile(pub KeymapProfileName);
+
+/// Why a profile was not switched to, imported, or exported.
+///
+/// Every error leaves the active profile, its file, and the live keymap as they were.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeymapProfileError {
+    /// No configuration directory resolved, so there are no profiles to read or write.
+    Unavailable(KeymapPathFailure),
+    /// No stored profile has this name.
+    NotFound(KeymapProfileName),
+    /// A stored profile already has this name, so the import would overwrite it.
+    AlreadyExists(KeymapProfileName),
+    /// The profile is not UTF-8 text.
+    Malformed(String),
+    /// The profile reports these failures, so it cannot become active.
+    Rejected(Vec<Diagnostic>),
+    /// Reading or writing a keymap file failed.
+    Disk(String),
+}
+
+impl Display for KeymapProfileError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable(keymap_path_failure) => {
+                formatter.write_str(keymap_path_failure.reason())
+            },
+            Self::NotFound(profile) => write!(formatter, "There is no keymap profile `{profile}`."),
+            Self::AlreadyExists(profile) => {
+                write!(
+                    formatter,
+                    "A keymap profile named `{profile}` already exists."
+                )
+            },
+            Self::Malformed(message) => {
+                write!(formatter, "The keymap profile cannot be read: {message}")
+            },
+            Self::Rejected(diagnostics) => {
+                formatter.write_str("The keymap profile was rejected:")?;
+                for diagnostic in diagnostics {
+                    write!(formatter, " {}", diagnostic.message)?;
+                }
+                Ok(())
+            },
+            Self::Disk(message) => {
+                write!(formatter, "Could not access the keymap profiles: {message}")
+            },
+        }
+    }
+}
+
+impl Error for KeymapProfileError {}
+
+/// Loads the profiles at startup, returning the failure diagnostic when the disk refuses.
+pub(crate) fn load_startup_profiles(world: &mut World, paths: &KeymapPaths) -> Option<Diagnostic> {
+    load_profiles(world, paths).err().map(|error| {
+        startup_diagnostic(
+            DiagnosticOrigin::KeymapDirectory(paths.profiles_directory().to_path_buf()),
+            DiagnosticKind::Disk,
+            &format!("Could not load the keymap profiles: {error}"),
+            DiagnosticSeverity::Failure,
+        )
+    })
+}
+
+/// Migrates a single-file keymap into profiles and publishes the stored profiles.
+fn load_profiles(world: &mut World, paths: &KeymapPaths) -> io::Result<()> {
+    let active = migrate_profiles(paths)?;
+    world
+        .resource_mut::<KeymapProfiles>()
+        .refresh(paths, active)
+}
+
+/// Makes `profile` the active profile once its bindings validate.
+///
+/// The outgoing profile's stored copy is refreshed from the user keymap before the incoming
+/// profile replaces it, so edits made since the last switch travel with the profile they were
+/// made in. The new contents commit through the reload transaction immediately; when that commit
+/// is refused, the user keymap and active profile marker are restored and the commit's failures
+/// are returned.
+pub(crate) fn switch_profile(
+    world: &mut World,
+    profile: &KeymapProfileName,
+) -> Result<(), KeymapProfileError> {
+    let paths = keymap_paths(world)?;
+    let outgoing = migrate_profiles(&paths).map_err(disk_error)?;
+    if outgoing == *profile {
+        return Ok(());
+    }
+
+    let contents = read_profile(&paths, profile)
+        .map_err(disk_error)?
+        .ok_or_else(|| KeymapProfileError::NotFound(profile.clone()))?;
+    validate(
+        world,
+        &DiagnosticOrigin::KeymapFile(paths.profile(profile)),
+        &contents,
+    )?;
+
+    let current = read_config_file(paths.user_keymap()).map_err(disk_error)?;
+    if let Some(current) = &current {
+        write_profile(&paths, &outgoing, current).map_err(disk_error)?;
+    }
+    replace_config_file(paths.user_keymap(), &contents).map_err(disk_error)?;
+    write_active_profile(&paths, profile).map_err(disk_error)?;
+    if !commit_user_keymap(world, paths.user_keymap().to_path_buf(), contents) {
+        let restored = current.unwrap_or_else(|| USER_KEYMAP_STUB.to_vec());
+        replace_config_file(paths.user_keymap(), &restored).map_err(disk_error)?;
+        write_active_profile(&paths, &outgoing).map_err(disk_error)?;
+        return Err(KeymapProfileError::Rejected(commit_failures(world)));
+    }
+
+    world
+        .resource_mut::<KeymapProfiles>()
+        .refresh(&paths, profile.clone())
+        .map_err(disk_error)
+}
+
+/// Stores the JSONC keymap at `source` as a new profile once its bindings validate.
+pub(crate) fn import_profile(
+    world: &mut World,
+    source: &Path,
+    profile: &KeymapProfileName,
+) -> Result<(), KeymapProfileError> {
+    let paths = keymap_paths(world)?;
+    let active = migrate_profiles(&paths).map_err(disk_error)?;
+    if read_profile(&paths, profile).map_err(disk_error)?.is_some() {
+        return Err(KeymapProfileError::AlreadyExists(profile.clone()));
+    }
+
+    let contents = fs::read(source).map_err(disk_error)?;
+    validate(
+        world,
+        &DiagnosticOrigin::KeymapFile(source.to_path_buf()),
+        &contents,
+    )?;
+    write_profile(&paths, profile, &contents).map_err(disk_error)?;
+
+    world
+        .resource_mut::<KeymapProfiles>()
+        .refresh(&paths, active)
+        .map_err(disk_error)
+}
+
+/// Writes `profile` to `destination` as one portable JSONC file.
+///
+/// The active profile is exported from the user keymap, so its latest edits are included.
+pub(crate) fn export_profile(
+    world: &World,
+    profile: &KeymapProfileName,
+    destination: &Path,
+) -> Result<(), KeymapProfileError> {
+    let paths = keymap_paths(world)?;
+    let active = migrate_profiles(&paths).map_err(disk_error)?;
+    let contents = if active == *profile {
+        read_config_file(paths.user_keymap())
+            .map_err(disk_error)?
+            .unwrap_or_else(|| USER_KEYMAP_STUB.to_vec())
+    } else {
+        read_profile(&paths, profile)
+            .map_err(disk_error)?
+            .ok_or_else(|| KeymapProfileError::NotFound(profile.clone()))?
+    };
+
+    replace_config_file(destination, &contents).map_err(disk_error)
+}
+
+/// Switches profiles for a triggered [`SwitchKeymapProfile`].
+pub(crate) fn switch_on_request(switch: On<SwitchKeymapProfile>, mut commands: Commands) {
+    let profile = switch.event().0.clone();
+    commands.queue(move |world: &mut World| {
+        let result = switch_profile(world, &profile);
+        if let Err(error) = &result {
+            bevy::log::warn!("{error}");
+        }
+        world.resource_mut::<KeymapProfiles>().last_failure = result.err();
+    });
+}
+
+/// Refuses a profile that reports any failure, so a broken profile never becomes active.
+fn validate(
+    world: &World,
+    origin: &DiagnosticOrigin,
+    contents: &[u8],
+) -> Result<(), KeymapProfileError> {
+    let source = str::from_utf8(contents).map_err(|error| {
+        KeymapProfileError::Malformed(format!("the profile is not valid UTF-8: {error}"))
+    })?;
+    let failures = merge_failures(world, origin, source);
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(KeymapProfileError::Rejected(failures))
+    }
+}
+
+/// The failures a refused commit recorded.
+fn commit_failures(world: &World) -> Vec<Diagnostic> {
+    world
+        .resource::<KeymapLoadFailures>()
+        .diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Failure)
+        .cloned()
+        .collect()
+}
+
+fn keymap_paths(world: &World) -> Result<KeymapPaths, KeymapProfileError> {
+    world
+        .get_resource::<KeymapPathAvailability>()
+        .map_or(
+            Err(KeymapPathFailure::AppNameNotConfigured),
+            |availability| availability.resolved().cloned(),
+        )
+        .map_err(KeymapProfileError::Unavailable)
+}
+
+fn disk_error(error: io::Error) -> KeymapProfileError {
+    KeymapProfileError::Disk(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use bevy::ecs::world::World;
+    use bevy::prelude::Event;
+    use bevy::prelude::Reflect;
+    use bevy::prelude::ReflectEvent;
+    use bevy::reflect::TypeRegistry;
+    use bevy_enhanced_input::prelude::CustomInputs;
+
+    use super::KeymapProfileError;
+    use super::KeymapProfiles;
+    use super::export_profile;
+    use super::import_profile;
+    use super::load_profiles;
+    use super::switch_profile;
+    use crate::Capability;
+    use crate::CommandRegistry;
+    use crate::DiagnosticOrigin;
+    use crate::HoldPhase;
+    use crate::KeymapCommand;
+    use crate::KeymapLoadFailures;
+    use crate::KeymapPathAvailability;
+    use crate::KeymapPaths;
+    use crate::ReflectKeymapCommand;
+    use crate::condition::ConditionRegistry;
+    use crate::disk::ENVIRONMENT_LOCK;
+    use crate::disk::KeymapProfileName;
+    use crate::disk::TestDirectory;
+    use crate::disk::XdgConfigHome;
+    use crate::keymap::CompiledKeymap;
+    use crate::keymap::KeymapHistory;
+    use crate::keymap::ReloadConfiguration;
+
+    const TEST_APP_NAME: &str = "hana-rubric-keymap-profiles-test";
+    const DEFAULTS: &str = r#"{ "bindings": [ { "bindings": { "ctrl-1": "profile::first" } } ] }"#;
+    const USER_KEYMAP: &str = r#"// my keymap
+{
+  "bindings": [ { "bindings": { "ctrl-9": "profile::first" } } ],
+}
+"#;
+    const LAPTOP: &str = r#"// laptop keys
+{
+  "bindings": [ { "bindings": { "f9": "profile::first" } } ],
+}
+"#;
+    const BROKEN: &str = r#"{ "bindings": [ { "bindings": { "f8": "profile::missing" } } ] }"#;
+
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand)]
+    struct ProfileFirst;
+
+    impl KeymapCommand for ProfileFirst {
+        const ID: &'static str = "profile::first";
+        const TITLE: &'static str = "Profile First";
+        const DESCRIPTION: &'static str = "Command used by keymap profile tests.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    /// A world holding the resources profile switches read, with `USER_KEYMAP` migrated into the
+    /// `default` profile.
+    fn profile_world() -> Result<(World, KeymapPaths), String> {
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<ProfileFirst>();
+        let command_registry = CommandRegistry::build(&type_registry, &mut CustomInputs::default())
+            .map_err(|diagnostics| format!("profile registry diagnostics: {diagnostics:?}"))?;
+        let keymap_path_availability = KeymapPathAvailability::for_app_name(TEST_APP_NAME);
+        let paths = keymap_path_availability
+            .resolved()
+            .map_err(|keymap_path_failure| format!("{keymap_path_failure:?}"))?
+            .clone();
+        fs::create_dir_all(paths.config_directory()).map_err(|error| error.to_string())?;
+        fs::write(paths.user_keymap(), USER_KEYMAP).map_err(|error| error.to_string())?;
+
+        let mut world = World::new();
+        world.insert_resource(command_registry);
+        world.insert_resource(ConditionRegistry::default());
+        world.insert_resource(keymap_path_availability);
+        world.init_resource::<KeymapHistory>();
+        world.init_resource::<KeymapLoadFailures>();
+        world.init_resource::<KeymapProfiles>();
+        world.insert_resource(ReloadConfiguration::new(
+            DiagnosticOrigin::EmbeddedDefaults,
+            DEFAULTS.to_owned(),
+            Vec::new(),
+            false,
+        ));
+        load_profiles(&mut world, &paths).map_err(|error| error.to_string())?;
+
+        Ok((world, paths))
+    }
+
+    fn profile(name: &str) -> Result<KeymapProfileName, String> {
+        KeymapProfileName::try_from(name).map_err(|error| error.to_string())
+    }
+
+    fn read(path: PathBuf) -> Result<String, String> {
+        fs::read_to_string(path).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn switching_commits_the_profile_and_keeps_the_outgoing_edits() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-profile-switch").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, paths) = profile_world()?;
+        let default_profile = KeymapProfileName::default_profile();
+        let laptop = profile("laptop")?;
+        fs::write(paths.profile(&laptop), LAPTOP).map_err(|error| error.to_string())?;
+        let edited = USER_KEYMAP.replace("ctrl-9", "ctrl-8");
+        fs::write(paths.user_keymap(), &edited).map_err(|error| error.to_string())?;
+
+        switch_profile(&mut world, &laptop).map_err(|error| error.to_string())?;
+
+        assert_eq!(read(paths.user_keymap().to_path_buf())?, LAPTOP);
+        assert_eq!(read(paths.profile(&default_profile))?, edited);
+        assert!(world.contains_resource::<CompiledKeymap>());
+        let keymap_profiles = world.resource::<KeymapProfiles>();
+        assert_eq!(keymap_profiles.active(), Some(&laptop));
+        assert_eq!(keymap_profiles.names(), [default_profile.clone(), laptop]);
+
+        switch_profile(&mut world, &default_profile).map_err(|error| error.to_string())?;
+        assert_eq!(read(paths.user_keymap().to_path_buf())?, edited);
+        Ok(())
+    }
+
+    #[test]
+    fn a_broken_profile_never_replaces_the_active_one() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-profile-broken").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, paths) = profile_world()?;
+        let broken = profile("broken")?;
+        fs::write(paths.profile(&broken), BROKEN).map_err(|error| error.to_string())?;
+
+        let result = switch_profile(&mut world, &broken);
+
+        assert!(
+            matches!(result, Err(KeymapProfileError::Rejected(failures)) if !failures.is_empty())
+        );
+        assert_eq!(read(paths.user_keymap().to_path_buf())?, USER_KEYMAP);
+        assert!(!world.contains_resource::<CompiledKeymap>());
+        assert_eq!(
+            world.resource::<KeymapProfiles>().active(),
+            Some(&KeymapProfileName::default_profile())
+        );
+        assert_eq!(
+            switch_profile(&mut world, &profile("absent")?),
+            Err(KeymapProfileError::NotFound(profile("absent")?))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn an_exported_profile_imports_with_its_comments() -> Result<(), String> {
+        let _environment_lock = ENVIRONMENT_LOCK
+            .lock()
+            .map_err(|_| String::from("environment lock is poisoned"))?;
+        let temporary_directory =
+            TestDirectory::new("keymap-profile-export").map_err(|error| error.to_string())?;
+        let _xdg_config_home = XdgConfigHome::set(temporary_directory.path());
+        let (mut world, paths) = profile_world()?;
+        let exported = temporary_directory.path().join("shared.jsonc");
+        let shared = profile("shared")?;
+
+        export_profile(&world, &KeymapProfileName::default_profile(), &exported)
+            .map_err(|error| error.to_string())?;
+        import_profile(&mut world, &exported, &shared).map_err(|error| error.to_string())?;
+
+        assert_eq!(read(paths.profile(&shared))?, USER_KEYMAP);
+        assert!(world.resource::<KeymapProfiles>().names().contains(&shared));
+        assert_eq!(
+            import_profile(&mut world, &exported, &shared),
+            Err(KeymapProfileError::AlreadyExists(shared))
+        );
+
+        fs::write(&exported, BROKEN).map_err(|error| error.to_string())?;
+        let rejected = profile("rejected")?;
+        assert!(matches!(
+            import_profile(&mut world, &exported, &rejected),
+            Err(KeymapProfileError::Rejected(_))
+        ));
+        assert!(!paths.profile(&rejected).exists());
+        Ok(())
+    }
+}
@@ -12,6 +12,10 @@ use super::CompiledKeymap;
 use super::Generation;
 use super::KeymapBindings;
 use super::MergedKeymap;
+use super::edit::KeymapHistory;
+use super::edit::KeymapRevision;
+use super::explain::ExplanationCache;
+use super::merged::BindingIndex;
 use super::merged::UserKeymap;
 use crate::CommandRegistry;
 use crate::Diagnostic;
@@ -49,10 +53,69 @@ impl ReloadConfiguration {
         }
     }
 
-    #[cfg(test)]
     pub(crate) fn published_defaults(&self) -> &str { &self.published_defaults }
+
+    /// Indexes the edits `user_keymap` and the published defaults author, layering them the same
+    /// way [`Self::merge`] does.
+    pub(super) fn index(
+        &self,
+        user_keymap: &UserKeymap,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+    ) -> Option<BindingIndex> {
+        MergedKeymap::index_sources(
+            &self.defaults_diagnostic_origin,
+            &self.published_defaults,
+            user_keymap,
+            command_registry,
+            condition_registry,
+            &self.protected_keystrokes,
+        )
+    }
+
+    /// Reports the conflicts in `user_keymap` layered over the published defaults, the way
+    /// [`MergedKeymap::conflicts_from_sources`] analyzes them.
+    pub(super) fn conflicts(
+        &self,
+        user_keymap: &UserKeymap,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+    ) -> Vec<Diagnostic> {
+        MergedKeymap::conflicts_from_sources(
+            &self.defaults_diagnostic_origin,
+            &self.published_defaults,
+            user_keymap,
+            command_registry,
+            condition_registry,
+            &self.protected_keystrokes,
+        )
+    }
+
+    /// Layers `user_keymap` over the published defaults with this configuration's protections.
+    pub(super) fn merge(
+        &self,
+        user_keymap: &UserKeymap,
+        command_registry: &CommandRegistry,
+        condition_registry: &ConditionRegistry,
+    ) -> Result<(MergedKeymap, Vec<Diagnostic>), Vec<Diagnostic>> {
+        MergedKeymap::from_sources(
+            &self.defaults_diagnostic_origin,
+            &self.published_defaults,
+            user_keymap,
+            command_registry,
+            condition_registry,
+            &self.protected_keystrokes,
+        )
+    }
 }
 
+/// The user keymap the live generation was compiled from.
+///
+/// Explanations read this rather than the file on disk, which may hold a newer save that did not
+/// commit.
+#[derive(Resource)]
+pub(crate) struct CommittedUserKeymap(pub(super) UserKeymap);
+
 /// One source update waiting for the next reload transaction.
 #[derive(Default, Resource)]
 pub(crate) struct PendingReload {
@@ -119,7 +182,34 @@ pub(crate) fn commit_reload(world: &mut World) {
     let _ = commit_request(world, request);
 }
 
+/// Commits `contents` as the user keymap without waiting for the disk worker to read it.
+///
+/// A profile switch writes the user keymap and commits it in the same call, so its bindings are
+/// live before the watcher reports the write. Returns whether the keymap was replaced; a refused
+/// commit records its failures in [`KeymapLoadFailures`].
+pub(crate) fn commit_user_keymap(
+    world: &mut World,
+    source_path: PathBuf,
+    contents: Vec<u8>,
+) -> bool {
+    matches!(
+        commit_request(
+            world,
+            ReloadRequest::UserSnapshot {
+                source_path,
+                contents: UserKeymapContents::Read(Arc::from(contents)),
+                diagnostics: Vec::new(),
+            },
+        ),
+        CommitOutcome::Committed
+    )
+}
+
 fn commit_request(world: &mut World, request: ReloadRequest) -> CommitOutcome {
+    if let ReloadRequest::UserSnapshot { contents, .. } = &request {
+        observe_user_keymap(world, contents);
+    }
+
     let (user_keymap, disk_diagnostics, validates_defaults) = match request {
         ReloadRequest::Defaults => (UserKeymap::DefaultsOnly, Vec::new(), true),
         ReloadRequest::DiskDiagnostics(diagnostics) => {
@@ -158,14 +248,7 @@ fn commit_request(world: &mut World, request: ReloadRequest) -> CommitOutcome {
     let reload_configuration = world.resource::<ReloadConfiguration>().clone();
     let merged_keymap = world.resource_scope::<CommandRegistry, _>(|world, command_registry| {
         let condition_registry = world.resource::<ConditionRegistry>();
-        MergedKeymap::from_sources(
-            &reload_configuration.defaults_diagnostic_origin,
-            &reload_configuration.published_defaults,
-            &user_keymap,
-            &command_registry,
-            condition_registry,
-            &reload_configuration.protected_keystrokes,
-        )
+        reload_configuration.merge(&user_keymap, &command_registry, condition_registry)
     });
 
     let (merged_keymap, diagnostics) = match merged_keymap {
@@ -192,11 +275,25 @@ fn commit_request(world: &mut World, request: ReloadRequest) -> CommitOutcome {
     });
     world.insert_resource(compiled_keymap);
     world.insert_resource(KeymapBindings::from_bindings(merged_keymap.bindings()));
+    world.insert_resource(CommittedUserKeymap(user_keymap));
+    world.insert_resource(ExplanationCache::default());
     record_load_diagnostics(world, diagnostics);
 
     CommitOutcome::Committed
 }
 
+/// Reports the user keymap revision the disk worker delivered to the edit history, which forgets
+/// its transactions when the revision is an external edit.
+fn observe_user_keymap(world: &mut World, contents: &UserKeymapContents) {
+    let revision = match contents {
+        UserKeymapContents::Read(contents) => KeymapRevision::of(Some(&contents[..])),
+        UserKeymapContents::Absent => KeymapRevision::of(None),
+    };
+    if let Some(mut keymap_history) = world.get_resource_mut::<KeymapHistory>() {
+        keymap_history.observe(revision);
+    }
+}
+
 fn next_generation(world: &World) -> Generation {
     world
         .get_resource::<CompiledKeymap>()
@@ -268,6 +365,7 @@ const fn is_reload_diagnostic(diagnostic: &Diagnostic) -> bool {
             | DiagnosticKind::BareModifierRequiresHeldCommand
             | DiagnosticKind::UnremappableCommand
             | DiagnosticKind::HeldCommandInSequence
+            | DiagnosticKind::BindingArguments
     )
 }
 
@@ -0,0 +1,93 @@
+use bevy::ecs::prelude::Resource;
+use bevy::ecs::system::Query;
+use bevy::ecs::system::ResMut;
+use bevy::ecs::world::World;
+use bevy::input::ButtonInput;
+use bevy::input::gamepad::Gamepad;
+use bevy::input::gamepad::GamepadButton;
+use bevy::input::keyboard::KeyCode;
+use bevy::input::mouse::MouseButton;
+
+use crate::KeymapGamepadButton;
+use crate::KeymapMouseButton;
+use crate::Keystroke;
+use crate::Modifiers;
+
+/// One physical input that can own a held chord, an inhibition, or a processed edge.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(super) enum PhysicalButton {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl From<KeyCode> for PhysicalButton {
+    fn from(key: KeyCode) -> Self { Self::Key(key) }
+}
+
+impl PhysicalButton {
+    /// The keystroke a press of this mouse or gamepad button completes under `modifiers`.
+    ///
+    /// Keyboard keys route through `PhysicalKeyRole` instead, so they never produce one here.
+    pub(super) fn button_keystroke(self, modifiers: Modifiers) -> Option<Keystroke> {
+        match self {
+            Self::Key(_) => None,
+            Self::Mouse(mouse_button) => KeymapMouseButton::try_from(mouse_button)
+                .ok()
+                .map(|mouse_button| Keystroke::from_mouse_button(modifiers, mouse_button)),
+            Self::Gamepad(gamepad_button) => KeymapGamepadButton::try_from(gamepad_button)
+                .ok()
+                .map(|gamepad_button| Keystroke::from_gamepad_button(modifiers, gamepad_button)),
+        }
+    }
+
+    pub(super) fn is_pressed(self, world: &World) -> bool {
+        match self {
+            Self::Key(key) => world
+                .get_resource::<ButtonInput<KeyCode>>()
+                .is_some_and(|key_input| key_input.pressed(key)),
+            Self::Mouse(mouse_button) => world
+                .get_resource::<ButtonInput<MouseButton>>()
+                .is_some_and(|mouse_input| mouse_input.pressed(mouse_button)),
+            Self::Gamepad(gamepad_button) => world
+                .get_resource::<GamepadButtons>()
+                .is_some_and(|gamepad_buttons| gamepad_buttons.0.pressed(gamepad_button)),
+        }
+    }
+}
+
+/// The gamepad buttons held on any connected gamepad, merged into one input.
+///
+/// Bevy reports gamepad buttons per gamepad entity, while a keymap binds `gamepad-south` from
+/// whichever controller presses it. Mirroring them into one resource lets `route_input` read
+/// gamepads the way it reads the keyboard and mouse resources, without building a query.
+#[derive(Default, Resource)]
+pub(crate) struct GamepadButtons(ButtonInput<GamepadButton>);
+
+impl GamepadButtons {
+    pub(super) const fn input(&self) -> &ButtonInput<GamepadButton> { &self.0 }
+
+    #[cfg(test)]
+    pub(super) const fn input_mut(&mut self) -> &mut ButtonInput<GamepadButton> { &mut self.0 }
+}
+
+/// Merges every connected gamepad's named buttons into [`GamepadButtons`].
+///
+/// A button stays pressed until no gamepad holds it, so two controllers pressing the same button
+/// produce one press edge and one release edge.
+pub(crate) fn mirror_gamepad_buttons(
+    gamepads: Query<&Gamepad>,
+    mut gamepad_buttons: ResMut<GamepadButtons>,
+) {
+    gamepad_buttons.0.clear();
+    for gamepad_button in KeymapGamepadButton::all().map(KeymapGamepadButton::gamepad_button) {
+        let is_pressed = gamepads
+            .iter()
+            .any(|gamepad| gamepad.pressed(gamepad_button));
+        match (is_pressed, gamepad_buttons.0.pressed(gamepad_button)) {
+            (true, false) => gamepad_buttons.0.press(gamepad_button),
+            (false, true) => gamepad_buttons.0.release(gamepad_button),
+            (true, true) | (false, false) => {},
+        }
+    }
+}
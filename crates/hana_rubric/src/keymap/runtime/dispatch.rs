@@ -3,9 +3,12 @@ use std::time::Instant;
 use bevy::ecs::world::World;
 use bevy::input::ButtonInput;
 use bevy::input::keyboard::KeyCode;
+use bevy::input::mouse::MouseButton;
 use bevy_enhanced_input::prelude::CustomInput;
 use bevy_enhanced_input::prelude::CustomInputs;
 
+use super::button_edge::GamepadButtons;
+use super::button_edge::PhysicalButton;
 use super::held::ActiveMatcher;
 use super::held::CustomInputTransition;
 use super::held::HeldChordPhysicalOwnership;
@@ -16,19 +19,29 @@ use super::key_edge;
 use super::key_edge::OrdinaryKeyRoutingState;
 use super::key_edge::PhysicalKeyRole;
 use super::key_edge::PrimaryTriggerOwnership;
+#[cfg(any(test, feature = "input_script"))]
+use super::trace;
+#[cfg(any(test, feature = "input_script"))]
+use super::trace::RoutingTraceEvent;
 use crate::ActiveCondition;
 use crate::ActiveConditionState;
 use crate::DeferredMatch;
+use crate::KeymapCommand;
+use crate::KeymapMouseButton;
+use crate::Keystroke;
 use crate::MatchOutcome;
 use crate::Modifiers;
 use crate::SequenceMatcher;
 use crate::TimeoutOutcome;
+use crate::command::CommandDispatch;
 use crate::command::Invocation;
 use crate::keymap::ActiveKeymapScope;
 use crate::keymap::CommandHandle;
 use crate::keymap::CompiledKeymap;
+use crate::keymap::KeymapMacros;
 use crate::keymap::KeystrokeRouting;
 use crate::keymap::ModifierFamilyHeldBinding;
+use crate::keymap::PlayKeymapMacro;
 use crate::keymap::constants::SEQUENCE_TIMEOUT;
 
 pub(crate) fn cancel_pending_sequences(world: &mut World) {
@@ -47,10 +60,7 @@ pub(crate) fn reset_physical_input(world: &mut World) {
     world.init_resource::<KeymapRuntime>();
     world.init_resource::<CustomInputs>();
 
-    let pressed = world
-        .get_resource::<ButtonInput<KeyCode>>()
-        .map(|keys| keys.get_pressed().copied().collect::<Vec<_>>())
-        .unwrap_or_default();
+    let pressed = pressed_physical_buttons(world);
     release_all_physical_sources(world);
     world
         .resource_mut::<KeymapRuntime>()
@@ -124,10 +134,7 @@ fn synchronize_and_resolve_timeout(
     });
 
     if reset_required {
-        let pressed = world
-            .get_resource::<ButtonInput<KeyCode>>()
-            .map(|pressed| pressed.get_pressed().copied().collect::<Vec<_>>())
-            .unwrap_or_default();
+        let pressed = pressed_physical_buttons(world);
         release_all_physical_sources(world);
         world
             .resource_mut::<KeymapRuntime>()
@@ -140,6 +147,8 @@ fn synchronize_and_resolve_timeout(
             .map_or(TimeoutOutcome::NoPendingSequence, |sequence_matcher| {
                 sequence_matcher.resolve_timeout(now, SEQUENCE_TIMEOUT)
             });
+        #[cfg(any(test, feature = "input_script"))]
+        trace_timeout(world, &timeout_outcome);
 
         match timeout_outcome {
             TimeoutOutcome::Resolved(command_handle) => RoutedCommands::from(routed_command(
@@ -154,18 +163,49 @@ fn synchronize_and_resolve_timeout(
     })
 }
 
+/// Every key, mouse button, and gamepad button held at a keymap swap, which the runtime then
+/// inhibits until each is released.
+fn pressed_physical_buttons(world: &World) -> Vec<PhysicalButton> {
+    let keys = world
+        .get_resource::<ButtonInput<KeyCode>>()
+        .into_iter()
+        .flat_map(|key_input| key_input.get_pressed().copied().map(PhysicalButton::Key));
+    let mouse_buttons = world
+        .get_resource::<ButtonInput<MouseButton>>()
+        .into_iter()
+        .flat_map(|mouse_input| {
+            mouse_input
+                .get_pressed()
+                .copied()
+                .map(PhysicalButton::Mouse)
+        });
+    let gamepad_buttons =
+        world
+            .get_resource::<GamepadButtons>()
+            .into_iter()
+            .flat_map(|gamepad_buttons| {
+                gamepad_buttons
+                    .input()
+                    .get_pressed()
+                    .copied()
+                    .map(PhysicalButton::Gamepad)
+            });
+
+    keys.chain(mouse_buttons).chain(gamepad_buttons).collect()
+}
+
 fn route_releases(world: &mut World) {
-    clear_processed_keycodes(world);
-    while let Some(key) = next_released_key(world) {
+    clear_processed_buttons(world);
+    while let Some(button) = next_released_button(world) {
         let pressed_modifiers = world
             .get_resource::<ButtonInput<KeyCode>>()
             .map(Modifiers::from_pressed)
             .unwrap_or_default();
         let custom_input_transition = {
             let mut keymap_runtime = world.resource_mut::<KeymapRuntime>();
-            keymap_runtime.mark_processed(key);
-            keymap_runtime.release_inhibition(key);
-            keymap_runtime.release_key(key)
+            keymap_runtime.mark_processed(button);
+            keymap_runtime.release_inhibition(button);
+            keymap_runtime.release_button(button)
         };
         write_custom_input_transition(world, custom_input_transition);
         release_chords_missing_modifiers(world, pressed_modifiers);
@@ -177,11 +217,21 @@ fn route_presses(
     active_keymap_scope: ActiveKeymapScope,
     keystroke_routing: &KeystrokeRouting,
 ) {
-    clear_processed_keycodes(world);
-    let primary_trigger_ownership = world.get_resource::<ButtonInput<KeyCode>>().map_or(
+    clear_processed_buttons(world);
+    let mut primary_trigger_ownership = world.get_resource::<ButtonInput<KeyCode>>().map_or(
         PrimaryTriggerOwnership::Unclaimed,
         PrimaryTriggerOwnership::from,
     );
+    if let Some(mouse_input) = world.get_resource::<ButtonInput<MouseButton>>() {
+        primary_trigger_ownership = primary_trigger_ownership
+            .with_buttons(mouse_input, |mouse_button| {
+                KeymapMouseButton::try_from(mouse_button).is_ok()
+            });
+    }
+    if let Some(gamepad_buttons) = world.get_resource::<GamepadButtons>() {
+        primary_trigger_ownership =
+            primary_trigger_ownership.with_buttons(gamepad_buttons.input(), |_| true);
+    }
 
     match primary_trigger_ownership {
         PrimaryTriggerOwnership::Unclaimed => {},
@@ -191,6 +241,7 @@ fn route_presses(
         PrimaryTriggerOwnership::OrdinaryKeys(ordinary_key_routing_state) => {
             suspend_modifier_family_held_bindings(world);
             route_ordinary_key_presses(world, active_keymap_scope, keystroke_routing);
+            route_button_presses(world, active_keymap_scope, keystroke_routing);
             match ordinary_key_routing_state {
                 OrdinaryKeyRoutingState::Held => {},
                 OrdinaryKeyRoutingState::PressEdgesOnly => {
@@ -211,8 +262,9 @@ fn route_ordinary_key_presses(
     keystroke_routing: &KeystrokeRouting,
 ) {
     while let Some(key) = next_pressed_key(world) {
-        world.resource_mut::<KeymapRuntime>().mark_processed(key);
-        if world.resource::<KeymapRuntime>().is_inhibited(key) {
+        let button = PhysicalButton::Key(key);
+        world.resource_mut::<KeymapRuntime>().mark_processed(button);
+        if world.resource::<KeymapRuntime>().is_inhibited(button) {
             continue;
         }
         let PhysicalKeyRole::OrdinaryKey(ordinary_key) = PhysicalKeyRole::from(key) else {
@@ -225,32 +277,80 @@ fn route_ordinary_key_presses(
         else {
             continue;
         };
-        let held_chord_physical_ownership =
-            HeldChordPhysicalOwnership::new(key, keystroke.modifiers());
-        let routed_commands =
-            route_keystroke(world, active_keymap_scope, keystroke, keystroke_routing);
-        claim_held_chords(world, routed_commands, held_chord_physical_ownership);
-        release_physical_if_no_longer_pressed(world, key);
-        dispatch_all(world, routed_commands);
+        route_pressed_button(
+            world,
+            active_keymap_scope,
+            button,
+            keystroke,
+            keystroke_routing,
+        );
     }
 }
 
+/// Routes mouse and gamepad button presses through the same matcher as ordinary keys, with the
+/// keyboard modifiers held at the press.
+fn route_button_presses(
+    world: &mut World,
+    active_keymap_scope: ActiveKeymapScope,
+    keystroke_routing: &KeystrokeRouting,
+) {
+    while let Some(button) = next_pressed_button(world) {
+        world.resource_mut::<KeymapRuntime>().mark_processed(button);
+        if world.resource::<KeymapRuntime>().is_inhibited(button) {
+            continue;
+        }
+        let pressed_modifiers = world
+            .get_resource::<ButtonInput<KeyCode>>()
+            .map(Modifiers::from_pressed)
+            .unwrap_or_default();
+        let Some(keystroke) = button.button_keystroke(pressed_modifiers) else {
+            continue;
+        };
+        route_pressed_button(
+            world,
+            active_keymap_scope,
+            button,
+            keystroke,
+            keystroke_routing,
+        );
+    }
+}
+
+fn route_pressed_button(
+    world: &mut World,
+    active_keymap_scope: ActiveKeymapScope,
+    button: PhysicalButton,
+    keystroke: Keystroke,
+    keystroke_routing: &KeystrokeRouting,
+) {
+    let held_chord_physical_ownership =
+        HeldChordPhysicalOwnership::new(button, keystroke.modifiers());
+    let routed_commands = route_keystroke(world, active_keymap_scope, keystroke, keystroke_routing);
+    claim_held_chords(world, &routed_commands, held_chord_physical_ownership);
+    release_physical_if_no_longer_pressed(world, button);
+    dispatch_all(world, routed_commands);
+}
+
 /// Activates the held custom input of every hold-to-act command the pressed chord matched.
 fn claim_held_chords(
     world: &mut World,
-    routed_commands: RoutedCommands,
+    routed_commands: &RoutedCommands,
     held_chord_physical_ownership: HeldChordPhysicalOwnership,
 ) {
-    claim_held_chord(world, routed_commands.first, held_chord_physical_ownership);
-    claim_held_chord(world, routed_commands.second, held_chord_physical_ownership);
+    claim_held_chord(world, &routed_commands.first, held_chord_physical_ownership);
+    claim_held_chord(
+        world,
+        &routed_commands.second,
+        held_chord_physical_ownership,
+    );
 }
 
 fn claim_held_chord(
     world: &mut World,
-    routed_command: RoutedCommand,
+    routed_command: &RoutedCommand,
     held_chord_physical_ownership: HeldChordPhysicalOwnership,
 ) {
-    let RoutedCommand::HoldChord(custom_input) = routed_command else {
+    let RoutedCommand::HoldChord(custom_input) = *routed_command else {
         return;
     };
     let custom_input_transition = world
@@ -259,16 +359,14 @@ fn claim_held_chord(
     write_custom_input_transition(world, custom_input_transition);
 }
 
-fn release_physical_if_no_longer_pressed(world: &mut World, key: KeyCode) {
-    let key_remains_pressed = world
-        .get_resource::<ButtonInput<KeyCode>>()
-        .is_some_and(|key_input| key_input.pressed(key));
+fn release_physical_if_no_longer_pressed(world: &mut World, button: PhysicalButton) {
+    let button_remains_pressed = button.is_pressed(world);
     let pressed_modifiers = world
         .get_resource::<ButtonInput<KeyCode>>()
         .map(Modifiers::from_pressed)
         .unwrap_or_default();
-    if !key_remains_pressed {
-        let custom_input_transition = world.resource_mut::<KeymapRuntime>().release_key(key);
+    if !button_remains_pressed {
+        let custom_input_transition = world.resource_mut::<KeymapRuntime>().release_button(button);
         write_custom_input_transition(world, custom_input_transition);
     }
     release_chords_missing_modifiers(world, pressed_modifiers);
@@ -289,7 +387,11 @@ fn activate_modifier_family_held_bindings(
         let is_pressed = world
             .get_resource::<ButtonInput<KeyCode>>()
             .is_some_and(|pressed| pressed.pressed(key));
-        if !is_pressed || world.resource::<KeymapRuntime>().is_inhibited(key) {
+        if !is_pressed
+            || world
+                .resource::<KeymapRuntime>()
+                .is_inhibited(PhysicalButton::Key(key))
+        {
             continue;
         }
         let PhysicalKeyRole::ModifierFamily(modifier_family) = PhysicalKeyRole::from(key) else {
@@ -304,22 +406,24 @@ fn activate_modifier_family_held_bindings(
 
         let custom_input_transition = world
             .resource_mut::<KeymapRuntime>()
-            .activate_modifier_family(key, custom_input);
+            .activate_modifier_family(PhysicalButton::Key(key), custom_input);
         write_custom_input_transition(world, custom_input_transition);
     }
 }
 
 fn suspend_modifier_family_held_bindings(world: &mut World) {
     for key in key_edge::PHYSICAL_MODIFIER_KEYS {
-        let custom_input_transition = world.resource_mut::<KeymapRuntime>().release_key(key);
+        let custom_input_transition = world
+            .resource_mut::<KeymapRuntime>()
+            .release_button(PhysicalButton::Key(key));
         write_custom_input_transition(world, custom_input_transition);
     }
 }
 
-fn clear_processed_keycodes(world: &mut World) {
+fn clear_processed_buttons(world: &mut World) {
     world
         .resource_mut::<KeymapRuntime>()
-        .clear_processed_keycodes();
+        .clear_processed_buttons();
 }
 
 fn next_pressed_key(world: &World) -> Option<KeyCode> {
@@ -328,22 +432,69 @@ fn next_pressed_key(world: &World) -> Option<KeyCode> {
         .get_resource::<ButtonInput<KeyCode>>()?
         .get_just_pressed()
         .copied()
-        .find(|key| !keymap_runtime.is_processed(*key))
+        .find(|key| !keymap_runtime.is_processed(PhysicalButton::Key(*key)))
 }
 
-fn next_released_key(world: &World) -> Option<KeyCode> {
+/// The next mouse or gamepad button pressed this frame that routing has not consumed yet.
+fn next_pressed_button(world: &World) -> Option<PhysicalButton> {
     let keymap_runtime = world.get_resource::<KeymapRuntime>()?;
-    world
-        .get_resource::<ButtonInput<KeyCode>>()?
-        .get_just_released()
-        .copied()
-        .find(|key| !keymap_runtime.is_processed(*key))
+    let mouse_button = world
+        .get_resource::<ButtonInput<MouseButton>>()
+        .and_then(|mouse_input| {
+            mouse_input
+                .get_just_pressed()
+                .copied()
+                .map(PhysicalButton::Mouse)
+                .find(|button| !keymap_runtime.is_processed(*button))
+        });
+
+    mouse_button.or_else(|| {
+        world
+            .get_resource::<GamepadButtons>()?
+            .input()
+            .get_just_pressed()
+            .copied()
+            .map(PhysicalButton::Gamepad)
+            .find(|button| !keymap_runtime.is_processed(*button))
+    })
+}
+
+fn next_released_button(world: &World) -> Option<PhysicalButton> {
+    let keymap_runtime = world.get_resource::<KeymapRuntime>()?;
+    let key = world
+        .get_resource::<ButtonInput<KeyCode>>()
+        .and_then(|key_input| {
+            key_input
+                .get_just_released()
+                .copied()
+                .map(PhysicalButton::Key)
+                .find(|button| !keymap_runtime.is_processed(*button))
+        });
+    let mouse_button = || {
+        world
+            .get_resource::<ButtonInput<MouseButton>>()?
+            .get_just_released()
+            .copied()
+            .map(PhysicalButton::Mouse)
+            .find(|button| !keymap_runtime.is_processed(*button))
+    };
+    let gamepad_button = || {
+        world
+            .get_resource::<GamepadButtons>()?
+            .input()
+            .get_just_released()
+            .copied()
+            .map(PhysicalButton::Gamepad)
+            .find(|button| !keymap_runtime.is_processed(*button))
+    };
+
+    key.or_else(mouse_button).or_else(gamepad_button)
 }
 
 fn route_keystroke(
     world: &mut World,
     active_keymap_scope: ActiveKeymapScope,
-    keystroke: crate::Keystroke,
+    keystroke: Keystroke,
     keystroke_routing: &KeystrokeRouting,
 ) -> RoutedCommands {
     world.resource_scope::<CompiledKeymap, _>(|world, mut compiled_keymap| {
@@ -429,7 +580,9 @@ fn routed_command(
         Some(Invocation::Held(custom_input)) => RoutedCommand::HoldChord(custom_input),
         Some(Invocation::OneShot | Invocation::Unremappable) => compiled_keymap
             .dispatch(command_handle)
-            .map_or(RoutedCommand::Nothing, RoutedCommand::Dispatch),
+            .map_or(RoutedCommand::Nothing, |command_dispatch| {
+                RoutedCommand::Dispatch(command_handle, command_dispatch)
+            }),
         None => RoutedCommand::Nothing,
     }
 }
@@ -501,21 +654,75 @@ fn dispatch_all(world: &mut World, routed_commands: RoutedCommands) {
 
 fn dispatch_one(world: &mut World, routed_command: RoutedCommand) {
     match routed_command {
-        RoutedCommand::Dispatch(dispatch) => dispatch(world),
+        RoutedCommand::Dispatch(command_handle, command_dispatch) => {
+            record_macro_step(world, command_handle, &command_dispatch);
+            #[cfg(any(test, feature = "input_script"))]
+            trace_dispatch(world, command_handle);
+            command_dispatch.run(world);
+        },
         RoutedCommand::HoldChord(_) | RoutedCommand::Nothing => {},
     }
 }
 
+#[cfg(any(test, feature = "input_script"))]
+fn trace_dispatch(world: &mut World, command_handle: CommandHandle) {
+    let Some(command_id) = world
+        .get_resource::<CompiledKeymap>()
+        .and_then(|compiled_keymap| compiled_keymap.command_id(command_handle))
+        .cloned()
+    else {
+        return;
+    };
+    trace::record(world, RoutingTraceEvent::Dispatched(command_id));
+}
+
+#[cfg(any(test, feature = "input_script"))]
+fn trace_timeout(world: &mut World, timeout_outcome: &TimeoutOutcome<CommandHandle>) {
+    let resolved = match timeout_outcome {
+        TimeoutOutcome::Resolved(_) => true,
+        TimeoutOutcome::DiscardedPartialPrefix => false,
+        TimeoutOutcome::AwaitingKeystroke | TimeoutOutcome::NoPendingSequence => return,
+    };
+    trace::record(world, RoutingTraceEvent::SequenceTimeout { resolved });
+}
+
+/// Appends a dispatched command to the macro being recorded, if any.
+///
+/// Playing a macro is never recorded, so a recording cannot replay itself.
+fn record_macro_step(
+    world: &mut World,
+    command_handle: CommandHandle,
+    command_dispatch: &CommandDispatch,
+) {
+    if !world
+        .get_resource::<KeymapMacros>()
+        .is_some_and(KeymapMacros::is_recording)
+    {
+        return;
+    }
+    let Some(command_id) = world
+        .get_resource::<CompiledKeymap>()
+        .and_then(|compiled_keymap| compiled_keymap.command_id(command_handle))
+        .filter(|command_id| command_id.as_str() != PlayKeymapMacro::ID)
+        .cloned()
+    else {
+        return;
+    };
+    world
+        .resource_mut::<KeymapMacros>()
+        .record(command_id, command_dispatch.arguments().cloned());
+}
+
 /// What a matched keystroke resolves to before the runtime acts on it.
 ///
 /// Routing decides this from the compiled keymap alone; the caller then decides which halves it
 /// honors, so the sequence-timeout path can drop a [`RoutedCommand::HoldChord`] that has no
 /// physical key to own it.
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Default)]
 enum RoutedCommand {
     #[default]
     Nothing,
-    Dispatch(fn(&mut World)),
+    Dispatch(CommandHandle, CommandDispatch),
     HoldChord(CustomInput),
 }
 
@@ -523,14 +730,14 @@ enum RoutedCommand {
 ///
 /// A keystroke yields at most two: a sequence prefix that a longer sequence just abandoned, plus
 /// the command the reprocessed keystroke matches on its own.
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Default)]
 struct RoutedCommands {
     first:  RoutedCommand,
     second: RoutedCommand,
 }
 
 impl RoutedCommands {
-    const fn push(&mut self, routed_command: RoutedCommand) {
+    fn push(&mut self, routed_command: RoutedCommand) {
         if matches!(routed_command, RoutedCommand::Nothing) {
             return;
         }
@@ -563,7 +770,9 @@ mod tests {
     use bevy::ecs::spawn::SpawnRelated;
     use bevy::ecs::spawn::SpawnWith;
     use bevy::input::ButtonInput;
+    use bevy::input::gamepad::GamepadButton;
     use bevy::input::keyboard::KeyCode;
+    use bevy::input::mouse::MouseButton;
     use bevy::prelude::App;
     use bevy::prelude::Component;
     use bevy::prelude::Entity;
@@ -594,22 +803,28 @@ mod tests {
     use bevy_enhanced_input::prelude::InputAction;
     use bevy_enhanced_input::prelude::InputContextAppExt;
     use bevy_enhanced_input::prelude::Start;
+    use serde::Deserialize;
     use strum::AsRefStr;
     use strum::EnumIter;
     use strum::EnumMessage;
 
+    use super::GamepadButtons;
     use super::KeymapRuntime;
+    use super::PhysicalButton;
     use super::route_input;
     use crate::ActiveCondition;
+    use crate::Capability;
     use crate::CommandId;
     use crate::CommandRegistry;
     use crate::DiagnosticOrigin;
     use crate::HoldPhase;
     use crate::KeymapCommand;
+    use crate::KeymapCommandArguments;
     use crate::KeymapPlugin;
     use crate::KeymapSystems;
     use crate::KeystrokeSequence;
     use crate::ReflectKeymapCommand;
+    use crate::ReflectKeymapCommandArguments;
     use crate::SequenceMatcher;
     use crate::command::Invocation;
     use crate::condition::ConditionLookup;
@@ -619,9 +834,11 @@ mod tests {
     use crate::keymap::CompiledKeymap;
     use crate::keymap::Generation;
     use crate::keymap::KeyboardOwner;
+    use crate::keymap::KeymapMacros;
     use crate::keymap::KeystrokeRouting;
     use crate::keymap::MergedKeymap;
     use crate::keymap::merged::UserKeymap;
+    use crate::keymap::start_recording;
 
     const DEFAULTS_PATH: &str = "runtime-defaults.jsonc";
     const FIRST_GENERATION: Generation = Generation(1);
@@ -708,6 +925,41 @@ mod tests {
         capability:  Unremappable,
     }
 
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand, KeymapCommandArguments)]
+    struct RuntimeSelectTab {
+        index: usize,
+    }
+
+    #[derive(Deserialize, Reflect)]
+    struct RuntimeSelectTabArguments {
+        index: usize,
+    }
+
+    impl KeymapCommand for RuntimeSelectTab {
+        const ID: &'static str = "runtime::select_tab";
+        const TITLE: &'static str = "Runtime Select Tab";
+        const DESCRIPTION: &'static str = "Selects the tab named by the binding's arguments.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self::default() }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    impl KeymapCommandArguments for RuntimeSelectTab {
+        type Arguments = RuntimeSelectTabArguments;
+
+        fn build_with(arguments: RuntimeSelectTabArguments) -> Self {
+            Self {
+                index: arguments.index,
+            }
+        }
+    }
+
+    #[derive(Debug, Default, Eq, PartialEq, Resource)]
+    struct SelectedTabs(Vec<usize>);
+
     #[derive(Debug, Default, Eq, PartialEq, Resource)]
     struct DispatchCounts {
         one_shot:     usize,
@@ -736,6 +988,86 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn bindings_deliver_their_arguments_with_the_fired_event() -> Result<(), String> {
+        let mut app = runtime_app();
+        app.init_resource::<SelectedTabs>();
+        app.world_mut().add_observer(
+            |select_tab: On<RuntimeSelectTab>, mut selected_tabs: ResMut<SelectedTabs>| {
+                selected_tabs.0.push(select_tab.event().index);
+            },
+        );
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<RuntimeSelectTab>();
+        let command_registry = built_command_registry(&mut app, &type_registry)?;
+        insert_compiled_for_registry(
+            &mut app,
+            command_registry,
+            r#"{ "bindings": [{ "bindings": {
+                "1": ["runtime::select_tab", { "index": 1 }],
+                "2": ["runtime::select_tab", { "index": 2 }],
+                "0": "runtime::select_tab"
+            } }] }"#
+                .to_owned(),
+            FIRST_GENERATION,
+        )?;
+
+        press(&mut app, KeyCode::Digit2);
+        release(&mut app, KeyCode::Digit2);
+        press(&mut app, KeyCode::Digit1);
+        release(&mut app, KeyCode::Digit1);
+        press(&mut app, KeyCode::Digit0);
+
+        assert_eq!(app.world().resource::<SelectedTabs>().0, vec![2, 1, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn recording_captures_dispatched_commands_with_their_arguments() -> Result<(), String> {
+        let mut app = runtime_app();
+        app.init_resource::<KeymapMacros>();
+        let mut type_registry = TypeRegistry::default();
+        type_registry.register::<RuntimeOneShot>();
+        type_registry.register::<RuntimeSelectTab>();
+        let command_registry = built_command_registry(&mut app, &type_registry)?;
+        insert_compiled_for_registry(
+            &mut app,
+            command_registry,
+            r#"{ "bindings": [{ "bindings": {
+                "g": "runtime::one_shot",
+                "2": ["runtime::select_tab", { "index": 2 }]
+            } }] }"#
+                .to_owned(),
+            FIRST_GENERATION,
+        )?;
+
+        press(&mut app, KeyCode::KeyG);
+        release(&mut app, KeyCode::KeyG);
+        start_recording(app.world_mut()).map_err(|error| error.to_string())?;
+        press(&mut app, KeyCode::Digit2);
+        release(&mut app, KeyCode::Digit2);
+        press(&mut app, KeyCode::KeyG);
+
+        let recorded = app
+            .world()
+            .resource::<KeymapMacros>()
+            .recorded_steps()
+            .iter()
+            .map(|step| step.command().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            recorded,
+            [RuntimeSelectTab::ID, RuntimeOneShot::ID],
+            "only commands dispatched while recording are captured"
+        );
+        assert_eq!(
+            app.world().resource::<DispatchCounts>().one_shot,
+            2,
+            "recording does not swallow the commands it captures"
+        );
+        Ok(())
+    }
+
     #[test]
     fn same_frame_press_and_release_dispatches_one_shot() -> Result<(), String> {
         let mut app = runtime_app();
@@ -795,7 +1127,7 @@ mod tests {
         let now = Instant::now();
         app.world_mut()
             .resource_mut::<KeymapRuntime>()
-            .set_test_clock(now);
+            .set_virtual_clock(now);
 
         press(&mut app, KeyCode::KeyG);
         release(&mut app, KeyCode::KeyG);
@@ -803,7 +1135,7 @@ mod tests {
 
         app.world_mut()
             .resource_mut::<KeymapRuntime>()
-            .set_test_clock(now + crate::keymap::constants::SEQUENCE_TIMEOUT);
+            .set_virtual_clock(now + crate::keymap::constants::SEQUENCE_TIMEOUT);
         route_input(app.world_mut());
 
         assert_eq!(app.world().resource::<DispatchCounts>().one_shot, 1);
@@ -1027,7 +1359,7 @@ mod tests {
         assert!(
             app.world()
                 .resource::<KeymapRuntime>()
-                .is_inhibited(KeyCode::ControlLeft)
+                .is_inhibited(KeyCode::ControlLeft.into())
         );
         assert_eq!(
             app.world().resource::<CustomInputs>().get(&custom_input),
@@ -1597,7 +1929,7 @@ mod tests {
         assert!(
             app.world()
                 .resource::<KeymapRuntime>()
-                .is_inhibited(KeyCode::KeyG)
+                .is_inhibited(KeyCode::KeyG.into())
         );
         app.world_mut()
             .resource_mut::<ButtonInput<KeyCode>>()
@@ -1659,7 +1991,7 @@ mod tests {
         assert!(
             app.world()
                 .resource::<KeymapRuntime>()
-                .is_inhibited(KeyCode::KeyG)
+                .is_inhibited(KeyCode::KeyG.into())
         );
         press(&mut app, KeyCode::KeyJ);
         assert_eq!(app.world().resource::<DispatchCounts>().two_stroke, 0);
@@ -1680,7 +2012,7 @@ mod tests {
         assert!(
             !app.world()
                 .resource::<KeymapRuntime>()
-                .is_inhibited(KeyCode::KeyG)
+                .is_inhibited(KeyCode::KeyG.into())
         );
         app.world_mut().trigger(RuntimeHeld {
             phase: HoldPhase::End,
@@ -1806,6 +2138,113 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mouse_buttons_dispatch_with_the_keyboard_modifiers_held_at_the_press() -> Result<(), String>
+    {
+        let mut app = runtime_app();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        insert_compiled(
+            &mut app,
+            bindings(&[
+                ("ctrl-mouse-back", RuntimeOneShot::ID),
+                ("mouse-forward", RuntimeTwoStroke::ID),
+            ]),
+            FIRST_GENERATION,
+        )?;
+
+        press_mouse(&mut app, MouseButton::Back);
+        assert_eq!(app.world().resource::<DispatchCounts>().one_shot, 0);
+        release_mouse(&mut app, MouseButton::Back);
+        press(&mut app, KeyCode::ControlLeft);
+        press_mouse(&mut app, MouseButton::Back);
+        release_mouse(&mut app, MouseButton::Back);
+        release(&mut app, KeyCode::ControlLeft);
+        press_mouse(&mut app, MouseButton::Forward);
+
+        assert_eq!(app.world().resource::<DispatchCounts>().one_shot, 1);
+        assert_eq!(app.world().resource::<DispatchCounts>().two_stroke, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn held_gamepad_binding_writes_its_custom_input_until_the_button_is_released()
+    -> Result<(), String> {
+        let mut app = runtime_app();
+        app.init_resource::<GamepadButtons>();
+        insert_compiled(
+            &mut app,
+            bindings(&[("gamepad-south", RuntimeHeld::ID)]),
+            FIRST_GENERATION,
+        )?;
+        let custom_input = held_custom_input(&app)?;
+
+        app.world_mut()
+            .resource_mut::<GamepadButtons>()
+            .input_mut()
+            .press(GamepadButton::South);
+        route_input(app.world_mut());
+        assert_eq!(
+            app.world().resource::<CustomInputs>().get(&custom_input),
+            Some(&ActionValue::Bool(true))
+        );
+        app.world_mut()
+            .resource_mut::<GamepadButtons>()
+            .input_mut()
+            .clear();
+        route_input(app.world_mut());
+        assert_eq!(
+            app.world().resource::<CustomInputs>().get(&custom_input),
+            Some(&ActionValue::Bool(true))
+        );
+
+        app.world_mut()
+            .resource_mut::<GamepadButtons>()
+            .input_mut()
+            .release(GamepadButton::South);
+        route_input(app.world_mut());
+
+        assert_eq!(
+            app.world().resource::<CustomInputs>().get(&custom_input),
+            Some(&ActionValue::Bool(false))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn a_mouse_button_held_across_a_keymap_swap_stays_inert_until_released() -> Result<(), String> {
+        let mut app = runtime_app();
+        app.init_resource::<ButtonInput<MouseButton>>();
+        insert_compiled(
+            &mut app,
+            bindings(&[("mouse-back", RuntimeOneShot::ID)]),
+            FIRST_GENERATION,
+        )?;
+        route_input(app.world_mut());
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(MouseButton::Back);
+        insert_compiled(
+            &mut app,
+            bindings(&[("mouse-back", RuntimeOneShot::ID)]),
+            SECOND_GENERATION,
+        )?;
+        route_input(app.world_mut());
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear_just_pressed(MouseButton::Back);
+
+        assert_eq!(app.world().resource::<DispatchCounts>().one_shot, 0);
+        assert!(
+            app.world()
+                .resource::<KeymapRuntime>()
+                .is_inhibited(PhysicalButton::Mouse(MouseButton::Back))
+        );
+        release_mouse(&mut app, MouseButton::Back);
+        press_mouse(&mut app, MouseButton::Back);
+        assert_eq!(app.world().resource::<DispatchCounts>().one_shot, 1);
+        Ok(())
+    }
+
     #[test]
     fn steady_state_held_routing_does_not_allocate() -> Result<(), String> {
         let mut app = runtime_app();
@@ -2183,6 +2622,26 @@ mod tests {
             .clear_just_released(key);
     }
 
+    fn press_mouse(app: &mut App, mouse_button: MouseButton) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .press(mouse_button);
+        route_input(app.world_mut());
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear_just_pressed(mouse_button);
+    }
+
+    fn release_mouse(app: &mut App, mouse_button: MouseButton) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .release(mouse_button);
+        route_input(app.world_mut());
+        app.world_mut()
+            .resource_mut::<ButtonInput<MouseButton>>()
+            .clear_just_released(mouse_button);
+    }
+
     fn press_together<const COUNT: usize>(app: &mut App, keys: [KeyCode; COUNT]) {
         for key in keys {
             app.world_mut()
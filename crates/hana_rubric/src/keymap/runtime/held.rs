@@ -1,83 +1,98 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+#[cfg(any(test, feature = "input_script"))]
+use std::time::Duration;
 use std::time::Instant;
 
 use bevy::ecs::prelude::Resource;
-use bevy::input::keyboard::KeyCode;
 use bevy_enhanced_input::prelude::ActionValue;
 use bevy_enhanced_input::prelude::CustomInput;
 use bevy_enhanced_input::prelude::CustomInputs;
 
+use super::button_edge::PhysicalButton;
 use super::key_edge::PHYSICAL_MODIFIER_KEYS;
+use crate::KeymapGamepadButton;
+use crate::KeymapMouseButton;
 use crate::Modifiers;
 use crate::OrdinaryKey;
 use crate::condition::ConditionHandle;
 use crate::keymap::ActiveKeymapScope;
 use crate::keymap::Generation;
 use crate::keymap::KeystrokeRouting;
+#[cfg(any(test, feature = "input_script"))]
+use crate::keymap::constants::SEQUENCE_TIMEOUT;
 
-/// How many physical keys can own an entry in `KeymapRuntime::physical_sources` at once.
+/// How many physical buttons can own an entry in `KeymapRuntime::physical_sources` at once.
 ///
-/// A key reaches that map as either the primary key of an ordinary chord — necessarily an
-/// [`OrdinaryKey`] — or one side of a modifier family, so the whole routable domain is every
-/// `OrdinaryKey` plus `PHYSICAL_MODIFIER_KEYS`. Reserving that much at setup keeps routing free
-/// of reallocation even when all four modifier families and both sides of each are held at once.
-const ROUTABLE_PHYSICAL_KEYS: usize = OrdinaryKey::COUNT + PHYSICAL_MODIFIER_KEYS.len();
+/// A button reaches that map as the primary trigger of a chord — necessarily an [`OrdinaryKey`],
+/// a [`KeymapMouseButton`], or a [`KeymapGamepadButton`] — or as one side of a modifier family, so
+/// the whole routable domain is every one of those plus `PHYSICAL_MODIFIER_KEYS`. Reserving that
+/// much at setup keeps routing free of reallocation even when all four modifier families and both
+/// sides of each are held at once.
+const ROUTABLE_PHYSICAL_BUTTONS: usize = OrdinaryKey::COUNT
+    + KeymapMouseButton::COUNT
+    + KeymapGamepadButton::COUNT
+    + PHYSICAL_MODIFIER_KEYS.len();
 
 /// How many custom inputs `KeymapRuntime::held_sources` can carry while routing drives them.
 ///
 /// Routing reaches that map only through `KeymapRuntime::activate_physical_source`, which records
-/// at most one physical source per key, so it can never make more distinct [`CustomInput`]s active
-/// than there are routable physical keys. `KeymapRuntime::set_event_source` adds further entries
-/// from `HoldPhase` events, which arrive from observers rather than from the input path.
-const ROUTING_HELD_CUSTOM_INPUTS: usize = ROUTABLE_PHYSICAL_KEYS;
+/// at most one physical source per button, so it can never make more distinct [`CustomInput`]s
+/// active than there are routable physical buttons. `KeymapRuntime::set_event_source` adds further
+/// entries from `HoldPhase` events, which arrive from observers rather than from the input path.
+const ROUTING_HELD_CUSTOM_INPUTS: usize = ROUTABLE_PHYSICAL_BUTTONS;
 
-/// How many key codes `KeymapRuntime::inhibited` reserves room for.
+/// How many physical buttons `KeymapRuntime::inhibited` reserves room for.
 ///
-/// `KeymapRuntime::inhibit` refills the set from every key `ButtonInput<KeyCode>` reports pressed
-/// at a keymap swap, including key codes no keystroke can spell, so the routable domain is a
-/// reservation rather than a ceiling — a swap that exceeds it grows the set on the swap path,
-/// which already collects those keys into a `Vec`. Routing only ever removes from the set, through
-/// `KeymapRuntime::release_inhibition`.
-const INHIBITED_KEYS_AT_A_KEYMAP_SWAP: usize = ROUTABLE_PHYSICAL_KEYS;
-
-/// How many key codes `KeymapRuntime::processed_keycodes` can gather in one routing pass.
+/// `KeymapRuntime::inhibit` refills the set from every key, mouse button, and gamepad button
+/// reported pressed at a keymap swap, including ones no keystroke can spell, so the routable
+/// domain is a reservation rather than a ceiling — a swap that exceeds it grows the set on the
+/// swap path, which already collects those buttons into a `Vec`. Routing only ever removes from
+/// the set, through `KeymapRuntime::release_inhibition`.
+const INHIBITED_BUTTONS_AT_A_KEYMAP_SWAP: usize = ROUTABLE_PHYSICAL_BUTTONS;
+
+/// How many physical buttons `KeymapRuntime::processed_buttons` can gather in one routing pass.
 ///
-/// The set records the keys `route_releases` and `route_ordinary_key_presses` already consumed
-/// this frame, including key codes routing cannot spell as an [`OrdinaryKey`], so it is bounded by
-/// how many keys change state in a single frame. Reserving the routable domain leaves that count
-/// far below capacity — no keyboard reports every ordinary key and both sides of all four modifier
-/// families changing state at once.
-const KEYS_PROCESSED_IN_ONE_FRAME: usize = ROUTABLE_PHYSICAL_KEYS;
+/// The set records the buttons `route_releases`, `route_ordinary_key_presses`, and
+/// `route_button_presses` already consumed this frame, including key codes routing cannot spell as
+/// an [`OrdinaryKey`], so it is bounded by how many buttons change state in a single frame.
+/// Reserving the routable domain leaves that count far below capacity — no device reports every
+/// button changing state at once.
+const BUTTONS_PROCESSED_IN_ONE_FRAME: usize = ROUTABLE_PHYSICAL_BUTTONS;
 
 #[derive(Resource)]
 pub(crate) struct KeymapRuntime {
-    clock:              Clock,
-    generation:         Option<Generation>,
-    active_matcher:     ActiveMatcher,
-    keystroke_routing:  KeystrokeRouting,
-    held_sources:       HashMap<CustomInput, HeldSources>,
-    physical_sources:   HashMap<KeyCode, HeldPhysicalSource>,
-    inhibited:          HashSet<KeyCode>,
-    processed_keycodes: HashSet<KeyCode>,
+    clock:             Clock,
+    generation:        Option<Generation>,
+    active_matcher:    ActiveMatcher,
+    keystroke_routing: KeystrokeRouting,
+    held_sources:      HashMap<CustomInput, HeldSources>,
+    physical_sources:  HashMap<PhysicalButton, HeldPhysicalSource>,
+    inhibited:         HashSet<PhysicalButton>,
+    processed_buttons: HashSet<PhysicalButton>,
 }
 
 impl Default for KeymapRuntime {
     fn default() -> Self {
         Self {
-            clock:              Clock::System,
-            generation:         None,
-            active_matcher:     ActiveMatcher::Uninitialized,
-            keystroke_routing:  KeystrokeRouting::default(),
-            held_sources:       HashMap::with_capacity(ROUTING_HELD_CUSTOM_INPUTS),
-            physical_sources:   HashMap::with_capacity(ROUTABLE_PHYSICAL_KEYS),
-            inhibited:          HashSet::with_capacity(INHIBITED_KEYS_AT_A_KEYMAP_SWAP),
-            processed_keycodes: HashSet::with_capacity(KEYS_PROCESSED_IN_ONE_FRAME),
+            clock:             Clock::System,
+            generation:        None,
+            active_matcher:    ActiveMatcher::Uninitialized,
+            keystroke_routing: KeystrokeRouting::default(),
+            held_sources:      HashMap::with_capacity(ROUTING_HELD_CUSTOM_INPUTS),
+            physical_sources:  HashMap::with_capacity(ROUTABLE_PHYSICAL_BUTTONS),
+            inhibited:         HashSet::with_capacity(INHIBITED_BUTTONS_AT_A_KEYMAP_SWAP),
+            processed_buttons: HashSet::with_capacity(BUTTONS_PROCESSED_IN_ONE_FRAME),
         }
     }
 }
 
 impl KeymapRuntime {
+    /// Maximum elapsed time between keystrokes in one sequence, for harnesses that step virtual
+    /// time past a pending sequence.
+    #[cfg(any(test, feature = "input_script"))]
+    pub(crate) const fn sequence_timeout() -> Duration { SEQUENCE_TIMEOUT }
+
     pub(super) fn now(&self) -> Instant { self.clock.now() }
 
     pub(super) const fn generation(&self) -> Option<Generation> { self.generation }
@@ -112,29 +127,35 @@ impl KeymapRuntime {
         KeyboardHandover::Crossed
     }
 
-    pub(super) fn inhibit(&mut self, pressed: impl Iterator<Item = KeyCode>) {
+    pub(super) fn inhibit(&mut self, pressed: impl Iterator<Item = PhysicalButton>) {
         self.inhibited.clear();
         self.inhibited.extend(pressed);
     }
 
-    pub(super) fn is_inhibited(&self, key: KeyCode) -> bool { self.inhibited.contains(&key) }
+    pub(super) fn is_inhibited(&self, button: PhysicalButton) -> bool {
+        self.inhibited.contains(&button)
+    }
 
-    pub(super) fn release_inhibition(&mut self, key: KeyCode) { self.inhibited.remove(&key); }
+    pub(super) fn release_inhibition(&mut self, button: PhysicalButton) {
+        self.inhibited.remove(&button);
+    }
 
-    pub(super) fn clear_processed_keycodes(&mut self) { self.processed_keycodes.clear(); }
+    pub(super) fn clear_processed_buttons(&mut self) { self.processed_buttons.clear(); }
 
-    pub(super) fn is_processed(&self, key: KeyCode) -> bool {
-        self.processed_keycodes.contains(&key)
+    pub(super) fn is_processed(&self, button: PhysicalButton) -> bool {
+        self.processed_buttons.contains(&button)
     }
 
-    pub(super) fn mark_processed(&mut self, key: KeyCode) { self.processed_keycodes.insert(key); }
+    pub(super) fn mark_processed(&mut self, button: PhysicalButton) {
+        self.processed_buttons.insert(button);
+    }
 
     pub(super) fn activate_modifier_family(
         &mut self,
-        key: KeyCode,
+        button: PhysicalButton,
         custom_input: CustomInput,
     ) -> CustomInputTransition {
-        self.activate_physical_source(key, HeldPhysicalSource::ModifierFamily { custom_input })
+        self.activate_physical_source(button, HeldPhysicalSource::ModifierFamily { custom_input })
     }
 
     pub(super) fn activate_ordinary_chord(
@@ -143,7 +164,7 @@ impl KeymapRuntime {
         custom_input: CustomInput,
     ) -> CustomInputTransition {
         self.activate_physical_source(
-            ownership.primary_key,
+            ownership.primary_button,
             HeldPhysicalSource::OrdinaryChord {
                 custom_input,
                 ownership,
@@ -151,8 +172,8 @@ impl KeymapRuntime {
         )
     }
 
-    pub(super) fn release_key(&mut self, key: KeyCode) -> CustomInputTransition {
-        let Some(held_physical_source) = self.physical_sources.remove(&key) else {
+    pub(super) fn release_button(&mut self, button: PhysicalButton) -> CustomInputTransition {
+        let Some(held_physical_source) = self.physical_sources.remove(&button) else {
             return CustomInputTransition::Unchanged;
         };
         let custom_input = held_physical_source.custom_input();
@@ -178,36 +199,38 @@ impl KeymapRuntime {
         &mut self,
         pressed_modifiers: Modifiers,
     ) -> PhysicalSourceReleaseProgress {
-        let key = self
+        let button = self
             .physical_sources
             .iter()
-            .find_map(|(key, held_physical_source)| match held_physical_source {
-                HeldPhysicalSource::ModifierFamily { .. } => None,
-                HeldPhysicalSource::OrdinaryChord { ownership, .. } => {
-                    (!ownership.modifiers_are_held(pressed_modifiers)).then_some(*key)
+            .find_map(
+                |(button, held_physical_source)| match held_physical_source {
+                    HeldPhysicalSource::ModifierFamily { .. } => None,
+                    HeldPhysicalSource::OrdinaryChord { ownership, .. } => {
+                        (!ownership.modifiers_are_held(pressed_modifiers)).then_some(*button)
+                    },
                 },
-            });
-        let Some(key) = key else {
+            );
+        let Some(button) = button else {
             return PhysicalSourceReleaseProgress::Complete;
         };
 
-        PhysicalSourceReleaseProgress::ReleasedOne(self.release_key(key))
+        PhysicalSourceReleaseProgress::ReleasedOne(self.release_button(button))
     }
 
     pub(super) fn release_one_physical_source(&mut self) -> PhysicalSourceReleaseProgress {
-        let Some(key) = self.physical_sources.keys().next().copied() else {
+        let Some(button) = self.physical_sources.keys().next().copied() else {
             return PhysicalSourceReleaseProgress::Complete;
         };
 
-        PhysicalSourceReleaseProgress::ReleasedOne(self.release_key(key))
+        PhysicalSourceReleaseProgress::ReleasedOne(self.release_button(button))
     }
 
     fn activate_physical_source(
         &mut self,
-        key: KeyCode,
+        button: PhysicalButton,
         held_physical_source: HeldPhysicalSource,
     ) -> CustomInputTransition {
-        if self.physical_sources.contains_key(&key) {
+        if self.physical_sources.contains_key(&button) {
             return CustomInputTransition::Unchanged;
         }
 
@@ -218,7 +241,7 @@ impl KeymapRuntime {
             held_sources.physical += 1;
             (before, held_sources.activity())
         };
-        self.physical_sources.insert(key, held_physical_source);
+        self.physical_sources.insert(button, held_physical_source);
 
         CustomInputTransition::between(custom_input, before, after)
     }
@@ -251,8 +274,11 @@ impl KeymapRuntime {
         }
     }
 
-    #[cfg(test)]
-    pub(super) const fn set_test_clock(&mut self, now: Instant) { self.clock = Clock::Test(now); }
+    /// Replaces the system clock with `now`, so sequence timeouts follow a virtual clock.
+    #[cfg(any(test, feature = "input_script"))]
+    pub(crate) const fn set_virtual_clock(&mut self, now: Instant) {
+        self.clock = Clock::Virtual(now);
+    }
 }
 
 /// Whether [`KeystrokeRouting`] moved the keyboard between the keymap and a text
@@ -321,14 +347,14 @@ impl HeldInputActivity {
 
 #[derive(Clone, Copy)]
 pub(super) struct HeldChordPhysicalOwnership {
-    primary_key:        KeyCode,
+    primary_button:     PhysicalButton,
     required_modifiers: Modifiers,
 }
 
 impl HeldChordPhysicalOwnership {
-    pub(super) const fn new(primary_key: KeyCode, required_modifiers: Modifiers) -> Self {
+    pub(super) const fn new(primary_button: PhysicalButton, required_modifiers: Modifiers) -> Self {
         Self {
-            primary_key,
+            primary_button,
             required_modifiers,
         }
     }
@@ -381,16 +407,16 @@ impl From<ActiveKeymapScope> for ActiveMatcher {
 #[derive(Clone, Copy)]
 enum Clock {
     System,
-    #[cfg(test)]
-    Test(Instant),
+    #[cfg(any(test, feature = "input_script"))]
+    Virtual(Instant),
 }
 
 impl Clock {
     fn now(self) -> Instant {
         match self {
             Self::System => Instant::now(),
-            #[cfg(test)]
-            Self::Test(now) => now,
+            #[cfg(any(test, feature = "input_script"))]
+            Self::Virtual(now) => now,
         }
     }
 }
@@ -1,3 +1,5 @@
+use std::hash::Hash;
+
 use bevy::input::ButtonInput;
 use bevy::input::keyboard::KeyCode;
 
@@ -56,6 +58,35 @@ impl From<&ButtonInput<KeyCode>> for PrimaryTriggerOwnership {
     }
 }
 
+impl PrimaryTriggerOwnership {
+    /// Lets a held or just-pressed mouse or gamepad button claim the primary trigger the way an
+    /// ordinary key does, so a nameable button suspends bare-modifier holds while it is down.
+    pub(super) fn with_buttons<T>(
+        self,
+        button_input: &ButtonInput<T>,
+        is_routable: impl Fn(T) -> bool,
+    ) -> Self
+    where
+        T: Copy + Eq + Hash + Send + Sync + 'static,
+    {
+        if self == Self::OrdinaryKeys(OrdinaryKeyRoutingState::Held)
+            || button_input
+                .get_pressed()
+                .any(|button| is_routable(*button))
+        {
+            return Self::OrdinaryKeys(OrdinaryKeyRoutingState::Held);
+        }
+        if button_input
+            .get_just_pressed()
+            .any(|button| is_routable(*button))
+        {
+            return Self::OrdinaryKeys(OrdinaryKeyRoutingState::PressEdgesOnly);
+        }
+
+        self
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(super) enum PhysicalKeyRole {
     OrdinaryKey(OrdinaryKey),
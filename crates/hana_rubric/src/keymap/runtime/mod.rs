@@ -1,13 +1,22 @@
+mod button_edge;
 mod dispatch;
 mod held;
 mod key_edge;
+#[cfg(any(test, feature = "input_script"))]
+mod trace;
 
 use bevy_enhanced_input::prelude::CustomInput;
 use bevy_enhanced_input::prelude::CustomInputs;
+pub(crate) use button_edge::GamepadButtons;
+pub(crate) use button_edge::mirror_gamepad_buttons;
 pub(crate) use dispatch::cancel_pending_sequences;
 pub(crate) use dispatch::reset_physical_input;
 pub(crate) use dispatch::route_input;
 pub(crate) use held::KeymapRuntime;
+#[cfg(any(test, feature = "input_script"))]
+pub(crate) use trace::RoutingTrace;
+#[cfg(any(test, feature = "input_script"))]
+pub(crate) use trace::RoutingTraceEvent;
 
 pub(super) fn set_event_source(
     keymap_runtime: &mut KeymapRuntime,
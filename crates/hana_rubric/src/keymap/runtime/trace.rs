@@ -0,0 +1,33 @@
+//! What routing did, for the input-script harness to report.
+
+use bevy::ecs::world::World;
+use bevy::prelude::Resource;
+
+use crate::CommandId;
+
+/// Routing events in the order they happened, recorded only while this resource exists.
+#[derive(Default, Resource)]
+pub(crate) struct RoutingTrace(Vec<RoutingTraceEvent>);
+
+impl RoutingTrace {
+    /// Removes and returns every event recorded since the last drain.
+    pub(crate) fn drain(&mut self) -> impl Iterator<Item = RoutingTraceEvent> + '_ {
+        self.0.drain(..)
+    }
+}
+
+/// One routing decision the harness reports.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) enum RoutingTraceEvent {
+    /// Dispatch triggered this command's event.
+    Dispatched(CommandId),
+    /// A pending sequence timed out. `resolved` is whether it completed a shorter binding that
+    /// then fired, rather than being discarded.
+    SequenceTimeout { resolved: bool },
+}
+
+pub(super) fn record(world: &mut World, routing_trace_event: RoutingTraceEvent) {
+    if let Some(mut routing_trace) = world.get_resource_mut::<RoutingTrace>() {
+        routing_trace.0.push(routing_trace_event);
+    }
+}
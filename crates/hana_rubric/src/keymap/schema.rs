@@ -1,5 +1,9 @@
 //! Runtime-generated JSON Schema for the keymap document.
 
+use bevy::reflect::NamedField;
+use bevy::reflect::TypeInfo;
+use bevy::reflect::UnnamedField;
+use bevy::reflect::enums::VariantInfo;
 use serde_json_lenient::Error;
 use serde_json_lenient::Map;
 use serde_json_lenient::Value;
@@ -65,7 +69,7 @@ fn schema_document(
     let command_alternatives = command_alternatives(command_registry);
     let condition_alternatives = condition_alternatives(condition_registry);
     let binding_value_schema = binding_value_schema(command_alternatives);
-    let context_schema = context_schema(condition_alternatives);
+    let context_schema = context_schema(condition_registry, condition_alternatives);
     let block_schema = block_schema(binding_value_schema, context_schema);
 
     serde_json_lenient::json!({
@@ -93,18 +97,109 @@ fn command_alternatives(command_registry: &CommandRegistry) -> Vec<Value> {
     command_registry
         .iter()
         .filter(|command| command.capability.is_user_bindable())
-        .map(|command| {
+        .flat_map(|command| {
             let description = command_description(command.description, command.capability);
-
-            serde_json_lenient::json!({
+            let arguments_alternative = command_registry
+                .arguments(command.id)
+                .filter(|_| command.capability != Capability::Held)
+                .map(|arguments| {
+                    serde_json_lenient::json!({
+                        "type": "array",
+                        "description": format!("{description} Arguments are decoded as `{}`.", arguments.type_path),
+                        "items": [
+                            { "type": "string", "const": command.id.as_str() },
+                            arguments_schema((arguments.type_info)())
+                        ],
+                        "minItems": 2,
+                        "maxItems": 2
+                    })
+                });
+
+            std::iter::once(serde_json_lenient::json!({
                 "type": "string",
                 "const": command.id.as_str(),
                 "description": description
-            })
+            }))
+            .chain(arguments_alternative)
         })
         .collect()
 }
 
+/// Describes a reflected binding argument type by the field names and primitive types that
+/// reflection reports for it.
+fn arguments_schema(type_info: &TypeInfo) -> Value {
+    match type_info {
+        TypeInfo::Struct(struct_info) => {
+            let mut properties = Map::new();
+            for field in struct_info.iter() {
+                properties.insert(
+                    field.name().to_owned(),
+                    field
+                        .type_info()
+                        .map_or_else(|| serde_json_lenient::json!({}), arguments_schema),
+                );
+            }
+            let required = struct_info.iter().map(NamedField::name).collect::<Vec<_>>();
+
+            serde_json_lenient::json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false
+            })
+        },
+        TypeInfo::TupleStruct(tuple_struct_info) if tuple_struct_info.field_len() == 1 => {
+            tuple_struct_info
+                .field_at(0)
+                .and_then(UnnamedField::type_info)
+                .map_or_else(|| serde_json_lenient::json!({}), arguments_schema)
+        },
+        TypeInfo::Enum(enum_info) => {
+            let unit_variants = enum_info
+                .iter()
+                .map(|variant| match variant {
+                    VariantInfo::Unit(unit_variant) => Some(unit_variant.name()),
+                    VariantInfo::Struct(_) | VariantInfo::Tuple(_) => None,
+                })
+                .collect::<Option<Vec<_>>>();
+
+            unit_variants.map_or_else(
+                || serde_json_lenient::json!({}),
+                |variants| serde_json_lenient::json!({ "type": "string", "enum": variants }),
+            )
+        },
+        TypeInfo::List(list_info) => serde_json_lenient::json!({
+            "type": "array",
+            "items": list_info.item_info().map_or_else(|| serde_json_lenient::json!({}), arguments_schema)
+        }),
+        TypeInfo::Array(array_info) => serde_json_lenient::json!({
+            "type": "array",
+            "items": array_info.item_info().map_or_else(|| serde_json_lenient::json!({}), arguments_schema),
+            "minItems": array_info.capacity(),
+            "maxItems": array_info.capacity()
+        }),
+        TypeInfo::Opaque(_) => primitive_schema(type_info.type_path()),
+        _ => serde_json_lenient::json!({}),
+    }
+}
+
+fn primitive_schema(type_path: &str) -> Value {
+    match type_path {
+        "bool" => serde_json_lenient::json!({ "type": "boolean" }),
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => {
+            serde_json_lenient::json!({ "type": "integer", "minimum": 0 })
+        },
+        "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => {
+            serde_json_lenient::json!({ "type": "integer" })
+        },
+        "f32" | "f64" => serde_json_lenient::json!({ "type": "number" }),
+        "char" | "alloc::string::String" | "alloc::borrow::Cow<str>" => {
+            serde_json_lenient::json!({ "type": "string" })
+        },
+        _ => serde_json_lenient::json!({}),
+    }
+}
+
 fn command_description(description: &str, capability: Capability) -> String {
     if capability == Capability::Held {
         format!(
@@ -138,7 +233,10 @@ fn binding_value_schema(command_alternatives: Vec<Value>) -> Value {
     serde_json_lenient::json!({ "anyOf": alternatives })
 }
 
-fn context_schema(condition_alternatives: Vec<Value>) -> Value {
+fn context_schema(
+    condition_registry: &ConditionRegistry,
+    condition_alternatives: Vec<Value>,
+) -> Value {
     if condition_alternatives.is_empty() {
         return serde_json_lenient::json!({
             "description": "No keymap contexts are registered by this application.",
@@ -146,7 +244,39 @@ fn context_schema(condition_alternatives: Vec<Value>) -> Value {
         });
     }
 
-    serde_json_lenient::json!({ "anyOf": condition_alternatives })
+    let mut alternatives = condition_alternatives;
+    alternatives.push(serde_json_lenient::json!({
+        "type": "string",
+        "description": "A boolean expression over registered contexts using &&, ||, !, and parentheses.",
+        "pattern": context_expression_pattern(condition_registry)
+    }));
+
+    serde_json_lenient::json!({ "anyOf": alternatives })
+}
+
+/// A pattern accepting registered context names joined by the expression operators.
+///
+/// Parenthesis balance is left to the keymap loader, which reports it with a located diagnostic.
+fn context_expression_pattern(condition_registry: &ConditionRegistry) -> String {
+    let names = condition_registry
+        .iter()
+        .map(|condition| regex_escape(condition.name.as_str()))
+        .collect::<Vec<_>>()
+        .join("|");
+    let operand = format!(r"[\s!(]*(?:{names})[\s)]*");
+
+    format!(r"^{operand}(?:(?:&&|\|\|){operand})*$")
+}
+
+fn regex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        if r"\^$.|?*+()[]{}".contains(character) {
+            escaped.push('\\');
+        }
+        escaped.push(character);
+    }
+    escaped
 }
 
 fn block_schema(binding_value_schema: Value, context_schema: Value) -> Value {
@@ -155,10 +285,10 @@ fn block_schema(binding_value_schema: Value, context_schema: Value) -> Value {
         "bindings".to_owned(),
         serde_json_lenient::json!({
             "type": "object",
-            "description": "Maps a keystroke sequence to a command or null tombstone.",
+            "description": "Maps a keystroke sequence to a command, a command and its arguments, or a null tombstone.",
             "propertyNames": {
                 "type": "string",
-                "description": "A Hana keystroke sequence. Modifiers before an ordinary key form a chord. A bare shift, ctrl, alt, or secondary modifier family is valid only as the sole keystroke bound to a held command."
+                "description": "A Hana keystroke sequence. Modifiers before an ordinary key, a mouse button such as mouse-back, or a gamepad button such as gamepad-south form a chord. A bare shift, ctrl, alt, or secondary modifier family is valid only as the sole keystroke bound to a held command."
             },
             "additionalProperties": binding_value_schema
         }),
@@ -187,6 +317,7 @@ mod tests {
     use bevy::reflect::TypeRegistry;
     use bevy_enhanced_input::prelude::CustomInputs;
     use jsonschema::Validator;
+    use serde::Deserialize;
     use serde_json_lenient::Value;
     use strum::AsRefStr;
     use strum::EnumIter;
@@ -197,8 +328,10 @@ mod tests {
     use crate::DiagnosticOrigin;
     use crate::HoldPhase;
     use crate::KeymapCommand;
+    use crate::KeymapCommandArguments;
     use crate::Keystroke;
     use crate::ReflectKeymapCommand;
+    use crate::ReflectKeymapCommandArguments;
     use crate::condition::ConditionRegistry;
     use crate::keymap;
     use crate::keymap::KeymapDocument;
@@ -267,9 +400,42 @@ mod tests {
         fn hold_phase(&self) -> Option<HoldPhase> { None }
     }
 
+    #[derive(Default, Event, Reflect)]
+    #[reflect(Event, KeymapCommand, KeymapCommandArguments)]
+    struct TabSelect {
+        index: u32,
+    }
+
+    #[derive(Deserialize, Reflect)]
+    struct TabSelectArguments {
+        index: u32,
+    }
+
+    impl KeymapCommand for TabSelect {
+        const ID: &'static str = "tab::select";
+        const TITLE: &'static str = "Tab Select";
+        const DESCRIPTION: &'static str = "Selects the numbered tab.";
+        const CAPABILITY: Capability = Capability::OneShot;
+
+        fn build() -> Self { Self::default() }
+
+        fn hold_phase(&self) -> Option<HoldPhase> { None }
+    }
+
+    impl KeymapCommandArguments for TabSelect {
+        type Arguments = TabSelectArguments;
+
+        fn build_with(arguments: TabSelectArguments) -> Self {
+            Self {
+                index: arguments.index,
+            }
+        }
+    }
+
     fn registries() -> Result<(CommandRegistry, ConditionRegistry), String> {
         let mut type_registry = TypeRegistry::default();
         type_registry.register::<CameraHome>();
+        type_registry.register::<TabSelect>();
         type_registry.register::<CameraMove>();
         type_registry.register::<RecoveryOpen>();
         let mut custom_inputs = CustomInputs::default();
@@ -542,6 +708,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn binding_arguments_validate_against_the_reflected_argument_type() -> Result<(), String> {
+        let (command_registry, condition_registry) = registries()?;
+        let schema = generated_schema(&command_registry, &condition_registry)?;
+        let validator = draft_seven_validator(&schema)?;
+        let with_arguments = |arguments: &str| {
+            jsonc_document(&format!(
+                r#"{{ "bindings": [{{ "bindings": {{ "ctrl-1": ["tab::select", {arguments}] }} }}] }}"#
+            ))
+        };
+
+        assert!(validator.is_valid(&with_arguments(r#"{ "index": 1 }"#)?));
+        assert!(!validator.is_valid(&with_arguments(r#"{ "index": -1 }"#)?));
+        assert!(!validator.is_valid(&with_arguments(r#"{ "position": 1 }"#)?));
+        assert!(!validator.is_valid(&jsonc_document(
+            r#"{ "bindings": [{ "bindings": { "ctrl-1": ["camera::home", {}] } }] }"#
+        )?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn context_expressions_validate_against_registered_context_names() -> Result<(), String> {
+        let (command_registry, condition_registry) = registries()?;
+        let schema = generated_schema(&command_registry, &condition_registry)?;
+        let validator = draft_seven_validator(&schema)?;
+        let with_context = |context: &str| {
+            jsonc_document(&format!(
+                r#"{{ "bindings": [{{ "context": "{context}", "bindings": {{ "g": "camera::home" }} }}] }}"#
+            ))
+        };
+
+        assert!(validator.is_valid(&with_context("!dimension_lock")?));
+        assert!(validator.is_valid(&with_context(
+            "(dimension_lock || dimension_lock) && !dimension_lock"
+        )?));
+        assert!(!validator.is_valid(&with_context("dimension_lock && unknown_context")?));
+        assert!(!validator.is_valid(&with_context("dimension_lock & dimension_lock")?));
+
+        Ok(())
+    }
+
     #[test]
     fn keystroke_schema_describes_bare_modifier_family_triggers() -> Result<(), String> {
         let (command_registry, condition_registry) = registries()?;
@@ -561,6 +769,7 @@ mod tests {
         assert!(property_names.contains("bare shift, ctrl, alt, or secondary"));
         assert!(property_names.contains("sole keystroke bound to a held command"));
         assert!(property_names.contains("ordinary key"));
+        assert!(property_names.contains("gamepad button"));
         Ok(())
     }
 
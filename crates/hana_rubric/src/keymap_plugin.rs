@@ -137,8 +137,13 @@ impl KeymapPlugin {
             .init_resource::<KeymapLoadFailures>()
             .init_resource::<keymap::KeystrokeRouting>()
             .init_resource::<keymap::KeymapBindings>()
+            .init_resource::<keymap::KeymapHistory>()
+            .init_resource::<keymap::KeymapProfiles>()
+            .init_resource::<keymap::KeymapMacros>()
             .init_resource::<PendingReload>()
             .init_resource::<KeymapRuntime>()
+            .init_resource::<keymap::GamepadButtons>()
+            .register_type::<keymap::PlayKeymapMacro>()
             .configure_sets(
                 PreUpdate,
                 (KeymapSystems::UpdateActiveCondition, KeymapSystems::Route).chain(),
@@ -151,11 +156,14 @@ impl KeymapPlugin {
             )
             .add_systems(
                 PreUpdate,
-                keymap::route_input
+                (keymap::mirror_gamepad_buttons, keymap::route_input)
+                    .chain()
                     .in_set(KeymapSystems::Route)
                     .after(InputSystems)
                     .before(EnhancedInputSystems::Update),
-            );
+            )
+            .add_observer(keymap::switch_on_request)
+            .add_observer(keymap::play_on_request);
         app.world_mut()
             .resource_mut::<ActiveCondition>()
             .enable_global();
@@ -252,6 +260,9 @@ impl KeymapPlugin {
                 None
             },
         };
+        if let Some(diagnostic) = keymap::load_startup_profiles(app.world_mut(), paths) {
+            record_startup_diagnostics(app, &[diagnostic]);
+        }
         app.world_mut().insert_resource(KeymapDiskWorker {
             disk_worker_channels: disk::start_disk_worker(
                 paths,
@@ -413,7 +424,7 @@ fn record_unavailable_keymap_paths(app: &mut App, keymap_path_failure: KeymapPat
     );
 }
 
-fn startup_diagnostic(
+pub(crate) fn startup_diagnostic(
     origin: DiagnosticOrigin,
     kind: DiagnosticKind,
     message: &str,
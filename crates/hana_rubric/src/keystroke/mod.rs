@@ -1,4 +1,4 @@
-//! Canonical keyboard keystrokes and their parser.
+//! Canonical keystrokes and their parser.
 
 mod sequence;
 mod sequence_matcher;
@@ -10,7 +10,9 @@ use std::fmt::Formatter;
 use std::str::FromStr;
 
 use bevy::input::ButtonInput;
+use bevy::input::gamepad::GamepadButton;
 use bevy::input::keyboard::KeyCode;
+use bevy::input::mouse::MouseButton;
 use bitflags::bitflags;
 pub use sequence::EmptyKeystrokeSequenceError;
 pub use sequence::KeystrokeSequence;
@@ -253,6 +255,138 @@ impl Display for InvalidOrdinaryKeyCode {
 
 impl Error for InvalidOrdinaryKeyCode {}
 
+/// A mouse button supported by Hana keymaps, spelled `mouse-back` and the like.
+///
+/// Construction rejects [`MouseButton::Other`], which has no canonical keymap spelling.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeymapMouseButton(MouseButton);
+
+impl KeymapMouseButton {
+    /// How many distinct mouse buttons a [`KeymapMouseButton`] can hold.
+    pub(crate) const COUNT: usize = CANONICAL_MOUSE_BUTTON_MAPPINGS.len();
+
+    /// Returns the validated mouse button.
+    #[must_use]
+    pub const fn mouse_button(self) -> MouseButton { self.0 }
+}
+
+impl TryFrom<MouseButton> for KeymapMouseButton {
+    type Error = InvalidMouseButton;
+
+    fn try_from(mouse_button: MouseButton) -> Result<Self, Self::Error> {
+        mouse_button_name(mouse_button).map_or_else(
+            || Err(InvalidMouseButton(mouse_button)),
+            |_| Ok(Self(mouse_button)),
+        )
+    }
+}
+
+impl Display for KeymapMouseButton {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(mouse_button_name(self.0).ok_or(fmt::Error)?)
+    }
+}
+
+impl FromStr for KeymapMouseButton {
+    type Err = KeystrokeParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_mouse_button(input).ok_or_else(|| KeystrokeParseError::new(input, 0))
+    }
+}
+
+/// A mouse button that cannot serve as a Hana keymap trigger.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidMouseButton(MouseButton);
+
+impl InvalidMouseButton {
+    /// Returns the rejected mouse button.
+    #[must_use]
+    pub const fn mouse_button(self) -> MouseButton { self.0 }
+}
+
+impl Display for InvalidMouseButton {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "mouse button {:?} is not a supported Hana keymap trigger",
+            self.0
+        )
+    }
+}
+
+impl Error for InvalidMouseButton {}
+
+/// A gamepad button supported by Hana keymaps, spelled `gamepad-south` and the like.
+///
+/// Any connected gamepad can press it. Construction rejects [`GamepadButton::Other`], which has no
+/// canonical keymap spelling.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeymapGamepadButton(GamepadButton);
+
+impl KeymapGamepadButton {
+    /// How many distinct gamepad buttons a [`KeymapGamepadButton`] can hold.
+    pub(crate) const COUNT: usize = CANONICAL_GAMEPAD_BUTTON_MAPPINGS.len();
+
+    /// Returns the validated gamepad button.
+    #[must_use]
+    pub const fn gamepad_button(self) -> GamepadButton { self.0 }
+
+    /// Every gamepad button a keymap can name.
+    pub(crate) fn all() -> impl Iterator<Item = Self> {
+        CANONICAL_GAMEPAD_BUTTON_MAPPINGS
+            .iter()
+            .map(|(_, gamepad_button)| Self(*gamepad_button))
+    }
+}
+
+impl TryFrom<GamepadButton> for KeymapGamepadButton {
+    type Error = InvalidGamepadButton;
+
+    fn try_from(gamepad_button: GamepadButton) -> Result<Self, Self::Error> {
+        gamepad_button_name(gamepad_button).map_or_else(
+            || Err(InvalidGamepadButton(gamepad_button)),
+            |_| Ok(Self(gamepad_button)),
+        )
+    }
+}
+
+impl Display for KeymapGamepadButton {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(gamepad_button_name(self.0).ok_or(fmt::Error)?)
+    }
+}
+
+impl FromStr for KeymapGamepadButton {
+    type Err = KeystrokeParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_gamepad_button(input).ok_or_else(|| KeystrokeParseError::new(input, 0))
+    }
+}
+
+/// A gamepad button that cannot serve as a Hana keymap trigger.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidGamepadButton(GamepadButton);
+
+impl InvalidGamepadButton {
+    /// Returns the rejected gamepad button.
+    #[must_use]
+    pub const fn gamepad_button(self) -> GamepadButton { self.0 }
+}
+
+impl Display for InvalidGamepadButton {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "gamepad button {:?} is not a supported Hana keymap trigger",
+            self.0
+        )
+    }
+}
+
+impl Error for InvalidGamepadButton {}
+
 /// The semantic trigger that completes a keystroke after its modifier set.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum PrimaryTrigger {
@@ -260,6 +394,10 @@ pub enum PrimaryTrigger {
     OrdinaryKey(OrdinaryKey),
     /// A bare modifier family with no other modifiers.
     ModifierFamily(ModifierFamily),
+    /// A mouse button with zero or more canonical modifiers.
+    MouseButton(KeymapMouseButton),
+    /// A gamepad button with zero or more canonical modifiers.
+    GamepadButton(KeymapGamepadButton),
 }
 
 impl Display for PrimaryTrigger {
@@ -267,6 +405,8 @@ impl Display for PrimaryTrigger {
         match self {
             Self::OrdinaryKey(ordinary_key) => ordinary_key.fmt(formatter),
             Self::ModifierFamily(modifier_family) => modifier_family.fmt(formatter),
+            Self::MouseButton(mouse_button) => mouse_button.fmt(formatter),
+            Self::GamepadButton(gamepad_button) => gamepad_button.fmt(formatter),
         }
     }
 }
@@ -278,6 +418,9 @@ impl FromStr for PrimaryTrigger {
         if let Some(modifier_family) = parse_modifier_family_primary(input) {
             return Ok(Self::ModifierFamily(modifier_family));
         }
+        if let Some(primary_trigger) = parse_button_primary(input) {
+            return Ok(primary_trigger);
+        }
 
         parse_ordinary_key(input)
             .map(Self::OrdinaryKey)
@@ -285,12 +428,13 @@ impl FromStr for PrimaryTrigger {
     }
 }
 
-/// A keyboard primary trigger and its canonical modifier set.
+/// A primary trigger and its canonical modifier set.
 ///
 /// Parse a [`Keystroke`] from text such as `"platform-shift-p"`. The parser canonicalizes
 /// modifier aliases and source ordering before the value is constructed. A bare `shift`, `ctrl`,
 /// `alt`, or `secondary` names a [`PrimaryTrigger::ModifierFamily`]; `shift-f` instead has an
-/// [`PrimaryTrigger::OrdinaryKey`] with Shift in its modifier set.
+/// [`PrimaryTrigger::OrdinaryKey`] with Shift in its modifier set. Mouse and gamepad buttons take
+/// keyboard modifiers the same way, as in `ctrl-mouse-middle`.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Keystroke {
     modifiers:       Modifiers,
@@ -307,6 +451,27 @@ impl Keystroke {
         }
     }
 
+    /// Creates a keystroke with a mouse button as its primary trigger.
+    #[must_use]
+    pub const fn from_mouse_button(modifiers: Modifiers, mouse_button: KeymapMouseButton) -> Self {
+        Self {
+            modifiers,
+            primary_trigger: PrimaryTrigger::MouseButton(mouse_button),
+        }
+    }
+
+    /// Creates a keystroke with a gamepad button as its primary trigger.
+    #[must_use]
+    pub const fn from_gamepad_button(
+        modifiers: Modifiers,
+        gamepad_button: KeymapGamepadButton,
+    ) -> Self {
+        Self {
+            modifiers,
+            primary_trigger: PrimaryTrigger::GamepadButton(gamepad_button),
+        }
+    }
+
     /// Creates a keystroke with a bare modifier family as its primary trigger.
     #[must_use]
     pub const fn from_modifier_family(modifier_family: ModifierFamily) -> Self {
@@ -356,6 +521,17 @@ impl FromStr for Keystroke {
         let mut remaining = input;
 
         loop {
+            if let Some(primary_trigger) = parse_button_primary(remaining) {
+                return Ok(Self {
+                    modifiers,
+                    primary_trigger,
+                });
+            }
+            let token_offset = input.len() - remaining.len();
+            if is_button_spelling(remaining) {
+                return Err(KeystrokeParseError::new(remaining, token_offset));
+            }
+
             let Some((token, after_token)) = remaining.split_once('-') else {
                 let offset = input.len() - remaining.len();
                 let Some(ordinary_key) = parse_ordinary_key(remaining) else {
@@ -366,7 +542,6 @@ impl FromStr for Keystroke {
             if after_token.is_empty() {
                 return Err(KeystrokeParseError::new("", input.len()));
             }
-            let token_offset = input.len() - remaining.len();
             let Some(modifier) = parse_modifier(token) else {
                 return Err(KeystrokeParseError::new(token, token_offset));
             };
@@ -587,6 +762,76 @@ define_canonical_key_mappings! {
     ("slash", KeyCode::Slash),
 }
 
+const CANONICAL_MOUSE_BUTTON_MAPPINGS: &[(&str, MouseButton)] = &[
+    ("mouse-left", MouseButton::Left),
+    ("mouse-right", MouseButton::Right),
+    ("mouse-middle", MouseButton::Middle),
+    ("mouse-back", MouseButton::Back),
+    ("mouse-forward", MouseButton::Forward),
+];
+
+const CANONICAL_GAMEPAD_BUTTON_MAPPINGS: &[(&str, GamepadButton)] = &[
+    ("gamepad-south", GamepadButton::South),
+    ("gamepad-east", GamepadButton::East),
+    ("gamepad-north", GamepadButton::North),
+    ("gamepad-west", GamepadButton::West),
+    ("gamepad-c", GamepadButton::C),
+    ("gamepad-z", GamepadButton::Z),
+    ("gamepad-left-bumper", GamepadButton::LeftTrigger),
+    ("gamepad-left-trigger", GamepadButton::LeftTrigger2),
+    ("gamepad-right-bumper", GamepadButton::RightTrigger),
+    ("gamepad-right-trigger", GamepadButton::RightTrigger2),
+    ("gamepad-select", GamepadButton::Select),
+    ("gamepad-start", GamepadButton::Start),
+    ("gamepad-mode", GamepadButton::Mode),
+    ("gamepad-left-stick", GamepadButton::LeftThumb),
+    ("gamepad-right-stick", GamepadButton::RightThumb),
+    ("gamepad-dpad-up", GamepadButton::DPadUp),
+    ("gamepad-dpad-down", GamepadButton::DPadDown),
+    ("gamepad-dpad-left", GamepadButton::DPadLeft),
+    ("gamepad-dpad-right", GamepadButton::DPadRight),
+];
+
+fn mouse_button_name(mouse_button: MouseButton) -> Option<&'static str> {
+    CANONICAL_MOUSE_BUTTON_MAPPINGS
+        .iter()
+        .find(|(_, candidate)| *candidate == mouse_button)
+        .map(|(name, _)| *name)
+}
+
+fn gamepad_button_name(gamepad_button: GamepadButton) -> Option<&'static str> {
+    CANONICAL_GAMEPAD_BUTTON_MAPPINGS
+        .iter()
+        .find(|(_, candidate)| *candidate == gamepad_button)
+        .map(|(name, _)| *name)
+}
+
+fn parse_mouse_button(token: &str) -> Option<KeymapMouseButton> {
+    CANONICAL_MOUSE_BUTTON_MAPPINGS
+        .iter()
+        .find(|(name, _)| *name == token)
+        .map(|(_, mouse_button)| KeymapMouseButton(*mouse_button))
+}
+
+fn parse_gamepad_button(token: &str) -> Option<KeymapGamepadButton> {
+    CANONICAL_GAMEPAD_BUTTON_MAPPINGS
+        .iter()
+        .find(|(name, _)| *name == token)
+        .map(|(_, gamepad_button)| KeymapGamepadButton(*gamepad_button))
+}
+
+fn parse_button_primary(token: &str) -> Option<PrimaryTrigger> {
+    parse_mouse_button(token)
+        .map(PrimaryTrigger::MouseButton)
+        .or_else(|| parse_gamepad_button(token).map(PrimaryTrigger::GamepadButton))
+}
+
+/// Whether `token` is written as a mouse or gamepad button, so a misspelled button reports the
+/// whole name rather than its `mouse` or `gamepad` prefix as an unknown modifier.
+fn is_button_spelling(token: &str) -> bool {
+    token.starts_with("mouse-") || token.starts_with("gamepad-")
+}
+
 fn parse_ordinary_key(token: &str) -> Option<OrdinaryKey> {
     if let Some((_, key)) = CANONICAL_KEY_MAPPINGS
         .iter()
@@ -631,10 +876,18 @@ mod tests {
     use std::error::Error;
 
     use bevy::input::ButtonInput;
+    use bevy::input::gamepad::GamepadButton;
     use bevy::input::keyboard::KeyCode;
+    use bevy::input::mouse::MouseButton;
 
+    use super::CANONICAL_GAMEPAD_BUTTON_MAPPINGS;
     use super::CANONICAL_KEY_MAPPINGS;
+    use super::CANONICAL_MOUSE_BUTTON_MAPPINGS;
+    use super::InvalidGamepadButton;
+    use super::InvalidMouseButton;
     use super::InvalidOrdinaryKeyCode;
+    use super::KeymapGamepadButton;
+    use super::KeymapMouseButton;
     use super::Keystroke;
     use super::KeystrokeParseError;
     use super::ModifierFamily;
@@ -1013,6 +1266,87 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn mouse_and_gamepad_buttons_parse_with_keyboard_modifiers() -> Result<(), Box<dyn Error>> {
+        let ctrl_middle = parsed("ctrl-mouse-middle")?;
+
+        assert_eq!(
+            ctrl_middle,
+            Keystroke::from_mouse_button(
+                Modifiers::none().with_control(),
+                KeymapMouseButton::try_from(MouseButton::Middle)?
+            )
+        );
+        assert_eq!(ctrl_middle, parsed("control-left-mouse-middle")?);
+        assert_eq!(
+            parsed("gamepad-south")?,
+            Keystroke::from_gamepad_button(
+                Modifiers::none(),
+                KeymapGamepadButton::try_from(GamepadButton::South)?
+            )
+        );
+        assert_eq!(
+            parsed("shift-gamepad-dpad-left")?.primary_trigger(),
+            PrimaryTrigger::GamepadButton(KeymapGamepadButton::try_from(GamepadButton::DPadLeft)?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn every_mouse_and_gamepad_button_round_trips() -> Result<(), Box<dyn Error>> {
+        for &(name, mouse_button) in CANONICAL_MOUSE_BUTTON_MAPPINGS {
+            let keystroke = parsed(name)?;
+
+            assert_eq!(
+                keystroke.primary_trigger(),
+                PrimaryTrigger::MouseButton(KeymapMouseButton::try_from(mouse_button)?)
+            );
+            assert_eq!(keystroke.to_string(), name);
+            assert_eq!(
+                parsed(&format!("alt-{name}"))?.to_string(),
+                format!("alt-{name}")
+            );
+        }
+        for &(name, gamepad_button) in CANONICAL_GAMEPAD_BUTTON_MAPPINGS {
+            let keystroke = parsed(name)?;
+
+            assert_eq!(
+                keystroke.primary_trigger(),
+                PrimaryTrigger::GamepadButton(KeymapGamepadButton::try_from(gamepad_button)?)
+            );
+            assert_eq!(keystroke.to_string(), name);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn unnamed_buttons_cannot_be_constructed() {
+        assert_eq!(
+            KeymapMouseButton::try_from(MouseButton::Other(9)).map(KeymapMouseButton::mouse_button),
+            Err(InvalidMouseButton(MouseButton::Other(9)))
+        );
+        assert_eq!(
+            KeymapGamepadButton::try_from(GamepadButton::Other(9))
+                .map(KeymapGamepadButton::gamepad_button),
+            Err(InvalidGamepadButton(GamepadButton::Other(9)))
+        );
+    }
+
+    #[test]
+    fn misspelled_buttons_report_the_whole_button_name() -> Result<(), KeystrokeParseError> {
+        let unknown_mouse_button = parse_error("ctrl-mouse-sideways")?;
+        assert_eq!(unknown_mouse_button.token(), "mouse-sideways");
+        assert_eq!(unknown_mouse_button.offset(), 5);
+
+        let unknown_gamepad_button = parse_error("gamepad-triangle")?;
+        assert_eq!(unknown_gamepad_button.token(), "gamepad-triangle");
+        assert_eq!(unknown_gamepad_button.offset(), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn key_p_constant_matches_the_fallible_conversion() -> Result<(), Box<dyn Error>> {
         assert_eq!(OrdinaryKey::KEY_P, OrdinaryKey::try_from(KeyCode::KeyP)?);
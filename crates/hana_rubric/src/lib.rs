@@ -10,6 +10,8 @@ mod condition;
 mod derived_context;
 mod diagnostic;
 mod disk;
+#[cfg(any(test, feature = "input_script"))]
+mod input_script;
 mod keymap;
 mod keymap_plugin;
 mod keystroke;
@@ -31,6 +33,205 @@ pub fn cancel_pending_sequences(world: &mut World) { keymap::cancel_pending_sequ
 /// from the keys still down.
 pub fn reset_physical_input(world: &mut World) { keymap::reset_physical_input(world); }
 
+/// Writes `transaction` over the user keymap file and records it for undo.
+///
+/// The edited keymap is validated against the registered commands and conditions before anything
+/// is written, and the file is replaced in one rename, so every error leaves both the file and the
+/// live keymap as they were. Comments, ordering, and every binding the transaction does not touch
+/// keep their authored bytes. The disk watcher reloads the written file like any other save.
+///
+/// # Errors
+///
+/// Returns [`KeymapEditError::Stale`] when the file no longer has the transaction's base revision,
+/// and the other [`KeymapEditError`] variants when the edit cannot be applied, validated, or
+/// written.
+pub fn apply_keymap_transaction(
+    world: &mut World,
+    transaction: &KeymapTransaction,
+) -> Result<KeymapRevision, KeymapEditError> {
+    keymap::apply_transaction(world, transaction)
+}
+
+/// Reports the conflicts the keymap would have with `transaction` applied, without writing it.
+///
+/// The report is the one [`keymap_conflicts`] returns for the live keymap, taken over the edited
+/// user keymap instead, so an editor can warn about a rebinding before the user saves it.
+///
+/// # Errors
+///
+/// Returns [`KeymapEditError::Rejected`] with the failures the edit would introduce, and the same
+/// errors as [`apply_keymap_transaction`] when the edit cannot be applied.
+pub fn preview_keymap_transaction(
+    world: &World,
+    transaction: &KeymapTransaction,
+) -> Result<Vec<Diagnostic>, KeymapEditError> {
+    keymap::preview_transaction(world, transaction)
+}
+
+/// Restores the user keymap file from before the latest applied transaction.
+///
+/// # Errors
+///
+/// Returns [`KeymapEditError::NothingToUndo`] when no transaction is left, and
+/// [`KeymapEditError::Stale`] when the file changed since the transaction was written.
+pub fn undo_keymap_transaction(world: &mut World) -> Result<KeymapRevision, KeymapEditError> {
+    keymap::undo_transaction(world)
+}
+
+/// Reapplies the latest undone transaction to the user keymap file.
+///
+/// # Errors
+///
+/// Returns [`KeymapEditError::NothingToRedo`] when no undone transaction is left, and
+/// [`KeymapEditError::Stale`] when the file changed since the transaction was undone.
+pub fn redo_keymap_transaction(world: &mut World) -> Result<KeymapRevision, KeymapEditError> {
+    keymap::redo_transaction(world)
+}
+
+/// Explains which authored binding `keystroke_sequence` dispatches under the active condition.
+///
+/// Every binding and unbind the shipped defaults and the user keymap author for the sequence is
+/// listed with its source location, its context, and whether it fires, is shadowed, tombstoned,
+/// inactive, rejected, or unbound. The sources are the ones the live keymap was compiled from, so
+/// a newer save that failed to load does not change the answer. They are indexed once per commit,
+/// so repeated explanations do not re-read them.
+#[must_use]
+pub fn explain_keystroke_sequence(
+    world: &World,
+    keystroke_sequence: &KeystrokeSequence,
+) -> BindingExplanation {
+    keymap::explain_keystroke_sequence(world, keystroke_sequence)
+}
+
+/// Explains which authored binding `keystroke_sequence` dispatches while `context` is the active
+/// condition, whatever the application's context currently is.
+///
+/// `None` explains global routing, as when no context source is registered. A context whose
+/// condition is not registered routes nothing, so every candidate that would fire or unbind is
+/// reported as [`BindingOutcome::Inactive`].
+#[must_use]
+pub fn explain_keystroke_sequence_in_context<C: KeymapContext>(
+    world: &World,
+    keystroke_sequence: &KeystrokeSequence,
+    context: Option<C>,
+) -> BindingExplanation {
+    keymap::explain_keystroke_sequence_in_context(world, keystroke_sequence, context)
+}
+
+/// Analyzes the live keymap for bindings that load cleanly but conflict with one another.
+///
+/// Every entry is an advisory [`Diagnostic`] located at the binding it describes:
+/// [`DiagnosticKind::DuplicateBinding`] for equally specific blocks that bind one sequence,
+/// [`DiagnosticKind::PrefixConflict`] for a binding that waits for the sequence timeout because a
+/// longer sequence starts with it, [`DiagnosticKind::ShadowedDefault`] for a user binding that
+/// replaces a shipped default's command, and [`DiagnosticKind::UnreachableBinding`] for a binding
+/// every context overrides.
+#[must_use]
+pub fn keymap_conflicts(world: &World) -> Vec<Diagnostic> { keymap::keymap_conflicts(world) }
+
+/// Makes `profile` the active keymap profile, committing its bindings immediately.
+///
+/// The profile is validated against the registered commands and conditions first, and a profile
+/// that reports any failure is refused, leaving the active profile and the live keymap in place.
+/// Edits made to the outgoing profile are stored with it before the switch.
+///
+/// # Errors
+///
+/// Returns [`KeymapProfileError::NotFound`] when no stored profile has the name, and
+/// [`KeymapProfileError::Rejected`] with the profile's failures when it does not validate.
+pub fn switch_keymap_profile(
+    world: &mut World,
+    profile: &KeymapProfileName,
+) -> Result<(), KeymapProfileError> {
+    keymap::switch_profile(world, profile)
+}
+
+/// Stores the JSONC keymap file at `source` as a new, inactive keymap profile.
+///
+/// # Errors
+///
+/// Returns [`KeymapProfileError::AlreadyExists`] rather than overwriting a stored profile, and
+/// [`KeymapProfileError::Rejected`] with the file's failures when it does not validate.
+pub fn import_keymap_profile(
+    world: &mut World,
+    source: &Path,
+    profile: &KeymapProfileName,
+) -> Result<(), KeymapProfileError> {
+    keymap::import_profile(world, source, profile)
+}
+
+/// Writes a keymap profile to `destination` as a single JSONC file another installation can
+/// import.
+///
+/// # Errors
+///
+/// Returns [`KeymapProfileError::NotFound`] when no stored profile has the name, and
+/// [`KeymapProfileError::Disk`] when the profile cannot be read or the file cannot be written.
+pub fn export_keymap_profile(
+    world: &World,
+    profile: &KeymapProfileName,
+    destination: &Path,
+) -> Result<(), KeymapProfileError> {
+    keymap::export_profile(world, profile, destination)
+}
+
+/// Starts recording the commands keymap dispatch fires, for
+/// [`finish_keymap_macro_recording`] to store as a macro.
+///
+/// # Errors
+///
+/// Returns [`KeymapMacroError::AlreadyRecording`] when a recording is already in progress.
+pub fn start_keymap_macro_recording(world: &mut World) -> Result<(), KeymapMacroError> {
+    keymap::start_recording(world)
+}
+
+/// Discards the macro recording in progress.
+///
+/// # Errors
+///
+/// Returns [`KeymapMacroError::NotRecording`] when no recording is in progress.
+pub fn cancel_keymap_macro_recording(world: &mut World) -> Result<(), KeymapMacroError> {
+    keymap::cancel_recording(world)
+}
+
+/// Stores the recording in progress as the macro `name` and binds `keystroke` to play it under
+/// `context` in the user keymap.
+///
+/// The binding is written as a keymap transaction, so it validates, lands in undo history, and
+/// reloads like any other edit. A refused binding leaves the stored macros as they were and the
+/// recording in progress.
+///
+/// # Errors
+///
+/// Returns [`KeymapMacroError::NotRecording`] or [`KeymapMacroError::EmptyRecording`] when there
+/// is nothing to store, and [`KeymapMacroError::Edit`] when the binding cannot be applied.
+pub fn finish_keymap_macro_recording(
+    world: &mut World,
+    name: &KeymapMacroName,
+    context: Option<String>,
+    keystroke: KeystrokeSequence,
+) -> Result<KeymapRevision, KeymapMacroError> {
+    keymap::finish_recording(world, name, context, keystroke)
+}
+
+/// Replays the macro `name` through the command registry and reports what each step did.
+///
+/// Every step is checked before the first one runs, so a macro naming a command that is unknown,
+/// held, or unavailable in the current context runs nothing.
+///
+/// # Errors
+///
+/// Returns [`KeymapMacroError::NotFound`] when no stored macro has the name, and
+/// [`KeymapMacroError::Malformed`] when the macros file cannot be read.
+pub fn play_keymap_macro(
+    world: &mut World,
+    name: &KeymapMacroName,
+) -> Result<KeymapMacroReplay, KeymapMacroError> {
+    keymap::play_macro(world, name)
+}
+
+use std::path::Path;
+
 #[cfg(test)]
 pub(crate) use allocation_test_support::TEST_ALLOCATOR;
 use bevy::prelude::World;
@@ -45,7 +246,9 @@ pub use command::HeldCommandLookupOutcome;
 pub use command::HoldPhase;
 pub use command::Keybindings;
 pub use command::KeymapCommand;
+pub use command::KeymapCommandArguments;
 pub use command::ReflectKeymapCommand;
+pub use command::ReflectKeymapCommandArguments;
 pub use condition::ActiveCondition;
 pub use condition::ActiveConditionState;
 pub use condition::ConditionHandle;
@@ -61,18 +264,54 @@ pub use disk::KeymapConfigurationDirectory;
 pub use disk::KeymapPathAvailability;
 pub use disk::KeymapPathFailure;
 pub use disk::KeymapPaths;
+pub use disk::KeymapProfileName;
+pub use disk::KeymapProfileNameError;
+#[cfg(any(test, feature = "input_script"))]
+pub use input_script::InputScriptError;
+#[cfg(any(test, feature = "input_script"))]
+pub use input_script::InputScriptEvent;
+#[cfg(any(test, feature = "input_script"))]
+pub use input_script::InputScriptHarness;
+#[cfg(any(test, feature = "input_script"))]
+pub use input_script::InputScriptReport;
+pub use keymap::BindingCandidate;
+pub use keymap::BindingExplanation;
+pub use keymap::BindingLayer;
+pub use keymap::BindingOutcome;
 pub use keymap::CommandKeystroke;
 pub use keymap::KeyboardClaim;
 pub use keymap::KeyboardOwner;
 pub use keymap::KeyboardRelease;
 pub use keymap::KeymapBindings;
+pub use keymap::KeymapEdit;
+pub use keymap::KeymapEditError;
+pub use keymap::KeymapHistory;
+pub use keymap::KeymapMacroError;
+pub use keymap::KeymapMacroName;
+pub use keymap::KeymapMacroNameError;
+pub use keymap::KeymapMacroReplay;
+pub use keymap::KeymapMacroStep;
+pub use keymap::KeymapMacroStepOutcome;
+pub use keymap::KeymapMacroStepReport;
+pub use keymap::KeymapMacros;
+pub use keymap::KeymapProfileError;
+pub use keymap::KeymapProfiles;
+pub use keymap::KeymapRevision;
+pub use keymap::KeymapTransaction;
 pub use keymap::KeystrokeRouting;
+pub use keymap::PlayKeymapMacro;
+pub use keymap::PlayKeymapMacroArguments;
+pub use keymap::SwitchKeymapProfile;
 pub use keymap_plugin::DefaultKeymapSource;
 pub use keymap_plugin::KeymapPlugin;
 pub use keymap_plugin::KeymapSystems;
 pub use keystroke::DeferredMatch;
 pub use keystroke::EmptyKeystrokeSequenceError;
+pub use keystroke::InvalidGamepadButton;
+pub use keystroke::InvalidMouseButton;
 pub use keystroke::InvalidOrdinaryKeyCode;
+pub use keystroke::KeymapGamepadButton;
+pub use keystroke::KeymapMouseButton;
 pub use keystroke::Keystroke;
 pub use keystroke::KeystrokeParseError;
 pub use keystroke::KeystrokeSequence;
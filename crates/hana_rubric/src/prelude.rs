@@ -1,5 +1,9 @@
 pub use super::ActiveCondition;
 pub use super::ActiveConditionState;
+pub use super::BindingCandidate;
+pub use super::BindingExplanation;
+pub use super::BindingLayer;
+pub use super::BindingOutcome;
 pub use super::Capability;
 pub use super::CommandId;
 pub use super::CommandInfo;
@@ -18,17 +22,47 @@ pub use super::DiagnosticSeverity;
 pub use super::EmptyKeystrokeSequenceError;
 pub use super::HeldCommandLookupOutcome;
 pub use super::HoldPhase;
+#[cfg(any(test, feature = "input_script"))]
+pub use super::InputScriptError;
+#[cfg(any(test, feature = "input_script"))]
+pub use super::InputScriptEvent;
+#[cfg(any(test, feature = "input_script"))]
+pub use super::InputScriptHarness;
+#[cfg(any(test, feature = "input_script"))]
+pub use super::InputScriptReport;
+pub use super::InvalidGamepadButton;
+pub use super::InvalidMouseButton;
 pub use super::InvalidOrdinaryKeyCode;
 pub use super::Keybindings;
 pub use super::KeymapCommand;
+pub use super::KeymapCommandArguments;
 pub use super::KeymapConfigurationDirectory;
 pub use super::KeymapContext;
+pub use super::KeymapEdit;
+pub use super::KeymapEditError;
+pub use super::KeymapGamepadButton;
+pub use super::KeymapHistory;
 pub use super::KeymapLoadFailures;
+pub use super::KeymapMacroError;
+pub use super::KeymapMacroName;
+pub use super::KeymapMacroNameError;
+pub use super::KeymapMacroReplay;
+pub use super::KeymapMacroStep;
+pub use super::KeymapMacroStepOutcome;
+pub use super::KeymapMacroStepReport;
+pub use super::KeymapMacros;
+pub use super::KeymapMouseButton;
 pub use super::KeymapPathAvailability;
 pub use super::KeymapPathFailure;
 pub use super::KeymapPaths;
 pub use super::KeymapPlugin;
+pub use super::KeymapProfileError;
+pub use super::KeymapProfileName;
+pub use super::KeymapProfileNameError;
+pub use super::KeymapProfiles;
+pub use super::KeymapRevision;
 pub use super::KeymapSystems;
+pub use super::KeymapTransaction;
 pub use super::Keystroke;
 pub use super::KeystrokeParseError;
 pub use super::KeystrokeSequence;
@@ -37,9 +71,27 @@ pub use super::MatchOutcome;
 pub use super::ModifierFamily;
 pub use super::Modifiers;
 pub use super::OrdinaryKey;
+pub use super::PlayKeymapMacro;
+pub use super::PlayKeymapMacroArguments;
 pub use super::PrimaryTrigger;
 pub use super::ReflectKeymapCommand;
+pub use super::ReflectKeymapCommandArguments;
 pub use super::SequenceMatcher;
+pub use super::SwitchKeymapProfile;
 pub use super::TimeoutOutcome;
+pub use super::apply_keymap_transaction;
+pub use super::cancel_keymap_macro_recording;
 pub use super::cancel_pending_sequences;
+pub use super::explain_keystroke_sequence;
+pub use super::explain_keystroke_sequence_in_context;
+pub use super::export_keymap_profile;
+pub use super::finish_keymap_macro_recording;
+pub use super::import_keymap_profile;
+pub use super::keymap_conflicts;
+pub use super::play_keymap_macro;
+pub use super::preview_keymap_transaction;
+pub use super::redo_keymap_transaction;
 pub use super::reset_physical_input;
+pub use super::start_keymap_macro_recording;
+pub use super::switch_keymap_profile;
+pub use super::undo_keymap_transaction;